@@ -0,0 +1,114 @@
+//! This module implements the AES-128 block cipher in counter mode, using booleans or FheBool
+//! for the representation of the inner bits.
+
+use crate::aes::bitsliced::{encrypt_block, expand_key, AesBitOps, Block};
+use crate::aes::with_server_key;
+use std::array;
+use tfhe::{FheBool, ServerKey};
+
+/// Internal trait specifying which operations are necessary for AesStream generic type
+pub trait AesBoolInput<OpOutput>:
+    Sized
+    + Clone
+    + std::ops::BitXor<Output = OpOutput>
+    + std::ops::BitAnd<Output = OpOutput>
+    + std::ops::Not<Output = OpOutput>
+{
+}
+impl AesBoolInput<bool> for bool {}
+impl AesBoolInput<bool> for &bool {}
+impl AesBoolInput<FheBool> for FheBool {}
+impl AesBoolInput<FheBool> for &FheBool {}
+
+/// Gates of the bitsliced AES for the types implementing AesBoolInput
+struct BoolOps;
+
+impl<T> AesBitOps<T> for BoolOps
+where
+    for<'a> &'a T: AesBoolInput<T>,
+{
+    fn xor(&self, a: &T, b: &T) -> T {
+        a ^ b
+    }
+
+    fn and(&self, a: &T, b: &T) -> T {
+        a & b
+    }
+
+    fn not(&self, a: &T) -> T {
+        !a
+    }
+}
+
+/// AesStream: a struct implementing the AES-128 block cipher in counter mode, using T for the
+/// internal representation of bits (bool or FheBool). The key schedule is run once at
+/// construction, and the counter is public. To be able to compute FHE operations, it also owns
+/// an Option for a ServerKey.
+pub struct AesStream<T> {
+    round_keys: Vec<Block<T>>,
+    counter: u128,
+    buffer: Vec<T>,
+    fhe_key: Option<ServerKey>,
+}
+
+impl AesStream<bool> {
+    /// Constructor for `AesStream<bool>`: arguments are the secret key (the bits of each byte
+    /// being least significant bit first) and the initial counter block.
+    pub fn new(key: [bool; 128], iv: [u8; 16]) -> AesStream<bool> {
+        AesStream::<bool>::new_from_key(key, iv, None)
+    }
+}
+
+impl AesStream<FheBool> {
+    /// Constructor for `AesStream<FheBool>`: arguments are the encrypted secret key (the bits of
+    /// each byte being least significant bit first), the initial counter block, and the FHE
+    /// server key.
+    pub fn new(key: [FheBool; 128], iv: [u8; 16], sk: &ServerKey) -> AesStream<FheBool> {
+        AesStream::<FheBool>::new_from_key(key, iv, Some(sk.clone()))
+    }
+
+    pub fn get_server_key(&self) -> &ServerKey {
+        self.fhe_key.as_ref().unwrap()
+    }
+}
+
+impl<T> AesStream<T>
+where
+    T: AesBoolInput<T> + Send + Sync,
+    for<'a> &'a T: AesBoolInput<T>,
+{
+    /// Internal generic constructor: runs the key schedule, with an optional FHE server key
+    fn new_from_key(key: [T; 128], iv: [u8; 16], fhe_key: Option<ServerKey>) -> Self {
+        let key: Block<T> = array::from_fn(|i| array::from_fn(|j| key[8 * i + j].clone()));
+        let round_keys = with_server_key(fhe_key.as_ref(), || expand_key(&BoolOps, &key));
+
+        Self {
+            round_keys,
+            counter: u128::from_be_bytes(iv),
+            buffer: Vec::with_capacity(128),
+            fhe_key,
+        }
+    }
+
+    /// Encrypts the current counter block and increments the counter, outputting the 128 bits of
+    /// the key stream block
+    fn next_block(&mut self) -> Vec<T> {
+        let counter = self.counter.to_be_bytes();
+        self.counter = self.counter.wrapping_add(1);
+
+        let block = with_server_key(self.fhe_key.as_ref(), || {
+            encrypt_block(&BoolOps, &self.round_keys, counter)
+        });
+        block.into_iter().flatten().collect()
+    }
+
+    /// Computes 64 bits of the key stream, outputting them all at once in a Vec (bytes are in
+    /// stream order, each byte being least significant bit first). A new block is encrypted
+    /// every other call.
+    pub fn next_64(&mut self) -> Vec<T> {
+        if self.buffer.is_empty() {
+            self.buffer = self.next_block();
+        }
+        self.buffer.drain(..64).collect()
+    }
+}
@@ -0,0 +1,217 @@
+//! This module implements the AES-128 block cipher in counter mode, using u8 or FheUint8
+//! for the representation of the inner bytes.
+
+use crate::aes::tables::{NUM_ROUNDS, RCON, SBOX};
+use crate::aes::{collect_array, shift_rows, with_server_key};
+use rayon::prelude::*;
+use std::array;
+use std::sync::OnceLock;
+use tfhe::{FheBool, FheUint8, MatchValues, ServerKey};
+
+/// Internal trait specifying which operations are necessary for AesStreamByte generic type
+pub trait AesByteInput<OpOutput>:
+    Sized
+    + Clone
+    + Send
+    + Sync
+    + std::ops::BitXor<Output = OpOutput>
+    + std::ops::BitXor<u8, Output = OpOutput>
+    + std::ops::Shr<u8, Output = OpOutput>
+    + std::ops::Shl<u8, Output = OpOutput>
+    + std::ops::Mul<u8, Output = OpOutput>
+{
+}
+impl AesByteInput<u8> for u8 {}
+impl AesByteInput<u8> for &u8 {}
+impl AesByteInput<FheUint8> for FheUint8 {}
+impl AesByteInput<FheUint8> for &FheUint8 {}
+
+/// Internal trait specifying how the AES S-box is evaluated on AesStreamByte generic type
+pub trait AesSubByte {
+    fn sub_byte(&self) -> Self;
+}
+
+impl AesSubByte for u8 {
+    fn sub_byte(&self) -> Self {
+        SBOX[*self as usize]
+    }
+}
+
+impl AesSubByte for FheUint8 {
+    /// The S-box is evaluated as a lookup table, using `match_value`
+    fn sub_byte(&self) -> Self {
+        static SBOX_MATCH_VALUES: OnceLock<MatchValues<u16>> = OnceLock::new();
+        let match_values = SBOX_MATCH_VALUES
+            .get_or_init(|| MatchValues::from_fn_and_range(|x| SBOX[x as usize] as u16, 0..256));
+
+        let (ret, _): (FheUint8, FheBool) = self
+            .match_value(match_values)
+            .expect("S-box outputs fit in a byte");
+        ret
+    }
+}
+
+/// AesStreamByte: a struct implementing the AES-128 block cipher in counter mode, using T for the
+/// internal representation of bytes (u8 or FheUint8). The key schedule is run once at
+/// construction, and the counter is public. To be able to compute FHE operations, it also owns
+/// an Option for a ServerKey.
+pub struct AesStreamByte<T> {
+    round_keys: Vec<[T; 16]>,
+    counter: u128,
+    buffer: Vec<T>,
+    fhe_key: Option<ServerKey>,
+}
+
+impl AesStreamByte<u8> {
+    /// Constructor for `AesStreamByte<u8>`: arguments are the secret key and the initial counter
+    /// block.
+    pub fn new(key: [u8; 16], iv: [u8; 16]) -> AesStreamByte<u8> {
+        AesStreamByte::<u8>::new_from_key(key, iv, None)
+    }
+}
+
+impl AesStreamByte<FheUint8> {
+    /// Constructor for `AesStreamByte<FheUint8>`: arguments are the encrypted secret key, the
+    /// initial counter block, and the FHE server key.
+    pub fn new(
+        key: [FheUint8; 16],
+        iv: [u8; 16],
+        server_key: &ServerKey,
+    ) -> AesStreamByte<FheUint8> {
+        AesStreamByte::<FheUint8>::new_from_key(key, iv, Some(server_key.clone()))
+    }
+
+    pub fn get_server_key(&self) -> &ServerKey {
+        self.fhe_key.as_ref().unwrap()
+    }
+}
+
+impl<T> AesStreamByte<T>
+where
+    T: AesByteInput<T> + AesSubByte,
+    for<'a> &'a T: AesByteInput<T>,
+{
+    /// Internal generic constructor: runs the key schedule, with an optional FHE server key
+    fn new_from_key(key: [T; 16], iv: [u8; 16], fhe_key: Option<ServerKey>) -> Self {
+        let round_keys = with_server_key(fhe_key.as_ref(), || Self::expand_key(key));
+
+        Self {
+            round_keys,
+            counter: u128::from_be_bytes(iv),
+            buffer: Vec::with_capacity(16),
+            fhe_key,
+        }
+    }
+
+    /// Multiplication by x in GF(2^8)
+    fn xtime(a: &T) -> T {
+        (a << 1u8) ^ ((a >> 7u8) * 0x1bu8)
+    }
+
+    /// The AES-128 key schedule, outputting the 11 round keys
+    fn expand_key(key: [T; 16]) -> Vec<[T; 16]> {
+        let mut words: Vec<[T; 4]> = (0..4)
+            .map(|w| array::from_fn(|b| key[4 * w + b].clone()))
+            .collect();
+
+        for i in 4..4 * (NUM_ROUNDS + 1) {
+            let previous = &words[i - 1];
+            let temp = if i % 4 == 0 {
+                // RotWord and SubWord, then the round constant is added to the first byte
+                let mut temp: [T; 4] = collect_array(
+                    (0..4)
+                        .into_par_iter()
+                        .map(|b| previous[(b + 1) % 4].sub_byte())
+                        .collect(),
+                );
+                temp[0] = &temp[0] ^ RCON[i / 4 - 1];
+                temp
+            } else {
+                previous.clone()
+            };
+
+            let word = collect_array(
+                (0..4)
+                    .into_par_iter()
+                    .map(|b| &words[i - 4][b] ^ &temp[b])
+                    .collect(),
+            );
+            words.push(word);
+        }
+
+        words
+            .chunks_exact(4)
+            .map(|round_words| array::from_fn(|i| round_words[i / 4][i % 4].clone()))
+            .collect()
+    }
+
+    /// The MixColumns step of AES: each output byte is 2.a0 + 3.a1 + a2 + a3, computed as
+    /// xtime(a0 + a1) + a1 + a2 + a3, where a0 is the input byte at the same position
+    fn mix_columns(state: &[T; 16]) -> [T; 16] {
+        collect_array(
+            (0..16)
+                .into_par_iter()
+                .map(|i| {
+                    let (row, column) = (i % 4, i / 4);
+                    let byte = |r: usize| &state[(row + r) % 4 + 4 * column];
+
+                    let mut ret = Self::xtime(&(byte(0) ^ byte(1)));
+                    for r in 1..4 {
+                        ret = &ret ^ byte(r);
+                    }
+                    ret
+                })
+                .collect(),
+        )
+    }
+
+    /// Encrypts a clear block (in counter mode, the counter) with the round keys
+    fn encrypt_block(&self, block: [u8; 16]) -> [T; 16] {
+        let mut state: [T; 16] = collect_array(
+            self.round_keys[0]
+                .par_iter()
+                .zip(block.par_iter())
+                .map(|(key_byte, &clear_byte)| key_byte ^ clear_byte)
+                .collect(),
+        );
+
+        for (round, round_key) in self.round_keys.iter().enumerate().skip(1) {
+            let substituted: [T; 16] =
+                collect_array(state.par_iter().map(|byte| byte.sub_byte()).collect());
+            let shifted = shift_rows(&substituted);
+            let mixed = if round == NUM_ROUNDS {
+                shifted
+            } else {
+                Self::mix_columns(&shifted)
+            };
+            state = collect_array(
+                mixed
+                    .par_iter()
+                    .zip(round_key.par_iter())
+                    .map(|(byte, key_byte)| byte ^ key_byte)
+                    .collect(),
+            );
+        }
+
+        state
+    }
+
+    /// Encrypts the current counter block and increments the counter, outputting the 16 bytes of
+    /// the key stream block
+    fn next_block(&mut self) -> Vec<T> {
+        let counter = self.counter.to_be_bytes();
+        self.counter = self.counter.wrapping_add(1);
+
+        let block = with_server_key(self.fhe_key.as_ref(), || self.encrypt_block(counter));
+        block.into()
+    }
+
+    /// Computes 64 bits of the key stream, outputting the 8 bytes all at once in a Vec (first
+    /// value is oldest, last is newest). A new block is encrypted every other call.
+    pub fn next_64(&mut self) -> Vec<T> {
+        if self.buffer.is_empty() {
+            self.buffer = self.next_block();
+        }
+        self.buffer.drain(..8).collect()
+    }
+}
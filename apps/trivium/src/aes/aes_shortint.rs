@@ -0,0 +1,92 @@
+//! This module implements the AES-128 block cipher in counter mode, using generic shortint
+//! Ciphertexts for the representation of the inner bits.
+
+use crate::aes::bitsliced::{encrypt_block, expand_key, AesBitOps, Block};
+use std::array;
+use tfhe::shortint::prelude::*;
+
+/// Gates of the bitsliced AES, for ciphertexts encrypting a single bit
+impl AesBitOps<Ciphertext> for ServerKey {
+    fn xor(&self, a: &Ciphertext, b: &Ciphertext) -> Ciphertext {
+        self.bitxor(a, b)
+    }
+
+    fn and(&self, a: &Ciphertext, b: &Ciphertext) -> Ciphertext {
+        self.bitand(a, b)
+    }
+
+    fn not(&self, a: &Ciphertext) -> Ciphertext {
+        self.scalar_bitxor(a, 1)
+    }
+}
+
+/// AesStreamShortint: a struct implementing the AES-128 block cipher in counter mode, using a
+/// generic Ciphertext for the internal representation of bits (intended to represent a single
+/// bit). The key schedule is run once at construction, and the counter is public. To be able to
+/// compute FHE operations, it also owns a ServerKey.
+pub struct AesStreamShortint {
+    round_keys: Vec<Block<Ciphertext>>,
+    counter: u128,
+    buffer: Vec<Ciphertext>,
+    internal_server_key: ServerKey,
+    transciphering_casting_key: KeySwitchingKey,
+    hl_server_key: tfhe::ServerKey,
+}
+
+impl AesStreamShortint {
+    /// Constructor for AesStreamShortint: arguments are the encrypted secret key (the bits of
+    /// each byte being least significant bit first), the initial counter block, the ServerKey
+    /// of the bits, and the casting key and high level ServerKey used for trans ciphering.
+    /// The key schedule is run before returning.
+    pub fn new(
+        key: [Ciphertext; 128],
+        iv: [u8; 16],
+        sk: ServerKey,
+        ksk: KeySwitchingKey,
+        hl_sk: tfhe::ServerKey,
+    ) -> Self {
+        let key: Block<Ciphertext> = array::from_fn(|i| array::from_fn(|j| key[8 * i + j].clone()));
+        let round_keys = expand_key(&sk, &key);
+
+        Self {
+            round_keys,
+            counter: u128::from_be_bytes(iv),
+            buffer: Vec::with_capacity(128),
+            internal_server_key: sk,
+            transciphering_casting_key: ksk,
+            hl_server_key: hl_sk,
+        }
+    }
+
+    /// Encrypts the current counter block and increments the counter, outputting the 128 bits of
+    /// the key stream block
+    fn next_block(&mut self) -> Vec<Ciphertext> {
+        let counter = self.counter.to_be_bytes();
+        self.counter = self.counter.wrapping_add(1);
+
+        let block = encrypt_block(&self.internal_server_key, &self.round_keys, counter);
+        block.into_iter().flatten().collect()
+    }
+
+    /// Computes 64 bits of the key stream, outputting them all at once in a Vec (bytes are in
+    /// stream order, each byte being least significant bit first). A new block is encrypted
+    /// every other call.
+    pub fn next_64(&mut self) -> Vec<Ciphertext> {
+        if self.buffer.is_empty() {
+            self.buffer = self.next_block();
+        }
+        self.buffer.drain(..64).collect()
+    }
+
+    pub fn get_internal_server_key(&self) -> &ServerKey {
+        &self.internal_server_key
+    }
+
+    pub fn get_casting_key(&self) -> &KeySwitchingKey {
+        &self.transciphering_casting_key
+    }
+
+    pub fn get_hl_server_key(&self) -> &tfhe::ServerKey {
+        &self.hl_server_key
+    }
+}
@@ -0,0 +1,240 @@
+//! This module implements AES-128 on a bitsliced representation: each byte is stored as its 8
+//! bits (least significant bit first), and the cipher only uses XOR, AND and NOT gates. It is
+//! shared by the boolean and shortint implementations.
+
+use crate::aes::tables::{affine_linear, gf_pow, xtime, AFFINE_CONSTANT, NUM_ROUNDS, RCON};
+use crate::aes::{collect_array, shift_rows};
+use rayon::prelude::*;
+use std::array;
+
+/// Internal trait specifying the gates needed by the bitsliced AES, T being the representation
+/// of a bit
+pub trait AesBitOps<T> {
+    fn xor(&self, a: &T, b: &T) -> T;
+    fn and(&self, a: &T, b: &T) -> T;
+    fn not(&self, a: &T) -> T;
+}
+
+/// A byte, stored least significant bit first
+pub type Byte<T> = [T; 8];
+
+/// The AES state (or a round key): 16 bytes in column-major order
+pub type Block<T> = [Byte<T>; 16];
+
+/// XORs all the bits of the iterator together
+fn xor_all<'a, T, O>(ops: &O, mut bits: impl Iterator<Item = &'a T>) -> T
+where
+    T: Clone + 'a,
+    O: AesBitOps<T>,
+{
+    let first = bits
+        .next()
+        .expect("Cannot XOR an empty set of bits")
+        .clone();
+    bits.fold(first, |acc, bit| ops.xor(&acc, bit))
+}
+
+/// Applies a GF(2)-linear function to a byte, given its clear definition: the output bit r
+/// is the XOR of the input bits i such that the bit r of f(2^i) is set
+fn linear_map<T, O>(ops: &O, byte: &Byte<T>, f: fn(u8) -> u8) -> Byte<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    collect_array(
+        (0..8)
+            .into_par_iter()
+            .map(|r| {
+                xor_all(
+                    ops,
+                    (0..8)
+                        .filter(|i| (f(1 << i) >> r) & 1 == 1)
+                        .map(|i| &byte[i]),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// XORs a byte with a clear byte, which only requires NOT gates
+fn xor_clear<T, O>(ops: &O, byte: &Byte<T>, clear: u8) -> Byte<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    collect_array(
+        (0..8)
+            .into_par_iter()
+            .map(|i| {
+                if (clear >> i) & 1 == 1 {
+                    ops.not(&byte[i])
+                } else {
+                    byte[i].clone()
+                }
+            })
+            .collect(),
+    )
+}
+
+fn xor_bytes<T, O>(ops: &O, a: &Byte<T>, b: &Byte<T>) -> Byte<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    collect_array(
+        (0..8)
+            .into_par_iter()
+            .map(|i| ops.xor(&a[i], &b[i]))
+            .collect(),
+    )
+}
+
+/// Multiplication in GF(2^8): the 64 products a_i.b_j are computed in parallel, then the output
+/// bit r is the XOR of the products such that the bit r of x^(i + j) is set
+fn gf_mul<T, O>(ops: &O, a: &Byte<T>, b: &Byte<T>) -> Byte<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    let products = (0..64)
+        .into_par_iter()
+        .map(|n| ops.and(&a[n / 8], &b[n % 8]))
+        .collect::<Vec<_>>();
+
+    collect_array(
+        (0..8)
+            .into_par_iter()
+            .map(|r| {
+                xor_all(
+                    ops,
+                    (0..64)
+                        .filter(|n| (gf_pow(2, (n / 8 + n % 8) as u32) >> r) & 1 == 1)
+                        .map(|n| &products[n]),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// The AES S-box: the inverse is computed as x^254 = x^240.x^14 using 4 multiplications
+/// (x^3, x^14 = x^12.x^2, x^15 = x^12.x^3 and x^254), powers of 2 of the exponent
+/// being linear maps
+pub fn sub_byte<T, O>(ops: &O, x: &Byte<T>) -> Byte<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    let x2 = linear_map(ops, x, |a| gf_pow(a, 2));
+    let x3 = gf_mul(ops, &x2, x);
+    let x12 = linear_map(ops, &x3, |a| gf_pow(a, 4));
+    let (x14, x15) = rayon::join(|| gf_mul(ops, &x12, &x2), || gf_mul(ops, &x12, &x3));
+    let x240 = linear_map(ops, &x15, |a| gf_pow(a, 16));
+    let inverse = gf_mul(ops, &x240, &x14);
+
+    xor_clear(
+        ops,
+        &linear_map(ops, &inverse, affine_linear),
+        AFFINE_CONSTANT,
+    )
+}
+
+/// The MixColumns step of AES: each output byte is 2.a0 + 3.a1 + a2 + a3, computed as
+/// xtime(a0 + a1) + a1 + a2 + a3, where a0 is the input byte at the same position
+fn mix_columns<T, O>(ops: &O, state: &Block<T>) -> Block<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    collect_array(
+        (0..16)
+            .into_par_iter()
+            .map(|i| {
+                let (row, column) = (i % 4, i / 4);
+                let byte = |r: usize| &state[(row + r) % 4 + 4 * column];
+
+                let mut ret = linear_map(ops, &xor_bytes(ops, byte(0), byte(1)), xtime);
+                for r in 1..4 {
+                    ret = xor_bytes(ops, &ret, byte(r));
+                }
+                ret
+            })
+            .collect(),
+    )
+}
+
+/// The AES-128 key schedule, outputting the 11 round keys
+pub fn expand_key<T, O>(ops: &O, key: &Block<T>) -> Vec<Block<T>>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    let mut words: Vec<[Byte<T>; 4]> = (0..4)
+        .map(|w| array::from_fn(|b| key[4 * w + b].clone()))
+        .collect();
+
+    for i in 4..4 * (NUM_ROUNDS + 1) {
+        let previous = &words[i - 1];
+        let temp = if i % 4 == 0 {
+            // RotWord and SubWord, then the round constant is added to the first byte
+            let mut temp: [Byte<T>; 4] = collect_array(
+                (0..4)
+                    .into_par_iter()
+                    .map(|b| sub_byte(ops, &previous[(b + 1) % 4]))
+                    .collect(),
+            );
+            temp[0] = xor_clear(ops, &temp[0], RCON[i / 4 - 1]);
+            temp
+        } else {
+            previous.clone()
+        };
+
+        let word = collect_array(
+            (0..4)
+                .into_par_iter()
+                .map(|b| xor_bytes(ops, &words[i - 4][b], &temp[b]))
+                .collect(),
+        );
+        words.push(word);
+    }
+
+    words
+        .chunks_exact(4)
+        .map(|round_words| array::from_fn(|i| round_words[i / 4][i % 4].clone()))
+        .collect()
+}
+
+/// Encrypts a clear block (in counter mode, the counter) with the given round keys
+pub fn encrypt_block<T, O>(ops: &O, round_keys: &[Block<T>], block: [u8; 16]) -> Block<T>
+where
+    T: Clone + Send + Sync,
+    O: AesBitOps<T> + Sync,
+{
+    assert_eq!(round_keys.len(), NUM_ROUNDS + 1);
+
+    let mut state: Block<T> = collect_array(
+        (0..16)
+            .into_par_iter()
+            .map(|i| xor_clear(ops, &round_keys[0][i], block[i]))
+            .collect(),
+    );
+
+    for (round, round_key) in round_keys.iter().enumerate().skip(1) {
+        let substituted: Block<T> =
+            collect_array(state.par_iter().map(|byte| sub_byte(ops, byte)).collect());
+        let shifted = shift_rows(&substituted);
+        let mixed = if round == NUM_ROUNDS {
+            shifted
+        } else {
+            mix_columns(ops, &shifted)
+        };
+        state = collect_array(
+            mixed
+                .par_iter()
+                .zip(round_key.par_iter())
+                .map(|(byte, key_byte)| xor_bytes(ops, byte, key_byte))
+                .collect(),
+        );
+    }
+
+    state
+}
@@ -0,0 +1,48 @@
+use std::array;
+use tfhe::{set_server_key, unset_server_key, ServerKey};
+
+mod tables;
+
+mod bitsliced;
+
+mod aes_bool;
+pub use aes_bool::AesStream;
+
+mod aes_byte;
+pub use aes_byte::AesStreamByte;
+
+mod aes_shortint;
+pub use aes_shortint::AesStreamShortint;
+
+#[cfg(test)]
+mod test;
+
+/// Converts a Vec into an array, without requiring the elements to implement Debug
+fn collect_array<T, const N: usize>(vec: Vec<T>) -> [T; N] {
+    assert_eq!(vec.len(), N);
+    let mut iter = vec.into_iter();
+    array::from_fn(|_| iter.next().unwrap())
+}
+
+/// The ShiftRows step of AES, on a state of 16 elements stored in column-major order
+fn shift_rows<T: Clone>(state: &[T; 16]) -> [T; 16] {
+    array::from_fn(|i| {
+        let (row, column) = (i % 4, i / 4);
+        state[row + 4 * ((column + row) % 4)].clone()
+    })
+}
+
+/// Runs the given computation with the FHE server key set on the current thread and on all the
+/// rayon threads, if there is one
+fn with_server_key<R>(fhe_key: Option<&ServerKey>, f: impl FnOnce() -> R) -> R {
+    if let Some(sk) = fhe_key {
+        set_server_key(sk.clone());
+        rayon::broadcast(|_| set_server_key(sk.clone()));
+    }
+    let ret = f();
+    if fhe_key.is_some() {
+        unset_server_key();
+        rayon::broadcast(|_| unset_server_key());
+    }
+    ret
+}
@@ -0,0 +1,63 @@
+//! This module contains the clear arithmetic of GF(2^8) used by AES, and the tables
+//! derived from it.
+
+/// Number of rounds of AES-128
+pub const NUM_ROUNDS: usize = 10;
+
+/// Round constants of the AES-128 key schedule
+pub const RCON: [u8; NUM_ROUNDS] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Constant of the affine transformation of the S-box
+pub const AFFINE_CONSTANT: u8 = 0x63;
+
+/// The AES S-box, computed from its algebraic definition
+pub const SBOX: [u8; 256] = compute_sbox();
+
+/// Multiplication by x in GF(2^8), modulo x^8 + x^4 + x^3 + x + 1
+pub const fn xtime(a: u8) -> u8 {
+    (a << 1) ^ ((a >> 7) * 0x1b)
+}
+
+/// Multiplication in GF(2^8)
+pub const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut res = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            res ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    res
+}
+
+/// Exponentiation in GF(2^8)
+pub const fn gf_pow(a: u8, mut exponent: u32) -> u8 {
+    let mut res = 1;
+    let mut square = a;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            res = gf_mul(res, square);
+        }
+        square = gf_mul(square, square);
+        exponent >>= 1;
+    }
+    res
+}
+
+/// Linear part of the affine transformation of the S-box
+pub const fn affine_linear(a: u8) -> u8 {
+    a ^ a.rotate_left(1) ^ a.rotate_left(2) ^ a.rotate_left(3) ^ a.rotate_left(4)
+}
+
+/// The S-box maps a byte to the affine transformation of its inverse (0 being mapped to 0),
+/// the inverse being computed as a^254
+const fn compute_sbox() -> [u8; 256] {
+    let mut sbox = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        sbox[i] = affine_linear(gf_pow(i as u8, 254)) ^ AFFINE_CONSTANT;
+        i += 1;
+    }
+    sbox
+}
@@ -0,0 +1,216 @@
+use crate::{AesStream, AesStreamByte, AesStreamShortint, TransCiphering};
+use tfhe::prelude::*;
+use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64;
+use tfhe::{generate_keys, ConfigBuilder, FheBool, FheUint64, FheUint8};
+// Values for these tests come from NIST SP 800-38A, appendix F.5.1 (CTR-AES128.Encrypt). The
+// expected key stream is the XOR of the plaintext and ciphertext blocks.
+
+const KEY: &str = "2B7E151628AED2A6ABF7158809CF4F3C";
+const IV: &str = "F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF";
+const PLAINTEXT: &str = "6BC1BEE22E409F96E93D7E117393172AAE2D8A571E03AC9C9EB76FAC45AF8E5130C81C46A35CE411E5FBC1191A0A52EFF69F2445DF4F9B17AD2B417BE66C3710";
+const CIPHERTEXT: &str = "874D6191B620E3261BEF6864990DB6CE9806F66B7970FDFF8617187BB9FFFDFF5AE4DF3EDBD5D35E5B4F09020DB03EAB1E031DDA2FBE03D1792170A0F3009CEE";
+const KEY_STREAM: &str = "EC8CDF7398607CB0F2D21675EA9EA1E4362B7C3C6773516318A077D7FC5073AE6A2CC3787889374FBEB4C81B17BA6C44E89C399FF0F198C6D40A31DB156CABFE";
+
+fn get_bytes_from_hexadecimal_string<const N: usize>(a: &str) -> [u8; N] {
+    assert_eq!(a.len(), 2 * N);
+    std::array::from_fn(|i| u8::from_str_radix(&a[2 * i..2 * i + 2], 16).unwrap())
+}
+
+fn get_u64s_from_hexadecimal_string(a: &str) -> Vec<u64> {
+    a.as_bytes()
+        .chunks(16)
+        .map(|word| u64::from_str_radix(std::str::from_utf8(word).unwrap(), 16).unwrap())
+        .collect()
+}
+
+/// Bits of each byte are least significant bit first
+fn get_bits_from_bytes<const N: usize>(a: &[u8]) -> [bool; N] {
+    assert_eq!(a.len() * 8, N);
+    std::array::from_fn(|i| (a[i / 8] >> (i % 8)) & 1 == 1)
+}
+
+fn get_hexadecimal_string_from_lsb_first_stream(a: Vec<bool>) -> String {
+    assert!(a.len() % 8 == 0);
+    a.chunks(8)
+        .map(|byte| {
+            let byte = byte
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (i, &bit)| acc | ((bit as u8) << i));
+            format!("{byte:02X}")
+        })
+        .collect()
+}
+
+fn get_hexagonal_string_from_bytes(a: Vec<u8>) -> String {
+    a.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+fn get_hexagonal_string_from_u64(a: Vec<u64>) -> String {
+    a.iter().map(|word| format!("{word:016X}")).collect()
+}
+
+#[test]
+fn aes_test_bool() {
+    let key = get_bits_from_bytes(&get_bytes_from_hexadecimal_string::<16>(KEY));
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let mut aes = AesStream::<bool>::new(key, iv);
+
+    let mut vec = Vec::<bool>::with_capacity(64 * 8);
+    while vec.len() < 64 * 8 {
+        vec.extend(aes.next_64());
+    }
+
+    let hexadecimal = get_hexadecimal_string_from_lsb_first_stream(vec);
+    assert_eq!(KEY_STREAM, hexadecimal);
+}
+
+#[test]
+fn aes_test_byte() {
+    let key = get_bytes_from_hexadecimal_string(KEY);
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let mut aes = AesStreamByte::<u8>::new(key, iv);
+
+    let mut vec = Vec::<u8>::with_capacity(64);
+    while vec.len() < 64 {
+        vec.extend(aes.next_64());
+    }
+
+    let hexadecimal = get_hexagonal_string_from_bytes(vec);
+    assert_eq!(KEY_STREAM, hexadecimal);
+}
+
+#[test]
+fn aes_test_fhe_long() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key = get_bits_from_bytes::<128>(&get_bytes_from_hexadecimal_string::<16>(KEY));
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let cipher_key = key.map(|x| FheBool::encrypt(x, &client_key));
+
+    let mut aes = AesStream::<FheBool>::new(cipher_key, iv, &server_key);
+
+    let mut vec = Vec::<bool>::with_capacity(64);
+    for c in aes.next_64() {
+        vec.push(c.decrypt(&client_key));
+    }
+
+    let hexadecimal = get_hexadecimal_string_from_lsb_first_stream(vec);
+    assert_eq!(KEY_STREAM[0..8 * 2], hexadecimal);
+}
+
+#[test]
+fn aes_test_fhe_transciphering_long() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key = get_bits_from_bytes::<128>(&get_bytes_from_hexadecimal_string::<16>(KEY));
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let cipher_key = key.map(|x| FheBool::encrypt(x, &client_key));
+
+    // The server receives AES ciphertexts, and outputs FHE ciphertexts of the plaintexts
+    let ciphertext = get_u64s_from_hexadecimal_string(&CIPHERTEXT[0..16 * 2]);
+
+    let mut aes = AesStream::<FheBool>::new(cipher_key, iv, &server_key);
+
+    let mut vec = Vec::<u64>::with_capacity(2);
+    for word in ciphertext {
+        let trans_ciphered_message =
+            aes.trans_decrypt_64(FheUint64::try_encrypt(word, &client_key).unwrap());
+        vec.push(trans_ciphered_message.decrypt(&client_key));
+    }
+
+    let hexadecimal = get_hexagonal_string_from_u64(vec);
+    assert_eq!(PLAINTEXT[0..16 * 2], hexadecimal);
+}
+
+#[test]
+fn aes_test_fhe_byte_long() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key = get_bytes_from_hexadecimal_string::<16>(KEY);
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+
+    let mut aes = AesStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
+
+    let mut vec = Vec::<u8>::with_capacity(8);
+    for c in aes.next_64() {
+        vec.push(c.decrypt(&client_key));
+    }
+
+    let hexadecimal = get_hexagonal_string_from_bytes(vec);
+    assert_eq!(KEY_STREAM[0..8 * 2], hexadecimal);
+}
+
+#[test]
+fn aes_test_fhe_byte_transciphering_long() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+
+    let key = get_bytes_from_hexadecimal_string::<16>(KEY);
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+
+    // The server receives AES ciphertexts, and outputs FHE ciphertexts of the plaintexts
+    let ciphertext = get_u64s_from_hexadecimal_string(&CIPHERTEXT[0..16 * 2]);
+
+    let mut aes = AesStreamByte::<FheUint8>::new(cipher_key, iv, &server_key);
+
+    let mut vec = Vec::<u64>::with_capacity(2);
+    for word in ciphertext {
+        let trans_ciphered_message =
+            aes.trans_decrypt_64(FheUint64::try_encrypt(word, &client_key).unwrap());
+        vec.push(trans_ciphered_message.decrypt(&client_key));
+    }
+
+    let hexadecimal = get_hexagonal_string_from_u64(vec);
+    assert_eq!(PLAINTEXT[0..16 * 2], hexadecimal);
+}
+
+use tfhe::shortint::prelude::*;
+
+#[test]
+fn aes_test_shortint_long() {
+    let config = ConfigBuilder::default().build();
+    let (hl_client_key, hl_server_key) = generate_keys(config);
+    let underlying_ck: tfhe::shortint::ClientKey = (*hl_client_key.as_ref()).clone().into();
+    let underlying_sk: tfhe::shortint::ServerKey = (*hl_server_key.as_ref()).clone().into();
+
+    let (client_key, server_key): (ClientKey, ServerKey) =
+        gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64);
+
+    let ksk = KeySwitchingKey::new(
+        (&client_key, Some(&server_key)),
+        (&underlying_ck, &underlying_sk),
+        PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    );
+
+    let key = get_bits_from_bytes::<128>(&get_bytes_from_hexadecimal_string::<16>(KEY));
+    let iv = get_bytes_from_hexadecimal_string(IV);
+
+    let cipher_key = key.map(|x| client_key.encrypt(x as u64));
+
+    // The server receives AES ciphertexts, and outputs FHE ciphertexts of the plaintexts
+    let ciphertext = get_u64s_from_hexadecimal_string(&CIPHERTEXT[0..16 * 2]);
+
+    let mut aes = AesStreamShortint::new(cipher_key, iv, server_key, ksk, hl_server_key);
+
+    let mut vec = Vec::<u64>::with_capacity(2);
+    for word in ciphertext {
+        let trans_ciphered_message =
+            aes.trans_decrypt_64(FheUint64::try_encrypt(word, &hl_client_key).unwrap());
+        vec.push(trans_ciphered_message.decrypt(&hl_client_key));
+    }
+
+    let hexadecimal = get_hexagonal_string_from_u64(vec);
+    assert_eq!(PLAINTEXT[0..16 * 2], hexadecimal);
+}
@@ -0,0 +1,34 @@
+//! This module implements the shift registers used by the high level API versions of Trivium and
+//! Kreyvium: a register of N bits is stored in a FheUint128, its oldest value being the least
+//! significant bit and its newest the bit N - 1, so that 64 steps can be computed at once on
+//! FheUint64 words.
+
+use tfhe::prelude::*;
+use tfhe::{FheUint128, FheUint64, FheUint8};
+
+/// Extracts the 64 values a register will have at index `i` in the next 64 steps: bit n of the
+/// output is the value used at step n. As in a StaticDeque, index 0 is the newest value.
+pub fn get_window<const N: u32>(register: &FheUint128, i: u32) -> FheUint64 {
+    FheUint64::cast_from(register >> (N - 1 - i))
+}
+
+/// Pushes 64 new values in a register, the value computed at step n being bit n of `values`
+pub fn push_64<const N: u32>(register: &FheUint128, values: FheUint64) -> FheUint128 {
+    (register >> 64u32) | (FheUint128::cast_from(values) << (N - 64))
+}
+
+/// Converts a word of the stream (the first value being the least significant bit) to the word
+/// used for trans ciphering, where the first byte of the stream is the most significant one
+pub fn swap_bytes(word: &FheUint64) -> FheUint64 {
+    let mut bytes = word.to_le_bytes();
+    bytes.reverse();
+    FheUint64::from_le_bytes(&bytes)
+}
+
+/// Builds a 128 bits integer from up to 16 bytes, least significant byte first
+pub fn from_le_bytes(bytes: &[FheUint8]) -> FheUint128 {
+    assert!(bytes.len() <= 16);
+
+    let padding = (bytes.len()..16).map(|_| FheUint8::encrypt_trivial(0u8));
+    FheUint128::from_le_bytes(&bytes.iter().cloned().chain(padding).collect::<Vec<_>>())
+}
@@ -0,0 +1,102 @@
+//! This module implements the Kreyvium stream cipher on the high level API integer types: the
+//! registers are stored in FheUint128, and 64 steps are computed at once on FheUint64 words.
+
+use crate::hl_register::{from_le_bytes, get_window, push_64, swap_bytes};
+use tfhe::prelude::*;
+use tfhe::{FheBool, FheUint128, FheUint64, FheUint8};
+
+/// KreyviumStreamHl: a struct implementing the Kreyvium stream cipher on the high level API
+/// integer types. Contrary to the other Kreyvium implementations, it does not own a ServerKey:
+/// the key and input vector are encrypted, and all computations use the server key set with
+/// `set_server_key` in the current thread, so that no casting key is ever needed.
+pub struct KreyviumStreamHl {
+    a: FheUint128,
+    b: FheUint128,
+    c: FheUint128,
+    k: FheUint128,
+    iv: FheUint128,
+}
+
+impl KreyviumStreamHl {
+    /// Constructor for `KreyviumStreamHl` from encrypted bits: arguments are the encrypted secret
+    /// key and input vector (the bits of each byte being least significant bit first).
+    /// Outputs a KreyviumStreamHl object already initialized (1152 steps have been run before
+    /// returning)
+    pub fn new(key: [FheBool; 128], iv: [FheBool; 128]) -> KreyviumStreamHl {
+        Self::new_from_registers(FheUint128::from_bits(&key), FheUint128::from_bits(&iv))
+    }
+
+    /// Constructor for `KreyviumStreamHl` from encrypted bytes: arguments are the encrypted
+    /// secret key and input vector.
+    /// Outputs a KreyviumStreamHl object already initialized (1152 steps have been run before
+    /// returning)
+    pub fn new_from_bytes(key: [FheUint8; 16], iv: [FheUint8; 16]) -> KreyviumStreamHl {
+        Self::new_from_registers(from_le_bytes(&key), from_le_bytes(&iv))
+    }
+
+    /// Internal constructor: arguments are the key and input vector as integers, bit i of each
+    /// integer being bit i of the key or input vector
+    fn new_from_registers(key: FheUint128, iv: FheUint128) -> Self {
+        // Initialization of Kreyvium registers: a has the secret key, b the input vector,
+        // and c the rest of the input vector and a lot of ones.
+        let ones = FheUint128::encrypt_trivial(((1u128 << 67) - 1) ^ 1);
+        let c = ((&iv << (128u32 - 44)) >> (128u32 - 44 - 67)) | ones;
+
+        // Key and iv are stored in reverse in their shift registers
+        let mut ret = Self {
+            a: &key >> (128u32 - 93),
+            b: &iv >> (128u32 - 84),
+            c,
+            k: key.reverse_bits(),
+            iv: iv.reverse_bits(),
+        };
+        ret.init();
+        ret
+    }
+
+    /// The specification of Kreyvium includes running 1152 (= 18*64) unused steps to mix up the
+    /// registers, before starting the proper stream
+    fn init(&mut self) {
+        for _ in 0..18 {
+            self.next_64_lsb_first();
+        }
+    }
+
+    /// Computes 64 turns of the stream, outputting the 64 bits in a word, the first value being
+    /// the least significant bit
+    fn next_64_lsb_first(&mut self) -> FheUint64 {
+        let a = |i| get_window::<93>(&self.a, i);
+        let b = |i| get_window::<84>(&self.b, i);
+        let c = |i| get_window::<111>(&self.c, i);
+        let k = get_window::<128>(&self.k, 127);
+        let iv = get_window::<128>(&self.iv, 127);
+
+        let temp_a = a(65) ^ a(92);
+        let temp_b = b(68) ^ b(83);
+        let temp_c = c(65) ^ c(110) ^ k;
+
+        let a_and = (a(91) & a(90)) ^ iv;
+        let b_and = b(82) & b(81);
+        let c_and = c(109) & c(108);
+
+        let o = &(&temp_a ^ &temp_b) ^ &temp_c;
+        let new_a = temp_c ^ c_and ^ a(68);
+        let new_b = temp_a ^ a_and ^ b(77);
+        let new_c = temp_b ^ b_and ^ c(86);
+
+        self.a = push_64::<93>(&self.a, new_a);
+        self.b = push_64::<84>(&self.b, new_b);
+        self.c = push_64::<111>(&self.c, new_c);
+        self.k = (&self.k).rotate_right(64u32);
+        self.iv = (&self.iv).rotate_right(64u32);
+
+        o
+    }
+
+    /// Computes 64 turns of the stream, outputting the 64 bits all at once in a FheUint64. As in
+    /// trans ciphering, the first byte of the stream is the most significant one, and the bits of
+    /// each byte are least significant bit first.
+    pub fn next_64(&mut self) -> FheUint64 {
+        swap_bytes(&self.next_64_lsb_first())
+    }
+}
@@ -8,5 +8,8 @@ pub use kreyvium_byte::KreyviumStreamByte;
 mod kreyvium_shortint;
 pub use kreyvium_shortint::KreyviumStreamShortint;
 
+mod kreyvium_hl;
+pub use kreyvium_hl::KreyviumStreamHl;
+
 #[cfg(test)]
 mod test;
@@ -1,7 +1,9 @@
-use crate::{KreyviumStream, KreyviumStreamByte, KreyviumStreamShortint, TransCiphering};
+use crate::{
+    KreyviumStream, KreyviumStreamByte, KreyviumStreamHl, KreyviumStreamShortint, TransCiphering,
+};
 use tfhe::prelude::*;
 use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64;
-use tfhe::{generate_keys, ConfigBuilder, FheBool, FheUint64, FheUint8};
+use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint64, FheUint8};
 // Values for these tests come from the github repo renaud1239/Kreyvium,
 // commit fd6828f68711276c25f55e605935028f5e843f43
 
@@ -370,3 +372,77 @@ fn kreyvium_test_fhe_byte_transciphering_long() {
     let hexadecimal = get_hexagonal_string_from_u64(vec![ciphered_message]);
     assert_eq!(output, hexadecimal);
 }
+
+#[test]
+fn kreyvium_test_hl_long() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let key_string = "0053A6F94C9FF24598EB000000000000".to_string();
+    let mut key = [false; 128];
+
+    for i in (0..key_string.len()).step_by(2) {
+        let mut val: u8 = u8::from_str_radix(&key_string[i..i + 2], 16).unwrap();
+        for j in 0..8 {
+            key[8 * (i >> 1) + j] = val % 2 == 1;
+            val >>= 1;
+        }
+    }
+
+    let iv_string = "0D74DB42A91077DE45AC000000000000".to_string();
+    let mut iv = [false; 128];
+
+    for i in (0..iv_string.len()).step_by(2) {
+        let mut val: u8 = u8::from_str_radix(&iv_string[i..i + 2], 16).unwrap();
+        for j in 0..8 {
+            iv[8 * (i >> 1) + j] = val % 2 == 1;
+            val >>= 1;
+        }
+    }
+
+    let output = "D1F0303482061111";
+
+    let cipher_key = key.map(|x| FheBool::encrypt(x, &client_key));
+    let cipher_iv = iv.map(|x| FheBool::encrypt(x, &client_key));
+
+    let mut kreyvium = KreyviumStreamHl::new(cipher_key, cipher_iv);
+
+    let hexadecimal = get_hexagonal_string_from_u64(vec![kreyvium.next_64().decrypt(&client_key)]);
+    assert_eq!(output, hexadecimal);
+}
+
+#[test]
+fn kreyvium_test_hl_byte_transciphering_long() {
+    let config = ConfigBuilder::default().build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let key_string = "0053A6F94C9FF24598EB000000000000".to_string();
+    let mut key = [0u8; 16];
+
+    for i in (0..key_string.len()).step_by(2) {
+        key[i >> 1] = u8::from_str_radix(&key_string[i..i + 2], 16).unwrap();
+    }
+
+    let iv_string = "0D74DB42A91077DE45AC000000000000".to_string();
+    let mut iv = [0u8; 16];
+
+    for i in (0..iv_string.len()).step_by(2) {
+        iv[i >> 1] = u8::from_str_radix(&iv_string[i..i + 2], 16).unwrap();
+    }
+
+    let output = "D1F0303482061111".to_string();
+
+    let cipher_key = key.map(|x| FheUint8::encrypt(x, &client_key));
+    let cipher_iv = iv.map(|x| FheUint8::encrypt(x, &client_key));
+
+    let mut kreyvium = KreyviumStreamHl::new_from_bytes(cipher_key, cipher_iv);
+
+    // The stream cipher text is in the clear, only the key and iv are encrypted
+    let trans_ciphered_message = kreyvium.trans_encrypt_64(0u64);
+    let ciphered_message = trans_ciphered_message.decrypt(&client_key);
+
+    let hexadecimal = get_hexagonal_string_from_u64(vec![ciphered_message]);
+    assert_eq!(output, hexadecimal);
+}
@@ -2,11 +2,16 @@
 
 mod static_deque;
 
+mod hl_register;
+
 mod kreyvium;
-pub use kreyvium::{KreyviumStream, KreyviumStreamByte, KreyviumStreamShortint};
+pub use kreyvium::{KreyviumStream, KreyviumStreamByte, KreyviumStreamHl, KreyviumStreamShortint};
 
 mod trivium;
-pub use trivium::{TriviumStream, TriviumStreamByte, TriviumStreamShortint};
+pub use trivium::{TriviumStream, TriviumStreamByte, TriviumStreamHl, TriviumStreamShortint};
+
+mod aes;
+pub use aes::{AesStream, AesStreamByte, AesStreamShortint};
 
 mod trans_ciphering;
 pub use trans_ciphering::TransCiphering;
@@ -1,17 +1,21 @@
-//! This module will contain extensions of some TriviumStream of KreyviumStream objects,
-//! when trans ciphering is available to them.
+//! This module will contain extensions of some TriviumStream, KreyviumStream or AesStream
+//! objects, when trans ciphering is available to them.
 
-use crate::{KreyviumStreamByte, KreyviumStreamShortint, TriviumStreamByte, TriviumStreamShortint};
+use crate::{
+    AesStream, AesStreamByte, AesStreamShortint, KreyviumStreamByte, KreyviumStreamHl,
+    KreyviumStreamShortint, TriviumStreamByte, TriviumStreamHl, TriviumStreamShortint,
+};
 use rayon::prelude::*;
 use tfhe::prelude::*;
 use tfhe::shortint::Ciphertext;
-use tfhe::{set_server_key, unset_server_key, FheUint64, FheUint8, ServerKey};
-
-/// Triat specifying the interface for trans ciphering a FheUint64 object. Since it is meant
-/// to be used with stream ciphers, encryption and decryption are by default the same.
-pub trait TransCiphering {
-    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64;
-    fn trans_decrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+use tfhe::{set_server_key, unset_server_key, FheBool, FheUint64, FheUint8, ServerKey};
+
+/// Triat specifying the interface for trans ciphering a FheUint64 object, or a clear u64 for the
+/// streams which do not need to encrypt it first. Since it is meant to be used with stream
+/// ciphers, encryption and decryption are by default the same.
+pub trait TransCiphering<Cipher = FheUint64> {
+    fn trans_encrypt_64(&mut self, cipher: Cipher) -> FheUint64;
+    fn trans_decrypt_64(&mut self, cipher: Cipher) -> FheUint64 {
         self.trans_encrypt_64(cipher)
     }
 }
@@ -39,6 +43,32 @@ fn transcipher_from_fheu8_stream(
     ret
 }
 
+fn transcipher_from_fhebool_stream(
+    stream: Vec<FheBool>,
+    cipher: FheUint64,
+    fhe_server_key: &ServerKey,
+) -> FheUint64 {
+    assert_eq!(stream.len(), 64);
+
+    set_server_key(fhe_server_key.clone());
+    rayon::broadcast(|_| set_server_key(fhe_server_key.clone()));
+
+    // The first byte of the stream is the most significant one, and the bits of each byte are
+    // least significant bit first
+    let bits = stream
+        .chunks_exact(8)
+        .rev()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>();
+    let ret = &cipher ^ &FheUint64::from_bits(&bits);
+
+    unset_server_key();
+    rayon::broadcast(|_| unset_server_key());
+
+    ret
+}
+
 fn transcipher_from_1_1_stream(
     stream: Vec<Ciphertext>,
     cipher: FheUint64,
@@ -115,3 +145,66 @@ impl TransCiphering for KreyviumStreamShortint {
         )
     }
 }
+
+impl TransCiphering for AesStream<FheBool> {
+    /// `AesStream<FheBool>`: since a full step outputs 64 bits, these bits are packed in a full
+    /// word, and XORed with the input cipher
+    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+        transcipher_from_fhebool_stream(self.next_64(), cipher, self.get_server_key())
+    }
+}
+
+impl TransCiphering for AesStreamByte<FheUint8> {
+    /// `AesStreamByte<FheUint8>`: since a full step outputs 8 bytes, these bytes
+    /// are each shifted by a number in [0, 8), and XORed with the input cipher
+    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+        transcipher_from_fheu8_stream(self.next_64(), cipher, self.get_server_key())
+    }
+}
+
+impl TransCiphering for AesStreamShortint {
+    /// AesStreamShortint: since a full step outputs 64 shortints, these bits
+    /// are paired 2 by 2 in the HL parameter space and packed in a full word,
+    /// and XORed with the input cipher
+    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+        transcipher_from_1_1_stream(
+            self.next_64(),
+            cipher,
+            self.get_hl_server_key(),
+            self.get_internal_server_key(),
+            self.get_casting_key(),
+        )
+    }
+}
+
+impl TransCiphering for TriviumStreamHl {
+    /// TriviumStreamHl: since a full step outputs a FheUint64, it is directly XORed with the
+    /// input cipher, using the server key set in the current thread
+    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+        cipher ^ self.next_64()
+    }
+}
+
+impl TransCiphering<u64> for TriviumStreamHl {
+    /// TriviumStreamHl: the clear cipher is XORed with the output FheUint64, producing the FHE
+    /// encryption of the plaintext without ever encrypting the cipher
+    fn trans_encrypt_64(&mut self, cipher: u64) -> FheUint64 {
+        self.next_64() ^ cipher
+    }
+}
+
+impl TransCiphering for KreyviumStreamHl {
+    /// KreyviumStreamHl: since a full step outputs a FheUint64, it is directly XORed with the
+    /// input cipher, using the server key set in the current thread
+    fn trans_encrypt_64(&mut self, cipher: FheUint64) -> FheUint64 {
+        cipher ^ self.next_64()
+    }
+}
+
+impl TransCiphering<u64> for KreyviumStreamHl {
+    /// KreyviumStreamHl: the clear cipher is XORed with the output FheUint64, producing the FHE
+    /// encryption of the plaintext without ever encrypting the cipher
+    fn trans_encrypt_64(&mut self, cipher: u64) -> FheUint64 {
+        self.next_64() ^ cipher
+    }
+}
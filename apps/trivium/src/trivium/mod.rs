@@ -7,5 +7,8 @@ pub use trivium_byte::TriviumStreamByte;
 mod trivium_shortint;
 pub use trivium_shortint::TriviumStreamShortint;
 
+mod trivium_hl;
+pub use trivium_hl::TriviumStreamHl;
+
 #[cfg(test)]
 mod test;
@@ -3,6 +3,7 @@
 
 pub mod pke;
 pub mod pke_v2;
+pub mod rlwe;
 
 use std::convert::Infallible;
 use std::error::Error;
@@ -17,7 +18,7 @@ use crate::proofs::GroupElements;
 use crate::serialization::{
     SerializableAffine, SerializableCubicExtField, SerializableFp, SerializableFp2,
     SerializableFp6, SerializableGroupElements, SerializablePKEv1PublicParams,
-    SerializablePKEv2PublicParams, SerializableQuadExtField,
+    SerializablePKEv2PublicParams, SerializableQuadExtField, SerializableRlwePublicParams,
 };
 
 #[derive(VersionsDispatch)]
@@ -140,6 +141,11 @@ pub enum SerializablePKEv1PublicParamsVersions {
     V0(SerializablePKEv1PublicParams),
 }
 
+#[derive(VersionsDispatch)]
+pub enum SerializableRlwePublicParamsVersions {
+    V0(SerializableRlwePublicParams),
+}
+
 #[derive(VersionsDispatch)]
 pub enum BoundVersions {
     V0(Bound),
@@ -5,7 +5,8 @@ use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 
 use crate::curve_api::{CompressedG1, CompressedG2, Compressible, Curve};
 use crate::proofs::pke_v2::{
-    CompressedComputeLoadProofFields, CompressedProof, ComputeLoadProofFields, Proof,
+    CompressedComputeLoadProofFields, CompressedProof, ComputeLoadProofFields, CrsUpdateProof,
+    MessageBitsOpening, Proof,
 };
 
 use super::IncompleteProof;
@@ -140,3 +141,13 @@ where
     #[allow(dead_code)]
     V0(CompressedComputeLoadProofFields<G>),
 }
+
+#[derive(VersionsDispatch)]
+pub enum CrsUpdateProofVersions<G: Curve> {
+    V0(CrsUpdateProof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum MessageBitsOpeningVersions<G: Curve> {
+    V0(MessageBitsOpening<G>),
+}
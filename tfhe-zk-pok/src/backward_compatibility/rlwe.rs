@@ -0,0 +1,15 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::curve_api::Curve;
+use crate::proofs::rlwe::{ComputeLoadProofFields, Proof};
+
+#[derive(VersionsDispatch)]
+pub enum ProofVersions<G: Curve> {
+    V0(Proof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub(crate) enum ComputeLoadProofFieldsVersions<G: Curve> {
+    #[allow(dead_code)]
+    V0(ComputeLoadProofFields<G>),
+}
@@ -656,6 +656,478 @@ pub fn crs_gen<G: Curve>(
     crs_gen_cs(d, k, B, q, t, msbs_zero_padding_bit_count, rng)
 }
 
+/// A proof that a CRS was updated with a secret known to the participant of a CRS ceremony.
+///
+/// The secret `alpha` of the CRS is multiplied by the secret `tau` of the participant, so that the
+/// updated CRS is secure as long as one of the participants erased its secret.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>, G::Zp: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize, G::Zp: serde::Serialize"
+))]
+#[versionize(CrsUpdateProofVersions)]
+pub struct CrsUpdateProof<G: Curve> {
+    /// `g^(alpha * tau)`, the first element of the updated CRS
+    pub(crate) new_g: G::G1,
+    /// `g^tau`
+    pub(crate) tau_g: G::G1,
+    /// `g_hat^tau`
+    pub(crate) tau_g_hat: G::G2,
+    /// Schnorr proof of knowledge of `tau`
+    pub(crate) schnorr_commit: G::G1,
+    pub(crate) schnorr_response: G::Zp,
+}
+
+impl<G: Curve> CrsUpdateProof<G> {
+    /// Check if the proof can be used by the Verifier.
+    ///
+    /// This means checking that the points are:
+    /// - valid points of the curve
+    /// - in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        [self.new_g, self.tau_g, self.schnorr_commit]
+            .iter()
+            .all(G::G1::validate_projective)
+            && self.tau_g_hat.validate_projective()
+    }
+
+    fn schnorr_challenge(
+        previous_g: G::G1,
+        new_g: G::G1,
+        tau_g: G::G1,
+        tau_g_hat: G::G2,
+        schnorr_commit: G::G1,
+    ) -> G::Zp {
+        let mut challenge = G::Zp::ZERO;
+        G::Zp::hash(
+            core::slice::from_mut(&mut challenge),
+            &[
+                b"tfhe-zk-pok crs update".as_slice(),
+                previous_g.to_le_bytes().as_ref(),
+                new_g.to_le_bytes().as_ref(),
+                tau_g.to_le_bytes().as_ref(),
+                tau_g_hat.to_le_bytes().as_ref(),
+                schnorr_commit.to_le_bytes().as_ref(),
+            ],
+        );
+        challenge
+    }
+
+    /// Verifies the update of a CRS whose first element was `previous_g`
+    fn verify(&self, previous_g: G::G1) -> Result<(), ()> {
+        let &Self {
+            new_g,
+            tau_g,
+            tau_g_hat,
+            schnorr_commit,
+            schnorr_response,
+        } = self;
+
+        let pairing = G::Gt::pairing;
+        let g = G::G1::GENERATOR;
+        let g_hat = G::G2::GENERATOR;
+
+        // A null tau would erase the secret of the CRS
+        if g1_eq::<G>(tau_g, G::G1::ZERO) {
+            return Err(());
+        }
+
+        let challenge =
+            Self::schnorr_challenge(previous_g, new_g, tau_g, tau_g_hat, schnorr_commit);
+        if !g1_eq::<G>(
+            g.mul_scalar(schnorr_response),
+            schnorr_commit + tau_g.mul_scalar(challenge),
+        ) {
+            return Err(());
+        }
+
+        if pairing(tau_g, g_hat) != pairing(g, tau_g_hat)
+            || pairing(new_g, g_hat) != pairing(previous_g, tau_g_hat)
+        {
+            return Err(());
+        }
+
+        Ok(())
+    }
+}
+
+fn g1_eq<G: Curve>(x: G::G1, y: G::G1) -> bool {
+    x.to_le_bytes().as_ref() == y.to_le_bytes().as_ref()
+}
+
+impl<G: Curve> PublicParams<G> {
+    /// Contributes to a CRS ceremony by updating the CRS with fresh randomness.
+    ///
+    /// Returns the updated CRS along with a proof of the update, that should be appended to the
+    /// transcript of the ceremony. The randomness is not returned and must not be kept, as
+    /// anyone knowing the secrets of all the participants could forge proofs.
+    pub fn update(&self, rng: &mut dyn RngCore) -> (Self, CrsUpdateProof<G>) {
+        let zero_bytes = G::Zp::ZERO.to_le_bytes();
+        let tau = loop {
+            let tau = G::Zp::rand(rng);
+            if tau.to_le_bytes().as_ref() != zero_bytes.as_ref() {
+                break tau;
+            }
+        };
+
+        let g_list = &self.g_lists.g_list.0;
+        let g_hat_list = &self.g_lists.g_hat_list.0;
+
+        let mut tau_powers = Vec::with_capacity(g_list.len());
+        let mut tau_pow = tau;
+        for _ in 0..g_list.len() {
+            tau_powers.push(tau_pow);
+            tau_pow = tau_pow * tau;
+        }
+
+        let (new_g_list, new_g_hat_list) = rayon::join(
+            || {
+                g_list
+                    .par_iter()
+                    .zip(tau_powers.par_iter())
+                    .map(|(&g, &tau_pow)| G::G1::projective(g).mul_scalar(tau_pow).normalize())
+                    .collect::<Vec<_>>()
+            },
+            || {
+                g_hat_list
+                    .par_iter()
+                    .zip(tau_powers.par_iter())
+                    .map(|(&g_hat, &tau_pow)| {
+                        G::G2::projective(g_hat).mul_scalar(tau_pow).normalize()
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        let previous_g = G::G1::projective(self.g_lists.g_list[1]);
+        let new_g = G::G1::projective(new_g_list[0]);
+        let tau_g = G::G1::GENERATOR.mul_scalar(tau);
+        let tau_g_hat = G::G2::GENERATOR.mul_scalar(tau);
+
+        let schnorr_nonce = G::Zp::rand(rng);
+        let schnorr_commit = G::G1::GENERATOR.mul_scalar(schnorr_nonce);
+        let challenge = CrsUpdateProof::<G>::schnorr_challenge(
+            previous_g,
+            new_g,
+            tau_g,
+            tau_g_hat,
+            schnorr_commit,
+        );
+
+        let updated = Self {
+            g_lists: GroupElements::from_vec(new_g_list, new_g_hat_list),
+            D: self.D,
+            n: self.n,
+            d: self.d,
+            k: self.k,
+            B_bound_squared: self.B_bound_squared,
+            B_inf: self.B_inf,
+            q: self.q,
+            t: self.t,
+            msbs_zero_padding_bit_count: self.msbs_zero_padding_bit_count,
+            bound_type: self.bound_type,
+            hash: self.hash,
+            hash_R: self.hash_R,
+            hash_t: self.hash_t,
+            hash_w: self.hash_w,
+            hash_agg: self.hash_agg,
+            hash_lmap: self.hash_lmap,
+            hash_phi: self.hash_phi,
+            hash_xi: self.hash_xi,
+            hash_z: self.hash_z,
+            hash_chi: self.hash_chi,
+        };
+        let proof = CrsUpdateProof {
+            new_g,
+            tau_g,
+            tau_g_hat,
+            schnorr_commit,
+            schnorr_response: schnorr_nonce + challenge * tau,
+        };
+
+        (updated, proof)
+    }
+
+    /// Checks that the group elements of the CRS are the successive powers of the same secret
+    ///
+    /// The pairing equations are combined with coefficients derived by hashing the CRS.
+    fn has_well_formed_powers(&self) -> bool {
+        let pairing = G::Gt::pairing;
+        let g = G::G1::GENERATOR;
+        let g_hat = G::G2::GENERATOR;
+
+        let g_list = &*self.g_lists.g_list.0;
+        let g_hat_list = &*self.g_lists.g_hat_list.0;
+        let n = g_hat_list.len();
+
+        if n < 2 || g_list.len() != 2 * n {
+            return false;
+        }
+
+        let zero_bytes = G::G1::ZERO.to_le_bytes();
+        let is_zero = |affine: Affine<G::Zp, G::G1>| {
+            G::G1::projective(affine).to_le_bytes().as_ref() == zero_bytes.as_ref()
+        };
+        // The power n + 1 of the secret is left out of the CRS, the secret must not be null
+        if !is_zero(g_list[n]) || is_zero(g_list[0]) {
+            return false;
+        }
+
+        let g_bytes = g_list
+            .par_iter()
+            .flat_map(|&x| G::G1::projective(x).to_le_bytes().as_ref().to_vec())
+            .collect::<Vec<_>>();
+        let g_hat_bytes = g_hat_list
+            .par_iter()
+            .flat_map(|&x| G::G2::projective(x).to_le_bytes().as_ref().to_vec())
+            .collect::<Vec<_>>();
+        let mut coeffs = vec![G::Zp::ZERO; 3 * n - 4];
+        G::Zp::hash_128bit(
+            &mut coeffs,
+            &[&self.hash, g_bytes.as_slice(), g_hat_bytes.as_slice()],
+        );
+        let (low_coeffs, coeffs) = coeffs.split_at(n - 1);
+        let (high_coeffs, g_hat_coeffs) = coeffs.split_at(n - 2);
+
+        // Each element of g_list is the previous one to the power alpha, on both sides of the
+        // missing power
+        let shifted_g = G::G1::multi_mul_scalar(&g_list[1..n], low_coeffs)
+            + G::G1::multi_mul_scalar(&g_list[n + 2..], high_coeffs);
+        let unshifted_g = G::G1::multi_mul_scalar(&g_list[..n - 1], low_coeffs)
+            + G::G1::multi_mul_scalar(&g_list[n + 1..2 * n - 1], high_coeffs);
+        if pairing(shifted_g, g_hat) != pairing(unshifted_g, G::G2::projective(g_hat_list[0])) {
+            return false;
+        }
+
+        // Across the missing power: g^(alpha^(n + 2)) = (g^(alpha^n))^(alpha^2)
+        if pairing(G::G1::projective(g_list[n + 1]), g_hat)
+            != pairing(
+                G::G1::projective(g_list[n - 1]),
+                G::G2::projective(g_hat_list[1]),
+            )
+        {
+            return false;
+        }
+
+        // Each element of g_hat_list is the previous one to the power alpha
+        if pairing(
+            G::G1::projective(g_list[0]),
+            G::G2::multi_mul_scalar(&g_hat_list[..n - 1], g_hat_coeffs),
+        ) != pairing(g, G::G2::multi_mul_scalar(&g_hat_list[1..], g_hat_coeffs))
+        {
+            return false;
+        }
+
+        // Both lists start with the same secret
+        pairing(G::G1::projective(g_list[0]), g_hat) == pairing(g, G::G2::projective(g_hat_list[0]))
+    }
+}
+
+/// Verifies the transcript of a CRS ceremony.
+///
+/// `proofs` are the proofs produced by each participant with [PublicParams::update], in order,
+/// starting from the `initial` CRS. This checks that `updated` was obtained by applying all the
+/// updates to `initial`, and that it is a well formed CRS. The intermediate CRS are not needed.
+#[allow(clippy::result_unit_err)]
+pub fn verify_crs_update_transcript<G: Curve>(
+    initial: &PublicParams<G>,
+    updated: &PublicParams<G>,
+    proofs: &[CrsUpdateProof<G>],
+) -> Result<(), ()> {
+    let same_parameters = {
+        let PublicParams {
+            g_lists: _,
+            D,
+            n,
+            d,
+            k,
+            B_bound_squared,
+            B_inf,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+            bound_type,
+            hash,
+            hash_R,
+            hash_t,
+            hash_w,
+            hash_agg,
+            hash_lmap,
+            hash_phi,
+            hash_xi,
+            hash_z,
+            hash_chi,
+        } = initial;
+
+        *D == updated.D
+            && *n == updated.n
+            && *d == updated.d
+            && *k == updated.k
+            && *B_bound_squared == updated.B_bound_squared
+            && *B_inf == updated.B_inf
+            && *q == updated.q
+            && *t == updated.t
+            && *msbs_zero_padding_bit_count == updated.msbs_zero_padding_bit_count
+            && *bound_type == updated.bound_type
+            && *hash == updated.hash
+            && *hash_R == updated.hash_R
+            && *hash_t == updated.hash_t
+            && *hash_w == updated.hash_w
+            && *hash_agg == updated.hash_agg
+            && *hash_lmap == updated.hash_lmap
+            && *hash_phi == updated.hash_phi
+            && *hash_xi == updated.hash_xi
+            && *hash_z == updated.hash_z
+            && *hash_chi == updated.hash_chi
+    };
+
+    if !same_parameters
+        || updated.g_lists.g_hat_list.0.len() != updated.n
+        || !updated.is_usable()
+        || !updated.has_well_formed_powers()
+        || initial.g_lists.g_list.0.is_empty()
+    {
+        return Err(());
+    }
+
+    let mut previous_g = G::G1::projective(initial.g_lists.g_list[1]);
+    for proof in proofs {
+        if !proof.is_usable() {
+            return Err(());
+        }
+        proof.verify(previous_g)?;
+        previous_g = proof.new_g;
+    }
+
+    if g1_eq::<G>(previous_g, G::G1::projective(updated.g_lists.g_list[1])) {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// The bit `bit_index` of the message `message_index` of a proof, with a weight in a linear
+/// combination of message bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeightedMessageBit {
+    pub message_index: usize,
+    pub bit_index: usize,
+    pub weight: i128,
+}
+
+/// An opening of a linear combination of the message bits committed in one or several proofs.
+///
+/// The openings of each proof, generated by [prove_with_message_bits_openings], are summed to
+/// open a combination of the message bits of several proofs. The value of the combination is
+/// checked by [verify_message_bits_relation].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize"
+))]
+#[versionize(MessageBitsOpeningVersions)]
+pub struct MessageBitsOpening<G: Curve> {
+    pub(crate) pi: G::G1,
+}
+
+impl<G: Curve> MessageBitsOpening<G> {
+    /// Check if the opening can be used by the Verifier.
+    ///
+    /// This means checking that the point is:
+    /// - a valid point of the curve
+    /// - in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.pi.validate_projective()
+    }
+}
+
+impl<G: Curve> core::iter::Sum for MessageBitsOpening<G> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self {
+            pi: iter.map(|opening| opening.pi).sum(),
+        }
+    }
+}
+
+/// Returns the positions of the bits of a linear combination in the binary witness `w_bin` of a
+/// proof with `k` messages, along with their weights.
+///
+/// The bits of the random vector `r` come first in `w_bin`, followed by the bits of each message.
+fn message_bits_positions(
+    combination: &[WeightedMessageBit],
+    d: usize,
+    k: usize,
+    bits_per_message: usize,
+) -> Option<Vec<(usize, i128)>> {
+    combination
+        .iter()
+        .map(
+            |&WeightedMessageBit {
+                 message_index,
+                 bit_index,
+                 weight,
+             }| {
+                (message_index < k && bit_index < bits_per_message)
+                    .then_some((d + message_index * bits_per_message + bit_index, weight))
+            },
+        )
+        .collect()
+}
+
+fn zp_from_i128<G: Curve>(x: i128) -> G::Zp {
+    let x_abs = G::Zp::from_u128(x.unsigned_abs());
+    if x < 0 {
+        -x_abs
+    } else {
+        x_abs
+    }
+}
+
+/// Computes `Y = prod_j g_{n + 1 - j}^y_j`, where `y_j` is the weight of the bit at position `j`
+/// (starting from 1) of `w_bin`.
+fn message_bits_base<G: Curve>(
+    g_list: &[Affine<G::Zp, G::G1>],
+    n: usize,
+    bits_positions: &[(usize, i128)],
+) -> G::G1 {
+    let (bases, scalars): (Vec<_>, Vec<_>) = bits_positions
+        .iter()
+        .map(|&(j, y)| (g_list[n - 1 - j], zp_from_i128::<G>(y)))
+        .unzip();
+
+    G::G1::multi_mul_scalar(&bases, &scalars)
+}
+
+/// Computes the opening of `<y, w_bin>` for `C_hat_bin = g_hat^gamma_bin prod_j g_hat_j^w_j`.
+///
+/// We have `e(Y, C_hat_bin) = e(g_1, g_hat_n)^<y, w_bin> e(pi, g_hat)`, with
+/// `pi = Y^gamma_bin prod_{i != j} g_{n + 1 - j + i}^(y_j w_i)`. The element `g_{n + 1}` is never
+/// needed, and is not part of the public parameters.
+fn message_bits_opening<G: Curve>(
+    g_list: &[Affine<G::Zp, G::G1>],
+    n: usize,
+    bits_positions: &[(usize, i128)],
+    w_bin: &[bool],
+    gamma_bin: G::Zp,
+) -> MessageBitsOpening<G> {
+    let mut coeffs = vec![0i128; 2 * n];
+    for &(j, y) in bits_positions {
+        for (i, _) in w_bin.iter().enumerate().filter(|&(i, &w)| w && i != j) {
+            coeffs[n + i - j] += y;
+        }
+    }
+
+    let scalars = coeffs
+        .iter()
+        .map(|&coeff| zp_from_i128::<G>(coeff))
+        .collect::<Box<[_]>>();
+
+    MessageBitsOpening {
+        pi: message_bits_base::<G>(g_list, n, bits_positions).mul_scalar(gamma_bin)
+            + G::G1::multi_mul_scalar(g_list, &scalars),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn commit<G: Curve>(
     a: Vec<i64>,
@@ -695,24 +1167,52 @@ pub fn prove<G: Curve>(
     load: ComputeLoad,
     rng: &mut dyn RngCore,
 ) -> Proof<G> {
+    let (proof, _) = prove_impl(
+        public,
+        private_commit,
+        metadata,
+        load,
+        &[],
+        rng,
+        ProofSanityCheckMode::Panic,
+    );
+    proof
+}
+
+/// Generates a proof along with one opening per linear combination of the message bits in
+/// `combinations`.
+///
+/// The openings can be checked with [verify_message_bits_relation], possibly after summing the
+/// openings of several proofs to prove a relation between the messages of different proofs.
+pub fn prove_with_message_bits_openings<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    combinations: &[&[WeightedMessageBit]],
+    rng: &mut dyn RngCore,
+) -> (Proof<G>, Vec<MessageBitsOpening<G>>) {
     prove_impl(
         public,
         private_commit,
         metadata,
         load,
+        combinations,
         rng,
         ProofSanityCheckMode::Panic,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn prove_impl<G: Curve>(
     public: (&PublicParams<G>, &PublicCommit<G>),
     private_commit: &PrivateCommit<G>,
     metadata: &[u8],
     load: ComputeLoad,
+    combinations: &[&[WeightedMessageBit]],
     rng: &mut dyn RngCore,
     sanity_check_mode: ProofSanityCheckMode,
-) -> Proof<G> {
+) -> (Proof<G>, Vec<MessageBitsOpening<G>>) {
     _ = load;
     let (
         &PublicParams {
@@ -996,6 +1496,17 @@ fn prove_impl<G: Curve>(
             .map(G::G2::projective)
             .sum::<G::G2>();
 
+    let message_bits_openings = combinations
+        .iter()
+        .map(|&combination| {
+            let bits_positions =
+                message_bits_positions(combination, d, k, effective_cleartext_t.ilog2() as usize)
+                    .expect("invalid message bit in a linear combination");
+
+            message_bits_opening(g_list, n, &bits_positions, &w_bin, gamma_bin)
+        })
+        .collect::<Vec<_>>();
+
     let mut xi = vec![G::Zp::ZERO; 128];
     G::Zp::hash(
         &mut xi,
@@ -1709,7 +2220,7 @@ fn prove_impl<G: Curve>(
 
     let pi_kzg = g.mul_scalar(q[0]) + G::G1::multi_mul_scalar(&g_list[..n - 1], &q[1..n]);
 
-    Proof {
+    let proof = Proof {
         C_hat_e,
         C_e,
         C_r_tilde,
@@ -1722,7 +2233,9 @@ fn prove_impl<G: Curve>(
         pi,
         pi_kzg,
         compute_load_proof_fields,
-    }
+    };
+
+    (proof, message_bits_openings)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1827,12 +2340,261 @@ fn compute_a_theta<G: Curve>(
     }
 }
 
+/// The pairing equations checked by the verifier, in a form that allows to aggregate them over
+/// several proofs
+///
+/// The first equation is
+/// `e(pi, g_hat) = sum(e(terms[i].0, terms[i].1)) - e(g_list[0], g_hat_list[n - 1])^s`
+/// with `s = g_hat_pow_scalar`, the second one is the KZG opening
+/// `e(kzg_g1, g_hat) + e(g, kzg_g2) = e(pi_kzg, g_hat_list[0] - z * g_hat)`.
+struct PairingChecks<G: Curve> {
+    terms: [(G::G1, G::G2); 6],
+    g_hat_pow_scalar: G::Zp,
+    pi: G::G1,
+    kzg_g1: G::G1,
+    kzg_g2: G::G2,
+    pi_kzg: G::G1,
+    z: G::Zp,
+}
+
+impl<G: Curve> PairingChecks<G> {
+    fn verify(&self, public: &PublicParams<G>) -> Result<(), ()> {
+        let pairing = G::Gt::pairing;
+        let g = G::G1::GENERATOR;
+        let g_hat = G::G2::GENERATOR;
+        let g_list = &*public.g_lists.g_list.0;
+        let g_hat_list = &*public.g_lists.g_hat_list.0;
+        let n = public.n;
+
+        let rhs = pairing(self.pi, g_hat);
+        let lhs = self.terms.iter().fold(
+            -pairing(
+                G::G1::projective(g_list[0]),
+                G::G2::projective(g_hat_list[n - 1]),
+            )
+            .mul_scalar(self.g_hat_pow_scalar),
+            |acc, &(g1, g2)| acc + pairing(g1, g2),
+        );
+
+        if lhs != rhs {
+            return Err(());
+        }
+
+        let lhs = pairing(self.kzg_g1, g_hat) + pairing(g, self.kzg_g2);
+        let rhs = pairing(
+            self.pi_kzg,
+            G::G2::projective(g_hat_list[0]) - g_hat.mul_scalar(self.z),
+        );
+
+        if lhs != rhs {
+            Err(())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for &(g1, g2) in &self.terms {
+            bytes.extend_from_slice(g1.to_le_bytes().as_ref());
+            bytes.extend_from_slice(g2.to_le_bytes().as_ref());
+        }
+        for g1 in [self.pi, self.kzg_g1, self.pi_kzg] {
+            bytes.extend_from_slice(g1.to_le_bytes().as_ref());
+        }
+        bytes.extend_from_slice(self.kzg_g2.to_le_bytes().as_ref());
+        for zp in [self.g_hat_pow_scalar, self.z] {
+            bytes.extend_from_slice(zp.to_le_bytes().as_ref());
+        }
+        bytes
+    }
+}
+
 #[allow(clippy::result_unit_err)]
 pub fn verify<G: Curve>(
     proof: &Proof<G>,
     public: (&PublicParams<G>, &PublicCommit<G>),
     metadata: &[u8],
 ) -> Result<(), ()> {
+    pairing_checks(proof, public, metadata)?.verify(public.0)
+}
+
+/// Verifies several proofs at once.
+///
+/// Each proof comes with its public commitment and the metadata it was generated with. The
+/// pairing equations of all the proofs are combined with random coefficients, so that the pairings
+/// involving only elements of the public parameters are computed once for the whole batch instead
+/// of once per proof. The coefficients are derived by hashing the equations of the whole batch.
+///
+/// If the batch is not valid, the proofs are verified one by one and the indices of the invalid
+/// ones are returned.
+pub fn verify_batch<G: Curve + Sync>(
+    proofs: &[(&Proof<G>, &PublicCommit<G>, &[u8])],
+    public: &PublicParams<G>,
+) -> Result<(), Vec<usize>> {
+    let checks = proofs
+        .par_iter()
+        .map(|&(proof, public_commit, metadata)| {
+            pairing_checks(proof, (public, public_commit), metadata)
+        })
+        .collect::<Vec<_>>();
+
+    // Proofs that are malformed for these public params are rejected right away
+    let mut invalid = Vec::new();
+    let mut batch_indices = Vec::new();
+    let checks = checks
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, check)| match check {
+            Ok(check) => {
+                batch_indices.push(i);
+                Some(check)
+            }
+            Err(()) => {
+                invalid.push(i);
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if checks.is_empty() {
+        return if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid)
+        };
+    }
+
+    let checks_bytes = checks
+        .par_iter()
+        .map(PairingChecks::to_le_bytes)
+        .collect::<Vec<_>>();
+    let mut hashed_data = vec![public.hash_agg.as_slice()];
+    hashed_data.extend(checks_bytes.iter().map(Vec::as_slice));
+
+    // One coefficient per equation of each proof
+    let mut coeffs = vec![G::Zp::ZERO; 2 * checks.len()];
+    G::Zp::hash_128bit(&mut coeffs, &hashed_data);
+
+    let pairing = G::Gt::pairing;
+    let g = G::G1::GENERATOR;
+    let g_hat = G::G2::GENERATOR;
+    let g_list = &*public.g_lists.g_list.0;
+    let g_hat_list = &*public.g_lists.g_hat_list.0;
+    let n = public.n;
+
+    // The pairings specific to each proof
+    let lhs = checks
+        .par_iter()
+        .zip(coeffs.par_chunks_exact(2))
+        .map(|(check, coeffs)| {
+            check
+                .terms
+                .iter()
+                .map(|&(g1, g2)| pairing(g1.mul_scalar(coeffs[0]), g2))
+                .reduce(|acc, x| acc + x)
+                .unwrap()
+        })
+        .reduce_with(|acc, x| acc + x)
+        .unwrap();
+
+    // The pairings on the elements shared by all the proofs
+    let mut g_hat_factor = G::G1::ZERO;
+    let mut g_factor = G::G2::ZERO;
+    let mut g_hat_0_factor = G::G1::ZERO;
+    let mut g_hat_pow_scalar = G::Zp::ZERO;
+    for (check, coeffs) in checks.iter().zip(coeffs.chunks_exact(2)) {
+        let [r, u] = [coeffs[0], coeffs[1]];
+        g_hat_factor += (check.kzg_g1 + check.pi_kzg.mul_scalar(check.z)).mul_scalar(u)
+            - check.pi.mul_scalar(r);
+        g_factor += check.kzg_g2.mul_scalar(u);
+        g_hat_0_factor += check.pi_kzg.mul_scalar(u);
+        g_hat_pow_scalar += r * check.g_hat_pow_scalar;
+    }
+
+    let lhs = lhs + pairing(g_hat_factor, g_hat) + pairing(g, g_factor);
+    let rhs = pairing(g_hat_0_factor, G::G2::projective(g_hat_list[0]))
+        + pairing(
+            G::G1::projective(g_list[0]),
+            G::G2::projective(g_hat_list[n - 1]),
+        )
+        .mul_scalar(g_hat_pow_scalar);
+
+    if lhs != rhs {
+        invalid.extend(
+            checks
+                .par_iter()
+                .zip(batch_indices.par_iter())
+                .filter_map(|(check, &i)| check.verify(public).is_err().then_some(i))
+                .collect::<Vec<_>>(),
+        );
+        invalid.sort_unstable();
+    }
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
+/// Verifies that a linear combination of the message bits of several proofs is equal to `value`.
+///
+/// Each term is a proof with its public commitment and the weighted message bits it contributes
+/// to the combination. The `opening` is the sum of the openings generated by
+/// [prove_with_message_bits_openings] for each term.
+///
+/// This only checks the combination of the bits committed in the proofs, the proofs themselves
+/// must be verified with [verify] or [verify_batch] for the bits to be those of the messages.
+#[allow(clippy::result_unit_err)]
+pub fn verify_message_bits_relation<G: Curve>(
+    opening: &MessageBitsOpening<G>,
+    terms: &[(&Proof<G>, &PublicCommit<G>, &[WeightedMessageBit])],
+    value: i128,
+    public: &PublicParams<G>,
+) -> Result<(), ()> {
+    let pairing = G::Gt::pairing;
+    let g_list = &*public.g_lists.g_list.0;
+    let g_hat_list = &*public.g_lists.g_hat_list.0;
+    let n = public.n;
+    let bits_per_message = (public.t >> public.msbs_zero_padding_bit_count).ilog2() as usize;
+
+    let lhs = terms.iter().try_fold(
+        -pairing(
+            G::G1::projective(g_list[0]),
+            G::G2::projective(g_hat_list[n - 1]),
+        )
+        .mul_scalar(zp_from_i128::<G>(value)),
+        |acc, &(proof, public_commit, combination)| {
+            let k = public_commit.c2.len();
+            if k > public.k {
+                return Err(());
+            }
+
+            let bits_positions =
+                message_bits_positions(combination, public.d, k, bits_per_message).ok_or(())?;
+
+            Ok(acc
+                + pairing(
+                    message_bits_base::<G>(g_list, n, &bits_positions),
+                    proof.C_hat_bin,
+                ))
+        },
+    )?;
+
+    if lhs == pairing(opening.pi, G::G2::GENERATOR) {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// Computes the pairing equations that must hold for the proof to be valid
+fn pairing_checks<G: Curve>(
+    proof: &Proof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
+) -> Result<PairingChecks<G>, ()> {
     let &Proof {
         C_hat_e,
         C_e,
@@ -1848,8 +2610,6 @@ pub fn verify<G: Curve>(
         ref compute_load_proof_fields,
     } = proof;
 
-    let pairing = G::Gt::pairing;
-
     let &PublicParams {
         ref g_lists,
         D: D_max,
@@ -1873,7 +2633,6 @@ pub fn verify<G: Curve>(
         ref hash_z,
         ref hash_chi,
     } = public.0;
-    let g_list = &*g_lists.g_list.0;
     let g_hat_list = &*g_lists.g_hat_list.0;
 
     let decoded_q = decode_q(q);
@@ -2165,12 +2924,10 @@ pub fn verify<G: Curve>(
 
     let delta_theta_q = delta_theta * G::Zp::from_u128(decoded_q);
 
-    let rhs = pairing(pi, g_hat);
-    let lhs = {
-        let lhs0 = pairing(C_y.mul_scalar(delta_y) + C_h1, C_hat_bin);
-        let lhs1 = pairing(C_e.mul_scalar(delta_l) + C_h2, C_hat_e);
-
-        let lhs2 = pairing(
+    let terms = [
+        (C_y.mul_scalar(delta_y) + C_h1, C_hat_bin),
+        (C_e.mul_scalar(delta_l) + C_h2, C_hat_e),
+        (
             C_r_tilde,
             match compute_load_proof_fields.as_ref() {
                 Some(&ComputeLoadProofFields {
@@ -2196,9 +2953,9 @@ pub fn verify<G: Curve>(
                         .collect::<Box<[_]>>(),
                 ),
             },
-        );
-        let lhs3 = pairing(
-            C_R,
+        ),
+        (
+            -C_R,
             G::G2::multi_mul_scalar(
                 &g_hat_list[n - 128..n],
                 &(0..128)
@@ -2206,9 +2963,9 @@ pub fn verify<G: Curve>(
                     .map(|j| delta_r * phi[j] + delta_dec * xi[j])
                     .collect::<Box<[_]>>(),
             ),
-        );
-        let lhs4 = pairing(
-            C_e.mul_scalar(delta_e),
+        ),
+        (
+            -C_e.mul_scalar(delta_e),
             match compute_load_proof_fields.as_ref() {
                 Some(&ComputeLoadProofFields {
                     C_hat_h3: _,
@@ -2216,20 +2973,10 @@ pub fn verify<G: Curve>(
                 }) => C_hat_w,
                 None => G::G2::multi_mul_scalar(&g_hat_list[..d + k + 4], &w[..d + k + 4]),
             },
-        );
-        let lhs5 = pairing(C_y.mul_scalar(delta_eq), C_hat_t);
-        let lhs6 = pairing(
-            G::G1::projective(g_list[0]),
-            G::G2::projective(g_hat_list[n - 1]),
-        )
-        .mul_scalar(delta_theta * t_theta + delta_l * G::Zp::from_u128(B_squared));
-
-        lhs0 + lhs1 + lhs2 - lhs3 - lhs4 - lhs5 - lhs6
-    };
-
-    if lhs != rhs {
-        return Err(());
-    }
+        ),
+        (-C_y.mul_scalar(delta_eq), C_hat_t),
+    ];
+    let g_hat_pow_scalar = delta_theta * t_theta + delta_l * G::Zp::from_u128(B_squared);
 
     let mut z = G::Zp::ZERO;
     G::Zp::hash(
@@ -2405,29 +3152,25 @@ pub fn verify<G: Curve>(
     let chi3 = chi2 * chi;
     let chi4 = chi3 * chi;
 
-    let lhs = pairing(
-        C_h1 + C_h2.mul_scalar(chi) - g.mul_scalar(p_h1 + chi * p_h2),
-        g_hat,
-    ) + pairing(
-        g,
-        {
-            let mut C_hat = C_hat_t.mul_scalar(chi2);
-            if let Some(ComputeLoadProofFields { C_hat_h3, C_hat_w }) = compute_load_proof_fields {
-                C_hat += C_hat_h3.mul_scalar(chi3);
-                C_hat += C_hat_w.mul_scalar(chi4);
-            }
-            C_hat
-        } - g_hat.mul_scalar(p_t * chi2 + p_h3 * chi3 + p_w * chi4),
-    );
-    let rhs = pairing(
+    let kzg_g1 = C_h1 + C_h2.mul_scalar(chi) - g.mul_scalar(p_h1 + chi * p_h2);
+    let kzg_g2 = {
+        let mut C_hat = C_hat_t.mul_scalar(chi2);
+        if let Some(ComputeLoadProofFields { C_hat_h3, C_hat_w }) = compute_load_proof_fields {
+            C_hat += C_hat_h3.mul_scalar(chi3);
+            C_hat += C_hat_w.mul_scalar(chi4);
+        }
+        C_hat
+    } - g_hat.mul_scalar(p_t * chi2 + p_h3 * chi3 + p_w * chi4);
+
+    Ok(PairingChecks {
+        terms,
+        g_hat_pow_scalar,
+        pi,
+        kzg_g1,
+        kzg_g2,
         pi_kzg,
-        G::G2::projective(g_hat_list[0]) - g_hat.mul_scalar(z),
-    );
-    if lhs != rhs {
-        Err(())
-    } else {
-        Ok(())
-    }
+        z,
+    })
 }
 
 #[cfg(test)]
@@ -2625,11 +3368,12 @@ mod tests {
             rng,
         );
 
-        let proof = prove_impl(
+        let (proof, _) = prove_impl(
             (crs, &public_commit),
             &private_commit,
             &testcase.metadata,
             load,
+            &[],
             rng,
             sanity_check_mode,
         );
@@ -3468,4 +4212,292 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_verify_batch() {
+        let PkeTestParameters {
+            d,
+            k,
+            B,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+        } = PKEV2_TEST_PARAMS;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let public_param = crs_gen::<Curve>(d, k, B, q, t, msbs_zero_padding_bit_count, rng);
+
+        let instances = [ComputeLoad::Proof, ComputeLoad::Verify, ComputeLoad::Proof]
+            .into_iter()
+            .map(|load| {
+                let testcase = PkeTestcase::gen(rng, PKEV2_TEST_PARAMS);
+                let ct = testcase.encrypt(PKEV2_TEST_PARAMS);
+
+                let (public_commit, private_commit) = commit(
+                    testcase.a.clone(),
+                    testcase.b.clone(),
+                    ct.c1.clone(),
+                    ct.c2.clone(),
+                    testcase.r.clone(),
+                    testcase.e1.clone(),
+                    testcase.m.clone(),
+                    testcase.e2.clone(),
+                    &public_param,
+                    rng,
+                );
+
+                let proof = prove(
+                    (&public_param, &public_commit),
+                    &private_commit,
+                    &testcase.metadata,
+                    load,
+                    rng,
+                );
+
+                (proof, public_commit, testcase.metadata)
+            })
+            .collect::<Vec<_>>();
+
+        let mut batch = instances
+            .iter()
+            .map(|(proof, public_commit, metadata)| (proof, public_commit, metadata.as_slice()))
+            .collect::<Vec<_>>();
+
+        assert!(verify_batch(&batch, &public_param).is_ok());
+        assert!(verify_batch(&[], &public_param).is_ok());
+
+        let fake_metadata = [255u8; METADATA_LEN];
+        batch[1].2 = &fake_metadata;
+        assert_eq!(verify_batch(&batch, &public_param), Err(vec![1]));
+
+        batch[2].2 = &fake_metadata;
+        assert_eq!(verify_batch(&batch, &public_param), Err(vec![1, 2]));
+
+        // A proof verified against the ciphertexts of another one
+        let (proof_0, _, metadata_0) = &instances[0];
+        let (proof_1, public_commit_1, metadata_1) = &instances[1];
+        let mismatched_batch = [
+            (proof_0, public_commit_1, metadata_0.as_slice()),
+            (proof_1, public_commit_1, metadata_1.as_slice()),
+        ];
+        assert_eq!(verify_batch(&mismatched_batch, &public_param), Err(vec![0]));
+    }
+
+    #[test]
+    fn test_message_bits_relation() {
+        let PkeTestParameters {
+            d,
+            k,
+            B,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+        } = PKEV2_TEST_PARAMS;
+
+        let effective_cleartext_t = t >> msbs_zero_padding_bit_count;
+        let bits_per_message = effective_cleartext_t.ilog2() as usize;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let public_param = crs_gen::<Curve>(d, k, B, q, t, msbs_zero_padding_bit_count, rng);
+
+        let message_bits = |message_index: usize, sign: i128, shift: usize| {
+            (0..bits_per_message)
+                .map(move |bit_index| WeightedMessageBit {
+                    message_index,
+                    bit_index,
+                    weight: sign << (bit_index + shift),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // m_0 + m_1 * t of the first proof minus m_2 of the second one
+        let combinations = [
+            [message_bits(0, 1, 0), message_bits(1, 1, bits_per_message)].concat(),
+            message_bits(2, -1, 0),
+        ];
+
+        let instances = [ComputeLoad::Proof, ComputeLoad::Verify]
+            .into_iter()
+            .zip(&combinations)
+            .map(|(load, combination)| {
+                let testcase = PkeTestcase::gen(rng, PKEV2_TEST_PARAMS);
+                let ct = testcase.encrypt(PKEV2_TEST_PARAMS);
+
+                let (public_commit, private_commit) = commit(
+                    testcase.a.clone(),
+                    testcase.b.clone(),
+                    ct.c1.clone(),
+                    ct.c2.clone(),
+                    testcase.r.clone(),
+                    testcase.e1.clone(),
+                    testcase.m.clone(),
+                    testcase.e2.clone(),
+                    &public_param,
+                    rng,
+                );
+
+                let (proof, openings) = prove_with_message_bits_openings(
+                    (&public_param, &public_commit),
+                    &private_commit,
+                    &testcase.metadata,
+                    load,
+                    &[combination],
+                    rng,
+                );
+
+                assert!(
+                    verify(&proof, (&public_param, &public_commit), &testcase.metadata).is_ok()
+                );
+                assert_eq!(openings.len(), 1);
+
+                (proof, public_commit, openings[0], testcase.m)
+            })
+            .collect::<Vec<_>>();
+
+        let [(proof_0, public_commit_0, opening_0, m_0), (proof_1, public_commit_1, opening_1, m_1)] =
+            instances.as_slice()
+        else {
+            unreachable!()
+        };
+
+        let value = (m_0[0] + m_0[1] * effective_cleartext_t as i64 - m_1[2]) as i128;
+        let opening: MessageBitsOpening<Curve> = [*opening_0, *opening_1].into_iter().sum();
+        let terms = [
+            (proof_0, public_commit_0, combinations[0].as_slice()),
+            (proof_1, public_commit_1, combinations[1].as_slice()),
+        ];
+
+        assert!(opening.is_usable());
+        assert!(verify_message_bits_relation(&opening, &terms, value, &public_param).is_ok());
+        assert!(verify_message_bits_relation(&opening, &terms, value + 1, &public_param).is_err());
+
+        // Each opening only covers the bits of its own proof
+        assert!(verify_message_bits_relation(opening_0, &terms, value, &public_param).is_err());
+        assert!(verify_message_bits_relation(
+            &opening,
+            &[
+                (proof_1, public_commit_1, combinations[0].as_slice()),
+                (proof_0, public_commit_0, combinations[1].as_slice()),
+            ],
+            value,
+            &public_param
+        )
+        .is_err());
+
+        // The combination of a single proof
+        let value_0 = (m_0[0] + m_0[1] * effective_cleartext_t as i64) as i128;
+        assert!(
+            verify_message_bits_relation(opening_0, &terms[..1], value_0, &public_param).is_ok()
+        );
+
+        // The bits must be message bits
+        let out_of_bounds = [
+            WeightedMessageBit {
+                message_index: 0,
+                bit_index: bits_per_message,
+                weight: 1,
+            },
+            WeightedMessageBit {
+                message_index: public_commit_0.c2.len(),
+                bit_index: 0,
+                weight: 1,
+            },
+        ];
+        for bit in out_of_bounds {
+            assert!(verify_message_bits_relation(
+                opening_0,
+                &[(proof_0, public_commit_0, &[bit])],
+                0,
+                &public_param
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_crs_ceremony() {
+        let PkeTestParameters {
+            d,
+            k,
+            B,
+            q,
+            t,
+            msbs_zero_padding_bit_count,
+        } = PKEV2_TEST_PARAMS;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let initial = crs_gen::<Curve>(d, k, B, q, t, msbs_zero_padding_bit_count, rng);
+
+        let mut crs = initial.clone();
+        let mut transcript = Vec::new();
+        for _ in 0..3 {
+            // Each participant checks the previous contributions before adding its own
+            assert!(verify_crs_update_transcript(&initial, &crs, &transcript).is_ok());
+
+            let (updated, proof) = crs.update(rng);
+            assert!(proof.is_usable());
+
+            let serialized = bincode::serialize(&proof).unwrap();
+            let proof: CrsUpdateProof<Curve> = bincode::deserialize(&serialized).unwrap();
+
+            crs = updated;
+            transcript.push(proof);
+        }
+        assert!(verify_crs_update_transcript(&initial, &crs, &transcript).is_ok());
+
+        // Missing or reordered contributions
+        assert!(verify_crs_update_transcript(&initial, &crs, &transcript[1..]).is_err());
+        let mut reordered_transcript = transcript.clone();
+        reordered_transcript.swap(0, 1);
+        assert!(verify_crs_update_transcript(&initial, &crs, &reordered_transcript).is_err());
+
+        // A CRS that does not come from the transcript
+        let unrelated_crs = crs_gen::<Curve>(d, k, B, q, t, msbs_zero_padding_bit_count, rng);
+        assert!(verify_crs_update_transcript(&initial, &unrelated_crs, &transcript).is_err());
+
+        // A CRS with an element that is not a power of the secret
+        let mut tampered_crs = crs.clone();
+        let n = tampered_crs.n;
+        tampered_crs.g_lists.g_list.0.swap(2, 3);
+        assert!(verify_crs_update_transcript(&initial, &tampered_crs, &transcript).is_err());
+        let mut tampered_crs = crs.clone();
+        tampered_crs.g_lists.g_hat_list.0[n - 1] = tampered_crs.g_lists.g_hat_list.0[0];
+        assert!(verify_crs_update_transcript(&initial, &tampered_crs, &transcript).is_err());
+
+        // A proof without the knowledge of the update secret
+        let mut forged_transcript = transcript.clone();
+        forged_transcript[2].schnorr_response += bls12_446::Zp::ONE;
+        assert!(verify_crs_update_transcript(&initial, &crs, &forged_transcript).is_err());
+
+        // The CRS resulting from the ceremony can be used as any other one
+        let testcase = PkeTestcase::gen(rng, PKEV2_TEST_PARAMS);
+        let ct = testcase.encrypt(PKEV2_TEST_PARAMS);
+
+        let (public_commit, private_commit) = commit(
+            testcase.a.clone(),
+            testcase.b.clone(),
+            ct.c1.clone(),
+            ct.c2.clone(),
+            testcase.r.clone(),
+            testcase.e1.clone(),
+            testcase.m.clone(),
+            testcase.e2.clone(),
+            &crs,
+            rng,
+        );
+
+        for load in [ComputeLoad::Proof, ComputeLoad::Verify] {
+            let proof = prove(
+                (&crs, &public_commit),
+                &private_commit,
+                &testcase.metadata,
+                load,
+                rng,
+            );
+            assert!(verify(&proof, (&crs, &public_commit), &testcase.metadata).is_ok());
+        }
+    }
 }
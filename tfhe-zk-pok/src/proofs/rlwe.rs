@@ -1,26 +1,72 @@
 use super::*;
+use crate::backward_compatibility::rlwe::{ComputeLoadProofFieldsVersions, ProofVersions};
+use crate::serialization::{InvalidSerializedPublicParamsError, SerializableRlwePublicParams};
 use core::iter::zip;
 use core::marker::PhantomData;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 fn bit_iter(x: u64, nbits: u32) -> impl Iterator<Item = bool> {
     (0..nbits).map(move |idx| ((x >> idx) & 1) == 1)
 }
 
-#[derive(Clone, Debug)]
+/// Number of bits of the signed decomposition of the values bounded by `b`, which are in
+/// `[-2^ilog2(b), 2^ilog2(b))`
+fn bit_count(b: u64) -> usize {
+    1 + b.ilog2() as usize
+}
+
+/// Check that the bounds are non zero and small enough for the products of the statement to fit
+/// in an i128
+fn are_valid_bounds(d: usize, b_i: &[u64]) -> bool {
+    b_i.iter().all(|&b| b != 0)
+        && b_i
+            .iter()
+            .try_fold(0u64, |acc, &b| acc.checked_add(b))
+            .and_then(|sum| sum.checked_mul(d as u64))
+            .is_some()
+}
+
+/// Bound on the coefficients of the quotients by q of the equations
+fn compute_b_r(d: usize, b_i: &[u64]) -> u64 {
+    ((d as u64 * b_i.iter().sum::<u64>()) / 2).max(1)
+}
+
+/// Number of bits of the witness, made of the decompositions of the rows of `s` and of the
+/// quotients
+fn compute_big_d(d: usize, big_n: usize, b_i: &[u64]) -> usize {
+    d * (b_i.iter().map(|&b| bit_count(b)).sum::<usize>() + big_n * bit_count(compute_b_r(d, b_i)))
+}
+
+/// Public parameters of the proof that `sum_i a_ij * s_i = c_j mod q` for all `j` in `[1, N]`,
+/// where the `a_ij`, `s_i` and `c_j` are polynomials of degree `d` in the negacyclic ring, and each
+/// row `s_i` has its own bound `b_i`.
+///
+/// The coefficients of `s_i` are in `[-2^ilog2(b_i), 2^ilog2(b_i))`, so a bound of 1 restricts them
+/// to `{-1, 0}`.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[serde(
+    try_from = "SerializableRlwePublicParams",
+    into = "SerializableRlwePublicParams",
+    bound(
+        deserialize = "PublicParams<G>: TryFrom<SerializableRlwePublicParams, Error = InvalidSerializedPublicParamsError>",
+        serialize = "PublicParams<G>: Into<SerializableRlwePublicParams>"
+    )
+)]
+#[versionize(try_convert = SerializableRlwePublicParams)]
 pub struct PublicParams<G: Curve> {
-    g_lists: GroupElements<G>,
-    d: usize,
-    big_n: usize,
-    big_m: usize,
-    b_i: u64,
-    q: u64,
-    hash: [u8; HASH_METADATA_LEN_BYTES],
-    hash_t: [u8; HASH_METADATA_LEN_BYTES],
-    hash_agg: [u8; HASH_METADATA_LEN_BYTES],
-    hash_lmap: [u8; HASH_METADATA_LEN_BYTES],
-    hash_z: [u8; HASH_METADATA_LEN_BYTES],
-    hash_w: [u8; HASH_METADATA_LEN_BYTES],
+    pub(crate) g_lists: GroupElements<G>,
+    pub(crate) d: usize,
+    pub(crate) big_n: usize,
+    pub(crate) big_m: usize,
+    pub(crate) b_i: Vec<u64>,
+    pub(crate) q: u64,
+    pub(crate) hash: [u8; HASH_METADATA_LEN_BYTES],
+    pub(crate) hash_t: [u8; HASH_METADATA_LEN_BYTES],
+    pub(crate) hash_agg: [u8; HASH_METADATA_LEN_BYTES],
+    pub(crate) hash_lmap: [u8; HASH_METADATA_LEN_BYTES],
+    pub(crate) hash_z: [u8; HASH_METADATA_LEN_BYTES],
+    pub(crate) hash_w: [u8; HASH_METADATA_LEN_BYTES],
 }
 
 impl<G: Curve> PublicParams<G> {
@@ -31,7 +77,7 @@ impl<G: Curve> PublicParams<G> {
         d: usize,
         big_n: usize,
         big_m: usize,
-        b_i: u64,
+        b_i: Vec<u64>,
         q: u64,
         hash: [u8; HASH_METADATA_LEN_BYTES],
         hash_t: [u8; HASH_METADATA_LEN_BYTES],
@@ -55,6 +101,50 @@ impl<G: Curve> PublicParams<G> {
             hash_w,
         }
     }
+
+    /// Degree `d` of the polynomials of the statement
+    pub fn d(&self) -> usize {
+        self.d
+    }
+
+    /// Number `N` of equations of the statement
+    pub fn big_n(&self) -> usize {
+        self.big_n
+    }
+
+    /// Number of rows `s_i` of the witness
+    pub fn big_m(&self) -> usize {
+        self.big_m
+    }
+
+    /// Bounds `b_i` of the coefficients of the rows of the witness
+    pub fn b_i(&self) -> &[u64] {
+        &self.b_i
+    }
+
+    /// Modulus `q` of the equations, 0 for the native modulus `2^64`
+    pub fn q(&self) -> u64 {
+        self.q
+    }
+
+    /// Check if the crs can be used to generate or verify a proof
+    ///
+    /// This means checking that:
+    /// - the number of elements matches the dimensions of the statement
+    /// - the points are valid points of the curve
+    /// - the points are in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.has_consistent_dimensions() && self.g_lists.is_valid()
+    }
+
+    fn has_consistent_dimensions(&self) -> bool {
+        self.d >= 2
+            && self.big_n != 0
+            && self.big_m != 0
+            && self.b_i.len() == self.big_m
+            && are_valid_bounds(self.d, &self.b_i)
+            && self.g_lists.message_len == compute_big_d(self.d, self.big_n, &self.b_i) + 1
+    }
 }
 
 #[allow(dead_code)]
@@ -70,38 +160,95 @@ pub struct PublicCommit<G: Curve> {
     __marker: PhantomData<G>,
 }
 
+impl<G: Curve> PublicCommit<G> {
+    pub fn new(a: Matrix<i64>, c: Vector<i64>) -> Self {
+        Self {
+            a,
+            c,
+            __marker: PhantomData,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PrivateCommit<G: Curve> {
     s: Vector<i64>,
     __marker: PhantomData<G>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
+))]
+#[versionize(ProofVersions)]
 pub struct Proof<G: Curve> {
-    c_hat: G::G2,
-    c_y: G::G1,
-    pi: G::G1,
-    compute_load_proof_fields: Option<ComputeLoadProofFields<G>>,
+    pub(crate) c_hat: G::G2,
+    pub(crate) c_y: G::G1,
+    pub(crate) pi: G::G1,
+    pub(crate) compute_load_proof_fields: Option<ComputeLoadProofFields<G>>,
 }
 
-#[derive(Clone, Debug)]
-struct ComputeLoadProofFields<G: Curve> {
-    c_hat_t: G::G2,
-    c_h: G::G1,
-    pi_kzg: G::G1,
+impl<G: Curve> Proof<G> {
+    /// Check if the proof can be used by the Verifier.
+    ///
+    /// This means checking that the points in the proof are:
+    /// - valid points of the curve
+    /// - in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        let &Proof {
+            c_hat,
+            c_y,
+            pi,
+            ref compute_load_proof_fields,
+        } = self;
+
+        c_hat.validate_projective()
+            && c_y.validate_projective()
+            && pi.validate_projective()
+            && compute_load_proof_fields.as_ref().is_none_or(
+                |&ComputeLoadProofFields {
+                     c_hat_t,
+                     c_h,
+                     pi_kzg,
+                 }| {
+                    c_hat_t.validate_projective()
+                        && c_h.validate_projective()
+                        && pi_kzg.validate_projective()
+                },
+            )
+    }
+}
+
+/// These fields can be pre-computed on the prover side in the faster Verifier scheme. If that's the
+/// case, they should be included in the proof.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(ComputeLoadProofFieldsVersions)]
+pub(crate) struct ComputeLoadProofFields<G: Curve> {
+    pub(crate) c_hat_t: G::G2,
+    pub(crate) c_h: G::G1,
+    pub(crate) pi_kzg: G::G1,
 }
 
+/// Generates the public parameters of the proofs, see [PublicParams] for the meaning of the
+/// dimensions.
+///
+/// `b_i` holds one bound per row of the witness, so it must have `big_m` elements.
 pub fn crs_gen<G: Curve>(
     d: usize,
     big_n: usize,
     big_m: usize,
-    b_i: u64,
+    b_i: Vec<u64>,
     q: u64,
     rng: &mut dyn RngCore,
 ) -> PublicParams<G> {
+    assert!(d >= 2);
+    assert!(big_n != 0 && big_m != 0);
+    assert_eq!(b_i.len(), big_m);
+    assert!(are_valid_bounds(d, &b_i));
+
     let alpha = G::Zp::rand(rng);
-    let b_r = ((d * big_m) as u64 * b_i) / 2;
-    let big_d = d * (big_m * (1 + b_i.ilog2() as usize) + (big_n * (1 + b_r.ilog2() as usize)));
+    let big_d = compute_big_d(d, big_n, &b_i);
     let n = big_d + 1;
     PublicParams {
         g_lists: GroupElements::new(n, alpha),
@@ -217,7 +364,7 @@ pub fn prove<G: Curve>(
         d,
         big_n,
         big_m,
-        b_i,
+        ref b_i,
         q,
         ref hash,
         ref hash_t,
@@ -226,33 +373,42 @@ pub fn prove<G: Curve>(
         ref hash_z,
         ref hash_w,
     } = public.0;
+    assert!(public.0.has_consistent_dimensions());
+
     let g_list = &g_lists.g_list;
     let g_hat_list = &g_lists.g_hat_list;
     let s = &private_commit.s;
     let a = &public.1.a;
+    let c = &public.1.c;
 
-    let b_r = ((d * big_m) as u64 * b_i) / 2;
-    let big_d = d * (big_m * (1 + b_i.ilog2() as usize) + (big_n * (1 + b_r.ilog2() as usize)));
+    assert_eq!(s.data.len(), d * big_m);
+    assert_eq!(a.data.len(), d * big_m * big_n);
+    assert_eq!(c.data.len(), d * big_n);
+    for (i, &b) in b_i.iter().enumerate() {
+        let half_range = 1i64 << b.ilog2();
+        assert!(s[i + 1]
+            .iter()
+            .all(|&x| (-half_range..half_range).contains(&x)));
+    }
+
+    let b_r = compute_b_r(d, b_i);
+    let big_d = compute_big_d(d, big_n, b_i);
     let n = big_d + 1;
+    let decoded_q = decode_q(q);
 
     let g = G::G1::GENERATOR;
     let g_hat = G::G2::GENERATOR;
     let gamma = G::Zp::rand(rng);
     let gamma_y = G::Zp::rand(rng);
 
-    let mut c = Vector {
-        data: vec![0i64; d],
-        polynomial_size: d,
-        nrows: big_n,
-    };
     let mut r = Vector {
-        data: vec![0i64; d],
+        data: vec![0i64; d * big_n],
         polynomial_size: d,
         nrows: big_n,
     };
 
     for j in 1..big_n + 1 {
-        let c = &mut c[j];
+        let c = &c[j];
         let r = &mut r[j];
 
         let mut polymul = vec![0i128; d];
@@ -272,24 +428,23 @@ pub fn prove<G: Curve>(
             }
         }
 
-        for ((ck, rk), old_ck) in zip(zip(c, r), &polymul) {
-            let q = if q == 0 { q as i128 } else { 1i128 << 64 };
-            let mut new_ck = old_ck.rem_euclid(q);
-            if new_ck >= q / 2 {
-                new_ck -= q;
-            }
-            assert!((old_ck - new_ck) % q == 0);
-            assert!((*rk).unsigned_abs() < b_r);
+        for ((&ck, rk), &old_ck) in zip(zip(c, r), &polymul) {
+            let q = decoded_q as i128;
+            let diff = old_ck - ck as i128;
+            assert!(diff % q == 0);
 
-            *ck = new_ck as i64;
-            *rk = ((old_ck - new_ck) / q) as i64;
+            let new_rk = diff / q;
+            let half_range = 1i128 << b_r.ilog2();
+            assert!((-half_range..half_range).contains(&new_rk));
+            *rk = new_rk as i64;
         }
     }
     let w_tilde = Iterator::chain(
         (1..big_m + 1).flat_map(|i| {
+            let nbits = bit_count(b_i[i - 1]) as u32;
             s[i].iter()
                 .copied()
-                .flat_map(|x| bit_iter(x as u64, b_i.ilog2() + 1))
+                .flat_map(move |x| bit_iter(x as u64, nbits))
         }),
         (1..big_n + 1).flat_map(|i| {
             r[i].iter()
@@ -317,7 +472,10 @@ pub fn prove<G: Curve>(
         &(d as u64).to_le_bytes(),
         &(big_m as u64).to_le_bytes(),
         &(big_n as u64).to_le_bytes(),
-        &b_i.to_le_bytes(),
+        &*b_i
+            .iter()
+            .flat_map(|b| b.to_le_bytes())
+            .collect::<Box<[u8]>>(),
         &*(1..big_m + 1)
             .flat_map(|i| {
                 (1..big_n + 1).flat_map(move |j| a[(i, j)].iter().flat_map(|ai| ai.to_le_bytes()))
@@ -338,7 +496,7 @@ pub fn prove<G: Curve>(
     let y = OneBased(y);
 
     let scalars = (n + 1 - big_d..n + 1)
-        .map(|j| (y[n + 1 - j] * G::Zp::from_u64(w[n + 1 - j] as u64)))
+        .map(|j| y[n + 1 - j] * G::Zp::from_u64(w[n + 1 - j] as u64))
         .collect::<Vec<_>>();
     let c_y = g.mul_scalar(gamma_y) + G::G1::multi_mul_scalar(&g_list.0[n - big_d..n], &scalars);
 
@@ -381,11 +539,14 @@ pub fn prove<G: Curve>(
     }
 
     let mut a_theta = vec![G::Zp::ZERO; big_d];
-    let b_step = 1 + b_i.ilog2() as usize;
-    let step = d * b_step;
-    for i in 0..big_m {
+    let mut offset_m = 0;
+    for (i, &b) in b_i.iter().enumerate() {
+        let b_step = bit_count(b);
+        let step = d * b_step;
+
         // a_theta_i = A_tilde_{i + 1}.T × theta0
-        let a_theta_i = &mut a_theta[step * i..][..step];
+        let a_theta_i = &mut a_theta[offset_m..][..step];
+        offset_m += step;
 
         for j in 0..big_n {
             let aij = &a[(i + 1, j + 1)];
@@ -415,14 +576,13 @@ pub fn prove<G: Curve>(
                 }
 
                 for (dst, src) in zip(a_theta_ik.iter_mut().step_by(b_step), &rot_aij_theta0_j) {
-                    *dst = c * *src;
+                    *dst += c * *src;
                 }
             }
         }
     }
 
-    let offset_m = step * big_m;
-    let b_step = 1 + b_r.ilog2() as usize;
+    let b_step = bit_count(b_r);
     let step = d * b_step;
     for j in 0..big_n {
         // a_theta_j -= q G.T theta0_j
@@ -431,7 +591,7 @@ pub fn prove<G: Curve>(
 
         for k in 0..b_step {
             let a_theta_jk = &mut a_theta_j[k..];
-            let mut c = -G::Zp::from_u64(1 << k) * G::Zp::from_u64(q);
+            let mut c = -G::Zp::from_u64(1 << k) * G::Zp::from_u128(decoded_q);
             if k + 1 == b_step {
                 c = -c;
             }
@@ -634,7 +794,7 @@ pub fn verify<G: Curve>(
         d,
         big_n,
         big_m,
-        b_i,
+        ref b_i,
         q,
         ref hash,
         ref hash_t,
@@ -643,22 +803,34 @@ pub fn verify<G: Curve>(
         ref hash_z,
         ref hash_w,
     } = public.0;
+
+    let a = &public.1.a;
+    let c = &public.1.c;
+
+    if !public.0.has_consistent_dimensions()
+        || a.data.len() != d * big_m * big_n
+        || c.data.len() != d * big_n
+    {
+        return Err(());
+    }
+
     let g_list = &g_lists.g_list;
     let g_hat_list = &g_lists.g_hat_list;
 
-    let b_r = ((d * big_m) as u64 * b_i) / 2;
-    let big_d = d * (big_m * (1 + b_i.ilog2() as usize) + (big_n * (1 + b_r.ilog2() as usize)));
+    let b_r = compute_b_r(d, b_i);
+    let big_d = compute_big_d(d, big_n, b_i);
     let n = big_d + 1;
-
-    let a = &public.1.a;
-    let c = &public.1.c;
+    let decoded_q = decode_q(q);
 
     let x_bytes = &*[
         &q.to_le_bytes(),
         &(d as u64).to_le_bytes(),
         &(big_m as u64).to_le_bytes(),
         &(big_n as u64).to_le_bytes(),
-        &b_i.to_le_bytes(),
+        &*b_i
+            .iter()
+            .flat_map(|b| b.to_le_bytes())
+            .collect::<Box<[u8]>>(),
         &*(1..big_m + 1)
             .flat_map(|i| {
                 (1..big_n + 1).flat_map(move |j| a[(i, j)].iter().flat_map(|ai| ai.to_le_bytes()))
@@ -729,11 +901,14 @@ pub fn verify<G: Curve>(
     }
 
     let mut a_theta = vec![G::Zp::ZERO; big_d];
-    let b_step = 1 + b_i.ilog2() as usize;
-    let step = d * b_step;
-    for i in 0..big_m {
+    let mut offset_m = 0;
+    for (i, &b) in b_i.iter().enumerate() {
+        let b_step = bit_count(b);
+        let step = d * b_step;
+
         // a_theta_i = A_tilde_{i + 1}.T × theta0
-        let a_theta_i = &mut a_theta[step * i..][..step];
+        let a_theta_i = &mut a_theta[offset_m..][..step];
+        offset_m += step;
 
         for j in 0..big_n {
             let aij = &a[(i + 1, j + 1)];
@@ -763,14 +938,13 @@ pub fn verify<G: Curve>(
                 }
 
                 for (dst, src) in zip(a_theta_ik.iter_mut().step_by(b_step), &rot_aij_theta0_j) {
-                    *dst = c * *src;
+                    *dst += c * *src;
                 }
             }
         }
     }
 
-    let offset_m = step * big_m;
-    let b_step = 1 + b_r.ilog2() as usize;
+    let b_step = bit_count(b_r);
     let step = d * b_step;
     for j in 0..big_n {
         // a_theta_j -= q G.T theta0_j
@@ -779,7 +953,7 @@ pub fn verify<G: Curve>(
 
         for k in 0..b_step {
             let a_theta_jk = &mut a_theta_j[k..];
-            let mut c = -G::Zp::from_u64(1 << k) * G::Zp::from_u64(q);
+            let mut c = -G::Zp::from_u64(1 << k) * G::Zp::from_u128(decoded_q);
             if k + 1 == b_step {
                 c = -c;
             }
@@ -941,6 +1115,46 @@ mod tests {
         r
     }
 
+    /// Compute the `c_j = sum_i a_ij * s_i mod q`, centered around 0
+    fn compute_c(a: &Matrix<i64>, s: &Vector<i64>, q: u64) -> Vector<i64> {
+        let d = a.polynomial_size;
+        let big_m = a.nrows;
+        let big_n = a.ncols;
+        let mut c = Vector::new(d, big_n, 0i64);
+
+        for j in 1..big_n + 1 {
+            let c = &mut c[j];
+
+            let mut polymul = vec![0i128; d];
+            for i in 1..big_m + 1 {
+                let si = &s[i];
+                let aij = &a[(i, j)];
+
+                for ii in 0..d {
+                    for jj in 0..d {
+                        let p = (aij[ii] as i128) * si[jj] as i128;
+                        if ii + jj < d {
+                            polymul[ii + jj] += p;
+                        } else {
+                            polymul[ii + jj - d] -= p;
+                        }
+                    }
+                }
+            }
+
+            for (ck, old_ck) in core::iter::zip(c, &polymul) {
+                let q = decode_q(q) as i128;
+                let mut new_ck = old_ck.rem_euclid(q);
+                if new_ck >= q / 2 {
+                    new_ck -= q;
+                }
+                *ck = new_ck as i64;
+            }
+        }
+
+        c
+    }
+
     #[test]
     fn test_rlwe() {
         let rng = &mut StdRng::seed_from_u64(0);
@@ -952,7 +1166,6 @@ mod tests {
         let b_i: u64 = 512;
 
         let mut a = Matrix::new(d, big_m, big_n, 0i64);
-        let mut c = Vector::new(d, big_n, 0i64);
         let mut s = Vector::new(d, big_m, 0i64);
 
         for i in 0..big_m {
@@ -973,37 +1186,10 @@ mod tests {
             }
         }
 
-        for j in 1..big_n + 1 {
-            let c = &mut c[j];
+        let c = compute_c(&a, &s, q);
 
-            let mut polymul = vec![0i128; d];
-            for i in 1..big_m + 1 {
-                let si = &s[i];
-                let aij = &a[(i, j)];
-
-                for ii in 0..d {
-                    for jj in 0..d {
-                        let p = (aij[ii] as i128) * si[jj] as i128;
-                        if ii + jj < d {
-                            polymul[ii + jj] += p;
-                        } else {
-                            polymul[ii + jj - d] -= p;
-                        }
-                    }
-                }
-            }
-
-            for (ck, old_ck) in core::iter::zip(c, &polymul) {
-                let q = if q == 0 { q as i128 } else { 1i128 << 64 };
-                let mut new_ck = old_ck.rem_euclid(q);
-                if new_ck >= q / 2 {
-                    new_ck -= q;
-                }
-                *ck = new_ck as i64;
-            }
-        }
-
-        let public_params = crs_gen::<crate::curve_api::Bls12_446>(d, big_n, big_m, b_i, q, rng);
+        let public_params =
+            crs_gen::<crate::curve_api::Bls12_446>(d, big_n, big_m, vec![b_i], q, rng);
         let (public_commit, private_commit) = commit(a, c, s, &public_params, rng);
         for load in [ComputeLoad::Proof, ComputeLoad::Verify] {
             let proof =
@@ -1012,4 +1198,56 @@ mod tests {
             assert!(verify.is_ok());
         }
     }
+
+    /// Prove an rlwe sample `c = a * s + e mod 2^64` with a binary key and a bounded noise, using
+    /// one bound per row
+    #[test]
+    fn test_rlwe_row_bounds() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let d: usize = 512;
+        let big_m: usize = 2;
+        let big_n: usize = 1;
+
+        // Native modulus
+        let q = 0;
+        let b_e: u64 = 1 << 10;
+
+        let mut a = Matrix::new(d, big_m, big_n, 0i64);
+        let mut s = Vector::new(d, big_m, 0i64);
+
+        for k in 0..d {
+            // The key row is in {-1, 0}
+            s[1][k] = -((rng.gen::<u64>() % 2) as i64);
+            s[2][k] = (rng.gen::<u64>() % (2 * b_e)) as i64 - b_e as i64;
+            a[(1, 1)][k] = rng.gen::<i64>();
+        }
+        a[(2, 1)][0] = 1;
+
+        let c = compute_c(&a, &s, q);
+
+        let public_params =
+            crs_gen::<crate::curve_api::Bls12_446>(d, big_n, big_m, vec![1, b_e], q, rng);
+        assert!(public_params.is_usable());
+
+        let public_params: PublicParams<crate::curve_api::Bls12_446> =
+            bincode::deserialize(&bincode::serialize(&public_params).unwrap()).unwrap();
+
+        let mut c_wrong = c.clone();
+        c_wrong[1][0] = c_wrong[1][0].wrapping_add(1);
+
+        let (public_commit, private_commit) = commit(a.clone(), c, s, &public_params, rng);
+        let (public_commit_wrong, _) =
+            commit(a, c_wrong, Vector::new(d, big_m, 0), &public_params, rng);
+
+        for load in [ComputeLoad::Proof, ComputeLoad::Verify] {
+            let proof = prove((&public_params, &public_commit), &private_commit, load, rng);
+            assert!(proof.is_usable());
+
+            let proof: Proof<crate::curve_api::Bls12_446> =
+                bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap();
+
+            assert!(verify(&proof, (&public_params, &public_commit)).is_ok());
+            assert!(verify(&proof, (&public_params, &public_commit_wrong)).is_err());
+        }
+    }
 }
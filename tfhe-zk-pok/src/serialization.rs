@@ -8,6 +8,7 @@ use crate::backward_compatibility::{
     SerializableAffineVersions, SerializableCubicExtFieldVersions, SerializableFpVersions,
     SerializableGroupElementsVersions, SerializablePKEv1PublicParamsVersions,
     SerializablePKEv2PublicParamsVersions, SerializableQuadExtFieldVersions,
+    SerializableRlwePublicParamsVersions,
 };
 use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
 use ark_ec::AffineRepr;
@@ -18,6 +19,7 @@ use tfhe_versionable::Versionize;
 use crate::curve_api::{Curve, CurveGroupOps};
 use crate::proofs::pke::PublicParams as PKEv1PublicParams;
 use crate::proofs::pke_v2::{Bound, PublicParams as PKEv2PublicParams};
+use crate::proofs::rlwe::PublicParams as RlwePublicParams;
 use crate::proofs::GroupElements;
 
 /// Error returned when a conversion from a vec to a fixed size array failed because the vec size is
@@ -639,3 +641,96 @@ where
         })
     }
 }
+
+#[derive(serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SerializableRlwePublicParamsVersions)]
+pub struct SerializableRlwePublicParams {
+    pub(crate) g_lists: SerializableGroupElements,
+    pub d: usize,
+    pub big_n: usize,
+    pub big_m: usize,
+    pub b_i: Vec<u64>,
+    pub q: u64,
+    // We use Vec<u8> since serde does not support fixed size arrays of 256 elements
+    pub(crate) hash: Vec<u8>,
+    pub(crate) hash_t: Vec<u8>,
+    pub(crate) hash_agg: Vec<u8>,
+    pub(crate) hash_lmap: Vec<u8>,
+    pub(crate) hash_z: Vec<u8>,
+    pub(crate) hash_w: Vec<u8>,
+}
+
+impl<G: Curve> From<RlwePublicParams<G>> for SerializableRlwePublicParams
+where
+    GroupElements<G>: Into<SerializableGroupElements>,
+{
+    fn from(value: RlwePublicParams<G>) -> Self {
+        let RlwePublicParams {
+            g_lists,
+            d,
+            big_n,
+            big_m,
+            b_i,
+            q,
+            hash,
+            hash_t,
+            hash_agg,
+            hash_lmap,
+            hash_z,
+            hash_w,
+        } = value;
+        Self {
+            g_lists: g_lists.into(),
+            d,
+            big_n,
+            big_m,
+            b_i,
+            q,
+            hash: hash.to_vec(),
+            hash_t: hash_t.to_vec(),
+            hash_agg: hash_agg.to_vec(),
+            hash_lmap: hash_lmap.to_vec(),
+            hash_z: hash_z.to_vec(),
+            hash_w: hash_w.to_vec(),
+        }
+    }
+}
+
+impl<G: Curve> TryFrom<SerializableRlwePublicParams> for RlwePublicParams<G>
+where
+    GroupElements<G>:
+        TryFrom<SerializableGroupElements, Error = InvalidSerializedGroupElementsError>,
+{
+    type Error = InvalidSerializedPublicParamsError;
+
+    fn try_from(value: SerializableRlwePublicParams) -> Result<Self, Self::Error> {
+        let SerializableRlwePublicParams {
+            g_lists,
+            d,
+            big_n,
+            big_m,
+            b_i,
+            q,
+            hash,
+            hash_t,
+            hash_agg,
+            hash_lmap,
+            hash_z,
+            hash_w,
+        } = value;
+        Ok(Self {
+            g_lists: g_lists.try_into()?,
+            d,
+            big_n,
+            big_m,
+            b_i,
+            q,
+            hash: try_vec_to_array(hash)?,
+            hash_t: try_vec_to_array(hash_t)?,
+            hash_agg: try_vec_to_array(hash_agg)?,
+            hash_lmap: try_vec_to_array(hash_lmap)?,
+            hash_z: try_vec_to_array(hash_z)?,
+            hash_w: try_vec_to_array(hash_w)?,
+        })
+    }
+}
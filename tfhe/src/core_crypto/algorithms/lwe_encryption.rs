@@ -2698,6 +2698,70 @@ pub fn encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key<
     metadata: &[u8],
     load: ZkComputeLoad,
 ) -> crate::Result<CompactPkeProof>
+where
+    Scalar: Encryptable<MaskDistribution, NoiseDistribution>
+        + RandomGenerable<UniformBinary>
+        + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
+    MaskDistribution: BoundedDistribution<Scalar::Signed>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings(
+        lwe_compact_public_key,
+        output,
+        messages,
+        delta,
+        mask_noise_distribution,
+        body_noise_distribution,
+        secret_generator,
+        encryption_generator,
+        random_generator,
+        crs,
+        metadata,
+        load,
+        &[],
+    )
+    .map(|(proof, _)| proof)
+}
+
+/// Same as [`encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key`], also
+/// returning one opening per linear combination of the message bits in `combinations`, see
+/// [`CompactPkeCrs::prove_with_message_bits_openings`].
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    MaskDistribution,
+    NoiseDistribution,
+    SecretGen,
+    EncryptionGen,
+    G,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    mask_noise_distribution: MaskDistribution,
+    body_noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    crs: &CompactPkeCrs,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+    combinations: &[&[crate::zk::WeightedMessageBit]],
+) -> crate::Result<(CompactPkeProof, Vec<MessageBitsOpening>)>
 where
     Scalar: Encryptable<MaskDistribution, NoiseDistribution>
         + RandomGenerable<UniformBinary>
@@ -2747,7 +2811,7 @@ where
         encryption_generator,
     );
 
-    Ok(crs.prove(
+    Ok(crs.prove_with_message_bits_openings(
         lwe_compact_public_key,
         messages,
         output,
@@ -2756,6 +2820,7 @@ where
         &body_noise,
         metadata,
         load,
+        combinations,
         random_generator,
     ))
 }
@@ -3182,6 +3247,70 @@ pub fn par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key
     metadata: &[u8],
     load: ZkComputeLoad,
 ) -> crate::Result<CompactPkeProof>
+where
+    Scalar: Encryptable<MaskDistribution, NoiseDistribution>
+        + RandomGenerable<UniformBinary>
+        + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
+    MaskDistribution: BoundedDistribution<Scalar::Signed>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    par_encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings(
+        lwe_compact_public_key,
+        output,
+        messages,
+        delta,
+        mask_noise_distribution,
+        body_noise_distribution,
+        secret_generator,
+        encryption_generator,
+        random_generator,
+        crs,
+        metadata,
+        load,
+        &[],
+    )
+    .map(|(proof, _)| proof)
+}
+
+/// Same as [`par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key`], also
+/// returning one opening per linear combination of the message bits in `combinations`, see
+/// [`CompactPkeCrs::prove_with_message_bits_openings`].
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn par_encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    MaskDistribution,
+    NoiseDistribution,
+    SecretGen,
+    EncryptionGen,
+    G,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    mask_noise_distribution: MaskDistribution,
+    body_noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    crs: &CompactPkeCrs,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+    combinations: &[&[crate::zk::WeightedMessageBit]],
+) -> crate::Result<(CompactPkeProof, Vec<MessageBitsOpening>)>
 where
     Scalar: Encryptable<MaskDistribution, NoiseDistribution>
         + RandomGenerable<UniformBinary>
@@ -3231,7 +3360,7 @@ where
         encryption_generator,
     );
 
-    Ok(crs.prove(
+    Ok(crs.prove_with_message_bits_openings(
         lwe_compact_public_key,
         messages,
         output,
@@ -3240,6 +3369,7 @@ where
         &body_noise,
         metadata,
         load,
+        combinations,
         random_generator,
     ))
 }
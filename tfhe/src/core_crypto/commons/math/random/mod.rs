@@ -248,6 +248,15 @@ impl<T: UnsignedInteger> DynamicDistribution<T> {
         }
     }
 
+    /// Returns the variance of the distribution on the torus, `modulus` being used to normalize the
+    /// TUniform distributions which are defined on integers.
+    pub fn variance(&self, modulus: f64) -> Variance {
+        match self {
+            Self::Gaussian(gaussian) => StandardDev::from_standard_dev(gaussian.std).get_variance(),
+            Self::TUniform(t_uniform) => t_uniform.variance(modulus),
+        }
+    }
+
     #[track_caller]
     pub fn gaussian_variance(&self) -> Variance {
         match self {
@@ -1,6 +1,7 @@
 use crate::core_crypto::backward_compatibility::commons::math::random::TUniformVersions;
 
 use super::*;
+use crate::core_crypto::commons::dispersion::Variance;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -70,6 +71,14 @@ impl<T: UnsignedInteger> TUniform<T> {
     pub fn max_value_inclusive(&self) -> T::Signed {
         T::Signed::ONE << self.bound_log2 as usize
     }
+
+    /// Returns the variance of the distribution normalized by `modulus`, i.e. on the torus.
+    pub fn variance(&self, modulus: f64) -> Variance {
+        // The values in ]-2^b, 2^b[ have a probability of 2^-(b+1) and the two bounds of
+        // 2^-(b+2), which gives a variance of (2 * 4^b + 1) / 6
+        let bound = 2.0f64.powi(self.bound_log2 as i32);
+        Variance((2.0 * bound * bound + 1.0) / 6.0 / (modulus * modulus))
+    }
 }
 
 macro_rules! implement_t_uniform_uint {
@@ -690,6 +690,29 @@ fn test_t_uniform_random_u64() {
     test_random_from_distribution_custom_mod::<u64, TUniform<_>>(bound_log2, ciphertext_modulus);
 }
 
+#[test]
+fn test_t_uniform_variance() {
+    for bound_log2 in [0u32, 1, 5, 11] {
+        let t_uniform = TUniform::<u64>::new(bound_log2);
+        let bound = 1i64 << bound_log2;
+
+        // Exact variance from the probabilities of the values, without normalization
+        let expected_variance: f64 = (-bound..=bound)
+            .map(|value| {
+                let probability = if value.abs() == bound {
+                    2.0f64.powi(-(bound_log2 as i32 + 2))
+                } else {
+                    2.0f64.powi(-(bound_log2 as i32 + 1))
+                };
+                probability * (value * value) as f64
+            })
+            .sum();
+
+        let variance = t_uniform.variance(1.0).0;
+        assert!((variance - expected_variance).abs() <= expected_variance * 1e-12);
+    }
+}
+
 #[test]
 fn test_uniform_sample_success_probability() {
     {
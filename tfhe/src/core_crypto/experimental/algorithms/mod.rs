@@ -7,6 +7,8 @@ pub mod pseudo_ggsw_conversion;
 pub mod pseudo_ggsw_encryption;
 pub mod shared_glwe_secret_key_generation;
 pub mod shared_lwe_secret_key_generation;
+pub mod threshold_decryption;
+pub mod threshold_key_generation;
 
 pub use glwe_fast_keyswitch::*;
 pub use glwe_partial_sample_extraction::*;
@@ -17,6 +19,8 @@ pub use pseudo_ggsw_conversion::*;
 pub use pseudo_ggsw_encryption::*;
 pub use shared_glwe_secret_key_generation::*;
 pub use shared_lwe_secret_key_generation::*;
+pub use threshold_decryption::*;
+pub use threshold_key_generation::*;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,77 @@
+//! Module containing primitives pertaining to the decryption of [`LWE
+//! ciphertexts`](`LweCiphertext`) by parties holding additive shares of the [`LWE secret
+//! key`](`LweSecretKey`).
+//!
+//! Each party publishes a partial decryption: the dot product of the ciphertext mask with its key
+//! share, to which a smudging noise is added so that the partial decryption does not leak
+//! information about the key share. The plaintext is recovered by subtracting all the partial
+//! decryptions from the ciphertext body, the smudging noises of all parties adding up to the noise
+//! of the ciphertext.
+
+use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, RandomGenerator};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Compute the partial decryption of an [`LWE ciphertext`](`LweCiphertext`) with a share of the
+/// [`LWE secret key`](`LweSecretKey`), adding a noise drawn from the smudging distribution.
+pub fn partial_decrypt_lwe_ciphertext<Scalar, SmudgingDistribution, KeyCont, InputCont, Gen>(
+    lwe_secret_key_share: &LweSecretKey<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    smudging_noise_distribution: SmudgingDistribution,
+    generator: &mut RandomGenerator<Gen>,
+) -> Scalar
+where
+    Scalar: UnsignedInteger + RandomGenerable<SmudgingDistribution>,
+    SmudgingDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        lwe_ciphertext.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    assert!(
+        lwe_ciphertext.lwe_size().to_lwe_dimension() == lwe_secret_key_share.lwe_dimension(),
+        "Mismatch between LweDimension of input ciphertext and input secret key share. \
+        Got {:?} in input, and {:?} in secret key share.",
+        lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        lwe_secret_key_share.lwe_dimension()
+    );
+
+    let mask_key_dot_product = slice_wrapping_dot_product(
+        lwe_ciphertext.get_mask().as_ref(),
+        lwe_secret_key_share.as_ref(),
+    );
+    let smudging_noise: Scalar = generator.random_from_distribution(smudging_noise_distribution);
+
+    mask_key_dot_product.wrapping_add(smudging_noise)
+}
+
+/// Combine the partial decryptions of all parties for an [`LWE ciphertext`](`LweCiphertext`),
+/// returning the noisy [`Plaintext`].
+pub fn combine_lwe_partial_decryptions<Scalar, InputCont>(
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    partial_decryptions: &[Scalar],
+) -> Plaintext<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        lwe_ciphertext.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    let body = *lwe_ciphertext.get_body().data;
+
+    Plaintext(
+        partial_decryptions
+            .iter()
+            .fold(body, |acc, &partial_decryption| {
+                acc.wrapping_sub(partial_decryption)
+            }),
+    )
+}
@@ -0,0 +1,259 @@
+//! Module containing primitives pertaining to the generation of shares of public keys, from
+//! additive shares of secret keys.
+//!
+//! Each party holds an additive share (modulo the native ciphertext modulus) of the secret keys,
+//! and all parties use [`EncryptionRandomGenerator`] instances seeded with the same mask seed, but
+//! with their own private noise seeder. The masks drawn by the parties are therefore identical, and
+//! every key is linear in the secret key shares: the key is obtained by summing the shares of all
+//! parties coefficient-wise.
+//!
+//! Only the leading party keeps the common mask in its share, the others zero it out so that the
+//! sum of the shares contains the mask exactly once. The noise drawn by each party is added in the
+//! sum, the caller is responsible for scaling the noise distribution passed to each party so that
+//! the sum has the expected variance.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Encrypt a share of a plaintext in a [`GGSW ciphertext`](`GgswCiphertext`) in the constant
+/// coefficient, using a share of the [`GLWE secret key`](`GlweSecretKey`).
+///
+/// The rows of the level matrices are encrypted as encryptions of zero, and the plaintext share
+/// is moved to the mask: a row encrypting $\mathsf{S}\_k \cdot f$ with mask $\vec{A}$ is
+/// distributed exactly as the encryption of zero with mask $\vec{A} - f \cdot \vec{e}\_k$, $f$
+/// being the [`multiplicative factor`](`ggsw_encryption_multiplicative_factor`) of the level.
+/// Contrary to the regular encryption, the output is thus linear in both the key share and the
+/// cleartext share.
+///
+/// If `is_leading_party` is false, the common mask is zeroed out of the output.
+pub fn encrypt_constant_ggsw_ciphertext_share<Scalar, NoiseDistribution, KeyCont, OutputCont, Gen>(
+    glwe_secret_key_share: &GlweSecretKey<KeyCont>,
+    output: &mut GgswCiphertext<OutputCont>,
+    cleartext_share: Cleartext<Scalar>,
+    is_leading_party: bool,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    assert!(
+        output.polynomial_size() == glwe_secret_key_share.polynomial_size(),
+        "Mismatch between polynomial sizes of output ciphertexts and input secret key share. \
+        Got {:?} in output, and {:?} in secret key share.",
+        output.polynomial_size(),
+        glwe_secret_key_share.polynomial_size()
+    );
+
+    assert!(
+        output.glwe_size().to_glwe_dimension() == glwe_secret_key_share.glwe_dimension(),
+        "Mismatch between GlweDimension of output ciphertexts and input secret key share. \
+        Got {:?} in output, and {:?} in secret key share.",
+        output.glwe_size().to_glwe_dimension(),
+        glwe_secret_key_share.glwe_dimension()
+    );
+
+    let gen_iter = generator
+        .try_fork_from_config(output.encryption_fork_config(Uniform, noise_distribution))
+        .expect("Failed to split generator into ggsw levels");
+
+    let decomp_base_log = output.decomposition_base_log();
+    let decomp_level_count = output.decomposition_level_count();
+    let ciphertext_modulus = output.ciphertext_modulus();
+
+    for (output_index, (mut level_matrix, mut generator)) in
+        output.iter_mut().zip(gen_iter).enumerate()
+    {
+        let decomp_level = DecompositionLevel(decomp_level_count.0 - output_index);
+        let factor = ggsw_encryption_multiplicative_factor(
+            ciphertext_modulus,
+            decomp_level,
+            decomp_base_log,
+            cleartext_share,
+        );
+
+        let gen_iter = generator
+            .try_fork_from_config(level_matrix.encryption_fork_config(Uniform, noise_distribution))
+            .expect("Failed to split generator into glwe");
+
+        let last_row_index = level_matrix.glwe_size().0 - 1;
+
+        for ((row_index, mut row_as_glwe), mut generator) in level_matrix
+            .as_mut_glwe_list()
+            .iter_mut()
+            .enumerate()
+            .zip(gen_iter)
+        {
+            row_as_glwe.as_mut().fill(Scalar::ZERO);
+            encrypt_glwe_ciphertext_assign(
+                glwe_secret_key_share,
+                &mut row_as_glwe,
+                noise_distribution,
+                &mut generator,
+            );
+
+            if !is_leading_party {
+                row_as_glwe.get_mut_mask().as_mut().fill(Scalar::ZERO);
+            }
+
+            if row_index < last_row_index {
+                // Not the last row, the factor is subtracted from the mask polynomial multiplying
+                // the key polynomial of the same index
+                let mut mask = row_as_glwe.get_mut_mask();
+                let mut mask_polynomials = mask.as_mut_polynomial_list();
+                let mut mask_polynomial = mask_polynomials.get_mut(row_index);
+                let constant_coefficient = &mut mask_polynomial.as_mut()[0];
+                *constant_coefficient = constant_coefficient.wrapping_sub(factor);
+            } else {
+                // The last row needs a slightly different treatment
+                let mut body = row_as_glwe.get_mut_body();
+                let constant_coefficient = &mut body.as_mut()[0];
+                *constant_coefficient = constant_coefficient.wrapping_sub(factor);
+            }
+        }
+    }
+}
+
+/// Fill an [`LWE bootstrap key`](`LweBootstrapKey`) with a share of a bootstrapping key,
+/// constructed from a share of the input [`LWE secret key`](`LweSecretKey`) and a share of the
+/// output [`GLWE secret key`](`GlweSecretKey`).
+///
+/// The bootstrapping key is the sum of the shares of all parties, see
+/// [`encrypt_constant_ggsw_ciphertext_share`].
+pub fn generate_lwe_bootstrap_key_share<
+    Scalar,
+    NoiseDistribution,
+    InputKeyCont,
+    OutputKeyCont,
+    OutputCont,
+    Gen,
+>(
+    input_lwe_secret_key_share: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key_share: &GlweSecretKey<OutputKeyCont>,
+    output: &mut LweBootstrapKey<OutputCont>,
+    is_leading_party: bool,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key_share.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key share and LWE bootstrap key. \
+        Input LWE secret key share LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key_share.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    let gen_iter = generator
+        .try_fork_from_config(output.encryption_fork_config(Uniform, noise_distribution))
+        .unwrap();
+
+    for ((mut ggsw, &input_key_element), mut generator) in output
+        .iter_mut()
+        .zip(input_lwe_secret_key_share.as_ref())
+        .zip(gen_iter)
+    {
+        encrypt_constant_ggsw_ciphertext_share(
+            output_glwe_secret_key_share,
+            &mut ggsw,
+            Cleartext(input_key_element),
+            is_leading_party,
+            noise_distribution,
+            &mut generator,
+        );
+    }
+}
+
+/// Fill an [`LWE keyswitch key`](`LweKeyswitchKey`) with a share of a keyswitching key,
+/// constructed from a share of the input and output [`LWE secret keys`](`LweSecretKey`).
+///
+/// The keyswitching key is the sum of the shares of all parties.
+pub fn generate_lwe_keyswitch_key_share<
+    Scalar,
+    NoiseDistribution,
+    InputKeyCont,
+    OutputKeyCont,
+    KSKeyCont,
+    Gen,
+>(
+    input_lwe_secret_key_share: &LweSecretKey<InputKeyCont>,
+    output_lwe_secret_key_share: &LweSecretKey<OutputKeyCont>,
+    lwe_keyswitch_key: &mut LweKeyswitchKey<KSKeyCont>,
+    is_leading_party: bool,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    KSKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        lwe_keyswitch_key.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    generate_lwe_keyswitch_key(
+        input_lwe_secret_key_share,
+        output_lwe_secret_key_share,
+        lwe_keyswitch_key,
+        noise_distribution,
+        generator,
+    );
+
+    if !is_leading_party {
+        for mut lwe in lwe_keyswitch_key.as_mut_lwe_ciphertext_list().iter_mut() {
+            lwe.get_mut_mask().as_mut().fill(Scalar::ZERO);
+        }
+    }
+}
+
+/// Fill an [`LWE compact public key`](`LweCompactPublicKey`) with a share of a compact public key,
+/// constructed from a share of an [`LWE secret key`](`LweSecretKey`).
+///
+/// The compact public key is the sum of the shares of all parties.
+pub fn generate_lwe_compact_public_key_share<
+    Scalar,
+    NoiseDistribution,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+>(
+    lwe_secret_key_share: &LweSecretKey<InputKeyCont>,
+    output: &mut LweCompactPublicKey<OutputKeyCont>,
+    is_leading_party: bool,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generate_lwe_compact_public_key(lwe_secret_key_share, output, noise_distribution, generator);
+
+    if !is_leading_party {
+        output.get_mut_mask().as_mut().fill(Scalar::ZERO);
+    }
+}
@@ -0,0 +1,71 @@
+use super::sha256::{digest_to_bytes, sha256, BLOCK_SIZE};
+use super::trivial_bytes;
+use crate::{FheUint32, FheUint8};
+use rayon::prelude::*;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/// Computes the HMAC-SHA256 of the encrypted message with the encrypted key
+///
+/// Both the key and the message lengths are not hidden.
+/// The tag is returned as 8 big-endian words, see [sha256].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::hash::hmac_sha256;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // Trivial encryptions are used to keep this example fast,
+/// // use `FheUint8::encrypt` to authenticate confidential data
+/// let key = b"Jefe".map(FheUint8::encrypt_trivial);
+/// let message = b"what do ya want for nothing?".map(FheUint8::encrypt_trivial);
+///
+/// let tag = hmac_sha256(&key, &message);
+/// let tag = tag
+///     .iter()
+///     .flat_map(|word| {
+///         let word: u32 = word.decrypt(&client_key);
+///         word.to_be_bytes()
+///     })
+///     .collect::<Vec<u8>>();
+/// assert_eq!(
+///     tag,
+///     [
+///         0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95,
+///         0x75, 0xc7, 0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83, 0x9d, 0xec, 0x58, 0xb9,
+///         0x64, 0xec, 0x38, 0x43,
+///     ]
+/// );
+/// ```
+pub fn hmac_sha256(key: &[FheUint8], message: &[FheUint8]) -> [FheUint32; 8] {
+    // Keys longer than a block are hashed, then all keys are padded with zeros
+    let mut key = if key.len() > BLOCK_SIZE {
+        digest_to_bytes(&sha256(key))
+    } else {
+        key.to_vec()
+    };
+    key.extend(trivial_bytes(
+        std::iter::repeat(0x00).take(BLOCK_SIZE - key.len()),
+    ));
+
+    let (inner_key, outer_key) =
+        rayon::join(|| xor_key_with(&key, IPAD), || xor_key_with(&key, OPAD));
+
+    let mut inner_input = inner_key;
+    inner_input.extend_from_slice(message);
+    let inner_digest = sha256(&inner_input);
+
+    let mut outer_input = outer_key;
+    outer_input.extend(digest_to_bytes(&inner_digest));
+    sha256(&outer_input)
+}
+
+fn xor_key_with(key: &[FheUint8], pad: u8) -> Vec<FheUint8> {
+    key.par_iter().map(|byte| byte ^ pad).collect()
+}
@@ -0,0 +1,27 @@
+//! Homomorphic hash functions over encrypted bytes
+//!
+//! This module provides SHA-256, HMAC-SHA256, SHA3-256 and Keccak-256 computed on
+//! [FheUint8](crate::FheUint8) inputs, using the operations of the high-level API.
+//!
+//! The length of the input is not hidden: padding is done in the clear
+//! and the number of compression rounds depends on the number of bytes.
+//!
+//! A server key must be set (see [set_server_key](crate::set_server_key))
+//! before calling any of the functions.
+mod hmac;
+mod sha256;
+mod sha3;
+#[cfg(test)]
+mod tests;
+
+pub use hmac::hmac_sha256;
+pub use sha256::sha256;
+pub use sha3::{keccak256, sha3_256};
+
+use crate::prelude::*;
+use crate::FheUint8;
+
+/// Trivially encrypts the clear padding bytes
+fn trivial_bytes(bytes: impl IntoIterator<Item = u8>) -> impl Iterator<Item = FheUint8> {
+    bytes.into_iter().map(FheUint8::encrypt_trivial)
+}
@@ -0,0 +1,187 @@
+use super::trivial_bytes;
+use crate::prelude::*;
+use crate::{FheUint32, FheUint8};
+use rayon::prelude::*;
+use std::array;
+
+/// Number of bytes in a SHA-256 message block
+pub(super) const BLOCK_SIZE: usize = 64;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INIT: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Computes the SHA-256 digest of the encrypted bytes
+///
+/// The digest is returned as 8 big-endian words, as in the SHA-256 specification:
+/// the bytes of the digest are the big-endian bytes of each word, in order.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::hash::sha256;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // Trivial encryptions are used to keep this example fast,
+/// // use `FheUint8::encrypt` to hash confidential data
+/// let input = b"abc".map(FheUint8::encrypt_trivial);
+///
+/// let digest = sha256(&input);
+/// let digest = digest
+///     .iter()
+///     .flat_map(|word| {
+///         let word: u32 = word.decrypt(&client_key);
+///         word.to_be_bytes()
+///     })
+///     .collect::<Vec<u8>>();
+/// assert_eq!(
+///     digest,
+///     [
+///         0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+///         0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+///         0xf2, 0x00, 0x15, 0xad,
+///     ]
+/// );
+/// ```
+pub fn sha256(input: &[FheUint8]) -> [FheUint32; 8] {
+    let words = pad_into_words(input);
+
+    let mut hash = INIT.map(FheUint32::encrypt_trivial);
+    for block in words.chunks_exact(BLOCK_SIZE / 4) {
+        compress(&mut hash, block);
+    }
+    hash
+}
+
+/// Returns the big-endian bytes of a digest
+pub(super) fn digest_to_bytes(digest: &[FheUint32; 8]) -> Vec<FheUint8> {
+    digest
+        .par_iter()
+        .flat_map_iter(|word| word.to_le_bytes().into_iter().rev())
+        .collect()
+}
+
+/// Pads the input as specified by SHA-256 and packs it into big-endian words
+fn pad_into_words(input: &[FheUint8]) -> Vec<FheUint32> {
+    let len = input.len();
+    let num_zeros = (BLOCK_SIZE - (len + 9) % BLOCK_SIZE) % BLOCK_SIZE;
+    let bit_len = (len as u64) * 8;
+
+    let padding = std::iter::once(0x80)
+        .chain(std::iter::repeat(0x00).take(num_zeros))
+        .chain(bit_len.to_be_bytes());
+
+    let bytes = input
+        .iter()
+        .cloned()
+        .chain(trivial_bytes(padding))
+        .collect::<Vec<_>>();
+    debug_assert_eq!(bytes.len() % BLOCK_SIZE, 0);
+
+    bytes
+        .par_chunks_exact(4)
+        .map(|word_bytes| {
+            let le_bytes = [
+                word_bytes[3].clone(),
+                word_bytes[2].clone(),
+                word_bytes[1].clone(),
+                word_bytes[0].clone(),
+            ];
+            FheUint32::from_le_bytes(&le_bytes)
+        })
+        .collect()
+}
+
+/// Xors the right rotations of the input by the given amounts
+fn xor_of_rotations<const N: usize>(input: &FheUint32, amounts: [u32; N]) -> FheUint32 {
+    let rotations = amounts
+        .into_par_iter()
+        .map(|amount| input.rotate_right(amount))
+        .collect::<Vec<_>>();
+    rotations
+        .into_iter()
+        .reduce(|acc, rotation| acc ^ rotation)
+        .unwrap()
+}
+
+/// Processes one block of 16 words, updating the hash state
+fn compress(hash: &mut [FheUint32; 8], block: &[FheUint32]) {
+    let mut w: [FheUint32; 64] = array::from_fn(|i| {
+        if i < 16 {
+            block[i].clone()
+        } else {
+            FheUint32::encrypt_trivial(0u32)
+        }
+    });
+
+    // Message schedule
+    for i in 16..64 {
+        let ((s0_rotations, s0_shift), (s1_rotations, s1_shift)) = rayon::join(
+            || {
+                rayon::join(
+                    || xor_of_rotations(&w[i - 15], [7, 18]),
+                    || &w[i - 15] >> 3u32,
+                )
+            },
+            || {
+                rayon::join(
+                    || xor_of_rotations(&w[i - 2], [17, 19]),
+                    || &w[i - 2] >> 10u32,
+                )
+            },
+        );
+        let (s0, s1) = rayon::join(|| s0_rotations ^ s0_shift, || s1_rotations ^ s1_shift);
+
+        w[i] = FheUint32::sum([&w[i - 16], &s0, &w[i - 7], &s1]);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = hash.clone();
+
+    for i in 0..64 {
+        let ((s1, ch), (s0, maj)) = rayon::join(
+            || {
+                rayon::join(
+                    || xor_of_rotations(&e, [6, 11, 25]),
+                    || (&e & &f) ^ (!&e & &g),
+                )
+            },
+            || {
+                rayon::join(
+                    || xor_of_rotations(&a, [2, 13, 22]),
+                    || (&a & &b) ^ (&a & &c) ^ (&b & &c),
+                )
+            },
+        );
+
+        let (t1, t2) = rayon::join(|| FheUint32::sum([&h, &s1, &ch, &w[i]]) + K[i], || s0 + maj);
+        let (d_plus_t1, t1_plus_t2) = rayon::join(|| d + &t1, || &t1 + t2);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d_plus_t1;
+        d = c;
+        c = b;
+        b = a;
+        a = t1_plus_t2;
+    }
+
+    hash.par_iter_mut()
+        .zip([a, b, c, d, e, f, g, h].par_iter())
+        .for_each(|(dest, src)| *dest += src);
+}
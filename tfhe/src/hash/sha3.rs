@@ -0,0 +1,204 @@
+use super::trivial_bytes;
+use crate::prelude::*;
+use crate::{FheUint64, FheUint8};
+use rayon::prelude::*;
+use std::array;
+
+/// Number of bytes absorbed per permutation for a 256-bit output (1600 - 2 * 256 bits)
+const RATE: usize = 136;
+
+/// Domain separation suffix of SHA-3
+const SHA3_SUFFIX: u8 = 0x06;
+/// Domain separation suffix of the original Keccak submission
+const KECCAK_SUFFIX: u8 = 0x01;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, indexed by `x + 5 * y`
+const RHO_OFFSETS: [u32; 25] = [
+    0, 1, 62, 28, 27, //
+    36, 44, 6, 55, 20, //
+    3, 10, 43, 25, 39, //
+    41, 45, 15, 21, 8, //
+    18, 2, 61, 56, 14, //
+];
+
+/// Computes the SHA3-256 digest of the encrypted bytes
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::hash::sha3_256;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // Trivial encryptions are used to keep this example fast,
+/// // use `FheUint8::encrypt` to hash confidential data
+/// let input = b"abc".map(FheUint8::encrypt_trivial);
+///
+/// let digest = sha3_256(&input);
+/// let digest = digest.map(|byte| -> u8 { byte.decrypt(&client_key) });
+/// assert_eq!(
+///     digest,
+///     [
+///         0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3,
+///         0x90, 0xbd, 0x85, 0x5f, 0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45,
+///         0x11, 0x43, 0x15, 0x32,
+///     ]
+/// );
+/// ```
+pub fn sha3_256(input: &[FheUint8]) -> [FheUint8; 32] {
+    keccak_sponge_256(input, SHA3_SUFFIX)
+}
+
+/// Computes the Keccak-256 digest of the encrypted bytes
+///
+/// This is the hash function of the original Keccak submission (as used by Ethereum),
+/// it only differs from [sha3_256] by its padding.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::hash::keccak256;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // Trivial encryptions are used to keep this example fast,
+/// // use `FheUint8::encrypt` to hash confidential data
+/// let input = b"abc".map(FheUint8::encrypt_trivial);
+///
+/// let digest = keccak256(&input);
+/// let digest = digest.map(|byte| -> u8 { byte.decrypt(&client_key) });
+/// assert_eq!(
+///     digest,
+///     [
+///         0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8,
+///         0xd6, 0x67, 0xc0, 0xd1, 0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f,
+///         0xa1, 0x2d, 0x6c, 0x45,
+///     ]
+/// );
+/// ```
+pub fn keccak256(input: &[FheUint8]) -> [FheUint8; 32] {
+    keccak_sponge_256(input, KECCAK_SUFFIX)
+}
+
+fn keccak_sponge_256(input: &[FheUint8], suffix: u8) -> [FheUint8; 32] {
+    let lanes = pad_into_lanes(input, suffix);
+
+    let mut state: [FheUint64; 25] = array::from_fn(|_| FheUint64::encrypt_trivial(0u64));
+    for block in lanes.chunks_exact(RATE / 8) {
+        state
+            .par_iter_mut()
+            .zip(block.par_iter())
+            .for_each(|(lane, block_lane)| *lane ^= block_lane);
+        keccak_f(&mut state);
+    }
+
+    // The output fits in the first 4 lanes, which are read as little-endian bytes
+    let mut digest = state[..4]
+        .par_iter()
+        .flat_map_iter(|lane| lane.to_le_bytes())
+        .collect::<Vec<_>>()
+        .into_iter();
+    array::from_fn(|_| digest.next().unwrap())
+}
+
+/// Applies the multi-rate padding with the given domain suffix,
+/// and packs the bytes into little-endian lanes
+fn pad_into_lanes(input: &[FheUint8], suffix: u8) -> Vec<FheUint64> {
+    let num_padding_bytes = RATE - input.len() % RATE;
+    let mut padding = vec![0u8; num_padding_bytes];
+    padding[0] = suffix;
+    padding[num_padding_bytes - 1] |= 0x80;
+
+    let bytes = input
+        .iter()
+        .cloned()
+        .chain(trivial_bytes(padding))
+        .collect::<Vec<_>>();
+    debug_assert_eq!(bytes.len() % RATE, 0);
+
+    bytes
+        .par_chunks_exact(8)
+        .map(FheUint64::from_le_bytes)
+        .collect()
+}
+
+/// The Keccak-f\[1600\] permutation
+fn keccak_f(state: &mut [FheUint64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // theta
+        let columns = (0..5)
+            .into_par_iter()
+            .map(|x| (1..5).fold(state[x].clone(), |acc, y| acc ^ &state[x + 5 * y]))
+            .collect::<Vec<_>>();
+        let columns_parities = (0..5)
+            .into_par_iter()
+            .map(|x| &columns[(x + 4) % 5] ^ (&columns[(x + 1) % 5]).rotate_left(1u32))
+            .collect::<Vec<_>>();
+        state
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, lane)| *lane ^= &columns_parities[i % 5]);
+
+        // rho and pi
+        let mut rotated: [FheUint64; 25] = array::from_fn(|_| FheUint64::encrypt_trivial(0u64));
+        let rotated_lanes = state
+            .par_iter()
+            .zip(RHO_OFFSETS.par_iter())
+            .map(|(lane, &offset)| {
+                if offset == 0 {
+                    lane.clone()
+                } else {
+                    lane.rotate_left(offset)
+                }
+            })
+            .collect::<Vec<_>>();
+        for (i, lane) in rotated_lanes.into_iter().enumerate() {
+            let (x, y) = (i % 5, i / 5);
+            rotated[y + 5 * ((2 * x + 3 * y) % 5)] = lane;
+        }
+
+        // chi
+        state.par_iter_mut().enumerate().for_each(|(i, lane)| {
+            let (x, y) = (i % 5, i / 5);
+            let next = &rotated[(x + 1) % 5 + 5 * y];
+            let next_next = &rotated[(x + 2) % 5 + 5 * y];
+            *lane = &rotated[i] ^ (!next & next_next);
+        });
+
+        // iota
+        state[0] ^= round_constant;
+    }
+}
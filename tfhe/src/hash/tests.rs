@@ -0,0 +1,125 @@
+use super::{hmac_sha256, keccak256, sha256, sha3_256};
+use crate::prelude::*;
+use crate::{generate_keys, set_server_key, ClientKey, ConfigBuilder, FheUint32, FheUint8};
+use rand::{thread_rng, Rng};
+use sha3::Digest;
+
+// Inputs are trivially encrypted so that the tests run in a reasonable time,
+// the hash functions do not special case trivial ciphertexts
+fn setup() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    client_key
+}
+
+fn trivial_input(input: &[u8]) -> Vec<FheUint8> {
+    input
+        .iter()
+        .map(|byte| FheUint8::encrypt_trivial(*byte))
+        .collect()
+}
+
+fn decrypt_words(words: &[FheUint32; 8], client_key: &ClientKey) -> Vec<u8> {
+    words
+        .iter()
+        .flat_map(|word| {
+            let word: u32 = word.decrypt(client_key);
+            word.to_be_bytes()
+        })
+        .collect()
+}
+
+fn decrypt_bytes(bytes: &[FheUint8; 32], client_key: &ClientKey) -> Vec<u8> {
+    bytes.iter().map(|byte| byte.decrypt(client_key)).collect()
+}
+
+#[test]
+fn test_sha256() {
+    let client_key = setup();
+
+    let cases: [(&[u8], &str); 3] = [
+        (
+            b"",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        (
+            b"abc",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        // Padding spans two blocks
+        (
+            b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let digest = sha256(&trivial_input(input));
+        assert_eq!(hex::encode(decrypt_words(&digest, &client_key)), expected);
+    }
+}
+
+#[test]
+fn test_sha256_encrypted_input() {
+    let client_key = setup();
+
+    let input = [b'a', b'b', b'c'].map(|byte| FheUint8::encrypt(byte, &client_key));
+    let digest = sha256(&input);
+    assert_eq!(
+        hex::encode(decrypt_words(&digest, &client_key)),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_hmac_sha256() {
+    let client_key = setup();
+
+    // Test cases 2 and 6 of RFC 4231, the latter uses a key longer than a block
+    let cases: [(&[u8], &[u8], &str); 2] = [
+        (
+            b"Jefe",
+            b"what do ya want for nothing?",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+        (
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+        ),
+    ];
+
+    for (key, message, expected) in cases {
+        let tag = hmac_sha256(&trivial_input(key), &trivial_input(message));
+        assert_eq!(hex::encode(decrypt_words(&tag, &client_key)), expected);
+    }
+}
+
+#[test]
+fn test_sha3_256_and_keccak256() {
+    let client_key = setup();
+    let mut rng = thread_rng();
+
+    // The rate is 136 bytes, these lengths cover one and two absorbed blocks,
+    // as well as a padding of a single byte
+    for len in [0, 3, 135, 136, 200] {
+        let input = (0..len).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+        let encrypted_input = trivial_input(&input);
+
+        let digest = sha3_256(&encrypted_input);
+        let expected = sha3::Sha3_256::digest(&input);
+        assert_eq!(
+            decrypt_bytes(&digest, &client_key),
+            expected.as_slice(),
+            "Invalid SHA3-256 digest for input of length {len}"
+        );
+
+        let digest = keccak256(&encrypted_input);
+        let expected = sha3::Keccak256::digest(&input);
+        assert_eq!(
+            decrypt_bytes(&digest, &client_key),
+            expected.as_slice(),
+            "Invalid Keccak-256 digest for input of length {len}"
+        );
+    }
+}
@@ -6,7 +6,30 @@ use crate::high_level_api::array::traits::{
 };
 use crate::high_level_api::array::FheArrayBase;
 use crate::FheId;
-use std::ops::{Add, BitAnd};
+use std::borrow::Cow;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Sub};
+
+// A clear array holding a single element is broadcast to the given shape, otherwise it must
+// already have this shape
+#[track_caller]
+fn broadcast_clear_array<'a, Clear>(
+    clear: &'a ClearArray<Clear>,
+    shape: &[usize],
+) -> Cow<'a, ClearArray<Clear>>
+where
+    Clear: Copy,
+{
+    if clear.shape() == shape {
+        return Cow::Borrowed(clear);
+    }
+
+    let [value] = clear.container().as_ref() else {
+        panic!("Array operands do not have the same shape");
+    };
+
+    let num_elems = shape.iter().copied().product::<usize>();
+    Cow::Owned(ClearArray::new(vec![*value; num_elems], shape.to_vec()))
+}
 
 macro_rules! impl_other_binary_ops_variants {
     (
@@ -23,9 +46,7 @@ macro_rules! impl_other_binary_ops_variants {
             type Output = FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>;
 
             fn $trait_method(self, rhs: ClearArray<Clear>) -> Self::Output {
-                if !self.has_same_shape(&rhs) {
-                    panic!("Array operands do not have the same shape");
-                }
+                let rhs = broadcast_clear_array(&rhs, self.shape());
                 let lhs_slice = self.as_tensor_slice();
                 let rhs_slice = rhs.as_tensor_slice().map(ClearContainer::into_inner);
                 let inner = C::Backend::$backend_method(lhs_slice, rhs_slice);
@@ -46,9 +67,7 @@ macro_rules! impl_other_binary_ops_variants {
             type Output = FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>;
 
             fn $trait_method(self, rhs: ClearArray<Clear>) -> Self::Output {
-                if !self.has_same_shape(&rhs) {
-                    panic!("Array operands do not have the same shape");
-                }
+                let rhs = broadcast_clear_array(&rhs, self.shape());
                 let lhs_slice = self.as_tensor_slice();
                 let rhs_slice = rhs.as_tensor_slice().map(ClearContainer::into_inner);
                 let inner = C::Backend::$backend_method(lhs_slice, rhs_slice);
@@ -69,9 +88,7 @@ macro_rules! impl_other_binary_ops_variants {
             type Output = FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>;
 
             fn $trait_method(self, rhs: &'a ClearArray<Clear>) -> Self::Output {
-                if !self.has_same_shape(rhs) {
-                    panic!("Array operands do not have the same shape");
-                }
+                let rhs = broadcast_clear_array(rhs, self.shape());
                 let lhs_slice = self.as_tensor_slice();
                 let rhs_slice = rhs.as_tensor_slice().map(ClearContainer::into_inner);
                 let inner = C::Backend::$backend_method(lhs_slice, rhs_slice);
@@ -92,9 +109,7 @@ macro_rules! impl_other_binary_ops_variants {
             type Output = FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>;
 
             fn $trait_method(self, rhs: &'a ClearArray<Clear>) -> Self::Output {
-                if !self.has_same_shape(rhs) {
-                    panic!("Array operands do not have the same shape");
-                }
+                let rhs = broadcast_clear_array(rhs, self.shape());
                 let lhs_slice = self.as_tensor_slice();
                 let rhs_slice = rhs.as_tensor_slice().map(ClearContainer::into_inner);
                 let inner = C::Backend::$backend_method(lhs_slice, rhs_slice);
@@ -106,4 +121,10 @@ macro_rules! impl_other_binary_ops_variants {
 }
 
 impl_other_binary_ops_variants!(Add(add) => ClearArithmeticArrayBackend(add_slices));
+impl_other_binary_ops_variants!(Sub(sub) => ClearArithmeticArrayBackend(sub_slices));
+impl_other_binary_ops_variants!(Mul(mul) => ClearArithmeticArrayBackend(mul_slices));
+impl_other_binary_ops_variants!(Div(div) => ClearArithmeticArrayBackend(div_slices));
+impl_other_binary_ops_variants!(Rem(rem) => ClearArithmeticArrayBackend(rem_slices));
 impl_other_binary_ops_variants!(BitAnd(bitand) => ClearBitwiseArrayBackend(bitand_slice));
+impl_other_binary_ops_variants!(BitOr(bitor) => ClearBitwiseArrayBackend(bitor_slice));
+impl_other_binary_ops_variants!(BitXor(bitxor) => ClearBitwiseArrayBackend(bitxor_slice));
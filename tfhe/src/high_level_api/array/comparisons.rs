@@ -0,0 +1,136 @@
+use super::traits::{ComparisonArrayBackend, SelectArrayBackend};
+use crate::high_level_api::array::{
+    ArrayBackend, BackendDataContainer, FheArrayBase, FheBackendArray, FheBoolId,
+};
+
+type BoolArrayOf<Backend> =
+    FheBackendArray<<Backend as ComparisonArrayBackend>::BoolBackend, FheBoolId>;
+
+macro_rules! impl_comparison_method {
+    (
+        $(#[$attr:meta])*
+        $method:ident
+    ) => {
+        $(#[$attr])*
+        #[track_caller]
+        #[allow(clippy::should_implement_trait)]
+        pub fn $method<C2>(&self, other: &FheArrayBase<C2, Id>) -> BoolArrayOf<C::Backend>
+        where
+            C2: BackendDataContainer<Backend = C::Backend>,
+        {
+            if !self.has_same_shape(other) {
+                panic!("Array operands do not have the same shape");
+            }
+            let result = C::Backend::$method(self.as_tensor_slice(), other.as_tensor_slice());
+            FheArrayBase::new(result, self.shape().to_vec())
+        }
+    };
+}
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: ComparisonArrayBackend,
+{
+    impl_comparison_method!(
+        /// Compares the elements of two arrays for equality, element-wise
+        ///
+        /// # Panics
+        ///
+        /// Panics if the arrays do not have the same shape.
+        eq
+    );
+
+    impl_comparison_method!(
+        /// Compares the elements of two arrays for difference, element-wise
+        ///
+        /// See [Self::eq] for panics.
+        ne
+    );
+
+    impl_comparison_method!(
+        /// Returns whether the elements of `self` are lower than the elements of `other`
+        ///
+        /// See [Self::eq] for panics.
+        lt
+    );
+
+    impl_comparison_method!(
+        /// Returns whether the elements of `self` are lower than or equal to the elements of
+        /// `other`
+        ///
+        /// See [Self::eq] for panics.
+        le
+    );
+
+    impl_comparison_method!(
+        /// Returns whether the elements of `self` are greater than the elements of `other`
+        ///
+        /// See [Self::eq] for panics.
+        gt
+    );
+
+    impl_comparison_method!(
+        /// Returns whether the elements of `self` are greater than or equal to the elements of
+        /// `other`
+        ///
+        /// See [Self::eq] for panics.
+        ge
+    );
+}
+
+impl<C> FheArrayBase<C, FheBoolId>
+where
+    C: BackendDataContainer,
+{
+    /// Selects, element-wise, the element of `if_true` where the mask is true and the element
+    /// of `if_false` otherwise
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask and the arrays do not have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32Array};
+    ///
+    /// let (cks, sks) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(sks);
+    ///
+    /// let xs = FheUint32Array::try_encrypt([1u32, 5, 3].as_slice(), &cks).unwrap();
+    /// let ys = FheUint32Array::try_encrypt([4u32, 2, 3].as_slice(), &cks).unwrap();
+    ///
+    /// // Element-wise maximum
+    /// let mask = xs.gt(&ys);
+    /// let result = mask.select(&xs, &ys);
+    ///
+    /// let result: Vec<u32> = result.decrypt(&cks);
+    /// assert_eq!(result, vec![4, 5, 3]);
+    /// ```
+    #[track_caller]
+    pub fn select<C1, C2, Id>(
+        &self,
+        if_true: &FheArrayBase<C1, Id>,
+        if_false: &FheArrayBase<C2, Id>,
+    ) -> FheArrayBase<<C1::Backend as ArrayBackend>::Owned, Id>
+    where
+        Id: Default,
+        C1: BackendDataContainer,
+        C1::Backend: SelectArrayBackend<BoolBackend = C::Backend>,
+        C2: BackendDataContainer<Backend = C1::Backend>,
+    {
+        if self.shape() != if_true.shape() || self.shape() != if_false.shape() {
+            panic!("Array operands do not have the same shape");
+        }
+
+        let result = C1::Backend::select(
+            self.as_tensor_slice(),
+            if_true.as_tensor_slice(),
+            if_false.as_tensor_slice(),
+        );
+        FheArrayBase::new(result, self.shape().to_vec())
+    }
+}
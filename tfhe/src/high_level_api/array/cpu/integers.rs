@@ -1,7 +1,13 @@
 //! This module contains the implementations of the FheUint array and FheInt array backend
 //! where the values and computations are always done on CPU
 use super::super::helpers::{create_sub_mut_slice_with_bound, create_sub_slice_with_bound};
-use super::super::traits::{ArithmeticArrayBackend, BitwiseArrayBackend, ClearBitwiseArrayBackend};
+use super::super::traits::{
+    ArithmeticArrayBackend, BitwiseArrayBackend, ClearBitwiseArrayBackend, ComparisonArrayBackend,
+    LinearAlgebraArrayBackend, ObliviousAccessArrayBackend, ReductionArrayBackend,
+    SelectArrayBackend, SortArrayBackend,
+};
+use super::booleans::CpuFheBoolArrayBackend;
+use crate::array::stride::StridedIndexProducer;
 use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
 use crate::high_level_api::array::{
     ArrayBackend, FheArrayBase, FheBackendArray, FheBackendArraySlice, FheBackendArraySliceMut,
@@ -14,11 +20,12 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheIntId, FheUintId};
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::client_key::RecomposableSignedInteger;
+use crate::integer::prelude::*;
 use crate::integer::server_key::radix_parallel::scalar_div_mod::SignedReciprocable;
 use crate::integer::server_key::{Reciprocable, ScalarMultiplier};
-use crate::integer::{IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::{BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
 use crate::prelude::{FheDecrypt, FheTryEncrypt};
-use crate::{ClientKey, Error};
+use crate::{ClientKey, Error, FheUint};
 use rayon::prelude::*;
 use std::marker::PhantomData;
 use std::ops::RangeBounds;
@@ -294,6 +301,441 @@ where
     }
 }
 
+#[inline]
+#[track_caller]
+fn par_reduce_lanes<T, F>(lhs: TensorSlice<'_, &[T]>, axis: usize, reduce: F) -> Vec<T>
+where
+    T: IntegerRadixCiphertext,
+    F: Send + Sync + Fn(&crate::integer::ServerKey, Vec<&T>) -> T,
+{
+    let lane_len = lhs.dims.shape()[axis];
+    let lane_stride = lhs.dims.strides()[axis];
+
+    // The lanes are reduced in the row-major order of the reduced shape
+    let lane_starts =
+        StridedIndexProducer::new(lhs.dims.with_reduced_axis(axis)).collect::<Vec<_>>();
+
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        lane_starts
+            .into_par_iter()
+            .map(|lane_start| {
+                let lane = (0..lane_len)
+                    .map(|i| &lhs.slice[lane_start + i * lane_stride])
+                    .collect::<Vec<_>>();
+                reduce(cpu_key.pbs_key(), lane)
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+// Checks that the indices of `len` elements can be encrypted as integers of `num_blocks` blocks
+#[track_caller]
+fn assert_indices_fit<T>(sks: &crate::integer::ServerKey, num_blocks: usize, len: usize)
+where
+    T: IntegerRadixCiphertext,
+{
+    let num_bits = num_blocks as u32 * sks.message_modulus().0.ilog2();
+    let num_index_bits = if T::IS_SIGNED { num_bits - 1 } else { num_bits };
+    assert!(
+        num_index_bits >= u64::BITS || (len as u64 - 1) >> num_index_bits == 0,
+        "the indices of {len} elements cannot be represented by the array type",
+    );
+}
+
+impl<T> ReductionArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn sum_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        par_reduce_lanes(lhs, axis, |sks, lane| {
+            sks.sum_ciphertexts_parallelized(lane)
+                .expect("lanes are not empty")
+        })
+    }
+
+    fn product_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        par_reduce_lanes(lhs, axis, |sks, lane| {
+            lane.into_par_iter()
+                .cloned()
+                .reduce_with(|lhs, rhs| sks.mul_parallelized(&lhs, &rhs))
+                .expect("lanes are not empty")
+        })
+    }
+
+    fn min_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        par_reduce_lanes(lhs, axis, |sks, lane| {
+            lane.into_par_iter()
+                .cloned()
+                .reduce_with(|lhs, rhs| sks.min_parallelized(&lhs, &rhs))
+                .expect("lanes are not empty")
+        })
+    }
+
+    fn max_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        par_reduce_lanes(lhs, axis, |sks, lane| {
+            lane.into_par_iter()
+                .cloned()
+                .reduce_with(|lhs, rhs| sks.max_parallelized(&lhs, &rhs))
+                .expect("lanes are not empty")
+        })
+    }
+
+    fn argmax_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        par_reduce_lanes(lhs, axis, |sks, lane| {
+            // Indices are encrypted with the same type as the values
+            let num_blocks = lane[0].blocks().len();
+            assert_indices_fit::<T>(sks, num_blocks, lane.len());
+
+            // rayon's reduce keeps the order of the elements, and the comparison is strict, so
+            // the index of the first maximum is kept
+            lane.into_par_iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let index: T = sks.create_trivial_radix(i as u64, num_blocks);
+                    (value.clone(), index)
+                })
+                .reduce_with(|(lhs_value, lhs_index), (rhs_value, rhs_index)| {
+                    let rhs_is_greater = sks.gt_parallelized(&rhs_value, &lhs_value);
+                    rayon::join(
+                        || sks.if_then_else_parallelized(&rhs_is_greater, &rhs_value, &lhs_value),
+                        || sks.if_then_else_parallelized(&rhs_is_greater, &rhs_index, &lhs_index),
+                    )
+                })
+                .expect("lanes are not empty")
+                .1
+        })
+    }
+}
+
+impl<T> LinearAlgebraArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn dot<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        let products = par_map_sks_op_on_pair_of_elements(
+            lhs,
+            rhs,
+            crate::integer::ServerKey::mul_parallelized,
+        );
+
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            vec![cpu_key
+                .pbs_key()
+                .sum_ciphertexts_parallelized(products.iter())
+                .expect("arrays are not empty")]
+        })
+    }
+
+    fn matmul<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        let (num_rows, inner_len) = (lhs.dims.shape()[0], lhs.dims.shape()[1]);
+        let num_cols = rhs.dims.shape()[1];
+
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+
+            (0..num_rows * num_cols)
+                .into_par_iter()
+                .map(|flat_index| {
+                    let (row, col) = (flat_index / num_cols, flat_index % num_cols);
+
+                    let products = (0..inner_len)
+                        .into_par_iter()
+                        .map(|k| {
+                            let lhs_index = lhs.dims.flatten_index(&[row, k]).unwrap();
+                            let rhs_index = rhs.dims.flatten_index(&[k, col]).unwrap();
+                            sks.mul_parallelized(&lhs.slice[lhs_index], &rhs.slice[rhs_index])
+                        })
+                        .collect::<Vec<_>>();
+
+                    sks.sum_ciphertexts_parallelized(products.iter())
+                        .expect("arrays are not empty")
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+#[inline]
+fn par_map_sks_cmp_on_pair_of_elements<'a, T, F>(
+    lhs: TensorSlice<'a, &'a [T]>,
+    rhs: TensorSlice<'a, &'a [T]>,
+    op: F,
+) -> Vec<BooleanBlock>
+where
+    T: IntegerRadixCiphertext,
+    F: Send + Sync + Fn(&crate::integer::ServerKey, &T, &T) -> BooleanBlock,
+{
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        lhs.par_iter()
+            .zip(rhs.par_iter())
+            .map(|(lhs, rhs)| op(cpu_key.pbs_key(), lhs, rhs))
+            .collect::<Vec<_>>()
+    })
+}
+
+impl<T> ComparisonArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    type BoolBackend = CpuFheBoolArrayBackend;
+
+    fn eq<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Vec<BooleanBlock> {
+        par_map_sks_cmp_on_pair_of_elements(lhs, rhs, crate::integer::ServerKey::eq_parallelized)
+    }
+
+    fn ne<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Vec<BooleanBlock> {
+        par_map_sks_cmp_on_pair_of_elements(lhs, rhs, crate::integer::ServerKey::ne_parallelized)
+    }
+
+    fn lt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Vec<BooleanBlock> {
+        par_map_sks_cmp_on_pair_of_elements(lhs, rhs, crate::integer::ServerKey::lt_parallelized)
+    }
+
+    fn le<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Vec<BooleanBlock> {
+        par_map_sks_cmp_on_pair_of_elements(lhs, rhs, crate::integer::ServerKey::le_parallelized)
+    }
+
+    fn gt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Vec<BooleanBlock> {
+        par_map_sks_cmp_on_pair_of_elements(lhs, rhs, crate::integer::ServerKey::gt_parallelized)
+    }
+
+    fn ge<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Vec<BooleanBlock> {
+        par_map_sks_cmp_on_pair_of_elements(lhs, rhs, crate::integer::ServerKey::ge_parallelized)
+    }
+}
+
+impl<T> SelectArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn select<'a, 'b>(
+        mask: TensorSlice<'_, &'b [BooleanBlock]>,
+        if_true: TensorSlice<'_, Self::Slice<'a>>,
+        if_false: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            mask.par_iter()
+                .zip(if_true.par_iter())
+                .zip(if_false.par_iter())
+                .map(|((condition, if_true), if_false)| {
+                    cpu_key
+                        .pbs_key()
+                        .if_then_else_parallelized(condition, if_true, if_false)
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+// Sorts the elements with a bitonic sorting network, in its variant that works for any number
+// of elements.
+//
+// `compare_exchange(a, b, ascending)` must obliviously swap `a` and `b` if they are not in the
+// requested order. As the network does not depend on the values, the comparisons of a same stage
+// are done in parallel.
+fn bitonic_sort<E, F>(elems: &mut [E], ascending: bool, compare_exchange: &F)
+where
+    E: Send,
+    F: Fn(&mut E, &mut E, bool) + Sync,
+{
+    if elems.len() <= 1 {
+        return;
+    }
+
+    // Sorting the halves in opposite orders gives a bitonic sequence
+    let (lower, upper) = elems.split_at_mut(elems.len() / 2);
+    rayon::join(
+        || bitonic_sort(lower, !ascending, compare_exchange),
+        || bitonic_sort(upper, ascending, compare_exchange),
+    );
+
+    bitonic_merge(elems, ascending, compare_exchange);
+}
+
+fn bitonic_merge<E, F>(elems: &mut [E], ascending: bool, compare_exchange: &F)
+where
+    E: Send,
+    F: Fn(&mut E, &mut E, bool) + Sync,
+{
+    if elems.len() <= 1 {
+        return;
+    }
+
+    // The greatest power of two lower than the number of elements
+    let half = 1 << (elems.len() - 1).ilog2();
+    let (lower, upper) = elems.split_at_mut(half);
+
+    lower
+        .par_iter_mut()
+        .zip(upper.par_iter_mut())
+        .for_each(|(lhs, rhs)| compare_exchange(lhs, rhs, ascending));
+
+    rayon::join(
+        || bitonic_merge(lower, ascending, compare_exchange),
+        || bitonic_merge(upper, ascending, compare_exchange),
+    );
+}
+
+impl<T> SortArrayBackend for CpuIntegerArrayBackend<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    fn sort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        let mut elems = lhs.iter().cloned().collect::<Vec<_>>();
+
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+
+            bitonic_sort(
+                &mut elems,
+                true,
+                &|lhs: &mut T, rhs: &mut T, ascending: bool| {
+                    let should_swap = if ascending {
+                        sks.gt_parallelized(lhs, rhs)
+                    } else {
+                        sks.lt_parallelized(lhs, rhs)
+                    };
+
+                    let (new_lhs, new_rhs) = rayon::join(
+                        || sks.if_then_else_parallelized(&should_swap, rhs, lhs),
+                        || sks.if_then_else_parallelized(&should_swap, lhs, rhs),
+                    );
+                    *lhs = new_lhs;
+                    *rhs = new_rhs;
+                },
+            );
+        });
+
+        elems
+    }
+
+    fn argsort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+
+            let num_values = lhs.dims.flattened_len();
+            let Some(num_blocks) = lhs.slice.first().map(|value| value.blocks().len()) else {
+                return vec![];
+            };
+            // Indices are encrypted with the same type as the values
+            assert_indices_fit::<T>(sks, num_blocks, num_values);
+
+            let mut elems = lhs
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let index: T = sks.create_trivial_radix(i as u64, num_blocks);
+                    (value.clone(), index)
+                })
+                .collect::<Vec<_>>();
+
+            // Ties are broken using the original indices, which makes the sort stable
+            bitonic_sort(
+                &mut elems,
+                true,
+                &|lhs: &mut (T, T), rhs: &mut (T, T), ascending: bool| {
+                    let (first, second) = if ascending {
+                        (&*lhs, &*rhs)
+                    } else {
+                        (&*rhs, &*lhs)
+                    };
+
+                    let (value_gt, (value_eq, index_gt)) = rayon::join(
+                        || sks.gt_parallelized(&first.0, &second.0),
+                        || {
+                            rayon::join(
+                                || sks.eq_parallelized(&first.0, &second.0),
+                                || sks.gt_parallelized(&first.1, &second.1),
+                            )
+                        },
+                    );
+                    let should_swap =
+                        sks.boolean_bitor(&value_gt, &sks.boolean_bitand(&value_eq, &index_gt));
+
+                    let ((new_lhs_value, new_rhs_value), (new_lhs_index, new_rhs_index)) =
+                        rayon::join(
+                            || {
+                                rayon::join(
+                                    || sks.if_then_else_parallelized(&should_swap, &rhs.0, &lhs.0),
+                                    || sks.if_then_else_parallelized(&should_swap, &lhs.0, &rhs.0),
+                                )
+                            },
+                            || {
+                                rayon::join(
+                                    || sks.if_then_else_parallelized(&should_swap, &rhs.1, &lhs.1),
+                                    || sks.if_then_else_parallelized(&should_swap, &lhs.1, &rhs.1),
+                                )
+                            },
+                        );
+                    *lhs = (new_lhs_value, new_lhs_index);
+                    *rhs = (new_rhs_value, new_rhs_index);
+                },
+            );
+
+            elems.into_iter().map(|(_, index)| index).collect()
+        })
+    }
+}
+
+impl ObliviousAccessArrayBackend for CpuUintArrayBackend {
+    fn get_encrypted<Id, IndexId>(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        index: &FheUint<IndexId>,
+    ) -> FheUint<Id>
+    where
+        Id: FheUintId,
+        IndexId: FheUintId,
+    {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let result = cpu_key
+                .pbs_key()
+                .oblivious_read_parallelized(lhs.slice, &index.ciphertext.on_cpu());
+            FheUint::new(result, cpu_key.tag.clone())
+        })
+    }
+
+    fn set_encrypted<Id, IndexId>(
+        lhs: TensorSlice<'_, Self::SliceMut<'_>>,
+        index: &FheUint<IndexId>,
+        value: &FheUint<Id>,
+    ) where
+        Id: FheUintId,
+        IndexId: FheUintId,
+    {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            cpu_key.pbs_key().oblivious_write_parallelized(
+                lhs.slice,
+                &index.ciphertext.on_cpu(),
+                &*value.ciphertext.on_cpu(),
+            );
+        });
+    }
+}
+
 impl<Clear, T> ClearBitwiseArrayBackend<Clear> for CpuIntegerArrayBackend<T>
 where
     T: IntegerRadixCiphertext,
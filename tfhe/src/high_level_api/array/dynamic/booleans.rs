@@ -230,7 +230,7 @@ pub enum InnerBoolSlice<'a> {
 }
 
 impl InnerBoolSlice<'_> {
-    fn on_cpu(&self) -> Cow<'_, [BooleanBlock]> {
+    pub(crate) fn on_cpu(&self) -> Cow<'_, [BooleanBlock]> {
         match self {
             InnerBoolSlice::Cpu(cpu_slice) => Cow::Borrowed(cpu_slice),
         }
@@ -2,9 +2,14 @@ use crate::array::cpu::CpuIntegerArrayBackend;
 use crate::array::helpers::{create_sub_mut_slice_with_bound, range_bounds_to_exclusive_range};
 use crate::array::traits::{
     ArithmeticArrayBackend, ArrayBackend, BackendDataContainer, BackendDataContainerMut,
-    BitwiseArrayBackend, TensorSlice,
+    BitwiseArrayBackend, ClearArithmeticArrayBackend, ComparisonArrayBackend,
+    LinearAlgebraArrayBackend, ReductionArrayBackend, SelectArrayBackend, SortArrayBackend,
+    TensorSlice,
 };
 use crate::core_crypto::prelude::SignedNumeric;
+use crate::high_level_api::array::dynamic::booleans::{
+    DynFheBoolArrayBackend, InnerBoolArray, InnerBoolSlice,
+};
 use crate::high_level_api::array::traits::ClearBitwiseArrayBackend;
 use crate::high_level_api::array::{
     FheArrayBase, FheBackendArray, FheBackendArraySlice, FheBackendArraySliceMut,
@@ -13,7 +18,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheIntId;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::client_key::RecomposableSignedInteger;
-use crate::integer::SignedRadixCiphertext;
+use crate::integer::{BooleanBlock, SignedRadixCiphertext};
 use crate::prelude::{FheDecrypt, FheTryEncrypt};
 use crate::{ClientKey, Device, Error};
 use std::borrow::{Borrow, Cow};
@@ -319,6 +324,264 @@ where
     }
 }
 
+impl ReductionArrayBackend for DynIntBackend {
+    fn sum_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::sum_along_axis(lhs, axis)
+        })
+    }
+
+    fn product_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::product_along_axis(lhs, axis)
+        })
+    }
+
+    fn min_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::min_along_axis(lhs, axis)
+        })
+    }
+
+    fn max_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::max_along_axis(lhs, axis)
+        })
+    }
+
+    fn argmax_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::argmax_along_axis(lhs, axis)
+        })
+    }
+}
+
+impl LinearAlgebraArrayBackend for DynIntBackend {
+    fn dot<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        dispatch_binary_op(&lhs, &rhs, CpuIntegerArrayBackend::dot)
+    }
+
+    fn matmul<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        dispatch_binary_op(&lhs, &rhs, CpuIntegerArrayBackend::matmul)
+    }
+}
+
+impl<Clear> ClearArithmeticArrayBackend<Clear> for DynIntBackend
+where
+    CpuIntegerArrayBackend<SignedRadixCiphertext>: ClearArithmeticArrayBackend<Clear>,
+{
+    fn add_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::add_slices,
+        )
+    }
+
+    fn sub_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::sub_slices,
+        )
+    }
+
+    fn mul_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::mul_slices,
+        )
+    }
+
+    fn div_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::div_slices,
+        )
+    }
+
+    fn rem_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::rem_slices,
+        )
+    }
+}
+
+impl ComparisonArrayBackend for DynIntBackend {
+    type BoolBackend = DynFheBoolArrayBackend;
+
+    fn eq<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ComparisonArrayBackend>::eq,
+        )
+    }
+
+    fn ne<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ComparisonArrayBackend>::ne,
+        )
+    }
+
+    fn lt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ComparisonArrayBackend>::lt,
+        )
+    }
+
+    fn le<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ComparisonArrayBackend>::le,
+        )
+    }
+
+    fn gt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ComparisonArrayBackend>::gt,
+        )
+    }
+
+    fn ge<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<SignedRadixCiphertext> as ComparisonArrayBackend>::ge,
+        )
+    }
+}
+
+impl SelectArrayBackend for DynIntBackend {
+    fn select<'a, 'b>(
+        mask: TensorSlice<'_, InnerBoolSlice<'b>>,
+        if_true: TensorSlice<'_, Self::Slice<'a>>,
+        if_false: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        match global_state::device_of_internal_keys() {
+            Some(Device::Cpu) => {
+                let mask_cpu_cow = mask.slice.on_cpu();
+                let if_true_cpu_cow = if_true.slice.on_cpu();
+                let if_false_cpu_cow = if_false.slice.on_cpu();
+
+                let mask_cpu_slice: &[BooleanBlock] = mask_cpu_cow.borrow();
+                let if_true_cpu_slice: &[SignedRadixCiphertext] = if_true_cpu_cow.borrow();
+                let if_false_cpu_slice: &[SignedRadixCiphertext] = if_false_cpu_cow.borrow();
+
+                let result = CpuIntegerArrayBackend::select(
+                    TensorSlice::new(mask_cpu_slice, mask.dims),
+                    TensorSlice::new(if_true_cpu_slice, if_true.dims),
+                    TensorSlice::new(if_false_cpu_slice, if_false.dims),
+                );
+                InnerIntArray::Cpu(result)
+            }
+            #[cfg(feature = "gpu")]
+            Some(Device::CudaGpu) => {
+                panic!("Not supported by Cuda devices")
+            }
+            None => {
+                panic!("{}", crate::high_level_api::errors::UninitializedServerKey);
+            }
+        }
+    }
+}
+
+impl SortArrayBackend for DynIntBackend {
+    fn sort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&lhs, CpuIntegerArrayBackend::sort)
+    }
+
+    fn argsort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&lhs, CpuIntegerArrayBackend::argsort)
+    }
+}
+
+#[inline]
+fn dispatch_comparison_op<CpuFn>(
+    lhs: &TensorSlice<'_, InnerIntSlice<'_>>,
+    rhs: &TensorSlice<'_, InnerIntSlice<'_>>,
+    cpu_fn: CpuFn,
+) -> InnerBoolArray
+where
+    CpuFn: for<'a> Fn(
+        TensorSlice<'_, &'a [SignedRadixCiphertext]>,
+        TensorSlice<'_, &'a [SignedRadixCiphertext]>,
+    ) -> Vec<BooleanBlock>,
+{
+    match global_state::device_of_internal_keys() {
+        Some(Device::Cpu) => {
+            let lhs_cpu_cow = lhs.slice.on_cpu();
+            let rhs_cpu_cow = rhs.slice.on_cpu();
+
+            let lhs_cpu_slice: &[SignedRadixCiphertext] = lhs_cpu_cow.borrow();
+            let rhs_cpu_slice: &[SignedRadixCiphertext] = rhs_cpu_cow.borrow();
+
+            let result = cpu_fn(
+                TensorSlice::new(lhs_cpu_slice, lhs.dims),
+                TensorSlice::new(rhs_cpu_slice, rhs.dims),
+            );
+            InnerBoolArray::Cpu(result)
+        }
+        #[cfg(feature = "gpu")]
+        Some(Device::CudaGpu) => {
+            panic!("Not supported by Cuda devices")
+        }
+        None => {
+            panic!("{}", crate::high_level_api::errors::UninitializedServerKey);
+        }
+    }
+}
+
 #[inline]
 fn dispatch_binary_scalar_op<CpuFn, Clear>(
     lhs: &TensorSlice<'_, InnerIntSlice<'_>>,
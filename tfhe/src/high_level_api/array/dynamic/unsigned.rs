@@ -3,10 +3,15 @@ use crate::array::helpers::{
 };
 use crate::array::traits::{
     ArithmeticArrayBackend, ArrayBackend, BackendDataContainer, BackendDataContainerMut,
-    BitwiseArrayBackend, TensorSlice,
+    BitwiseArrayBackend, ClearArithmeticArrayBackend, ComparisonArrayBackend,
+    LinearAlgebraArrayBackend, ObliviousAccessArrayBackend, ReductionArrayBackend,
+    SelectArrayBackend, SortArrayBackend, TensorSlice,
 };
 use crate::core_crypto::prelude::UnsignedNumeric;
 use crate::high_level_api::array::cpu::CpuIntegerArrayBackend;
+use crate::high_level_api::array::dynamic::booleans::{
+    DynFheBoolArrayBackend, InnerBoolArray, InnerBoolSlice,
+};
 use crate::high_level_api::array::traits::ClearBitwiseArrayBackend;
 use crate::high_level_api::array::{
     FheBackendArray, FheBackendArraySlice, FheBackendArraySliceMut,
@@ -14,9 +19,9 @@ use crate::high_level_api::array::{
 use crate::high_level_api::global_state;
 use crate::high_level_api::integers::FheUintId;
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
-use crate::integer::RadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext};
 use crate::prelude::{FheDecrypt, FheTryEncrypt};
-use crate::{ClientKey, Device, Error};
+use crate::{ClientKey, Device, Error, FheUint};
 use std::borrow::{Borrow, Cow};
 use std::ops::RangeBounds;
 
@@ -325,6 +330,298 @@ where
     }
 }
 
+impl ReductionArrayBackend for DynUintBackend {
+    fn sum_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::sum_along_axis(lhs, axis)
+        })
+    }
+
+    fn product_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::product_along_axis(lhs, axis)
+        })
+    }
+
+    fn min_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::min_along_axis(lhs, axis)
+        })
+    }
+
+    fn max_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::max_along_axis(lhs, axis)
+        })
+    }
+
+    fn argmax_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned {
+        dispatch_unary_op(&lhs, |lhs| {
+            CpuIntegerArrayBackend::argmax_along_axis(lhs, axis)
+        })
+    }
+}
+
+impl LinearAlgebraArrayBackend for DynUintBackend {
+    fn dot<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        dispatch_binary_op(&lhs, &rhs, CpuIntegerArrayBackend::dot)
+    }
+
+    fn matmul<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        dispatch_binary_op(&lhs, &rhs, CpuIntegerArrayBackend::matmul)
+    }
+}
+
+impl<Clear> ClearArithmeticArrayBackend<Clear> for DynUintBackend
+where
+    CpuIntegerArrayBackend<RadixCiphertext>: ClearArithmeticArrayBackend<Clear>,
+{
+    fn add_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::add_slices,
+        )
+    }
+
+    fn sub_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::sub_slices,
+        )
+    }
+
+    fn mul_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::mul_slices,
+        )
+    }
+
+    fn div_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::div_slices,
+        )
+    }
+
+    fn rem_slices(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        rhs: TensorSlice<'_, &'_ [Clear]>,
+    ) -> Self::Owned {
+        dispatch_binary_scalar_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ClearArithmeticArrayBackend<Clear>>::rem_slices,
+        )
+    }
+}
+
+impl ComparisonArrayBackend for DynUintBackend {
+    type BoolBackend = DynFheBoolArrayBackend;
+
+    fn eq<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ComparisonArrayBackend>::eq,
+        )
+    }
+
+    fn ne<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ComparisonArrayBackend>::ne,
+        )
+    }
+
+    fn lt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ComparisonArrayBackend>::lt,
+        )
+    }
+
+    fn le<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ComparisonArrayBackend>::le,
+        )
+    }
+
+    fn gt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ComparisonArrayBackend>::gt,
+        )
+    }
+
+    fn ge<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> InnerBoolArray {
+        dispatch_comparison_op(
+            &lhs,
+            &rhs,
+            <CpuIntegerArrayBackend<RadixCiphertext> as ComparisonArrayBackend>::ge,
+        )
+    }
+}
+
+impl SelectArrayBackend for DynUintBackend {
+    fn select<'a, 'b>(
+        mask: TensorSlice<'_, InnerBoolSlice<'b>>,
+        if_true: TensorSlice<'_, Self::Slice<'a>>,
+        if_false: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned {
+        match global_state::device_of_internal_keys() {
+            Some(Device::Cpu) => {
+                let mask_cpu_cow = mask.slice.on_cpu();
+                let if_true_cpu_cow = if_true.slice.on_cpu();
+                let if_false_cpu_cow = if_false.slice.on_cpu();
+
+                let mask_cpu_slice: &[BooleanBlock] = mask_cpu_cow.borrow();
+                let if_true_cpu_slice: &[RadixCiphertext] = if_true_cpu_cow.borrow();
+                let if_false_cpu_slice: &[RadixCiphertext] = if_false_cpu_cow.borrow();
+
+                let result = CpuIntegerArrayBackend::select(
+                    TensorSlice::new(mask_cpu_slice, mask.dims),
+                    TensorSlice::new(if_true_cpu_slice, if_true.dims),
+                    TensorSlice::new(if_false_cpu_slice, if_false.dims),
+                );
+                InnerUintArray::Cpu(result)
+            }
+            #[cfg(feature = "gpu")]
+            Some(Device::CudaGpu) => {
+                panic!("Not supported by Cuda devices")
+            }
+            None => {
+                panic!("{}", crate::high_level_api::errors::UninitializedServerKey);
+            }
+        }
+    }
+}
+
+impl SortArrayBackend for DynUintBackend {
+    fn sort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&lhs, CpuIntegerArrayBackend::sort)
+    }
+
+    fn argsort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned {
+        dispatch_unary_op(&lhs, CpuIntegerArrayBackend::argsort)
+    }
+}
+
+impl ObliviousAccessArrayBackend for DynUintBackend {
+    fn get_encrypted<Id, IndexId>(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        index: &FheUint<IndexId>,
+    ) -> FheUint<Id>
+    where
+        Id: FheUintId,
+        IndexId: FheUintId,
+    {
+        match lhs.slice {
+            InnerUintSlice::Cpu(cpu_slice) => {
+                CpuIntegerArrayBackend::get_encrypted(TensorSlice::new(cpu_slice, lhs.dims), index)
+            }
+        }
+    }
+
+    fn set_encrypted<Id, IndexId>(
+        lhs: TensorSlice<'_, Self::SliceMut<'_>>,
+        index: &FheUint<IndexId>,
+        value: &FheUint<Id>,
+    ) where
+        Id: FheUintId,
+        IndexId: FheUintId,
+    {
+        match lhs.slice {
+            InnerUintSliceMut::Cpu(cpu_slice) => CpuIntegerArrayBackend::set_encrypted(
+                TensorSlice::new(cpu_slice, lhs.dims),
+                index,
+                value,
+            ),
+        }
+    }
+}
+
+#[inline]
+fn dispatch_comparison_op<CpuFn>(
+    lhs: &TensorSlice<'_, InnerUintSlice<'_>>,
+    rhs: &TensorSlice<'_, InnerUintSlice<'_>>,
+    cpu_fn: CpuFn,
+) -> InnerBoolArray
+where
+    CpuFn: for<'a> Fn(
+        TensorSlice<'_, &'a [RadixCiphertext]>,
+        TensorSlice<'_, &'a [RadixCiphertext]>,
+    ) -> Vec<BooleanBlock>,
+{
+    match global_state::device_of_internal_keys() {
+        Some(Device::Cpu) => {
+            let lhs_cpu_cow = lhs.slice.on_cpu();
+            let rhs_cpu_cow = rhs.slice.on_cpu();
+
+            let lhs_cpu_slice: &[RadixCiphertext] = lhs_cpu_cow.borrow();
+            let rhs_cpu_slice: &[RadixCiphertext] = rhs_cpu_cow.borrow();
+
+            let result = cpu_fn(
+                TensorSlice::new(lhs_cpu_slice, lhs.dims),
+                TensorSlice::new(rhs_cpu_slice, rhs.dims),
+            );
+            InnerBoolArray::Cpu(result)
+        }
+        #[cfg(feature = "gpu")]
+        Some(Device::CudaGpu) => {
+            panic!("Not supported by Cuda devices")
+        }
+        None => {
+            panic!("{}", crate::high_level_api::errors::UninitializedServerKey);
+        }
+    }
+}
+
 #[inline]
 fn dispatch_binary_scalar_op<CpuFn, Clear>(
     lhs: &TensorSlice<'_, InnerUintSlice<'_>>,
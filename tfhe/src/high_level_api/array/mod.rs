@@ -1,10 +1,14 @@
 mod clear_ops;
+mod comparisons;
 mod cpu;
 mod dynamic;
 #[cfg(feature = "gpu")]
 mod gpu;
 mod helpers;
+mod oblivious_access;
 mod ops;
+mod reductions;
+mod sort;
 pub mod stride;
 #[cfg(test)]
 mod tests;
@@ -264,13 +268,28 @@ impl HasClear for FheBoolId {
     type Clear = bool;
 }
 
-impl HasClear for crate::FheUint32Id {
-    type Clear = u32;
+macro_rules! impl_has_clear {
+    ($($id:ident => $clear:ty),* $(,)?) => {
+        $(
+            impl HasClear for crate::$id {
+                type Clear = $clear;
+            }
+        )*
+    };
 }
 
-impl HasClear for crate::FheInt32Id {
-    type Clear = i32;
-}
+impl_has_clear!(
+    FheUint8Id => u8,
+    FheUint16Id => u16,
+    FheUint32Id => u32,
+    FheUint64Id => u64,
+    FheUint128Id => u128,
+    FheInt8Id => i8,
+    FheInt16Id => i16,
+    FheInt32Id => i32,
+    FheInt64Id => i64,
+    FheInt128Id => i128,
+);
 
 #[derive(Default, Copy, Clone)]
 pub struct ClearId;
@@ -0,0 +1,90 @@
+use super::traits::ObliviousAccessArrayBackend;
+use crate::array::traits::TensorSlice;
+use crate::high_level_api::array::{BackendDataContainer, BackendDataContainerMut, FheArrayBase};
+use crate::high_level_api::integers::FheUintId;
+use crate::FheUint;
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: FheUintId,
+    C: BackendDataContainer,
+    C::Backend: ObliviousAccessArrayBackend,
+{
+    /// Returns the element of the 1-D array at the encrypted `index`
+    ///
+    /// The access is oblivious: all the elements of the array are touched, so the sequence of
+    /// operations does not depend on the index.
+    ///
+    /// If the index is out of bounds, an encryption of 0 is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is not 1-D, or if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint32Array, FheUint8};
+    ///
+    /// let (cks, sks) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(sks);
+    ///
+    /// let mut xs = FheUint32Array::try_encrypt([10u32, 20, 30].as_slice(), &cks).unwrap();
+    /// let index = FheUint8::encrypt(1u8, &cks);
+    ///
+    /// let x: u32 = xs.get_encrypted(&index).decrypt(&cks);
+    /// assert_eq!(x, 20);
+    ///
+    /// let value = FheUint32::encrypt(42u32, &cks);
+    /// xs.set_encrypted(&index, &value);
+    ///
+    /// let values: Vec<u32> = xs.decrypt(&cks);
+    /// assert_eq!(values, vec![10, 42, 30]);
+    /// ```
+    #[track_caller]
+    pub fn get_encrypted<IndexId>(&self, index: &FheUint<IndexId>) -> FheUint<Id>
+    where
+        IndexId: FheUintId,
+    {
+        assert_eq!(
+            self.num_dim(),
+            1,
+            "encrypted indexing is only supported for 1-D arrays"
+        );
+        assert!(self.elems.len() > 0, "cannot index an empty array");
+
+        C::Backend::get_encrypted(self.as_tensor_slice(), index)
+    }
+
+    /// Replaces the element of the 1-D array at the encrypted `index` by `value`
+    ///
+    /// The access is oblivious: all the elements of the array are rewritten, so the sequence of
+    /// operations does not depend on the index.
+    ///
+    /// If the index is out of bounds, the array is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is not 1-D.
+    ///
+    /// See [Self::get_encrypted] for an example.
+    #[track_caller]
+    pub fn set_encrypted<IndexId>(&mut self, index: &FheUint<IndexId>, value: &FheUint<Id>)
+    where
+        IndexId: FheUintId,
+        C: BackendDataContainerMut,
+    {
+        assert_eq!(
+            self.num_dim(),
+            1,
+            "encrypted indexing is only supported for 1-D arrays"
+        );
+
+        C::Backend::set_encrypted(
+            TensorSlice::new(self.elems.as_slice_mut(), &self.dims),
+            index,
+            value,
+        );
+    }
+}
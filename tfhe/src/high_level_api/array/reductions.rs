@@ -0,0 +1,154 @@
+use super::traits::{LinearAlgebraArrayBackend, ReductionArrayBackend};
+use crate::high_level_api::array::{ArrayBackend, BackendDataContainer, FheArrayBase};
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: ReductionArrayBackend,
+{
+    #[track_caller]
+    fn check_reduction_axis(&self, axis: usize) {
+        assert!(
+            axis < self.num_dim(),
+            "axis {axis} is out of bounds for an array of {} dimensions",
+            self.num_dim()
+        );
+        assert!(
+            self.shape().iter().all(|&len| len > 0),
+            "cannot reduce an empty array"
+        );
+    }
+
+    /// Returns the sum of the elements along the given axis
+    ///
+    /// The reduced axis is kept in the resulting shape, with a length of 1
+    /// (e.g. reducing a [2, 3] array along axis 1 gives a [2, 1] array).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds or if the array is empty.
+    #[track_caller]
+    pub fn sum(&self, axis: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        self.check_reduction_axis(axis);
+        let result = C::Backend::sum_along_axis(self.as_tensor_slice(), axis);
+        FheArrayBase::new(result, self.dims.with_reduced_axis(axis).shape().to_vec())
+    }
+
+    /// Returns the product of the elements along the given axis
+    ///
+    /// See [Self::sum] for the resulting shape.
+    #[track_caller]
+    pub fn product(&self, axis: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        self.check_reduction_axis(axis);
+        let result = C::Backend::product_along_axis(self.as_tensor_slice(), axis);
+        FheArrayBase::new(result, self.dims.with_reduced_axis(axis).shape().to_vec())
+    }
+
+    /// Returns the minimum of the elements along the given axis
+    ///
+    /// See [Self::sum] for the resulting shape.
+    #[track_caller]
+    pub fn min(&self, axis: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        self.check_reduction_axis(axis);
+        let result = C::Backend::min_along_axis(self.as_tensor_slice(), axis);
+        FheArrayBase::new(result, self.dims.with_reduced_axis(axis).shape().to_vec())
+    }
+
+    /// Returns the maximum of the elements along the given axis
+    ///
+    /// See [Self::sum] for the resulting shape.
+    #[track_caller]
+    pub fn max(&self, axis: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        self.check_reduction_axis(axis);
+        let result = C::Backend::max_along_axis(self.as_tensor_slice(), axis);
+        FheArrayBase::new(result, self.dims.with_reduced_axis(axis).shape().to_vec())
+    }
+
+    /// Returns the index of the maximum along the given axis
+    ///
+    /// If the maximum appears several times, the index of its first occurrence is returned.
+    /// Indices are encrypted with the same type as the elements of the array.
+    ///
+    /// See [Self::sum] for the resulting shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds, if the array is empty, or if the axis is too long for
+    /// the indices to be represented by the type of the elements.
+    #[track_caller]
+    pub fn argmax(&self, axis: usize) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        self.check_reduction_axis(axis);
+        let result = C::Backend::argmax_along_axis(self.as_tensor_slice(), axis);
+        FheArrayBase::new(result, self.dims.with_reduced_axis(axis).shape().to_vec())
+    }
+}
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: LinearAlgebraArrayBackend,
+{
+    /// Returns the dot product of two 1-D arrays, as an array of shape [1]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays are not 1-D, do not have the same length, or are empty.
+    #[track_caller]
+    pub fn dot<C2>(
+        &self,
+        other: &FheArrayBase<C2, Id>,
+    ) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>
+    where
+        C2: BackendDataContainer<Backend = C::Backend>,
+    {
+        assert!(
+            self.num_dim() == 1 && other.num_dim() == 1,
+            "dot product is only supported for 1-D arrays"
+        );
+        if !self.has_same_shape(other) {
+            panic!("Array operands do not have the same shape");
+        }
+        assert!(
+            self.shape()[0] > 0,
+            "cannot compute the dot product of empty arrays"
+        );
+
+        let result = C::Backend::dot(self.as_tensor_slice(), other.as_tensor_slice());
+        FheArrayBase::new(result, vec![1])
+    }
+
+    /// Returns the matrix product of a (m, k) and a (k, n) 2-D arrays, as a (m, n) array
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays are not 2-D, if their shapes are not compatible, or if k is 0.
+    #[track_caller]
+    pub fn matmul<C2>(
+        &self,
+        other: &FheArrayBase<C2, Id>,
+    ) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id>
+    where
+        C2: BackendDataContainer<Backend = C::Backend>,
+    {
+        assert!(
+            self.num_dim() == 2 && other.num_dim() == 2,
+            "matrix product is only supported for 2-D arrays"
+        );
+        assert_eq!(
+            self.shape()[1],
+            other.shape()[0],
+            "matrix product operands have incompatible shapes {:?} and {:?}",
+            self.shape(),
+            other.shape()
+        );
+        assert!(
+            self.shape()[1] > 0,
+            "cannot compute the matrix product along an empty axis"
+        );
+
+        let result = C::Backend::matmul(self.as_tensor_slice(), other.as_tensor_slice());
+        FheArrayBase::new(result, vec![self.shape()[0], other.shape()[1]])
+    }
+}
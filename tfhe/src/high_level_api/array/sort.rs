@@ -0,0 +1,68 @@
+use super::traits::SortArrayBackend;
+use crate::high_level_api::array::{ArrayBackend, BackendDataContainer, FheArrayBase};
+
+impl<C, Id> FheArrayBase<C, Id>
+where
+    Id: Default,
+    C: BackendDataContainer,
+    C::Backend: SortArrayBackend,
+{
+    /// Returns a copy of the 1-D array, with its elements sorted in ascending order
+    ///
+    /// The sort is oblivious: it uses a sorting network, so the sequence of operations does not
+    /// depend on the values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is not 1-D.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32Array};
+    ///
+    /// let (cks, sks) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(sks);
+    ///
+    /// let xs = FheUint32Array::try_encrypt([3u32, 1, 2].as_slice(), &cks).unwrap();
+    ///
+    /// let sorted: Vec<u32> = xs.sort().decrypt(&cks);
+    /// assert_eq!(sorted, vec![1, 2, 3]);
+    ///
+    /// let indices: Vec<u32> = xs.argsort().decrypt(&cks);
+    /// assert_eq!(indices, vec![1, 2, 0]);
+    /// ```
+    #[track_caller]
+    pub fn sort(&self) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        assert_eq!(
+            self.num_dim(),
+            1,
+            "sorting is only supported for 1-D arrays"
+        );
+
+        let result = C::Backend::sort(self.as_tensor_slice());
+        FheArrayBase::new(result, self.shape().to_vec())
+    }
+
+    /// Returns the indices that sort the 1-D array in ascending order
+    ///
+    /// The sort is stable, equal elements keep their relative order. Indices are encrypted with
+    /// the same type as the elements of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is not 1-D, or if it is too long for the indices to be represented by
+    /// the type of the elements.
+    #[track_caller]
+    pub fn argsort(&self) -> FheArrayBase<<C::Backend as ArrayBackend>::Owned, Id> {
+        assert_eq!(
+            self.num_dim(),
+            1,
+            "sorting is only supported for 1-D arrays"
+        );
+
+        let result = C::Backend::argsort(self.as_tensor_slice());
+        FheArrayBase::new(result, self.shape().to_vec())
+    }
+}
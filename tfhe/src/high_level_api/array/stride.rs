@@ -55,6 +55,20 @@ impl DynDimensions {
         Some((new_dim, flat_start..=flat_end))
     }
 
+    /// Returns the dimensions where the given axis has a length of 1, keeping the strides.
+    ///
+    /// Iterating over these dimensions yields the flat index of the first element of each lane
+    /// along the axis.
+    pub fn with_reduced_axis(&self, axis: usize) -> Self {
+        let mut shape = self.shape.clone();
+        shape[axis] = 1;
+
+        Self {
+            shape,
+            strides: self.strides.clone(),
+        }
+    }
+
     pub fn flatten_index(&self, index: &[usize]) -> Option<usize> {
         if index.len() != self.num_dim() {
             return None;
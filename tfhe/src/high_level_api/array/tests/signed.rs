@@ -85,3 +85,57 @@ fn test_cpu_dyn_bitand_scalar_slice() {
     let ck = super::setup_default_cpu();
     super::bitand_scalar_slice_test_case::<crate::FheInt32Array, i32>(&ck);
 }
+
+#[test]
+fn test_reductions() {
+    use crate::prelude::*;
+    use crate::{CpuFheInt32Array, FheInt32Array};
+
+    let ck = super::setup_default_cpu();
+
+    let clear_xs = vec![-3i32, 7, -1, 7, -8];
+
+    let xs = CpuFheInt32Array::try_encrypt(clear_xs.as_slice(), &ck).unwrap();
+    let dyn_xs = FheInt32Array::try_encrypt(clear_xs.as_slice(), &ck).unwrap();
+
+    let r: Vec<i32> = xs.sum(0).decrypt(&ck);
+    assert_eq!(r, vec![2]);
+
+    let r: Vec<i32> = dyn_xs.min(0).decrypt(&ck);
+    assert_eq!(r, vec![-8]);
+
+    let r: Vec<i32> = dyn_xs.argmax(0).decrypt(&ck);
+    assert_eq!(r, vec![1]);
+
+    let r: Vec<i32> = xs.dot(&xs).decrypt(&ck);
+    assert_eq!(r, vec![172]);
+
+    let scalar = crate::array::ClearArray::new(vec![-2i32], vec![1]);
+    let r: Vec<i32> = (&dyn_xs * &scalar).decrypt(&ck);
+    assert_eq!(r, vec![6, -14, 2, -14, 16]);
+}
+
+#[test]
+fn test_sort() {
+    use crate::prelude::*;
+    use crate::FheInt32Array;
+
+    let ck = super::setup_default_cpu();
+
+    let clear_xs = vec![3i32, -7, 0, -7, 12, -1];
+
+    let xs = FheInt32Array::try_encrypt(clear_xs.as_slice(), &ck).unwrap();
+
+    let sorted: Vec<i32> = xs.sort().decrypt(&ck);
+    assert_eq!(sorted, vec![-7, -7, -1, 0, 3, 12]);
+
+    let indices: Vec<i32> = xs.argsort().decrypt(&ck);
+    assert_eq!(indices, vec![1, 3, 5, 2, 0, 4]);
+
+    let zeros = FheInt32Array::try_encrypt(vec![0i32; 6].as_slice(), &ck).unwrap();
+    let r: Vec<bool> = xs.lt(&zeros).decrypt(&ck);
+    assert_eq!(r, vec![false, true, false, true, false, true]);
+
+    let r: Vec<i32> = xs.lt(&zeros).select(&zeros, &xs).decrypt(&ck);
+    assert_eq!(r, vec![3, 0, 0, 0, 12, 0]);
+}
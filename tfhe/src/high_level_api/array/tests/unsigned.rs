@@ -1,6 +1,9 @@
 use crate::array::ClearArray;
 use crate::prelude::*;
-use crate::{generate_keys, set_server_key, ConfigBuilder, CpuFheUint32Array, FheUint32Array};
+use crate::{
+    generate_keys, set_server_key, ConfigBuilder, CpuFheUint32Array, FheUint32, FheUint32Array,
+    FheUint8,
+};
 use rand::prelude::*;
 use rand::thread_rng;
 
@@ -176,3 +179,231 @@ fn test_2_dimension() {
     let r: Vec<u32> = r.decrypt(&cks);
     assert_eq!(r, vec![20, 31, 44, 55]);
 }
+
+#[test]
+fn test_reductions() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let clear_xs = vec![3u32, 7, 1, 5, 7, 2];
+
+    let xs = CpuFheUint32Array::try_encrypt((clear_xs.as_slice(), vec![2, 3]), &cks).unwrap();
+    let dyn_xs = FheUint32Array::try_encrypt((clear_xs.as_slice(), vec![2, 3]), &cks).unwrap();
+
+    let r = xs.sum(0);
+    assert_eq!(r.shape(), &[1, 3]);
+    let r: Vec<u32> = r.decrypt(&cks);
+    assert_eq!(r, vec![8, 14, 3]);
+
+    let r = dyn_xs.sum(1);
+    assert_eq!(r.shape(), &[2, 1]);
+    let r: Vec<u32> = r.decrypt(&cks);
+    assert_eq!(r, vec![11, 14]);
+
+    let r: Vec<u32> = xs.product(1).decrypt(&cks);
+    assert_eq!(r, vec![21, 70]);
+
+    let r: Vec<u32> = xs.min(0).decrypt(&cks);
+    assert_eq!(r, vec![3, 7, 1]);
+
+    let r: Vec<u32> = dyn_xs.max(1).decrypt(&cks);
+    assert_eq!(r, vec![7, 7]);
+
+    let r: Vec<u32> = xs.argmax(1).decrypt(&cks);
+    assert_eq!(r, vec![1, 1]);
+
+    let r: Vec<u32> = dyn_xs.argmax(0).decrypt(&cks);
+    assert_eq!(r, vec![1, 0, 1]);
+}
+
+#[test]
+fn test_dot_and_matmul() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let clear_xs = vec![1u32, 2, 3];
+    let clear_ys = vec![4u32, 5, 6];
+
+    let xs = FheUint32Array::try_encrypt(clear_xs.as_slice(), &cks).unwrap();
+    let ys = FheUint32Array::try_encrypt(clear_ys.as_slice(), &cks).unwrap();
+
+    let r = xs.dot(&ys);
+    assert_eq!(r.shape(), &[1]);
+    let r: Vec<u32> = r.decrypt(&cks);
+    assert_eq!(r, vec![32]);
+
+    // [[1, 2, 3], [4, 5, 6]] x [[1, 2], [3, 4], [5, 6]]
+    let clear_lhs = (1..=6u32).collect::<Vec<_>>();
+    let clear_rhs = (1..=6u32).collect::<Vec<_>>();
+
+    let lhs = CpuFheUint32Array::try_encrypt((clear_lhs.as_slice(), vec![2, 3]), &cks).unwrap();
+    let rhs = CpuFheUint32Array::try_encrypt((clear_rhs.as_slice(), vec![3, 2]), &cks).unwrap();
+
+    let r = lhs.matmul(&rhs);
+    assert_eq!(r.shape(), &[2, 2]);
+    let r: Vec<u32> = r.decrypt(&cks);
+    assert_eq!(r, vec![22, 28, 49, 64]);
+
+    // Works on slices too
+    let r: Vec<u32> = lhs.slice(&[0..1, 0..3]).matmul(&rhs).decrypt(&cks);
+    assert_eq!(r, vec![22, 28]);
+}
+
+#[test]
+fn test_clear_scalar_broadcasting() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let clear_xs = vec![1u32, 2, 3, 4];
+
+    let xs = CpuFheUint32Array::try_encrypt((clear_xs.as_slice(), vec![2, 2]), &cks).unwrap();
+    let dyn_xs = FheUint32Array::try_encrypt((clear_xs.as_slice(), vec![2, 2]), &cks).unwrap();
+
+    let scalar = ClearArray::new(vec![10u32], vec![1]);
+
+    let r: Vec<u32> = (&xs + &scalar).decrypt(&cks);
+    assert_eq!(r, vec![11, 12, 13, 14]);
+
+    let r: Vec<u32> = (&dyn_xs * &scalar).decrypt(&cks);
+    assert_eq!(r, vec![10, 20, 30, 40]);
+
+    let r: Vec<u32> = (&dyn_xs - &ClearArray::new(vec![1u32], vec![1])).decrypt(&cks);
+    assert_eq!(r, vec![0, 1, 2, 3]);
+
+    let clear_array = ClearArray::new(vec![2u32, 3, 4, 5], vec![2, 2]);
+    let r: Vec<u32> = (&dyn_xs | &clear_array).decrypt(&cks);
+    assert_eq!(r, vec![3, 3, 7, 5]);
+}
+
+#[test]
+fn test_comparisons_and_select() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let clear_xs = vec![1u32, 5, 3, 8];
+    let clear_ys = vec![4u32, 2, 3, 9];
+
+    let xs = CpuFheUint32Array::try_encrypt((clear_xs.as_slice(), vec![2, 2]), &cks).unwrap();
+    let ys = CpuFheUint32Array::try_encrypt((clear_ys.as_slice(), vec![2, 2]), &cks).unwrap();
+
+    let mask = xs.eq(&ys);
+    assert_eq!(mask.shape(), &[2, 2]);
+    let r: Vec<bool> = mask.decrypt(&cks);
+    assert_eq!(r, vec![false, false, true, false]);
+
+    let r: Vec<bool> = xs.ne(&ys).decrypt(&cks);
+    assert_eq!(r, vec![true, true, false, true]);
+    let r: Vec<bool> = xs.lt(&ys).decrypt(&cks);
+    assert_eq!(r, vec![true, false, false, true]);
+    let r: Vec<bool> = xs.le(&ys).decrypt(&cks);
+    assert_eq!(r, vec![true, false, true, true]);
+    let r: Vec<bool> = xs.gt(&ys).decrypt(&cks);
+    assert_eq!(r, vec![false, true, false, false]);
+    let r: Vec<bool> = xs.ge(&ys).decrypt(&cks);
+    assert_eq!(r, vec![false, true, true, false]);
+
+    let mask = xs.gt(&ys);
+    let r: Vec<u32> = mask.select(&xs, &ys).decrypt(&cks);
+    assert_eq!(r, vec![4, 5, 3, 9]);
+
+    // Same on the dynamic backend, with slices
+    let xs = FheUint32Array::try_encrypt(clear_xs.as_slice(), &cks).unwrap();
+    let ys = FheUint32Array::try_encrypt(clear_ys.as_slice(), &cks).unwrap();
+
+    let xss = xs.slice(&[1..3]);
+    let yss = ys.slice(&[1..3]);
+
+    let mask = xss.lt(&yss);
+    let r: Vec<bool> = mask.decrypt(&cks);
+    assert_eq!(r, vec![false, false]);
+
+    let r: Vec<u32> = mask.select(&xss, &yss).decrypt(&cks);
+    assert_eq!(r, vec![2, 3]);
+}
+
+#[test]
+fn test_sort() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let mut rng = thread_rng();
+
+    for num_elems in [1, 2, 5, 8] {
+        // Small values so that there are duplicates
+        let clear_xs = (0..num_elems)
+            .map(|_| rng.gen_range(0..4u32))
+            .collect::<Vec<_>>();
+
+        let mut expected_sorted = clear_xs.clone();
+        expected_sorted.sort_unstable();
+
+        let mut expected_indices = (0..num_elems as u32).collect::<Vec<_>>();
+        expected_indices.sort_by_key(|&i| clear_xs[i as usize]);
+
+        let xs = FheUint32Array::try_encrypt(clear_xs.as_slice(), &cks).unwrap();
+
+        let sorted = xs.sort();
+        assert_eq!(sorted.shape(), &[num_elems]);
+        let sorted: Vec<u32> = sorted.decrypt(&cks);
+        assert_eq!(sorted, expected_sorted, "sort failed for {clear_xs:?}");
+
+        let indices: Vec<u32> = xs.argsort().decrypt(&cks);
+        assert_eq!(indices, expected_indices, "argsort failed for {clear_xs:?}");
+    }
+}
+
+#[test]
+fn test_encrypted_indexing() {
+    let config = ConfigBuilder::default().build();
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let mut rng = thread_rng();
+
+    let num_elems = 6;
+    let mut clear_xs = (0..num_elems).map(|_| rng.gen::<u32>()).collect::<Vec<_>>();
+
+    let mut xs = FheUint32Array::try_encrypt(clear_xs.as_slice(), &cks).unwrap();
+    let mut cpu_xs = CpuFheUint32Array::try_encrypt(clear_xs.as_slice(), &cks).unwrap();
+
+    // The last index is out of bounds
+    for clear_index in [0u8, 3, num_elems as u8 - 1, num_elems as u8] {
+        let index = FheUint8::encrypt(clear_index, &cks);
+        let expected = clear_xs.get(clear_index as usize).copied().unwrap_or(0);
+
+        let x: u32 = xs.get_encrypted(&index).decrypt(&cks);
+        assert_eq!(x, expected, "get_encrypted failed at index {clear_index}");
+        let x: u32 = cpu_xs.get_encrypted(&index).decrypt(&cks);
+        assert_eq!(x, expected, "get_encrypted failed at index {clear_index}");
+
+        let clear_value = rng.gen::<u32>();
+        let value = FheUint32::encrypt(clear_value, &cks);
+        xs.set_encrypted(&index, &value);
+        cpu_xs.set_encrypted(&index, &value);
+        if let Some(x) = clear_xs.get_mut(clear_index as usize) {
+            *x = clear_value;
+        }
+
+        let values: Vec<u32> = xs.decrypt(&cks);
+        assert_eq!(
+            values, clear_xs,
+            "set_encrypted failed at index {clear_index}"
+        );
+        let values: Vec<u32> = cpu_xs.decrypt(&cks);
+        assert_eq!(
+            values, clear_xs,
+            "set_encrypted failed at index {clear_index}"
+        );
+    }
+}
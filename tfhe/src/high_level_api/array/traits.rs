@@ -1,4 +1,6 @@
 use crate::array::stride::{DynDimensions, ParStridedIter, ParStridedIterMut, StridedIter};
+use crate::high_level_api::integers::FheUintId;
+use crate::FheUint;
 use std::ops::RangeBounds;
 
 pub struct TensorSlice<'a, Slc> {
@@ -150,6 +152,117 @@ pub trait ClearBitwiseArrayBackend<Clear>: ArrayBackend {
     ) -> Self::Owned;
 }
 
+/// Trait for backends that can reduce arrays along an axis
+///
+/// The reduced axis is kept in the resulting shape, with a length of 1
+pub trait ReductionArrayBackend: ArrayBackend {
+    fn sum_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned;
+
+    fn product_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned;
+
+    fn min_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned;
+
+    fn max_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned;
+
+    fn argmax_along_axis(lhs: TensorSlice<'_, Self::Slice<'_>>, axis: usize) -> Self::Owned;
+}
+
+/// Trait for backends that can do linear algebra operations
+pub trait LinearAlgebraArrayBackend: ArrayBackend {
+    /// Dot product of two 1-D arrays of the same length
+    fn dot<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned;
+
+    /// Matrix product of a (m, k) and a (k, n) 2-D arrays
+    fn matmul<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned;
+}
+
+/// Trait for backends that can do element-wise comparisons
+pub trait ComparisonArrayBackend: ArrayBackend {
+    /// The backend of the boolean arrays resulting from comparisons
+    type BoolBackend: ArrayBackend;
+
+    fn eq<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> <Self::BoolBackend as ArrayBackend>::Owned;
+
+    fn ne<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> <Self::BoolBackend as ArrayBackend>::Owned;
+
+    fn lt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> <Self::BoolBackend as ArrayBackend>::Owned;
+
+    fn le<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> <Self::BoolBackend as ArrayBackend>::Owned;
+
+    fn gt<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> <Self::BoolBackend as ArrayBackend>::Owned;
+
+    fn ge<'a>(
+        lhs: TensorSlice<'_, Self::Slice<'a>>,
+        rhs: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> <Self::BoolBackend as ArrayBackend>::Owned;
+}
+
+/// Trait for backends that can select elements from two arrays using a boolean mask
+pub trait SelectArrayBackend: ComparisonArrayBackend {
+    fn select<'a, 'b>(
+        mask: TensorSlice<'_, <Self::BoolBackend as ArrayBackend>::Slice<'b>>,
+        if_true: TensorSlice<'_, Self::Slice<'a>>,
+        if_false: TensorSlice<'_, Self::Slice<'a>>,
+    ) -> Self::Owned;
+}
+
+/// Trait for backends that can obliviously sort 1-D arrays
+pub trait SortArrayBackend: ArrayBackend {
+    /// Sorts the elements in ascending order
+    fn sort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+
+    /// Returns the indices that sort the elements in ascending order
+    ///
+    /// The sort is stable: equal elements keep their relative order
+    fn argsort(lhs: TensorSlice<'_, Self::Slice<'_>>) -> Self::Owned;
+}
+
+/// Trait for backends that can read and write elements of 1-D arrays at an encrypted index
+///
+/// The access pattern does not depend on the index, all the elements are touched
+pub trait ObliviousAccessArrayBackend: ArrayBackend {
+    /// Returns the element at the encrypted index, or an encryption of 0 if the index is out of
+    /// bounds
+    fn get_encrypted<Id, IndexId>(
+        lhs: TensorSlice<'_, Self::Slice<'_>>,
+        index: &FheUint<IndexId>,
+    ) -> FheUint<Id>
+    where
+        Id: FheUintId,
+        IndexId: FheUintId;
+
+    /// Replaces the element at the encrypted index, nothing is written if the index is out of
+    /// bounds
+    fn set_encrypted<Id, IndexId>(
+        lhs: TensorSlice<'_, Self::SliceMut<'_>>,
+        index: &FheUint<IndexId>,
+        value: &FheUint<Id>,
+    ) where
+        Id: FheUintId,
+        IndexId: FheUintId;
+}
+
 /// Internal trait to abstract how container store data for the
 /// associated backend
 ///
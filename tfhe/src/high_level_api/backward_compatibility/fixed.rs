@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::high_level_api::fixed::{FheFixed, FheFixedId};
+
+#[derive(VersionsDispatch)]
+pub enum FheFixedVersions<Id: FheFixedId> {
+    V0(FheFixed<Id>),
+}
@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::high_level_api::float::{FheFloat, FheFloatId};
+
+#[derive(VersionsDispatch)]
+pub enum FheFloatVersions<Id: FheFloatId> {
+    V0(FheFloat<Id>),
+}
@@ -4,6 +4,12 @@ pub mod booleans;
 pub mod compact_list;
 pub mod compressed_ciphertext_list;
 pub mod config;
+pub mod fixed;
+pub mod float;
 pub mod integers;
 pub mod keys;
+#[cfg(feature = "strings")]
+pub mod strings;
 pub mod tag;
+#[cfg(feature = "threshold")]
+pub mod threshold;
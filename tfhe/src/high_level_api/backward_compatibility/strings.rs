@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::FheAsciiString;
+
+#[derive(VersionsDispatch)]
+pub enum FheAsciiStringVersions {
+    V0(FheAsciiString),
+}
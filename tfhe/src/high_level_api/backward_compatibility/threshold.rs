@@ -0,0 +1,36 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::high_level_api::threshold::{
+    BeaverTripleShare, CompactPublicKeyShare, MaskedOpening, PartialDecryption, SecretKeyShare,
+    ServerKeyShare,
+};
+
+#[derive(VersionsDispatch)]
+pub enum SecretKeyShareVersions {
+    V0(SecretKeyShare),
+}
+
+#[derive(VersionsDispatch)]
+pub enum BeaverTripleShareVersions {
+    V0(BeaverTripleShare),
+}
+
+#[derive(VersionsDispatch)]
+pub enum MaskedOpeningVersions {
+    V0(MaskedOpening),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ServerKeyShareVersions {
+    V0(ServerKeyShare),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompactPublicKeyShareVersions {
+    V0(CompactPublicKeyShare),
+}
+
+#[derive(VersionsDispatch)]
+pub enum PartialDecryptionVersions {
+    V0(PartialDecryption),
+}
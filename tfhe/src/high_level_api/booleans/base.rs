@@ -17,7 +17,7 @@ use crate::named::Named;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::PBSParameters;
-use crate::{Device, ServerKey, Tag};
+use crate::{Device, NoiseInfo, ServerKey, Tag};
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
@@ -178,6 +178,41 @@ impl FheBool {
     pub fn is_trivial(&self) -> bool {
         self.ciphertext.on_cpu().is_trivial()
     }
+
+    /// Returns the degree and noise of the block of the ciphertext
+    ///
+    /// The variance of the noise of the block can be estimated from the parameters, see
+    /// [BlockNoiseInfo::estimated_variance](crate::BlockNoiseInfo::estimated_variance).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::ciphertext::NoiseLevel;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let parameters = client_key.computation_parameters();
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let noise_info = a.noise_info();
+    /// assert!(!noise_info.carry_propagation_needed());
+    /// for block in noise_info.blocks() {
+    ///     assert_eq!(block.noise_level(), NoiseLevel::NOMINAL);
+    ///     assert_eq!(
+    ///         block.estimated_variance(parameters),
+    ///         Some(parameters.encryption_noise_variance())
+    ///     );
+    /// }
+    ///
+    /// let trivial = FheBool::encrypt_trivial(true);
+    /// let noise_info = trivial.noise_info();
+    /// assert_eq!(noise_info.blocks()[0].noise_level(), NoiseLevel::ZERO);
+    /// ```
+    pub fn noise_info(&self) -> NoiseInfo {
+        NoiseInfo::from_blocks(std::slice::from_ref(&self.ciphertext.on_cpu().0))
+    }
 }
 
 impl<Id> IfThenElse<FheUint<Id>> for FheBool
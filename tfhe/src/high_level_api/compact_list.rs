@@ -23,6 +23,8 @@ pub use zk::ProvenCompactCiphertextList;
 #[cfg(feature = "zk-pok")]
 use crate::zk::{CompactPkeCrs, ZkComputeLoad};
 use crate::{CompactPublicKey, Tag};
+#[cfg(feature = "zk-pok")]
+use std::ops::RangeInclusive;
 
 impl crate::FheTypes {
     pub(crate) fn from_data_kind(
@@ -74,6 +76,7 @@ impl crate::FheTypes {
                 }
             }
             DataKind::Boolean => Self::Bool,
+            DataKind::String { .. } => Self::AsciiString,
         })
     }
 }
@@ -222,6 +225,22 @@ mod zk {
             self.inner.verify(crs, &pk.key.key, metadata)
         }
 
+        /// See [crate::zk::verify_batch]
+        pub(crate) fn verify_batch(
+            lists: &[&Self],
+            crs: &CompactPkeCrs,
+            pk: &CompactPublicKey,
+            metadata: &[u8],
+        ) -> crate::zk::ZkBatchVerificationOutcome {
+            let lists = lists.iter().map(|list| &list.inner).collect::<Vec<_>>();
+            crate::integer::ciphertext::ProvenCompactCiphertextList::verify_batch(
+                &lists,
+                crs,
+                &pk.key.key,
+                metadata,
+            )
+        }
+
         pub fn verify_and_expand(
             &self,
             crs: &CompactPkeCrs,
@@ -261,6 +280,29 @@ mod zk {
             })
         }
 
+        /// Returns the ranges proven for the values of the list, if it was built with
+        /// [CompactCiphertextListBuilder::build_with_proof_and_ranges]
+        ///
+        /// The ranges are only guaranteed once the list has been verified.
+        pub fn ranges(&self) -> Option<Vec<RangeInclusive<u64>>> {
+            self.inner.ranges()
+        }
+
+        /// Verifies the list then expands it, checking that the ranges proven for its values
+        /// are included in `ranges`, one per value.
+        ///
+        /// See [Self::verify_and_expand].
+        pub fn verify_and_expand_with_ranges(
+            &self,
+            crs: &CompactPkeCrs,
+            ranges: &[RangeInclusive<u64>],
+            pk: &CompactPublicKey,
+            metadata: &[u8],
+        ) -> crate::Result<CompactCiphertextListExpander> {
+            self.inner.check_ranges_are_included_in(ranges)?;
+            self.verify_and_expand(crs, pk, metadata)
+        }
+
         #[doc(hidden)]
         /// This function allows to expand a ciphertext without verifying the associated proof.
         ///
@@ -380,6 +422,138 @@ mod zk {
 
             assert!(proven_compact_list.is_conformant(&params));
         }
+
+        #[test]
+        fn conformance_zk_compact_ciphertext_list_with_ranges() {
+            let mut rng = thread_rng();
+
+            let params: crate::shortint::ClassicPBSParameters =
+                crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+            let config = crate::ConfigBuilder::with_custom_parameters(params);
+
+            let client_key = crate::ClientKey::generate(config.clone());
+            let crs = CompactPkeCrs::from_config(config.into(), 64).unwrap();
+            let public_key = crate::CompactPublicKey::try_new(&client_key).unwrap();
+
+            let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+
+            let clear_a = rng.gen_range(100u64..=200);
+            let clear_b = rng.gen::<bool>();
+            let ranges = [100..=200, 0..=1];
+
+            let proven_compact_list = crate::ProvenCompactCiphertextList::builder(&public_key)
+                .push(clear_a)
+                .push(clear_b)
+                .build_with_proof_and_ranges(&crs, &ranges, &metadata, ZkComputeLoad::Proof)
+                .unwrap();
+
+            assert_eq!(proven_compact_list.ranges().unwrap(), ranges);
+            assert!(proven_compact_list
+                .verify(&crs, &public_key, &metadata)
+                .is_valid());
+            assert!(proven_compact_list
+                .verify(&crs, &public_key, &[b'o', b't', b'h', b'e', b'r'])
+                .is_invalid());
+
+            // Values out of their range cannot be proven
+            assert!(crate::ProvenCompactCiphertextList::builder(&public_key)
+                .push(clear_a)
+                .push(clear_b)
+                .build_with_proof_and_ranges(
+                    &crs,
+                    &[0..=99, 0..=1],
+                    &metadata,
+                    ZkComputeLoad::Proof
+                )
+                .is_err());
+
+            // A range claimed for the list must match the one of its proof
+            let mut forged_list = proven_compact_list.clone();
+            forged_list.inner.proven_ranges.as_mut().unwrap().ranges[0] = (0, 99);
+            assert!(forged_list
+                .verify(&crs, &public_key, &metadata)
+                .is_invalid());
+
+            let params =
+                IntegerProvenCompactCiphertextListConformanceParams::from_crs_and_parameters(
+                    params.try_into().unwrap(),
+                    &crs,
+                );
+
+            assert!(proven_compact_list.is_conformant(&params));
+        }
+
+        #[test]
+        fn verify_batch_zk_compact_ciphertext_list() {
+            let mut rng = thread_rng();
+
+            let params = crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+            let config = crate::ConfigBuilder::with_custom_parameters(params).build();
+
+            let client_key = crate::ClientKey::generate(config);
+            let public_key = crate::CompactPublicKey::try_new(&client_key).unwrap();
+
+            let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+            let other_metadata = [b'o', b't', b'h', b'e', b'r'];
+
+            for crs in [
+                CompactPkeCrs::from_config(config, 64).unwrap(),
+                CompactPkeCrs::from_shortint_params_legacy_v1(
+                    params,
+                    crate::core_crypto::prelude::LweCiphertextCount(32),
+                )
+                .unwrap(),
+            ] {
+                let mut lists = (0..3)
+                    .map(|_| {
+                        crate::ProvenCompactCiphertextList::builder(&public_key)
+                            .push(rng.gen::<u64>())
+                            .push(rng.gen::<bool>())
+                            .build_with_proof_packed(&crs, &metadata, ZkComputeLoad::Proof)
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                if crs.scheme_version() == crate::zk::CompactPkeZkScheme::V2 {
+                    lists.push(
+                        crate::ProvenCompactCiphertextList::builder(&public_key)
+                            .push(rng.gen_range(0u64..=1000))
+                            .build_with_proof_and_ranges(
+                                &crs,
+                                &[0..=1000],
+                                &metadata,
+                                ZkComputeLoad::Verify,
+                            )
+                            .unwrap(),
+                    );
+                }
+
+                let batch = lists.iter().collect::<Vec<_>>();
+                assert!(crate::zk::verify_batch(&batch, &crs, &public_key, &metadata).is_valid());
+                assert!(crate::zk::verify_batch(&[], &crs, &public_key, &metadata).is_valid());
+
+                lists[1] = crate::ProvenCompactCiphertextList::builder(&public_key)
+                    .push(rng.gen::<u64>())
+                    .build_with_proof_packed(&crs, &other_metadata, ZkComputeLoad::Proof)
+                    .unwrap();
+
+                // The ranges of the list are part of the batch
+                let mut expected_invalid_indices = vec![1];
+                if let Some(range_list) = lists.get_mut(3) {
+                    range_list.inner.proven_ranges.as_mut().unwrap().ranges[0] = (0, 100);
+                    expected_invalid_indices.push(3);
+                }
+
+                let batch = lists.iter().collect::<Vec<_>>();
+                let outcome = crate::zk::verify_batch(&batch, &crs, &public_key, &metadata);
+                assert_eq!(outcome.invalid_indices(), expected_invalid_indices);
+                for (i, list) in lists.iter().enumerate() {
+                    assert_eq!(
+                        list.verify(&crs, &public_key, &metadata).is_invalid(),
+                        outcome.invalid_indices().contains(&i)
+                    );
+                }
+            }
+        }
     }
 }
 
@@ -480,6 +654,29 @@ impl CompactCiphertextListBuilder {
         Ok(self)
     }
 
+    /// Pushes an ASCII string, it will be expanded as a [crate::FheAsciiString]
+    #[cfg(feature = "strings")]
+    pub fn push_string(&mut self, string: &str) -> crate::Result<&mut Self> {
+        self.push_string_with_padding(string, 0)
+    }
+
+    /// Pushes an ASCII string followed by `padding` null chars,
+    /// it will be expanded as a [crate::FheAsciiString]
+    #[cfg(feature = "strings")]
+    pub fn push_string_with_padding(
+        &mut self,
+        string: &str,
+        padding: u32,
+    ) -> crate::Result<&mut Self> {
+        if !string.is_ascii() || string.contains('\0') {
+            return Err(crate::Error::new(
+                "Input is not an ASCII string or contains null characters".to_string(),
+            ));
+        }
+        self.inner.push_string_with_padding(string, padding);
+        Ok(self)
+    }
+
     pub fn build(&self) -> CompactCiphertextList {
         CompactCiphertextList {
             inner: self.inner.build(),
@@ -511,6 +708,77 @@ impl CompactCiphertextListBuilder {
                 tag: self.tag.clone(),
             })
     }
+
+    /// Builds a packed list with a proof of encryption, along with proofs that each pushed value
+    /// lies in the corresponding range of `ranges`.
+    ///
+    /// Only unsigned integers and booleans of at most 64 bits are supported, with parameters
+    /// where `CarryModulus == MessageModulus` and a CRS of the V2 zk scheme.
+    ///
+    /// # Security
+    ///
+    /// The ranges are proven over the values committed by the proof of encryption, so they hold
+    /// for the encrypted values. They are checked along with the proof of encryption by
+    /// [ProvenCompactCiphertextList::verify] and [ProvenCompactCiphertextList::verify_and_expand].
+    /// The ranges themselves are chosen by the prover: use
+    /// [ProvenCompactCiphertextList::verify_and_expand_with_ranges] to check that they are within
+    /// the expected ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::zk::{CompactPkeCrs, ZkComputeLoad};
+    /// use tfhe::{
+    ///     set_server_key, ClientKey, CompactPublicKey, ConfigBuilder, FheUint64,
+    ///     ProvenCompactCiphertextList, ServerKey,
+    /// };
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).build();
+    /// let crs = CompactPkeCrs::from_config(config, 64).unwrap();
+    ///
+    /// let client_key = ClientKey::generate(config);
+    /// let public_key = CompactPublicKey::try_new(&client_key).unwrap();
+    /// set_server_key(ServerKey::new(&client_key));
+    ///
+    /// // The transfer amount must not exceed the cap
+    /// let ranges = [0..=10_000];
+    /// let metadata = b"transfer";
+    ///
+    /// let proven_list = ProvenCompactCiphertextList::builder(&public_key)
+    ///     .push(4_200u64)
+    ///     .build_with_proof_and_ranges(&crs, &ranges, metadata, ZkComputeLoad::Proof)
+    ///     .unwrap();
+    ///
+    /// let expander = proven_list
+    ///     .verify_and_expand_with_ranges(&crs, &ranges, &public_key, metadata)
+    ///     .unwrap();
+    /// let amount: FheUint64 = expander.get(0).unwrap().unwrap();
+    /// let decrypted: u64 = amount.decrypt(&client_key);
+    /// assert_eq!(decrypted, 4_200);
+    ///
+    /// // A lower cap is rejected
+    /// assert!(proven_list
+    ///     .verify_and_expand_with_ranges(&crs, &[0..=1_000], &public_key, metadata)
+    ///     .is_err());
+    /// ```
+    #[cfg(feature = "zk-pok")]
+    pub fn build_with_proof_and_ranges(
+        &self,
+        crs: &CompactPkeCrs,
+        ranges: &[RangeInclusive<u64>],
+        metadata: &[u8],
+        compute_load: ZkComputeLoad,
+    ) -> crate::Result<ProvenCompactCiphertextList> {
+        self.inner
+            .build_with_proof_packed_and_ranges(crs, ranges, metadata, compute_load)
+            .map(|proved_list| ProvenCompactCiphertextList {
+                inner: proved_list,
+                tag: self.tag.clone(),
+            })
+    }
 }
 
 #[cfg(test)]
@@ -70,9 +70,24 @@ impl HlCompressible for FheBool {
     }
 }
 
+#[cfg(feature = "strings")]
+impl HlCompressible for crate::FheAsciiString {
+    fn compress_into(self, messages: &mut Vec<(ToBeCompressed, DataKind)>) {
+        let padded = self.inner.is_padded();
+        let blocks = self.inner.into_uint().into_blocks();
+        let kind = DataKind::String {
+            num_blocks: blocks.len(),
+            padded,
+        };
+        messages.push((ToBeCompressed::Cpu(blocks), kind));
+    }
+}
+
 impl<Id: FheUintId> HlExpandable for FheUint<Id> {}
 impl<Id: FheIntId> HlExpandable for FheInt<Id> {}
 impl HlExpandable for FheBool {}
+#[cfg(feature = "strings")]
+impl HlExpandable for crate::FheAsciiString {}
 
 #[cfg(not(feature = "gpu"))]
 pub trait HlExpandable: Expandable {}
@@ -478,6 +493,10 @@ pub mod gpu {
                     "Tried to expand a FheUint{} while a FheBool is stored in this slot",
                     Id::num_bits(),
                 ))),
+                DataKind::String { .. } => Err(crate::Error::new(format!(
+                    "Tried to expand a FheUint{} while a string is stored in this slot",
+                    Id::num_bits(),
+                ))),
             }
         }
     }
@@ -518,6 +537,10 @@ pub mod gpu {
                     "Tried to expand a FheUint{} while a FheBool is stored in this slot",
                     Id::num_bits(),
                 ))),
+                DataKind::String { .. } => Err(crate::Error::new(format!(
+                    "Tried to expand a FheInt{} while a string is stored in this slot",
+                    Id::num_bits(),
+                ))),
             }
         }
     }
@@ -549,9 +572,24 @@ pub mod gpu {
                     // The expander will be responsible for setting the correct tag
                     Ok(Self::new(boolean_block, Tag::default()))
                 }
+                DataKind::String { .. } => Err(crate::Error::new(
+                    "Tried to expand a FheBool while a string is stored in this slot".to_string(),
+                )),
             }
         }
     }
+
+    #[cfg(feature = "strings")]
+    impl CudaExpandable for crate::FheAsciiString {
+        fn from_expanded_blocks(
+            _blocks: CudaRadixCiphertext,
+            _kind: DataKind,
+        ) -> crate::Result<Self> {
+            Err(crate::Error::new(
+                "Cuda devices do not support strings".to_string(),
+            ))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -48,6 +48,20 @@ impl ConfigBuilder {
         self
     }
 
+    /// Generates a WoP-PBS key alongside the server key.
+    ///
+    /// This key is required to evaluate arbitrary functions over whole integers using
+    /// [FheBootstrap](crate::prelude::FheBootstrap).
+    ///
+    /// The WoP-PBS key is only generated for the [ServerKey](crate::ServerKey), it is not
+    /// part of the [CompressedServerKey](crate::CompressedServerKey).
+    #[cfg(feature = "experimental")]
+    pub fn enable_wopbs(mut self, wopbs_parameters: crate::shortint::WopbsParameters) -> Self {
+        self.config.inner.enable_wopbs(wopbs_parameters);
+
+        self
+    }
+
     pub fn with_custom_parameters<P>(block_parameters: P) -> Self
     where
         P: Into<crate::shortint::PBSParameters>,
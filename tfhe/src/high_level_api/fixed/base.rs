@@ -0,0 +1,352 @@
+use tfhe_versionable::Versionize;
+
+use crate::high_level_api::backward_compatibility::fixed::FheFixedVersions;
+use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::{global_state, FheId};
+use crate::integer::prelude::*;
+use crate::integer::SignedRadixCiphertext;
+use crate::named::Named;
+use crate::prelude::{FheDecrypt, FheTryEncrypt, FheTryTrivialEncrypt};
+use crate::shortint::MessageModulus;
+use crate::{ClientKey, Tag};
+
+/// Trait to mark ID type for fixed-point numbers
+// The 'static restrains implementor from holding non-static refs
+// which is ok as it is meant to be impld by zero sized types.
+pub trait FheFixedId: FheId + 'static {
+    /// Number of bits of the integer part, including the sign bit
+    const INT_BITS: u32;
+    /// Number of bits of the fractional part
+    const FRAC_BITS: u32;
+
+    fn num_bits() -> u32 {
+        Self::INT_BITS + Self::FRAC_BITS
+    }
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        (Self::num_bits() / message_modulus.0.ilog2()) as usize
+    }
+}
+
+/// A Generic FHE signed fixed-point number
+///
+/// The number is stored as a signed integer of `INT_BITS + FRAC_BITS` bits,
+/// which is the value scaled by `2^FRAC_BITS`.
+///
+/// You will need to use one of this type specialization (e.g., [FheFixedI16F16]).
+///
+/// Arithmetic operations are modular, i.e. on overflow they wrap around,
+/// and the results that cannot be represented exactly are rounded towards negative
+/// infinity for multiplications and towards zero for divisions.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI16F16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let price = FheFixedI16F16::try_encrypt(12.5f64, &client_key).unwrap();
+/// let rate = FheFixedI16F16::try_encrypt(-0.25f64, &client_key).unwrap();
+///
+/// let result = &price * &rate;
+/// let decrypted: f64 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, -3.125);
+/// ```
+///
+/// [FheFixedI16F16]: crate::high_level_api::FheFixedI16F16
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(FheFixedVersions)]
+pub struct FheFixed<Id: FheFixedId> {
+    pub(in crate::high_level_api) ciphertext: SignedRadixCiphertext,
+    pub(in crate::high_level_api) id: Id,
+    pub(crate) tag: Tag,
+}
+
+impl<Id: FheFixedId> Named for FheFixed<Id> {
+    const NAME: &'static str = "high_level_api::FheFixed";
+}
+
+impl<Id> Tagged for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl<Id> FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    pub(in crate::high_level_api) fn new(ciphertext: SignedRadixCiphertext, tag: Tag) -> Self {
+        Self {
+            ciphertext,
+            id: Id::default(),
+            tag,
+        }
+    }
+
+    pub fn into_raw_parts(self) -> (SignedRadixCiphertext, Id, Tag) {
+        let Self {
+            ciphertext,
+            id,
+            tag,
+        } = self;
+        (ciphertext, id, tag)
+    }
+
+    pub fn from_raw_parts(ciphertext: SignedRadixCiphertext, id: Id, tag: Tag) -> Self {
+        Self {
+            ciphertext,
+            id,
+            tag,
+        }
+    }
+
+    pub fn num_bits() -> usize {
+        Id::num_bits() as usize
+    }
+
+    /// Returns the largest integer less than or equal to `self`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::try_encrypt(-1.5f64, &client_key).unwrap();
+    ///
+    /// let decrypted: f64 = a.floor().decrypt(&client_key);
+    /// assert_eq!(decrypted, -2.0);
+    /// ```
+    pub fn floor(&self) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let inner = floor_raw::<Id>(cpu_key.pbs_key(), &self.ciphertext);
+            Self::new(inner, cpu_key.tag.clone())
+        })
+    }
+
+    /// Returns the smallest integer greater than or equal to `self`
+    ///
+    /// The operation wraps around if the result cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::try_encrypt(-1.5f64, &client_key).unwrap();
+    ///
+    /// let decrypted: f64 = a.ceil().decrypt(&client_key);
+    /// assert_eq!(decrypted, -1.0);
+    /// ```
+    pub fn ceil(&self) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let inner = ceil_raw::<Id>(cpu_key.pbs_key(), &self.ciphertext);
+            Self::new(inner, cpu_key.tag.clone())
+        })
+    }
+
+    /// Returns the integer part of `self`, i.e. rounds towards zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::try_encrypt(-1.75f64, &client_key).unwrap();
+    ///
+    /// let decrypted: f64 = a.trunc().decrypt(&client_key);
+    /// assert_eq!(decrypted, -1.0);
+    /// ```
+    pub fn trunc(&self) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let (is_negative, (floor, ceil)) = rayon::join(
+                || sks.scalar_lt_parallelized(&self.ciphertext, 0i64),
+                || {
+                    rayon::join(
+                        || floor_raw::<Id>(sks, &self.ciphertext),
+                        || ceil_raw::<Id>(sks, &self.ciphertext),
+                    )
+                },
+            );
+            let inner = sks.if_then_else_parallelized(&is_negative, &ceil, &floor);
+            Self::new(inner, cpu_key.tag.clone())
+        })
+    }
+
+    /// Returns the nearest integer to `self`, rounding half-way cases away from zero
+    ///
+    /// The operation wraps around if the result cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI8F8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI8F8::try_encrypt(-2.5f64, &client_key).unwrap();
+    /// let b = FheFixedI8F8::try_encrypt(2.25f64, &client_key).unwrap();
+    ///
+    /// let decrypted: f64 = a.round().decrypt(&client_key);
+    /// assert_eq!(decrypted, -3.0);
+    /// let decrypted: f64 = b.round().decrypt(&client_key);
+    /// assert_eq!(decrypted, 2.0);
+    /// ```
+    pub fn round(&self) -> Self {
+        if Id::FRAC_BITS == 0 {
+            return self.clone();
+        }
+
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let half = 1i64 << (Id::FRAC_BITS - 1);
+            let (is_negative, (rounded_up, rounded_down)) = rayon::join(
+                || sks.scalar_lt_parallelized(&self.ciphertext, 0i64),
+                || {
+                    rayon::join(
+                        || {
+                            let shifted = sks.scalar_add_parallelized(&self.ciphertext, half);
+                            floor_raw::<Id>(sks, &shifted)
+                        },
+                        || {
+                            let shifted = sks.scalar_sub_parallelized(&self.ciphertext, half);
+                            ceil_raw::<Id>(sks, &shifted)
+                        },
+                    )
+                },
+            );
+            let inner = sks.if_then_else_parallelized(&is_negative, &rounded_down, &rounded_up);
+            Self::new(inner, cpu_key.tag.clone())
+        })
+    }
+}
+
+// Clears the fractional bits, which rounds towards negative infinity in two's complement
+fn floor_raw<Id: FheFixedId>(
+    sks: &crate::integer::ServerKey,
+    ct: &SignedRadixCiphertext,
+) -> SignedRadixCiphertext {
+    if Id::FRAC_BITS == 0 {
+        return ct.clone();
+    }
+    let integer_mask = !((1i64 << Id::FRAC_BITS) - 1);
+    sks.scalar_bitand_parallelized(ct, integer_mask)
+}
+
+fn ceil_raw<Id: FheFixedId>(
+    sks: &crate::integer::ServerKey,
+    ct: &SignedRadixCiphertext,
+) -> SignedRadixCiphertext {
+    if Id::FRAC_BITS == 0 {
+        return ct.clone();
+    }
+    let shifted = sks.scalar_add_parallelized(ct, (1i64 << Id::FRAC_BITS) - 1);
+    floor_raw::<Id>(sks, &shifted)
+}
+
+// Returns the value scaled by 2^FRAC_BITS and rounded to the nearest integer,
+// or an error if it cannot be represented
+fn encode_f64<Id: FheFixedId>(value: f64) -> crate::Result<i64> {
+    if !value.is_finite() {
+        return Err(crate::Error::new(format!(
+            "Cannot encrypt {value} as a fixed-point number"
+        )));
+    }
+
+    let scaled = (value * 2f64.powi(Id::FRAC_BITS as i32)).round();
+    let bound = 2f64.powi(Id::num_bits() as i32 - 1);
+    if scaled < -bound || scaled >= bound {
+        return Err(crate::Error::new(format!(
+            "{value} is out of the range of a fixed-point number with {} integer bits",
+            Id::INT_BITS
+        )));
+    }
+
+    Ok(scaled as i64)
+}
+
+impl<Id> FheTryEncrypt<f64, ClientKey> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Error = crate::Error;
+
+    /// Encrypts a `f64`, rounded to the nearest representable value
+    ///
+    /// Returns an error if the value is not finite or is out of range.
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let raw = encode_f64::<Id>(value)?;
+        let ciphertext = key
+            .key
+            .key
+            .encrypt_signed_radix(raw, Id::num_blocks(key.message_modulus()));
+        Ok(Self::new(ciphertext, key.tag.clone()))
+    }
+}
+
+impl<Id> FheTryTrivialEncrypt<f64> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Error = crate::Error;
+
+    /// Creates a trivial encryption of a `f64`, rounded to the nearest representable value
+    ///
+    /// # Warning
+    ///
+    /// Trivial encryptions are not real encryptions, as a trivially encrypted
+    /// ciphertext can be decrypted by any key (in fact, no key is actually needed).
+    ///
+    /// Trivial encryptions become real encrypted data once used in an operation
+    /// that involves a real ciphertext
+    fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+        let raw = encode_f64::<Id>(value)?;
+        global_state::with_cpu_internal_keys(|sks| {
+            let ciphertext = sks
+                .pbs_key()
+                .create_trivial_radix::<i64, SignedRadixCiphertext>(
+                    raw,
+                    Id::num_blocks(sks.message_modulus()),
+                );
+            Ok(Self::new(ciphertext, sks.tag.clone()))
+        })
+    }
+}
+
+impl<Id> FheDecrypt<f64> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    /// Decrypts a [FheFixed] to a `f64`
+    ///
+    /// The value is exact as long as it fits in the 53 bits of precision of a `f64`.
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let raw: i64 = key.key.key.decrypt_signed_radix(&self.ciphertext);
+        raw as f64 / 2f64.powi(Id::FRAC_BITS as i32)
+    }
+}
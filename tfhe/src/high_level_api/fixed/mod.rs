@@ -0,0 +1,11 @@
+pub use base::{FheFixed, FheFixedId};
+pub use static_::{
+    FheFixedI16F16, FheFixedI16F16Id, FheFixedI32F32, FheFixedI32F32Id, FheFixedI8F8,
+    FheFixedI8F8Id,
+};
+
+mod base;
+mod ops;
+mod static_;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,364 @@
+use crate::high_level_api::fixed::{FheFixed, FheFixedId};
+use crate::high_level_api::global_state;
+use crate::high_level_api::traits::{FheEq, FheMax, FheMin, FheOrd};
+use crate::integer::{ServerKey as IntegerServerKey, SignedRadixCiphertext};
+use crate::FheBool;
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Multiplies the two scaled values, and scales the product back
+//
+// The product is computed on twice the number of blocks, so that it does not
+// overflow before being rescaled
+fn mul_raw<Id: FheFixedId>(
+    sks: &IntegerServerKey,
+    lhs: &SignedRadixCiphertext,
+    rhs: &SignedRadixCiphertext,
+) -> SignedRadixCiphertext {
+    let num_blocks = lhs.blocks.len();
+
+    let product = sks.widening_mul_parallelized(lhs, rhs);
+    let rescaled = sks.scalar_right_shift_parallelized(&product, Id::FRAC_BITS);
+
+    sks.cast_to_signed(rescaled, num_blocks)
+}
+
+// Scales the numerator once more before dividing, so that the quotient has the right scale
+//
+// As for the multiplication, the division is computed on twice the number of blocks
+fn div_raw<Id: FheFixedId>(
+    sks: &IntegerServerKey,
+    lhs: &SignedRadixCiphertext,
+    rhs: &SignedRadixCiphertext,
+) -> SignedRadixCiphertext {
+    let num_blocks = lhs.blocks.len();
+
+    let (numerator, divisor) = rayon::join(
+        || {
+            let numerator = sks.cast_to_signed(lhs.clone(), 2 * num_blocks);
+            sks.scalar_left_shift_parallelized(&numerator, Id::FRAC_BITS)
+        },
+        || sks.cast_to_signed(rhs.clone(), 2 * num_blocks),
+    );
+    let quotient = sks.div_parallelized(&numerator, &divisor);
+
+    sks.cast_to_signed(quotient, num_blocks)
+}
+
+macro_rules! generic_fixed_impl_operation (
+    (
+        $(#[$outer:meta])*
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        implem: {
+            $closure:expr
+        }
+        $(,)?
+    ) => {
+        impl<Id, B> $rust_trait_name<B> for FheFixed<Id>
+        where
+            Id: FheFixedId,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<Id, B> $rust_trait_name<B> for &FheFixed<Id>
+        where
+            Id: FheFixedId,
+            B: Borrow<FheFixed<Id>>,
+        {
+            type Output = FheFixed<Id>;
+
+            $(#[$outer])*
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let inner_result =
+                        $closure(cpu_key.pbs_key(), &self.ciphertext, &rhs.borrow().ciphertext);
+                    FheFixed::new(inner_result, cpu_key.tag.clone())
+                })
+            }
+        }
+    }
+);
+
+generic_fixed_impl_operation!(
+    /// Adds two [FheFixed]
+    ///
+    /// The operation is modular, i.e. on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI16F16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI16F16::try_encrypt(1.25f64, &client_key).unwrap();
+    /// let b = FheFixedI16F16::try_encrypt(-3.5f64, &client_key).unwrap();
+    ///
+    /// let result = &a + &b;
+    /// let result: f64 = result.decrypt(&client_key);
+    /// assert_eq!(result, -2.25);
+    /// ```
+    rust_trait: Add(add),
+    implem: {
+        IntegerServerKey::add_parallelized
+    },
+);
+generic_fixed_impl_operation!(
+    /// Subtracts two [FheFixed]
+    ///
+    /// The operation is modular, i.e. on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI16F16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI16F16::try_encrypt(1.25f64, &client_key).unwrap();
+    /// let b = FheFixedI16F16::try_encrypt(-3.5f64, &client_key).unwrap();
+    ///
+    /// let result = &a - &b;
+    /// let result: f64 = result.decrypt(&client_key);
+    /// assert_eq!(result, 4.75);
+    /// ```
+    rust_trait: Sub(sub),
+    implem: {
+        IntegerServerKey::sub_parallelized
+    },
+);
+generic_fixed_impl_operation!(
+    /// Multiplies two [FheFixed]
+    ///
+    /// The exact product is rounded towards negative infinity.
+    /// The operation is modular, i.e. on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI16F16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI16F16::try_encrypt(1.5f64, &client_key).unwrap();
+    /// let b = FheFixedI16F16::try_encrypt(-2.25f64, &client_key).unwrap();
+    ///
+    /// let result = &a * &b;
+    /// let result: f64 = result.decrypt(&client_key);
+    /// assert_eq!(result, -3.375);
+    /// ```
+    rust_trait: Mul(mul),
+    implem: {
+        mul_raw::<Id>
+    },
+);
+generic_fixed_impl_operation!(
+    /// Divides two [FheFixed]
+    ///
+    /// The exact quotient is rounded towards zero.
+    /// The operation is modular, i.e. on overflow it wraps around.
+    ///
+    /// As for [crate::FheInt], dividing by zero does not panic, and the result is not meaningful.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI16F16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI16F16::try_encrypt(-7.5f64, &client_key).unwrap();
+    /// let b = FheFixedI16F16::try_encrypt(2.5f64, &client_key).unwrap();
+    ///
+    /// let result = &a / &b;
+    /// let result: f64 = result.decrypt(&client_key);
+    /// assert_eq!(result, -3.0);
+    /// ```
+    rust_trait: Div(div),
+    implem: {
+        div_raw::<Id>
+    },
+);
+
+macro_rules! generic_fixed_impl_operation_assign (
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident) => $op:tt
+        $(,)?
+    ) => {
+        impl<Id, I> $rust_trait_name<I> for FheFixed<Id>
+        where
+            Id: FheFixedId,
+            I: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: I) {
+                *self = &*self $op rhs.borrow();
+            }
+        }
+    }
+);
+
+generic_fixed_impl_operation_assign!(rust_trait: AddAssign(add_assign) => +);
+generic_fixed_impl_operation_assign!(rust_trait: SubAssign(sub_assign) => -);
+generic_fixed_impl_operation_assign!(rust_trait: MulAssign(mul_assign) => *);
+generic_fixed_impl_operation_assign!(rust_trait: DivAssign(div_assign) => /);
+
+impl<Id> Neg for &FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Output = FheFixed<Id>;
+
+    /// Computes the negation of a [FheFixed]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI16F16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI16F16::try_encrypt(1.75f64, &client_key).unwrap();
+    ///
+    /// let result = -&a;
+    /// let result: f64 = result.decrypt(&client_key);
+    /// assert_eq!(result, -1.75);
+    /// ```
+    fn neg(self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let inner_result = cpu_key.pbs_key().neg_parallelized(&self.ciphertext);
+            FheFixed::new(inner_result, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<Id> Neg for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+macro_rules! generic_fixed_impl_comparison (
+    ($($method:ident => $integer_method:ident),* $(,)?) => {
+        $(
+            fn $method(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let inner_result = cpu_key
+                        .pbs_key()
+                        .$integer_method(&self.ciphertext, &rhs.ciphertext);
+                    FheBool::new(inner_result, cpu_key.tag.clone())
+                })
+            }
+        )*
+    }
+);
+
+impl<Id> FheEq<Self> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    fn eq(&self, rhs: Self) -> FheBool {
+        self.eq(&rhs)
+    }
+
+    fn ne(&self, rhs: Self) -> FheBool {
+        self.ne(&rhs)
+    }
+}
+
+impl<Id> FheEq<&Self> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    generic_fixed_impl_comparison!(
+        eq => eq_parallelized,
+        ne => ne_parallelized,
+    );
+}
+
+impl<Id> FheOrd<Self> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    fn lt(&self, rhs: Self) -> FheBool {
+        self.lt(&rhs)
+    }
+
+    fn le(&self, rhs: Self) -> FheBool {
+        self.le(&rhs)
+    }
+
+    fn gt(&self, rhs: Self) -> FheBool {
+        self.gt(&rhs)
+    }
+
+    fn ge(&self, rhs: Self) -> FheBool {
+        self.ge(&rhs)
+    }
+}
+
+impl<Id> FheOrd<&Self> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    generic_fixed_impl_comparison!(
+        lt => lt_parallelized,
+        le => le_parallelized,
+        gt => gt_parallelized,
+        ge => ge_parallelized,
+    );
+}
+
+impl<Id> FheMin<&Self> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Output = Self;
+
+    /// Returns the min between two [FheFixed]
+    fn min(&self, rhs: &Self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let inner_result = cpu_key
+                .pbs_key()
+                .min_parallelized(&self.ciphertext, &rhs.ciphertext);
+            Self::new(inner_result, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<Id> FheMax<&Self> for FheFixed<Id>
+where
+    Id: FheFixedId,
+{
+    type Output = Self;
+
+    /// Returns the max between two [FheFixed]
+    fn max(&self, rhs: &Self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let inner_result = cpu_key
+                .pbs_key()
+                .max_parallelized(&self.ciphertext, &rhs.ciphertext);
+            Self::new(inner_result, cpu_key.tag.clone())
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use crate::high_level_api::fixed::base::{FheFixed, FheFixedId};
+use crate::high_level_api::FheId;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::NotVersioned;
+
+macro_rules! static_fixed_type {
+    // Defines a static fixed-point type, backed by a signed integer
+    // of `int_bits + frac_bits` bits
+    (
+        $(#[$outer:meta])*
+        Signed {
+            int_bits: $int_bits:literal,
+            frac_bits: $frac_bits:literal,
+        }
+    ) => {
+        ::paste::paste! {
+            #[doc = concat!("Id for the [FheFixedI", stringify!($int_bits), "F", stringify!($frac_bits), "] data type.")]
+            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, NotVersioned)]
+            pub struct [<FheFixedI $int_bits F $frac_bits Id>];
+
+            impl FheId for [<FheFixedI $int_bits F $frac_bits Id>] { }
+
+            impl FheFixedId for [<FheFixedI $int_bits F $frac_bits Id>] {
+                const INT_BITS: u32 = $int_bits;
+                const FRAC_BITS: u32 = $frac_bits;
+            }
+
+            #[doc = concat!(
+                "A signed fixed-point type with ", stringify!($int_bits),
+                " integer bits (including the sign bit) and ", stringify!($frac_bits), " fractional bits"
+            )]
+            #[doc = ""]
+            #[doc = "See [FheFixed]"]
+            $(#[$outer])*
+            pub type [<FheFixedI $int_bits F $frac_bits>] = FheFixed<[<FheFixedI $int_bits F $frac_bits Id>]>;
+        }
+    };
+}
+
+static_fixed_type! {
+    Signed {
+        int_bits: 8,
+        frac_bits: 8,
+    }
+}
+
+static_fixed_type! {
+    Signed {
+        int_bits: 16,
+        frac_bits: 16,
+    }
+}
+
+static_fixed_type! {
+    Signed {
+        int_bits: 32,
+        frac_bits: 32,
+    }
+}
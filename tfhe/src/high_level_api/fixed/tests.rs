@@ -0,0 +1,142 @@
+use crate::prelude::*;
+use crate::safe_serialization::{safe_deserialize, safe_serialize};
+use crate::{
+    generate_keys, set_server_key, ClientKey, ConfigBuilder, FheFixedI16F16, FheFixedI8F8,
+    ServerKey,
+};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    client_key
+}
+
+#[test]
+fn test_fixed_encrypt_decrypt() {
+    let client_key = setup_default_cpu();
+
+    for value in [0.0, 1.5, -1.5, 127.99609375, -128.0, 0.00390625] {
+        let a = FheFixedI8F8::try_encrypt(value, &client_key).unwrap();
+        let decrypted: f64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value);
+    }
+
+    // Values are rounded to the nearest representable value
+    let a = FheFixedI8F8::try_encrypt(0.1, &client_key).unwrap();
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 26.0 / 256.0);
+
+    let a = FheFixedI8F8::try_encrypt_trivial(-3.25).unwrap();
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, -3.25);
+
+    for value in [128.0, -128.5, f64::NAN, f64::INFINITY] {
+        assert!(FheFixedI8F8::try_encrypt(value, &client_key).is_err());
+    }
+}
+
+#[test]
+fn test_fixed_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let cases = [(3.5, 1.25), (-7.75, 2.5), (0.5, -0.125), (-1.0, -1.0)];
+
+    for (clear_a, clear_b) in cases {
+        let a = FheFixedI16F16::try_encrypt(clear_a, &client_key).unwrap();
+        let b = FheFixedI16F16::try_encrypt(clear_b, &client_key).unwrap();
+
+        let decrypted: f64 = (&a + &b).decrypt(&client_key);
+        assert_eq!(decrypted, clear_a + clear_b);
+
+        let decrypted: f64 = (&a - &b).decrypt(&client_key);
+        assert_eq!(decrypted, clear_a - clear_b);
+
+        // All those products are exact
+        let decrypted: f64 = (&a * &b).decrypt(&client_key);
+        assert_eq!(decrypted, clear_a * clear_b);
+
+        let expected = ((clear_a / clear_b) * 65536.0).trunc() / 65536.0;
+        let decrypted: f64 = (&a / &b).decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+
+        let decrypted: f64 = (-&a).decrypt(&client_key);
+        assert_eq!(decrypted, -clear_a);
+    }
+
+    // Inexact products are rounded towards negative infinity
+    let a = FheFixedI8F8::try_encrypt(-0.00390625, &client_key).unwrap();
+    let b = FheFixedI8F8::try_encrypt(0.5, &client_key).unwrap();
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, -0.00390625);
+
+    let mut c = a.clone();
+    c += &b;
+    c *= &b;
+    let decrypted: f64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 63.0 / 256.0);
+}
+
+#[test]
+fn test_fixed_comparisons() {
+    let client_key = setup_default_cpu();
+
+    let a = FheFixedI8F8::try_encrypt(-1.5, &client_key).unwrap();
+    let b = FheFixedI8F8::try_encrypt(0.25, &client_key).unwrap();
+
+    assert!(!a.eq(&b).decrypt(&client_key));
+    assert!(a.ne(&b).decrypt(&client_key));
+    assert!(a.lt(&b).decrypt(&client_key));
+    assert!(a.le(&b).decrypt(&client_key));
+    assert!(!a.gt(&b).decrypt(&client_key));
+    assert!(!a.ge(&b).decrypt(&client_key));
+
+    let decrypted: f64 = a.min(&b).decrypt(&client_key);
+    assert_eq!(decrypted, -1.5);
+    let decrypted: f64 = a.max(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 0.25);
+}
+
+#[test]
+fn test_fixed_rounding() {
+    let client_key = setup_default_cpu();
+
+    for value in [2.5, 2.25, -2.5, -2.25, -0.75, 3.0, 0.0] {
+        let a = FheFixedI8F8::try_encrypt(value, &client_key).unwrap();
+
+        let decrypted: f64 = a.floor().decrypt(&client_key);
+        assert_eq!(decrypted, value.floor(), "floor of {value}");
+
+        let decrypted: f64 = a.ceil().decrypt(&client_key);
+        assert_eq!(decrypted, value.ceil(), "ceil of {value}");
+
+        let decrypted: f64 = a.trunc().decrypt(&client_key);
+        assert_eq!(decrypted, value.trunc(), "trunc of {value}");
+
+        let decrypted: f64 = a.round().decrypt(&client_key);
+        assert_eq!(decrypted, value.round(), "round of {value}");
+    }
+}
+
+#[test]
+fn test_fixed_tag_and_serialization() {
+    let config = ConfigBuilder::default().build();
+    let mut client_key = ClientKey::generate(config);
+    client_key.tag_mut().set_u64(42);
+
+    let server_key = ServerKey::new(&client_key);
+    set_server_key(server_key);
+
+    let a = FheFixedI16F16::try_encrypt(4.5, &client_key).unwrap();
+    assert_eq!(a.tag(), client_key.tag());
+
+    let b = &a * &a;
+    assert_eq!(b.tag(), client_key.tag());
+
+    let mut buffer = vec![];
+    safe_serialize(&b, &mut buffer, 1 << 30).unwrap();
+    let b: FheFixedI16F16 = safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+
+    assert_eq!(b.tag(), client_key.tag());
+    let decrypted: f64 = b.decrypt(&client_key);
+    assert_eq!(decrypted, 20.25);
+}
@@ -0,0 +1,564 @@
+use tfhe_versionable::Versionize;
+
+use crate::high_level_api::backward_compatibility::float::FheFloatVersions;
+use crate::high_level_api::float::raw::{
+    abs_raw, bias, from_integer_raw, implicit_bit, is_inf_raw, is_nan_raw, max_exponent,
+    nan_mantissa, to_integer_raw, FloatParts,
+};
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::traits::Tagged;
+use crate::high_level_api::{global_state, FheId};
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
+use crate::named::Named;
+use crate::prelude::{CastFrom, FheDecrypt, FheTrivialEncrypt, FheTryEncrypt};
+use crate::shortint::MessageModulus;
+use crate::{ClientKey, FheBool, FheInt, FheUint, Tag};
+
+/// Trait to mark ID type for floating-point numbers
+// The 'static restrains implementor from holding non-static refs
+// which is ok as it is meant to be impld by zero sized types.
+pub trait FheFloatId: FheId + 'static {
+    /// Number of bits of the exponent, at most 11
+    const EXPONENT_BITS: u32;
+    /// Number of bits of the mantissa, not counting the implicit leading one, at most 52
+    const MANTISSA_BITS: u32;
+
+    fn num_bits() -> u32 {
+        1 + Self::EXPONENT_BITS + Self::MANTISSA_BITS
+    }
+
+    fn exponent_blocks(message_modulus: MessageModulus) -> usize {
+        Self::EXPONENT_BITS.div_ceil(message_modulus.0.ilog2()) as usize
+    }
+
+    fn mantissa_blocks(message_modulus: MessageModulus) -> usize {
+        (Self::MANTISSA_BITS + 1).div_ceil(message_modulus.0.ilog2()) as usize
+    }
+}
+
+/// A Generic FHE floating-point number
+///
+/// The number is stored as an encrypted sign, biased exponent and mantissa,
+/// following the layout of the IEEE 754 binary formats.
+///
+/// You will need to use one of this type specialization (e.g., [FheFloat32]).
+///
+/// Results of operations are rounded to the nearest representable value, ties to even,
+/// and infinities and NaN follow IEEE 754 semantics. However only a subset of IEEE 754 is
+/// supported:
+///
+/// - Subnormal numbers are flushed to zero, both when encrypting and when they are the result of an
+///   operation.
+/// - There is a single NaN value, which is quiet; payloads and signaling NaNs are not preserved.
+/// - Exceptions flags are not raised.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFloat32::encrypt(1.5f32, &client_key);
+/// let b = FheFloat32::encrypt(-0.375f32, &client_key);
+///
+/// let result = &a * &b;
+/// let decrypted: f32 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, -0.5625);
+/// ```
+///
+/// [FheFloat32]: crate::high_level_api::FheFloat32
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(FheFloatVersions)]
+pub struct FheFloat<Id: FheFloatId> {
+    pub(in crate::high_level_api) sign: BooleanBlock,
+    pub(in crate::high_level_api) exponent: RadixCiphertext,
+    pub(in crate::high_level_api) mantissa: RadixCiphertext,
+    pub(in crate::high_level_api) id: Id,
+    pub(crate) tag: Tag,
+}
+
+impl<Id: FheFloatId> Named for FheFloat<Id> {
+    const NAME: &'static str = "high_level_api::FheFloat";
+}
+
+impl<Id> Tagged for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl<Id> FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    pub(super) fn new(parts: FloatParts, tag: Tag) -> Self {
+        let FloatParts {
+            sign,
+            exponent,
+            mantissa,
+        } = parts;
+        Self {
+            sign,
+            exponent,
+            mantissa,
+            id: Id::default(),
+            tag,
+        }
+    }
+
+    /// Returns the sign, biased exponent and mantissa, in which
+    /// the implicit leading one of normal numbers is explicit
+    pub fn into_raw_parts(self) -> (BooleanBlock, RadixCiphertext, RadixCiphertext, Id, Tag) {
+        let Self {
+            sign,
+            exponent,
+            mantissa,
+            id,
+            tag,
+        } = self;
+        (sign, exponent, mantissa, id, tag)
+    }
+
+    pub fn from_raw_parts(
+        sign: BooleanBlock,
+        exponent: RadixCiphertext,
+        mantissa: RadixCiphertext,
+        id: Id,
+        tag: Tag,
+    ) -> Self {
+        Self {
+            sign,
+            exponent,
+            mantissa,
+            id,
+            tag,
+        }
+    }
+
+    pub fn num_bits() -> usize {
+        Id::num_bits() as usize
+    }
+
+    /// Returns the absolute value of `self`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(-2.5f32, &client_key);
+    ///
+    /// let decrypted: f32 = a.abs().decrypt(&client_key);
+    /// assert_eq!(decrypted, 2.5);
+    /// ```
+    pub fn abs(&self) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let parts = abs_raw(cpu_key.pbs_key(), self);
+            Self::new(parts, cpu_key.tag.clone())
+        })
+    }
+
+    /// Returns whether `self` is NaN
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(0.0f32, &client_key);
+    ///
+    /// let result = (&a / &a).is_nan();
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    pub fn is_nan(&self) -> FheBool {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let result = is_nan_raw(cpu_key.pbs_key(), self);
+            FheBool::new(result, cpu_key.tag.clone())
+        })
+    }
+
+    /// Returns whether `self` is positive or negative infinity
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(f32::MAX, &client_key);
+    ///
+    /// let result = (&a + &a).is_infinite();
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    pub fn is_infinite(&self) -> FheBool {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let result = is_inf_raw(cpu_key.pbs_key(), self);
+            FheBool::new(result, cpu_key.tag.clone())
+        })
+    }
+}
+
+// Returns the sign, biased exponent and mantissa of the float nearest to `value`, ties to even
+pub(super) fn encode_f64<Id: FheFloatId>(value: f64) -> (bool, u64, u64) {
+    let sign = value.is_sign_negative();
+    let max_exponent = max_exponent::<Id>();
+    let implicit_bit = implicit_bit::<Id>();
+
+    if value.is_nan() {
+        return (sign, max_exponent, nan_mantissa::<Id>());
+    }
+    if value.is_infinite() {
+        return (sign, max_exponent, implicit_bit);
+    }
+
+    let bits = value.to_bits();
+    let f64_exponent = ((bits >> 52) & 0x7ff) as i64;
+    if f64_exponent == 0 {
+        // Zeros, and subnormals which are flushed to zero
+        return (sign, 0, 0);
+    }
+
+    let f64_significand = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let shift = 52 - Id::MANTISSA_BITS;
+    let mut mantissa = f64_significand >> shift;
+    let mut exponent = f64_exponent - 1023 + bias::<Id>() as i64;
+    if shift > 0 {
+        let remainder = f64_significand & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if remainder > half || (remainder == half && mantissa & 1 == 1) {
+            mantissa += 1;
+            if mantissa == implicit_bit << 1 {
+                mantissa = implicit_bit;
+                exponent += 1;
+            }
+        }
+    }
+
+    if exponent >= max_exponent as i64 {
+        (sign, max_exponent, implicit_bit)
+    } else if exponent <= 0 {
+        (sign, 0, 0)
+    } else {
+        (sign, exponent as u64, mantissa)
+    }
+}
+
+pub(super) fn decode_f64<Id: FheFloatId>(sign: bool, exponent: u64, mantissa: u64) -> f64 {
+    let implicit_bit = implicit_bit::<Id>();
+
+    let bits = if exponent == 0 {
+        0
+    } else if exponent == max_exponent::<Id>() {
+        if mantissa != implicit_bit {
+            return f64::NAN;
+        }
+        0x7ff << 52
+    } else {
+        let f64_exponent = exponent + 1023 - bias::<Id>();
+        let fraction = (mantissa & (implicit_bit - 1)) << (52 - Id::MANTISSA_BITS);
+        (f64_exponent << 52) | fraction
+    };
+
+    f64::from_bits((u64::from(sign) << 63) | bits)
+}
+
+impl<Id> FheTryEncrypt<f64, ClientKey> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    type Error = crate::Error;
+
+    /// Encrypts a `f64`, rounded to the nearest representable value
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let (sign, exponent, mantissa) = encode_f64::<Id>(value);
+        let message_modulus = key.message_modulus();
+        let integer_client_key = &key.key.key;
+        let parts = FloatParts {
+            sign: integer_client_key.encrypt_bool(sign),
+            exponent: integer_client_key
+                .encrypt_radix(exponent, Id::exponent_blocks(message_modulus)),
+            mantissa: integer_client_key
+                .encrypt_radix(mantissa, Id::mantissa_blocks(message_modulus)),
+        };
+        Ok(Self::new(parts, key.tag.clone()))
+    }
+}
+
+impl<Id> FheTryEncrypt<f32, ClientKey> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    type Error = crate::Error;
+
+    /// Encrypts a `f32`, rounded to the nearest representable value
+    fn try_encrypt(value: f32, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt(f64::from(value), key)
+    }
+}
+
+impl<Id> FheTrivialEncrypt<f64> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    /// Creates a trivial encryption of a `f64`, rounded to the nearest representable value
+    ///
+    /// # Warning
+    ///
+    /// Trivial encryptions are not real encryptions, as a trivially encrypted
+    /// ciphertext can be decrypted by any key (in fact, no key is actually needed).
+    ///
+    /// Trivial encryptions become real encrypted data once used in an operation
+    /// that involves a real ciphertext
+    fn encrypt_trivial(value: f64) -> Self {
+        let (sign, exponent, mantissa) = encode_f64::<Id>(value);
+        global_state::with_cpu_internal_keys(|sks| {
+            let message_modulus = sks.message_modulus();
+            let pbs_key = sks.pbs_key();
+            let parts = FloatParts {
+                sign: pbs_key.create_trivial_boolean_block(sign),
+                exponent: pbs_key
+                    .create_trivial_radix(exponent, Id::exponent_blocks(message_modulus)),
+                mantissa: pbs_key
+                    .create_trivial_radix(mantissa, Id::mantissa_blocks(message_modulus)),
+            };
+            Self::new(parts, sks.tag.clone())
+        })
+    }
+}
+
+impl<Id> FheTrivialEncrypt<f32> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    /// Creates a trivial encryption of a `f32`, rounded to the nearest representable value
+    ///
+    /// # Warning
+    ///
+    /// Trivial encryptions are not real encryptions, as a trivially encrypted
+    /// ciphertext can be decrypted by any key (in fact, no key is actually needed).
+    fn encrypt_trivial(value: f32) -> Self {
+        Self::encrypt_trivial(f64::from(value))
+    }
+}
+
+impl<Id> FheDecrypt<f64> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    /// Decrypts a [FheFloat] to a `f64`, which is exact
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let integer_client_key = &key.key.key;
+        let sign = integer_client_key.decrypt_bool(&self.sign);
+        let exponent: u64 = integer_client_key.decrypt_radix(&self.exponent);
+        let mantissa: u64 = integer_client_key.decrypt_radix(&self.mantissa);
+        decode_f64::<Id>(sign, exponent, mantissa)
+    }
+}
+
+impl<Id> FheDecrypt<f32> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    /// Decrypts a [FheFloat] to a `f32`, rounded to the nearest value
+    fn decrypt(&self, key: &ClientKey) -> f32 {
+        let value: f64 = self.decrypt(key);
+        value as f32
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheUint<FromId>> for FheFloat<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheFloatId,
+{
+    /// Cast a FheUint to a FheFloat
+    ///
+    /// The value is rounded to the nearest float, ties to even.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint32::encrypt(u32::MAX, &client_key);
+    /// let b = FheFloat32::cast_from(a);
+    ///
+    /// let decrypted: f32 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, u32::MAX as f32);
+    /// ```
+    fn cast_from(input: FheUint<FromId>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let sign = sks.create_trivial_boolean_block(false);
+            let parts = from_integer_raw::<IntoId>(sks, sign, input.ciphertext.into_cpu());
+            Self::new(parts, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheInt<FromId>> for FheFloat<IntoId>
+where
+    FromId: FheIntId,
+    IntoId: FheFloatId,
+{
+    /// Cast a FheInt to a FheFloat
+    ///
+    /// The value is rounded to the nearest float, ties to even.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-1234i16, &client_key);
+    /// let b = FheFloat32::cast_from(a);
+    ///
+    /// let decrypted: f32 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -1234.0);
+    /// ```
+    fn cast_from(input: FheInt<FromId>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let ciphertext = input.ciphertext.into_cpu();
+            let num_blocks = ciphertext.blocks.len();
+            // The absolute value of the min value wraps to itself,
+            // which is still correct once seen as unsigned
+            let (sign, absolute_value) = rayon::join(
+                || sks.scalar_lt_parallelized(&ciphertext, 0i64),
+                || sks.cast_to_unsigned(sks.abs_parallelized(&ciphertext), num_blocks),
+            );
+            let parts = from_integer_raw::<IntoId>(sks, sign, absolute_value);
+            Self::new(parts, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheFloat<FromId>> for FheUint<IntoId>
+where
+    FromId: FheFloatId,
+    IntoId: FheUintId,
+{
+    /// Cast a FheFloat to a FheUint
+    ///
+    /// As for the `as` keyword of Rust, the value is rounded towards zero and saturates to the
+    /// bounds of the integer type, NaN being converted to 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(42.75f32, &client_key);
+    /// let b = FheUint8::cast_from(a);
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42);
+    /// ```
+    fn cast_from(input: FheFloat<FromId>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = IntoId::num_blocks(cpu_key.message_modulus());
+            let ((integer_part, is_too_large), is_nan) = rayon::join(
+                || to_integer_raw(sks, &input, IntoId::num_bits()),
+                || is_nan_raw(sks, &input),
+            );
+
+            let integer_part = sks.cast_to_unsigned(integer_part, num_blocks);
+            let max: RadixCiphertext = sks.create_trivial_max_radix(num_blocks);
+            let result = sks.if_then_else_parallelized(&is_too_large, &max, &integer_part);
+
+            // Negative values saturate to 0
+            let is_zero = sks.boolean_bitor(&input.sign, &is_nan);
+            let zero: RadixCiphertext = sks.create_trivial_zero_radix(num_blocks);
+            let result = sks.if_then_else_parallelized(&is_zero, &zero, &result);
+            Self::new(result, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheFloat<FromId>> for FheInt<IntoId>
+where
+    FromId: FheFloatId,
+    IntoId: FheIntId,
+{
+    /// Cast a FheFloat to a FheInt
+    ///
+    /// As for the `as` keyword of Rust, the value is rounded towards zero and saturates to the
+    /// bounds of the integer type, NaN being converted to 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(-42.75f32, &client_key);
+    /// let b = FheInt8::cast_from(a);
+    ///
+    /// let decrypted: i8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -42);
+    /// ```
+    fn cast_from(input: FheFloat<FromId>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = IntoId::num_blocks(cpu_key.message_modulus());
+            // The sign bit is not available for the absolute value
+            let ((integer_part, is_too_large), is_nan) = rayon::join(
+                || to_integer_raw(sks, &input, IntoId::num_bits() - 1),
+                || is_nan_raw(sks, &input),
+            );
+
+            let integer_part = sks.cast_to_signed(integer_part, num_blocks);
+            let negated = sks.neg_parallelized(&integer_part);
+            let result = sks.if_then_else_parallelized(&input.sign, &negated, &integer_part);
+
+            let (min, max): (SignedRadixCiphertext, SignedRadixCiphertext) = (
+                sks.create_trivial_min_radix(num_blocks),
+                sks.create_trivial_max_radix(num_blocks),
+            );
+            let saturated = sks.if_then_else_parallelized(&input.sign, &min, &max);
+            let result = sks.if_then_else_parallelized(&is_too_large, &saturated, &result);
+
+            let zero: SignedRadixCiphertext = sks.create_trivial_zero_radix(num_blocks);
+            let result = sks.if_then_else_parallelized(&is_nan, &zero, &result);
+            Self::new(result, cpu_key.tag.clone())
+        })
+    }
+}
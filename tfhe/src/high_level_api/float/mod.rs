@@ -0,0 +1,9 @@
+pub use base::{FheFloat, FheFloatId};
+pub use static_::{FheFloat16, FheFloat16Id, FheFloat32, FheFloat32Id, FheFloat64, FheFloat64Id};
+
+mod base;
+mod ops;
+mod raw;
+mod static_;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,351 @@
+use crate::high_level_api::float::raw::{
+    add_raw, div_raw, eq_raw, lt_raw, min_raw, mul_raw, neg_raw, sub_raw,
+};
+use crate::high_level_api::float::{FheFloat, FheFloatId};
+use crate::high_level_api::global_state;
+use crate::high_level_api::traits::{FheEq, FheMax, FheMin, FheOrd};
+use crate::FheBool;
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! generic_float_impl_operation (
+    (
+        $(#[$outer:meta])*
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        implem: {
+            $closure:expr
+        }
+        $(,)?
+    ) => {
+        impl<Id, B> $rust_trait_name<B> for FheFloat<Id>
+        where
+            Id: FheFloatId,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<Id, B> $rust_trait_name<B> for &FheFloat<Id>
+        where
+            Id: FheFloatId,
+            B: Borrow<FheFloat<Id>>,
+        {
+            type Output = FheFloat<Id>;
+
+            $(#[$outer])*
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let parts = $closure(cpu_key.pbs_key(), self, rhs.borrow());
+                    FheFloat::new(parts, cpu_key.tag.clone())
+                })
+            }
+        }
+    }
+);
+
+generic_float_impl_operation!(
+    /// Adds two [FheFloat]
+    ///
+    /// The result is rounded to the nearest representable value, ties to even.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(1.25f32, &client_key);
+    /// let b = FheFloat32::encrypt(-3.5f32, &client_key);
+    ///
+    /// let result = &a + &b;
+    /// let result: f32 = result.decrypt(&client_key);
+    /// assert_eq!(result, 1.25f32 + -3.5f32);
+    /// ```
+    rust_trait: Add(add),
+    implem: {
+        add_raw::<Id>
+    },
+);
+generic_float_impl_operation!(
+    /// Subtracts two [FheFloat]
+    ///
+    /// The result is rounded to the nearest representable value, ties to even.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(1.25f32, &client_key);
+    /// let b = FheFloat32::encrypt(0.1f32, &client_key);
+    ///
+    /// let result = &a - &b;
+    /// let result: f32 = result.decrypt(&client_key);
+    /// assert_eq!(result, 1.25f32 - 0.1f32);
+    /// ```
+    rust_trait: Sub(sub),
+    implem: {
+        sub_raw::<Id>
+    },
+);
+generic_float_impl_operation!(
+    /// Multiplies two [FheFloat]
+    ///
+    /// The result is rounded to the nearest representable value, ties to even.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(1.5f32, &client_key);
+    /// let b = FheFloat32::encrypt(-0.1f32, &client_key);
+    ///
+    /// let result = &a * &b;
+    /// let result: f32 = result.decrypt(&client_key);
+    /// assert_eq!(result, 1.5f32 * -0.1f32);
+    /// ```
+    rust_trait: Mul(mul),
+    implem: {
+        mul_raw::<Id>
+    },
+);
+generic_float_impl_operation!(
+    /// Divides two [FheFloat]
+    ///
+    /// The result is rounded to the nearest representable value, ties to even.
+    /// Dividing a non-zero value by zero gives an infinity, and 0 / 0 gives NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(1.0f32, &client_key);
+    /// let b = FheFloat32::encrypt(3.0f32, &client_key);
+    ///
+    /// let result = &a / &b;
+    /// let result: f32 = result.decrypt(&client_key);
+    /// assert_eq!(result, 1.0f32 / 3.0f32);
+    /// ```
+    rust_trait: Div(div),
+    implem: {
+        div_raw::<Id>
+    },
+);
+
+macro_rules! generic_float_impl_operation_assign (
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident) => $op:tt
+        $(,)?
+    ) => {
+        impl<Id, I> $rust_trait_name<I> for FheFloat<Id>
+        where
+            Id: FheFloatId,
+            I: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: I) {
+                *self = &*self $op rhs.borrow();
+            }
+        }
+    }
+);
+
+generic_float_impl_operation_assign!(rust_trait: AddAssign(add_assign) => +);
+generic_float_impl_operation_assign!(rust_trait: SubAssign(sub_assign) => -);
+generic_float_impl_operation_assign!(rust_trait: MulAssign(mul_assign) => *);
+generic_float_impl_operation_assign!(rust_trait: DivAssign(div_assign) => /);
+
+impl<Id> Neg for &FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    type Output = FheFloat<Id>;
+
+    /// Computes the negation of a [FheFloat]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(1.75f32, &client_key);
+    ///
+    /// let result = -&a;
+    /// let result: f32 = result.decrypt(&client_key);
+    /// assert_eq!(result, -1.75);
+    /// ```
+    fn neg(self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let parts = neg_raw(cpu_key.pbs_key(), self);
+            FheFloat::new(parts, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<Id> Neg for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<Id> FheEq<Self> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    fn eq(&self, rhs: Self) -> FheBool {
+        self.eq(&rhs)
+    }
+
+    fn ne(&self, rhs: Self) -> FheBool {
+        self.ne(&rhs)
+    }
+}
+
+impl<Id> FheEq<&Self> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    /// Test for equality between two [FheFloat]
+    ///
+    /// As for IEEE 754, -0 is equal to +0 and NaN is not equal to anything, itself included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(-0.0f32, &client_key);
+    /// let b = FheFloat32::encrypt(0.0f32, &client_key);
+    ///
+    /// let result = a.eq(&b);
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    fn eq(&self, rhs: &Self) -> FheBool {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let result = eq_raw(cpu_key.pbs_key(), self, rhs);
+            FheBool::new(result, cpu_key.tag.clone())
+        })
+    }
+
+    /// Test for difference between two [FheFloat]
+    ///
+    /// NaN is different from anything, itself included.
+    fn ne(&self, rhs: &Self) -> FheBool {
+        !self.eq(rhs)
+    }
+}
+
+impl<Id> FheOrd<Self> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    fn lt(&self, rhs: Self) -> FheBool {
+        self.lt(&rhs)
+    }
+
+    fn le(&self, rhs: Self) -> FheBool {
+        self.le(&rhs)
+    }
+
+    fn gt(&self, rhs: Self) -> FheBool {
+        self.gt(&rhs)
+    }
+
+    fn ge(&self, rhs: Self) -> FheBool {
+        self.ge(&rhs)
+    }
+}
+
+macro_rules! generic_float_impl_comparison (
+    ($($method:ident => (swapped: $swapped:literal, or_equal: $or_equal:literal)),* $(,)?) => {
+        $(
+            fn $method(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let (lhs, rhs) = if $swapped { (rhs, self) } else { (self, rhs) };
+                    let result = lt_raw(cpu_key.pbs_key(), lhs, rhs, $or_equal);
+                    FheBool::new(result, cpu_key.tag.clone())
+                })
+            }
+        )*
+    }
+);
+
+/// As for IEEE 754, NaN is not ordered with anything: all comparisons involving a NaN are false.
+impl<Id> FheOrd<&Self> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    generic_float_impl_comparison!(
+        lt => (swapped: false, or_equal: false),
+        le => (swapped: false, or_equal: true),
+        gt => (swapped: true, or_equal: false),
+        ge => (swapped: true, or_equal: true),
+    );
+}
+
+impl<Id> FheMin<&Self> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    type Output = Self;
+
+    /// Returns the min between two [FheFloat]
+    ///
+    /// As for [f64::min], if one of the values is NaN, the other is returned.
+    fn min(&self, rhs: &Self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let parts = min_raw(cpu_key.pbs_key(), self, rhs, false);
+            Self::new(parts, cpu_key.tag.clone())
+        })
+    }
+}
+
+impl<Id> FheMax<&Self> for FheFloat<Id>
+where
+    Id: FheFloatId,
+{
+    type Output = Self;
+
+    /// Returns the max between two [FheFloat]
+    ///
+    /// As for [f64::max], if one of the values is NaN, the other is returned.
+    fn max(&self, rhs: &Self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let parts = min_raw(cpu_key.pbs_key(), self, rhs, true);
+            Self::new(parts, cpu_key.tag.clone())
+        })
+    }
+}
@@ -0,0 +1,703 @@
+//! The floating-point algorithms, on top of the integer server key
+//!
+//! All operations first compute the result of the generic case (i.e. finite operands)
+//! using integer operations, then the special cases (zeros, infinities and NaNs)
+//! are handled by selecting the right special value with encrypted conditions.
+use crate::high_level_api::float::{FheFloat, FheFloatId};
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+
+/// The encrypted sign, biased exponent and mantissa of a float
+///
+/// The implicit leading one of the mantissa of normal numbers is stored explicitly.
+pub(super) struct FloatParts {
+    pub(super) sign: BooleanBlock,
+    pub(super) exponent: RadixCiphertext,
+    pub(super) mantissa: RadixCiphertext,
+}
+
+struct Classes {
+    is_zero: BooleanBlock,
+    is_inf: BooleanBlock,
+    is_nan: BooleanBlock,
+}
+
+pub(super) fn bias<Id: FheFloatId>() -> u64 {
+    (1 << (Id::EXPONENT_BITS - 1)) - 1
+}
+
+/// The biased exponent of infinities and NaNs
+pub(super) fn max_exponent<Id: FheFloatId>() -> u64 {
+    (1 << Id::EXPONENT_BITS) - 1
+}
+
+pub(super) fn implicit_bit<Id: FheFloatId>() -> u64 {
+    1 << Id::MANTISSA_BITS
+}
+
+/// The mantissa of the (single) NaN value
+pub(super) fn nan_mantissa<Id: FheFloatId>() -> u64 {
+    implicit_bit::<Id>() | (implicit_bit::<Id>() >> 1)
+}
+
+fn precision<Id: FheFloatId>() -> usize {
+    Id::MANTISSA_BITS as usize + 1
+}
+
+fn bits_per_block(sks: &ServerKey) -> usize {
+    sks.message_modulus().0.ilog2() as usize
+}
+
+// Number of blocks of the signed exponent used during computations,
+// which must not overflow when adding offsets up to the number of bits of the significand
+fn work_exponent_blocks<Id: FheFloatId>(sks: &ServerKey, significand_bits: usize) -> usize {
+    let significand_bits_len = (usize::BITS - significand_bits.leading_zeros()) as usize;
+    let num_bits = (Id::EXPONENT_BITS as usize).max(significand_bits_len) + 4;
+    num_bits.div_ceil(bits_per_block(sks))
+}
+
+fn add_offset(
+    sks: &ServerKey,
+    exponent: &SignedRadixCiphertext,
+    offset: i64,
+) -> SignedRadixCiphertext {
+    if offset >= 0 {
+        sks.scalar_add_parallelized(exponent, offset as u64)
+    } else {
+        sks.scalar_sub_parallelized(exponent, offset.unsigned_abs())
+    }
+}
+
+fn classify<Id: FheFloatId>(sks: &ServerKey, x: &FheFloat<Id>) -> Classes {
+    let ((is_zero, is_special), is_fraction_zero) = rayon::join(
+        || {
+            rayon::join(
+                || sks.scalar_eq_parallelized(&x.exponent, 0u64),
+                || sks.scalar_eq_parallelized(&x.exponent, max_exponent::<Id>()),
+            )
+        },
+        || sks.scalar_eq_parallelized(&x.mantissa, implicit_bit::<Id>()),
+    );
+    let (is_inf, is_nan) = rayon::join(
+        || sks.boolean_bitand(&is_special, &is_fraction_zero),
+        || sks.boolean_bitand(&is_special, &sks.boolean_bitnot(&is_fraction_zero)),
+    );
+
+    Classes {
+        is_zero,
+        is_inf,
+        is_nan,
+    }
+}
+
+pub(super) fn is_nan_raw<Id: FheFloatId>(sks: &ServerKey, x: &FheFloat<Id>) -> BooleanBlock {
+    classify(sks, x).is_nan
+}
+
+pub(super) fn is_inf_raw<Id: FheFloatId>(sks: &ServerKey, x: &FheFloat<Id>) -> BooleanBlock {
+    classify(sks, x).is_inf
+}
+
+// The blocks of the mantissa followed by the blocks of the exponent
+//
+// Comparing these as integers compares the absolute values of the floats
+fn magnitude<Id: FheFloatId>(x: &FheFloat<Id>) -> RadixCiphertext {
+    let blocks = x
+        .mantissa
+        .blocks
+        .iter()
+        .chain(x.exponent.blocks.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+    RadixCiphertext::from_blocks(blocks)
+}
+
+fn split_magnitude(
+    magnitude: RadixCiphertext,
+    num_mantissa_blocks: usize,
+) -> (RadixCiphertext, RadixCiphertext) {
+    let mut blocks = magnitude.blocks;
+    let exponent_blocks = blocks.split_off(num_mantissa_blocks);
+    (
+        RadixCiphertext::from_blocks(blocks),
+        RadixCiphertext::from_blocks(exponent_blocks),
+    )
+}
+
+// Replaces the exponent and mantissa by the given clear values where `condition` is true,
+// the sign is kept
+fn with_special(
+    sks: &ServerKey,
+    condition: &BooleanBlock,
+    exponent: u64,
+    mantissa: u64,
+    parts: FloatParts,
+) -> FloatParts {
+    let (exponent, mantissa) = rayon::join(
+        || {
+            let special: RadixCiphertext =
+                sks.create_trivial_radix(exponent, parts.exponent.blocks.len());
+            sks.if_then_else_parallelized(condition, &special, &parts.exponent)
+        },
+        || {
+            let special: RadixCiphertext =
+                sks.create_trivial_radix(mantissa, parts.mantissa.blocks.len());
+            sks.if_then_else_parallelized(condition, &special, &parts.mantissa)
+        },
+    );
+
+    FloatParts {
+        sign: parts.sign,
+        exponent,
+        mantissa,
+    }
+}
+
+fn with_infinity<Id: FheFloatId>(
+    sks: &ServerKey,
+    condition: &BooleanBlock,
+    parts: FloatParts,
+) -> FloatParts {
+    with_special(
+        sks,
+        condition,
+        max_exponent::<Id>(),
+        implicit_bit::<Id>(),
+        parts,
+    )
+}
+
+fn with_nan<Id: FheFloatId>(
+    sks: &ServerKey,
+    condition: &BooleanBlock,
+    parts: FloatParts,
+) -> FloatParts {
+    with_special(
+        sks,
+        condition,
+        max_exponent::<Id>(),
+        nan_mantissa::<Id>(),
+        parts,
+    )
+}
+
+fn with_zero(sks: &ServerKey, condition: &BooleanBlock, parts: FloatParts) -> FloatParts {
+    with_special(sks, condition, 0, 0, parts)
+}
+
+pub(super) fn select_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    condition: &BooleanBlock,
+    if_true: &FheFloat<Id>,
+    if_false: &FheFloat<Id>,
+) -> FloatParts {
+    let (sign, (exponent, mantissa)) = rayon::join(
+        || sks.if_then_else_parallelized(condition, &if_true.sign, &if_false.sign),
+        || {
+            rayon::join(
+                || sks.if_then_else_parallelized(condition, &if_true.exponent, &if_false.exponent),
+                || sks.if_then_else_parallelized(condition, &if_true.mantissa, &if_false.mantissa),
+            )
+        },
+    );
+
+    FloatParts {
+        sign,
+        exponent,
+        mantissa,
+    }
+}
+
+/// Normalizes and rounds a significand to the nearest float, ties to even
+///
+/// The value represented is `significand * 2^(exponent - bias - (num_bits - 1))`,
+/// where `num_bits` is the number of bits of the significand, i.e. `exponent` is the
+/// biased exponent of the result if the most significant bit of the significand is set.
+///
+/// The significand must have at least 2 bits more than the precision of the float.
+/// Results too large are rounded to infinity, and results too small are flushed to zero.
+fn round_and_pack<Id: FheFloatId>(
+    sks: &ServerKey,
+    sign: BooleanBlock,
+    exponent: &SignedRadixCiphertext,
+    significand: &RadixCiphertext,
+) -> FloatParts {
+    let bits_per_block = bits_per_block(sks);
+    let num_bits = significand.blocks.len() * bits_per_block;
+    let precision = precision::<Id>();
+    assert!(num_bits >= precision + 2);
+
+    let (leading_zeros, is_zero) = rayon::join(
+        || sks.leading_zeros_parallelized(significand),
+        || sks.scalar_eq_parallelized(significand, 0u64),
+    );
+    let (normalized, exponent) = rayon::join(
+        || sks.left_shift_parallelized(significand, &leading_zeros),
+        || {
+            let leading_zeros = sks.cast_to_signed(leading_zeros.clone(), exponent.blocks.len());
+            sks.sub_parallelized(exponent, &leading_zeros)
+        },
+    );
+
+    // Keep the `precision` most significant bits and the guard bit after them,
+    // the bits below only matter through whether they are all zeros or not
+    let guard_shift = num_bits - precision - 1;
+    let (with_guard, sticky) = rayon::join(
+        || {
+            let shifted = sks.scalar_right_shift_parallelized(&normalized, guard_shift as u64);
+            sks.cast_to_unsigned(shifted, (precision + 2).div_ceil(bits_per_block))
+        },
+        || {
+            let low_bits = sks.scalar_left_shift_parallelized(&normalized, (precision + 1) as u64);
+            sks.scalar_ne_parallelized(&low_bits, 0u64)
+        },
+    );
+
+    let (guard, lsb) = rayon::join(
+        || {
+            let bit = sks.scalar_bitand_parallelized(&with_guard, 1u64);
+            sks.scalar_ne_parallelized(&bit, 0u64)
+        },
+        || {
+            let bit = sks.scalar_bitand_parallelized(&with_guard, 2u64);
+            sks.scalar_ne_parallelized(&bit, 0u64)
+        },
+    );
+    let round_up = sks.boolean_bitand(&guard, &sks.boolean_bitor(&sticky, &lsb));
+
+    let truncated = sks.scalar_right_shift_parallelized(&with_guard, 1u64);
+    let round_up = round_up.into_radix(truncated.blocks.len(), sks);
+    let rounded = sks.add_parallelized(&truncated, &round_up);
+
+    // Rounding up 1.11...1 gives 10.00...0, which has one more bit
+    let carry = sks.scalar_eq_parallelized(&rounded, 1u64 << precision);
+    let (mantissa, exponent) = rayon::join(
+        || {
+            let halved = sks.scalar_right_shift_parallelized(&rounded, 1u64);
+            let mantissa = sks.if_then_else_parallelized(&carry, &halved, &rounded);
+            sks.cast_to_unsigned(mantissa, Id::mantissa_blocks(sks.message_modulus()))
+        },
+        || {
+            let carry: SignedRadixCiphertext = carry.clone().into_radix(exponent.blocks.len(), sks);
+            sks.add_parallelized(&exponent, &carry)
+        },
+    );
+
+    let (overflow, underflow) = rayon::join(
+        || sks.scalar_ge_parallelized(&exponent, max_exponent::<Id>() as i64),
+        || sks.scalar_le_parallelized(&exponent, 0i64),
+    );
+    let flush_to_zero = sks.boolean_bitor(&underflow, &is_zero);
+
+    let exponent = sks.cast_to_unsigned(exponent, Id::exponent_blocks(sks.message_modulus()));
+    let parts = FloatParts {
+        sign,
+        exponent,
+        mantissa,
+    };
+    // A zero significand may come with any exponent, so zeros take precedence
+    let parts = with_infinity::<Id>(sks, &overflow, parts);
+    with_zero(sks, &flush_to_zero, parts)
+}
+
+pub(super) fn neg_raw<Id: FheFloatId>(sks: &ServerKey, x: &FheFloat<Id>) -> FloatParts {
+    FloatParts {
+        sign: sks.boolean_bitnot(&x.sign),
+        exponent: x.exponent.clone(),
+        mantissa: x.mantissa.clone(),
+    }
+}
+
+pub(super) fn abs_raw<Id: FheFloatId>(sks: &ServerKey, x: &FheFloat<Id>) -> FloatParts {
+    FloatParts {
+        sign: sks.create_trivial_boolean_block(false),
+        exponent: x.exponent.clone(),
+        mantissa: x.mantissa.clone(),
+    }
+}
+
+pub(super) fn add_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+) -> FloatParts {
+    let bits_per_block = bits_per_block(sks);
+    let precision = precision::<Id>();
+    let num_mantissa_blocks = lhs.mantissa.blocks.len();
+
+    let lhs_magnitude = magnitude(lhs);
+    let rhs_magnitude = magnitude(rhs);
+    let ((lhs_classes, rhs_classes), lhs_is_larger) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || sks.ge_parallelized(&lhs_magnitude, &rhs_magnitude),
+    );
+
+    let ((larger, smaller), (larger_sign, is_subtraction)) = rayon::join(
+        || {
+            rayon::join(
+                || sks.if_then_else_parallelized(&lhs_is_larger, &lhs_magnitude, &rhs_magnitude),
+                || sks.if_then_else_parallelized(&lhs_is_larger, &rhs_magnitude, &lhs_magnitude),
+            )
+        },
+        || {
+            rayon::join(
+                || sks.if_then_else_parallelized(&lhs_is_larger, &lhs.sign, &rhs.sign),
+                || sks.boolean_bitxor(&lhs.sign, &rhs.sign),
+            )
+        },
+    );
+    let (larger_mantissa, larger_exponent) = split_magnitude(larger, num_mantissa_blocks);
+    let (smaller_mantissa, smaller_exponent) = split_magnitude(smaller, num_mantissa_blocks);
+
+    // The significands are wide enough for the smaller one to be shifted right by
+    // `precision + 3` bits without losing any bit. Shifting it more would only move it further
+    // below the guard bit of the result, where only the fact that it is not zero matters,
+    // so the shift is clamped.
+    let significand_blocks = (2 * precision + 4).div_ceil(bits_per_block);
+    let num_bits = significand_blocks * bits_per_block;
+    let max_shift = (precision + 3) as u64;
+    let (larger_significand, smaller_significand) = rayon::join(
+        || {
+            let significand = sks.cast_to_unsigned(larger_mantissa, significand_blocks);
+            sks.scalar_left_shift_parallelized(&significand, max_shift)
+        },
+        || {
+            let shift = sks.sub_parallelized(&larger_exponent, &smaller_exponent);
+            let shift = sks.scalar_min_parallelized(&shift, max_shift);
+            let significand = sks.cast_to_unsigned(smaller_mantissa, significand_blocks);
+            let significand = sks.scalar_left_shift_parallelized(&significand, max_shift);
+            sks.right_shift_parallelized(&significand, &shift)
+        },
+    );
+
+    let (significand, exponent) = rayon::join(
+        || {
+            let (sum, difference) = rayon::join(
+                || sks.add_parallelized(&larger_significand, &smaller_significand),
+                || sks.sub_parallelized(&larger_significand, &smaller_significand),
+            );
+            sks.if_then_else_parallelized(&is_subtraction, &difference, &sum)
+        },
+        || {
+            let exponent = sks.cast_to_signed(
+                larger_exponent.clone(),
+                work_exponent_blocks::<Id>(sks, num_bits),
+            );
+            sks.scalar_add_parallelized(&exponent, (num_bits - 2 * precision - 3) as u64)
+        },
+    );
+
+    // x + (-x) is +0, while (-0) + (-0) is -0
+    let is_zero_sum = sks.scalar_eq_parallelized(&significand, 0u64);
+    let zero_sign = sks.boolean_bitand(&lhs.sign, &rhs.sign);
+    let sign = sks.if_then_else_parallelized(&is_zero_sum, &zero_sign, &larger_sign);
+
+    let result = round_and_pack::<Id>(sks, sign, &exponent, &significand);
+
+    // inf - inf is NaN, otherwise an infinite operand gives an infinite result
+    let is_inf = sks.boolean_bitor(&lhs_classes.is_inf, &rhs_classes.is_inf);
+    let both_inf = sks.boolean_bitand(&lhs_classes.is_inf, &rhs_classes.is_inf);
+    let is_nan = sks.boolean_bitor(
+        &sks.boolean_bitor(&lhs_classes.is_nan, &rhs_classes.is_nan),
+        &sks.boolean_bitand(&both_inf, &is_subtraction),
+    );
+    let inf_sign = sks.if_then_else_parallelized(&lhs_classes.is_inf, &lhs.sign, &rhs.sign);
+
+    let result = FloatParts {
+        sign: sks.if_then_else_parallelized(&is_inf, &inf_sign, &result.sign),
+        ..result
+    };
+    let result = with_infinity::<Id>(sks, &is_inf, result);
+    with_nan::<Id>(sks, &is_nan, result)
+}
+
+pub(super) fn sub_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+) -> FloatParts {
+    let negated_rhs = FheFloat::<Id>::new(neg_raw(sks, rhs), rhs.tag.clone());
+    add_raw(sks, lhs, &negated_rhs)
+}
+
+pub(super) fn mul_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+) -> FloatParts {
+    let bits_per_block = bits_per_block(sks);
+    let significand_blocks = 2 * lhs.mantissa.blocks.len();
+    let num_bits = significand_blocks * bits_per_block;
+    let exponent_blocks = work_exponent_blocks::<Id>(sks, num_bits);
+
+    let ((lhs_classes, rhs_classes), (significand, exponent)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            rayon::join(
+                || {
+                    let (lhs, rhs) = rayon::join(
+                        || sks.cast_to_unsigned(lhs.mantissa.clone(), significand_blocks),
+                        || sks.cast_to_unsigned(rhs.mantissa.clone(), significand_blocks),
+                    );
+                    sks.mul_parallelized(&lhs, &rhs)
+                },
+                || {
+                    let lhs = sks.cast_to_signed(lhs.exponent.clone(), exponent_blocks);
+                    let rhs = sks.cast_to_signed(rhs.exponent.clone(), exponent_blocks);
+                    let sum = sks.add_parallelized(&lhs, &rhs);
+                    // The product of the mantissas is scaled by 2^(2 * MANTISSA_BITS)
+                    let offset = num_bits as i64
+                        - 1
+                        - 2 * i64::from(Id::MANTISSA_BITS)
+                        - bias::<Id>() as i64;
+                    add_offset(sks, &sum, offset)
+                },
+            )
+        },
+    );
+
+    let sign = sks.boolean_bitxor(&lhs.sign, &rhs.sign);
+    let result = round_and_pack::<Id>(sks, sign, &exponent, &significand);
+
+    // 0 * inf is NaN
+    let is_inf = sks.boolean_bitor(&lhs_classes.is_inf, &rhs_classes.is_inf);
+    let is_nan = sks.boolean_bitor(
+        &sks.boolean_bitor(&lhs_classes.is_nan, &rhs_classes.is_nan),
+        &sks.boolean_bitor(
+            &sks.boolean_bitand(&lhs_classes.is_inf, &rhs_classes.is_zero),
+            &sks.boolean_bitand(&lhs_classes.is_zero, &rhs_classes.is_inf),
+        ),
+    );
+
+    let result = with_infinity::<Id>(sks, &is_inf, result);
+    with_nan::<Id>(sks, &is_nan, result)
+}
+
+pub(super) fn div_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+) -> FloatParts {
+    let bits_per_block = bits_per_block(sks);
+    let precision = precision::<Id>();
+    // The quotient of the mantissas, scaled by 2^(precision + 2), has at least
+    // precision + 2 bits, so that its rounding only depends on the remainder being zero or not
+    let quotient_shift = precision + 2;
+    let significand_blocks = (2 * precision + 3).div_ceil(bits_per_block);
+    let num_bits = significand_blocks * bits_per_block;
+    let exponent_blocks = work_exponent_blocks::<Id>(sks, num_bits);
+
+    let ((lhs_classes, rhs_classes), (significand, exponent)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            rayon::join(
+                || {
+                    let numerator = sks.cast_to_unsigned(lhs.mantissa.clone(), significand_blocks);
+                    let numerator =
+                        sks.scalar_left_shift_parallelized(&numerator, quotient_shift as u64);
+                    let divisor = sks.cast_to_unsigned(rhs.mantissa.clone(), significand_blocks);
+                    let (quotient, remainder) = sks.div_rem_parallelized(&numerator, &divisor);
+
+                    // The remainder is appended to the quotient as a sticky bit
+                    let sticky: RadixCiphertext = sks
+                        .scalar_ne_parallelized(&remainder, 0u64)
+                        .into_radix(significand_blocks, sks);
+                    let quotient = sks.scalar_left_shift_parallelized(&quotient, 1u64);
+                    sks.add_parallelized(&quotient, &sticky)
+                },
+                || {
+                    let lhs = sks.cast_to_signed(lhs.exponent.clone(), exponent_blocks);
+                    let rhs = sks.cast_to_signed(rhs.exponent.clone(), exponent_blocks);
+                    let difference = sks.sub_parallelized(&lhs, &rhs);
+                    let offset = bias::<Id>() as i64 + num_bits as i64 - quotient_shift as i64 - 2;
+                    add_offset(sks, &difference, offset)
+                },
+            )
+        },
+    );
+
+    let sign = sks.boolean_bitxor(&lhs.sign, &rhs.sign);
+    let result = round_and_pack::<Id>(sks, sign, &exponent, &significand);
+
+    // 0 / 0 and inf / inf are NaN, x / 0 is inf and x / inf is 0
+    let is_zero = rhs_classes.is_inf.clone();
+    let is_inf = sks.boolean_bitor(&lhs_classes.is_inf, &rhs_classes.is_zero);
+    let is_nan = sks.boolean_bitor(
+        &sks.boolean_bitor(&lhs_classes.is_nan, &rhs_classes.is_nan),
+        &sks.boolean_bitor(
+            &sks.boolean_bitand(&lhs_classes.is_zero, &rhs_classes.is_zero),
+            &sks.boolean_bitand(&lhs_classes.is_inf, &rhs_classes.is_inf),
+        ),
+    );
+
+    let result = with_zero(sks, &is_zero, result);
+    let result = with_infinity::<Id>(sks, &is_inf, result);
+    with_nan::<Id>(sks, &is_nan, result)
+}
+
+pub(super) fn eq_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+) -> BooleanBlock {
+    let lhs_magnitude = magnitude(lhs);
+    let rhs_magnitude = magnitude(rhs);
+    let ((lhs_classes, rhs_classes), (same_magnitude, different_signs)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            rayon::join(
+                || sks.eq_parallelized(&lhs_magnitude, &rhs_magnitude),
+                || sks.boolean_bitxor(&lhs.sign, &rhs.sign),
+            )
+        },
+    );
+
+    // -0 == +0, and NaN is not equal to anything
+    let both_zero = sks.boolean_bitand(&lhs_classes.is_zero, &rhs_classes.is_zero);
+    let is_equal = sks.boolean_bitor(
+        &both_zero,
+        &sks.boolean_bitand(&same_magnitude, &sks.boolean_bitnot(&different_signs)),
+    );
+    let is_unordered = sks.boolean_bitor(&lhs_classes.is_nan, &rhs_classes.is_nan);
+    sks.boolean_bitand(&is_equal, &sks.boolean_bitnot(&is_unordered))
+}
+
+/// Returns whether `lhs < rhs`, or `lhs <= rhs` if `or_equal` is true
+pub(super) fn lt_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+    or_equal: bool,
+) -> BooleanBlock {
+    let lhs_magnitude = magnitude(lhs);
+    let rhs_magnitude = magnitude(rhs);
+    let ((lhs_classes, rhs_classes), (magnitude_is_lower, magnitude_is_greater)) = rayon::join(
+        || rayon::join(|| classify(sks, lhs), || classify(sks, rhs)),
+        || {
+            if or_equal {
+                rayon::join(
+                    || sks.le_parallelized(&lhs_magnitude, &rhs_magnitude),
+                    || sks.ge_parallelized(&lhs_magnitude, &rhs_magnitude),
+                )
+            } else {
+                rayon::join(
+                    || sks.lt_parallelized(&lhs_magnitude, &rhs_magnitude),
+                    || sks.gt_parallelized(&lhs_magnitude, &rhs_magnitude),
+                )
+            }
+        },
+    );
+
+    // With different signs, lhs is lower only if it is negative, with the same sign
+    // the order of the magnitudes is reversed for negative numbers
+    let different_signs = sks.boolean_bitxor(&lhs.sign, &rhs.sign);
+    let same_sign_result =
+        sks.if_then_else_parallelized(&lhs.sign, &magnitude_is_greater, &magnitude_is_lower);
+    let result = sks.if_then_else_parallelized(&different_signs, &lhs.sign, &same_sign_result);
+
+    // -0 == +0, and NaN is not ordered with anything
+    let both_zero = sks.boolean_bitand(&lhs_classes.is_zero, &rhs_classes.is_zero);
+    let result = if or_equal {
+        sks.boolean_bitor(&result, &both_zero)
+    } else {
+        sks.boolean_bitand(&result, &sks.boolean_bitnot(&both_zero))
+    };
+    let is_unordered = sks.boolean_bitor(&lhs_classes.is_nan, &rhs_classes.is_nan);
+    sks.boolean_bitand(&result, &sks.boolean_bitnot(&is_unordered))
+}
+
+/// Returns the min of the two values, or the max if `max` is true
+///
+/// If one of the values is NaN, the other one is returned.
+pub(super) fn min_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    lhs: &FheFloat<Id>,
+    rhs: &FheFloat<Id>,
+    max: bool,
+) -> FloatParts {
+    let (rhs_is_better, lhs_is_nan) = rayon::join(
+        || {
+            if max {
+                lt_raw(sks, lhs, rhs, false)
+            } else {
+                lt_raw(sks, rhs, lhs, false)
+            }
+        },
+        || is_nan_raw(sks, lhs),
+    );
+    let select_rhs = sks.boolean_bitor(&rhs_is_better, &lhs_is_nan);
+    select_raw(sks, &select_rhs, rhs, lhs)
+}
+
+/// Converts an integer to the nearest float, ties to even
+///
+/// The integer is given as its sign and absolute value.
+pub(super) fn from_integer_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    sign: BooleanBlock,
+    absolute_value: RadixCiphertext,
+) -> FloatParts {
+    let bits_per_block = bits_per_block(sks);
+    let significand_blocks = absolute_value
+        .blocks
+        .len()
+        .max((precision::<Id>() + 2).div_ceil(bits_per_block));
+    let num_bits = significand_blocks * bits_per_block;
+
+    let significand = sks.cast_to_unsigned(absolute_value, significand_blocks);
+    let exponent: SignedRadixCiphertext = sks.create_trivial_radix(
+        bias::<Id>() + num_bits as u64 - 1,
+        work_exponent_blocks::<Id>(sks, num_bits),
+    );
+    round_and_pack::<Id>(sks, sign, &exponent, &significand)
+}
+
+/// Returns the integer part of the absolute value of `x`, rounded towards zero,
+/// and whether it does not fit in `integer_bits` bits or is not finite
+///
+/// The integer part is valid only if it fits.
+pub(super) fn to_integer_raw<Id: FheFloatId>(
+    sks: &ServerKey,
+    x: &FheFloat<Id>,
+    integer_bits: usize,
+) -> (RadixCiphertext, BooleanBlock) {
+    let bits_per_block = bits_per_block(sks);
+    let precision = precision::<Id>();
+    let work_blocks = (integer_bits + precision + 1).div_ceil(bits_per_block);
+    let exponent_blocks = work_exponent_blocks::<Id>(sks, work_blocks * bits_per_block);
+
+    // |x| = mantissa * 2^(exponent - bias - MANTISSA_BITS)
+    //     = (mantissa << integer_bits) >> (integer_bits + MANTISSA_BITS + bias - exponent)
+    let (integer_part, is_too_large) = rayon::join(
+        || {
+            let (mantissa, shift) = rayon::join(
+                || {
+                    let mantissa = sks.cast_to_unsigned(x.mantissa.clone(), work_blocks);
+                    sks.scalar_left_shift_parallelized(&mantissa, integer_bits as u64)
+                },
+                || {
+                    let exponent = sks.cast_to_signed(x.exponent.clone(), exponent_blocks);
+                    let negated_exponent = sks.neg_parallelized(&exponent);
+                    let offset = integer_bits as u64 + u64::from(Id::MANTISSA_BITS) + bias::<Id>();
+                    let shift = sks.scalar_add_parallelized(&negated_exponent, offset);
+                    // Too large exponents give a negative shift, and any shift larger than
+                    // `integer_bits + precision` gives 0
+                    let shift = sks.scalar_max_parallelized(&shift, 0i64);
+                    let shift =
+                        sks.scalar_min_parallelized(&shift, (integer_bits + precision) as i64);
+                    sks.cast_to_unsigned(shift, exponent_blocks)
+                },
+            );
+            sks.right_shift_parallelized(&mantissa, &shift)
+        },
+        || {
+            let (is_large, is_special) = rayon::join(
+                || sks.scalar_ge_parallelized(&x.exponent, bias::<Id>() + integer_bits as u64),
+                || sks.scalar_eq_parallelized(&x.exponent, max_exponent::<Id>()),
+            );
+            sks.boolean_bitor(&is_large, &is_special)
+        },
+    );
+
+    (integer_part, is_too_large)
+}
@@ -0,0 +1,64 @@
+use crate::high_level_api::float::base::{FheFloat, FheFloatId};
+use crate::high_level_api::FheId;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::NotVersioned;
+
+macro_rules! static_float_type {
+    // Defines a static floating-point type, with the same layout as
+    // the IEEE 754 binary format of `num_bits` bits
+    (
+        $(#[$outer:meta])*
+        Float {
+            num_bits: $num_bits:literal,
+            exponent_bits: $exponent_bits:literal,
+            mantissa_bits: $mantissa_bits:literal,
+        }
+    ) => {
+        ::paste::paste! {
+            #[doc = concat!("Id for the [FheFloat", stringify!($num_bits), "] data type.")]
+            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, NotVersioned)]
+            pub struct [<FheFloat $num_bits Id>];
+
+            impl FheId for [<FheFloat $num_bits Id>] { }
+
+            impl FheFloatId for [<FheFloat $num_bits Id>] {
+                const EXPONENT_BITS: u32 = $exponent_bits;
+                const MANTISSA_BITS: u32 = $mantissa_bits;
+            }
+
+            #[doc = concat!(
+                "A floating-point type with the layout of the IEEE 754 binary", stringify!($num_bits),
+                " format (", stringify!($exponent_bits), " exponent bits and ",
+                stringify!($mantissa_bits), " mantissa bits)"
+            )]
+            #[doc = ""]
+            #[doc = "See [FheFloat]"]
+            $(#[$outer])*
+            pub type [<FheFloat $num_bits>] = FheFloat<[<FheFloat $num_bits Id>]>;
+        }
+    };
+}
+
+static_float_type! {
+    Float {
+        num_bits: 16,
+        exponent_bits: 5,
+        mantissa_bits: 10,
+    }
+}
+
+static_float_type! {
+    Float {
+        num_bits: 32,
+        exponent_bits: 8,
+        mantissa_bits: 23,
+    }
+}
+
+static_float_type! {
+    Float {
+        num_bits: 64,
+        exponent_bits: 11,
+        mantissa_bits: 52,
+    }
+}
@@ -0,0 +1,205 @@
+use crate::high_level_api::float::base::{decode_f64, encode_f64};
+use crate::prelude::*;
+use crate::safe_serialization::{safe_deserialize, safe_serialize};
+use crate::{
+    generate_keys, set_server_key, ClientKey, ConfigBuilder, FheFloat16, FheFloat16Id, FheFloat32,
+    FheFloat64, FheInt16, FheInt8, FheUint16, FheUint8, ServerKey,
+};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    client_key
+}
+
+// Rounds a value to the nearest half-precision float
+//
+// Computing the exact result of an operation on two half-precision floats
+// with f64, and then rounding it, gives the correctly rounded result
+fn to_half(value: f64) -> f64 {
+    let (sign, exponent, mantissa) = encode_f64::<FheFloat16Id>(value);
+    decode_f64::<FheFloat16Id>(sign, exponent, mantissa)
+}
+
+#[track_caller]
+fn assert_same_float(result: f64, expected: f64) {
+    if expected.is_nan() {
+        assert!(result.is_nan(), "expected NaN, got {result}");
+    } else {
+        // Compare the bits, so that the sign of zeros is checked
+        assert_eq!(
+            result.to_bits(),
+            expected.to_bits(),
+            "{result} != {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_float_encrypt_decrypt() {
+    let client_key = setup_default_cpu();
+
+    for value in [
+        0.0f32,
+        -0.0,
+        1.5,
+        -3.25e10,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ] {
+        let a = FheFloat32::encrypt(value, &client_key);
+        let decrypted: f32 = a.decrypt(&client_key);
+        assert_eq!(decrypted.to_bits(), value.to_bits());
+    }
+
+    let a = FheFloat32::encrypt(f32::NAN, &client_key);
+    let decrypted: f32 = a.decrypt(&client_key);
+    assert!(decrypted.is_nan());
+
+    // Subnormals are flushed to zero
+    let a = FheFloat32::encrypt(1e-40f32, &client_key);
+    let decrypted: f32 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 0.0);
+
+    for value in [0.1f64, -1e300, f64::MIN_POSITIVE] {
+        let a = FheFloat64::encrypt(value, &client_key);
+        let decrypted: f64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value);
+    }
+
+    // Values are rounded to the nearest representable value
+    let a = FheFloat16::encrypt(0.1f64, &client_key);
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 0.0999755859375);
+
+    let a = FheFloat16::encrypt(1e5f64, &client_key);
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, f64::INFINITY);
+
+    let a = FheFloat16::encrypt_trivial(-2.5f64);
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, -2.5);
+}
+
+#[test]
+fn test_float_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        (1.5, 2.25),
+        (-7.75, 0.1),
+        (3.0, -3.0),
+        (1.0, 3.0),
+        (300.0, 400.0),
+        (0.001, -0.001),
+        (f64::INFINITY, f64::INFINITY),
+        (f64::NEG_INFINITY, 0.0),
+        (0.0, -0.0),
+        (f64::NAN, 1.0),
+    ];
+
+    for (clear_a, clear_b) in cases {
+        let (clear_a, clear_b) = (to_half(clear_a), to_half(clear_b));
+        let a = FheFloat16::encrypt(clear_a, &client_key);
+        let b = FheFloat16::encrypt(clear_b, &client_key);
+
+        let decrypted: f64 = (&a + &b).decrypt(&client_key);
+        assert_same_float(decrypted, to_half(clear_a + clear_b));
+
+        let decrypted: f64 = (&a - &b).decrypt(&client_key);
+        assert_same_float(decrypted, to_half(clear_a - clear_b));
+
+        let decrypted: f64 = (&a * &b).decrypt(&client_key);
+        assert_same_float(decrypted, to_half(clear_a * clear_b));
+
+        let decrypted: f64 = (&a / &b).decrypt(&client_key);
+        assert_same_float(decrypted, to_half(clear_a / clear_b));
+
+        let decrypted: f64 = (-&a).decrypt(&client_key);
+        assert_same_float(decrypted, -clear_a);
+    }
+}
+
+#[test]
+fn test_float_comparisons() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        (-1.5, 0.25),
+        (2.0, 2.0),
+        (-0.0, 0.0),
+        (-3.0, -4.0),
+        (f64::INFINITY, 1.0),
+        (f64::NAN, 1.0),
+    ];
+
+    for (clear_a, clear_b) in cases {
+        let a = FheFloat16::encrypt(clear_a, &client_key);
+        let b = FheFloat16::encrypt(clear_b, &client_key);
+
+        assert_eq!(a.eq(&b).decrypt(&client_key), clear_a == clear_b);
+        assert_eq!(a.ne(&b).decrypt(&client_key), clear_a != clear_b);
+        assert_eq!(a.lt(&b).decrypt(&client_key), clear_a < clear_b);
+        assert_eq!(a.le(&b).decrypt(&client_key), clear_a <= clear_b);
+        assert_eq!(a.gt(&b).decrypt(&client_key), clear_a > clear_b);
+        assert_eq!(a.ge(&b).decrypt(&client_key), clear_a >= clear_b);
+
+        let decrypted: f64 = a.min(&b).decrypt(&client_key);
+        assert_eq!(decrypted, clear_a.min(clear_b));
+        let decrypted: f64 = a.max(&b).decrypt(&client_key);
+        assert_eq!(decrypted, clear_a.max(clear_b));
+    }
+}
+
+#[test]
+fn test_float_casts() {
+    let client_key = setup_default_cpu();
+
+    for clear in [0i16, -1234, 2049, i16::MIN, i16::MAX] {
+        let a = FheInt16::encrypt(clear, &client_key);
+        let decrypted: f64 = FheFloat16::cast_from(a).decrypt(&client_key);
+        assert_same_float(decrypted, to_half(f64::from(clear)));
+    }
+
+    for clear in [0u16, 2049, u16::MAX] {
+        let a = FheUint16::encrypt(clear, &client_key);
+        let decrypted: f64 = FheFloat16::cast_from(a).decrypt(&client_key);
+        assert_same_float(decrypted, to_half(f64::from(clear)));
+    }
+
+    for clear in [0.0, 42.75, -42.75, -0.5, 1e4, -1e4, f64::INFINITY, f64::NAN] {
+        let a = FheFloat16::encrypt(clear, &client_key);
+
+        let decrypted: i8 = FheInt8::cast_from(a.clone()).decrypt(&client_key);
+        assert_eq!(decrypted, clear as i8, "{clear} as i8");
+
+        let decrypted: u8 = FheUint8::cast_from(a).decrypt(&client_key);
+        assert_eq!(decrypted, clear as u8, "{clear} as u8");
+    }
+}
+
+#[test]
+fn test_float_tag_and_serialization() {
+    let config = ConfigBuilder::default().build();
+    let mut client_key = ClientKey::generate(config);
+    client_key.tag_mut().set_u64(42);
+
+    let server_key = ServerKey::new(&client_key);
+    set_server_key(server_key);
+
+    let a = FheFloat16::encrypt(4.5f64, &client_key);
+    assert_eq!(a.tag(), client_key.tag());
+
+    let b = &a * &a;
+    assert_eq!(b.tag(), client_key.tag());
+
+    let mut buffer = vec![];
+    safe_serialize(&b, &mut buffer, 1 << 30).unwrap();
+    let b: FheFloat16 = safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+
+    assert_eq!(b.tag(), client_key.tag());
+    let decrypted: f64 = b.decrypt(&client_key);
+    assert_eq!(decrypted, 20.25);
+}
@@ -13,7 +13,7 @@ use crate::named::Named;
 use crate::prelude::CastFrom;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
-use crate::{Device, FheBool, ServerKey, Tag};
+use crate::{Device, FheBool, NoiseInfo, ServerKey, Tag};
 use std::marker::PhantomData;
 
 #[cfg(feature = "gpu")]
@@ -152,6 +152,42 @@ where
         self.ciphertext.current_device()
     }
 
+    /// Returns the degree and noise of the blocks of the ciphertext
+    ///
+    /// The variance of the noise of the blocks can be estimated from the parameters, see
+    /// [BlockNoiseInfo::estimated_variance](crate::BlockNoiseInfo::estimated_variance).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::ciphertext::NoiseLevel;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let parameters = client_key.computation_parameters();
+    ///
+    /// let a = FheInt16::encrypt(-1i16, &client_key);
+    /// let noise_info = a.noise_info();
+    /// assert!(!noise_info.carry_propagation_needed());
+    /// for block in noise_info.blocks() {
+    ///     assert_eq!(block.noise_level(), NoiseLevel::NOMINAL);
+    ///     assert_eq!(
+    ///         block.estimated_variance(parameters),
+    ///         Some(parameters.encryption_noise_variance())
+    ///     );
+    /// }
+    ///
+    /// let trivial = FheInt16::encrypt_trivial(-1i16);
+    /// for block in trivial.noise_info().blocks() {
+    ///     assert_eq!(block.noise_level(), NoiseLevel::ZERO);
+    /// }
+    /// ```
+    pub fn noise_info(&self) -> NoiseInfo {
+        NoiseInfo::from_blocks(&self.ciphertext.on_cpu().blocks)
+    }
+
     /// Returns the absolute value
     ///
     /// # Example
@@ -5,10 +5,12 @@ mod encrypt;
 mod inner;
 mod ops;
 mod overflowing_ops;
+mod saturating_ops;
 mod scalar_ops;
 mod static_;
 #[cfg(test)]
 mod tests;
+mod widening_ops;
 
 pub use base::{FheInt, FheIntId};
 pub use compressed::CompressedFheInt;
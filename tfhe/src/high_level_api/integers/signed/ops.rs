@@ -2,7 +2,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheIntId, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
+    DivRem, FheEq, FheMax, FheMin, FheOrd, Isqrt, Pow, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
 use crate::{FheBool, FheInt, FheUint};
@@ -521,6 +521,154 @@ where
     }
 }
 
+impl<Id, Id2> Pow<&FheUint<Id2>> for FheInt<Id>
+where
+    Id: FheIntId,
+    Id2: FheUintId,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: &FheUint<Id2>) -> Self::Output {
+        <&Self as Pow<&FheUint<Id2>>>::pow(&self, exponent)
+    }
+}
+
+impl<Id, Id2> Pow<&FheUint<Id2>> for &FheInt<Id>
+where
+    Id: FheIntId,
+    Id2: FheUintId,
+{
+    type Output = FheInt<Id>;
+
+    /// Raises a [FheInt] to the power of an encrypted exponent
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let b = FheUint8::encrypt(9u8, &client_key);
+    ///
+    /// let result = (&a).pow(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (-3i16).wrapping_pow(9));
+    /// ```
+    fn pow(self, exponent: &FheUint<Id2>) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .pow_parallelized(&*self.ciphertext.on_cpu(), &*exponent.ciphertext.on_cpu());
+                FheInt::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+}
+
+impl<Id> Pow<u32> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: u32) -> Self::Output {
+        <&Self as Pow<u32>>::pow(&self, exponent)
+    }
+}
+
+impl<Id> Pow<u32> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Raises a [FheInt] to the power of a clear exponent
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-7i16, &client_key);
+    ///
+    /// let result = (&a).pow(5);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (-7i16).wrapping_pow(5));
+    /// ```
+    fn pow(self, exponent: u32) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), exponent);
+                FheInt::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+}
+
+impl<Id> Isqrt for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// Negative numbers have no square root, the result will be 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(1000i16, &client_key);
+    ///
+    /// let result = a.isqrt();
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 31);
+    /// ```
+    fn isqrt(&self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .isqrt_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support isqrt yet");
+            }
+        })
+    }
+}
+
 macro_rules! generic_integer_impl_operation (
     (
         $(#[$outer:meta])*
@@ -0,0 +1,215 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheIntId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::prelude::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use crate::FheInt;
+
+impl<Id> SaturatingAdd<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Adds two [FheInt], saturating at the minimum or maximum value instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(i16::MAX - 10, &client_key);
+    /// let b = FheInt16::encrypt(20i16, &client_key);
+    ///
+    /// let result = (&a).saturating_add(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (i16::MAX - 10).saturating_add(20i16));
+    /// ```
+    fn saturating_add(self, other: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().signed_saturating_add_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                FheInt::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating_add yet")
+            }
+        })
+    }
+}
+
+impl<Id> SaturatingAdd<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Adds two [FheInt], saturating at the minimum or maximum value instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(i16::MAX - 10, &client_key);
+    /// let b = FheInt16::encrypt(20i16, &client_key);
+    ///
+    /// let result = a.saturating_add(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (i16::MAX - 10).saturating_add(20i16));
+    /// ```
+    fn saturating_add(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingAdd<&Self>>::saturating_add(&self, other)
+    }
+}
+
+impl<Id> SaturatingSub<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Subtracts two [FheInt], saturating at the minimum or maximum value instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(i16::MIN + 10, &client_key);
+    /// let b = FheInt16::encrypt(20i16, &client_key);
+    ///
+    /// let result = (&a).saturating_sub(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (i16::MIN + 10).saturating_sub(20i16));
+    /// ```
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().signed_saturating_sub_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                FheInt::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating_sub yet")
+            }
+        })
+    }
+}
+
+impl<Id> SaturatingSub<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Subtracts two [FheInt], saturating at the minimum or maximum value instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(i16::MIN + 10, &client_key);
+    /// let b = FheInt16::encrypt(20i16, &client_key);
+    ///
+    /// let result = a.saturating_sub(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (i16::MIN + 10).saturating_sub(20i16));
+    /// ```
+    fn saturating_sub(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingSub<&Self>>::saturating_sub(&self, other)
+    }
+}
+
+impl<Id> SaturatingMul<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Multiplies two [FheInt], saturating at the minimum or maximum value instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3434i16, &client_key);
+    /// let b = FheInt16::encrypt(54i16, &client_key);
+    ///
+    /// let result = (&a).saturating_mul(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (-3434i16).saturating_mul(54i16));
+    /// ```
+    fn saturating_mul(self, other: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().signed_saturating_mul_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                FheInt::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating_mul yet")
+            }
+        })
+    }
+}
+
+impl<Id> SaturatingMul<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Multiplies two [FheInt], saturating at the minimum or maximum value instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3434i16, &client_key);
+    /// let b = FheInt16::encrypt(54i16, &client_key);
+    ///
+    /// let result = a.saturating_mul(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, (-3434i16).saturating_mul(54i16));
+    /// ```
+    fn saturating_mul(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingMul<&Self>>::saturating_mul(&self, other)
+    }
+}
@@ -637,6 +637,87 @@ fn test_ilog2() {
     test_case_ilog2(&client_key);
 }
 
+#[test]
+fn test_pow_isqrt() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    for _ in 0..3 {
+        let clear_a = rng.gen::<i16>();
+        let clear_b = rng.gen::<u8>();
+        let a = FheInt16::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let pow: i16 = (&a).pow(&b).decrypt(&client_key);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b.into()));
+
+        let pow: i16 = (&a).pow(u32::from(clear_b)).decrypt(&client_key);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b.into()));
+
+        // Negative numbers have no square root, 0 is returned
+        let isqrt: i16 = a.isqrt().decrypt(&client_key);
+        let clear_a = i32::from(clear_a.max(0));
+        let isqrt = i32::from(isqrt);
+        assert!(isqrt * isqrt <= clear_a);
+        assert!((isqrt + 1) * (isqrt + 1) > clear_a);
+    }
+}
+
+#[test]
+fn test_saturating_ops() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    // Values close to the bounds so that saturation happens in both directions
+    let cases = [
+        (i8::MAX, 1i8),
+        (i8::MIN, 1),
+        (i8::MIN, -1),
+        (-16, 16),
+        (rng.gen(), rng.gen()),
+    ];
+    for (clear_a, clear_b) in cases {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let result: i8 = (&a).saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+
+        let result: i8 = (&a).saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+
+        let result: i8 = a.saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+#[test]
+fn test_widening_mul_mul_high() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    let cases = [
+        (i8::MIN, i8::MIN),
+        (i8::MIN, i8::MAX),
+        (-1, 1),
+        (rng.gen(), rng.gen()),
+    ];
+    for (clear_a, clear_b) in cases {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+        let expected = i16::from(clear_a) * i16::from(clear_b);
+
+        let result: i16 = (&a).widening_mul(&b).decrypt(&client_key);
+        assert_eq!(result, expected);
+
+        let result: i8 = a.mul_high(&b).decrypt(&client_key);
+        assert_eq!(result, (expected >> 8) as i8);
+    }
+}
+
 #[test]
 fn test_leading_trailing_zeros_ones() {
     let (client_key, server_key) = generate_keys(ConfigBuilder::default());
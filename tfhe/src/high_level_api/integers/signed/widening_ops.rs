@@ -0,0 +1,119 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheIntId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::prelude::{MulHigh, WideningMul};
+use crate::{
+    FheInt, FheInt128Id, FheInt12Id, FheInt16Id, FheInt256Id, FheInt2Id, FheInt32Id, FheInt4Id,
+    FheInt64Id, FheInt6Id, FheInt8Id,
+};
+
+/// Computes the full product of lhs and rhs into a FheInt with twice as many bits
+fn widening_mul_impl<Id, WideId>(lhs: &FheInt<Id>, rhs: &FheInt<Id>) -> FheInt<WideId>
+where
+    Id: FheIntId,
+    WideId: FheIntId,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let result = cpu_key
+                .pbs_key()
+                .widening_mul_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+            FheInt::new(result, cpu_key.tag.clone())
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support widening_mul yet")
+        }
+    })
+}
+
+macro_rules! impl_widening_mul {
+    ($($id:ty => $wide_id:ty),* $(,)?) => {
+        $(
+            impl WideningMul<Self> for &FheInt<$id> {
+                type Output = FheInt<$wide_id>;
+
+                fn widening_mul(self, rhs: Self) -> Self::Output {
+                    widening_mul_impl(self, rhs)
+                }
+            }
+
+            impl WideningMul<&Self> for FheInt<$id> {
+                type Output = FheInt<$wide_id>;
+
+                fn widening_mul(self, rhs: &Self) -> Self::Output {
+                    widening_mul_impl(&self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+// Each type is widened to the type with twice as many bits, when it exists
+impl_widening_mul!(
+    FheInt2Id => FheInt4Id,
+    FheInt4Id => FheInt8Id,
+    FheInt6Id => FheInt12Id,
+    FheInt8Id => FheInt16Id,
+    FheInt16Id => FheInt32Id,
+    FheInt32Id => FheInt64Id,
+    FheInt64Id => FheInt128Id,
+    FheInt128Id => FheInt256Id,
+);
+
+impl<Id> MulHigh<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Returns the most significant half of the full product of two [FheInt].
+    ///
+    /// For example, with [FheInt32](crate::FheInt32) this is
+    /// `((a as i64 * b as i64) >> 32) as i32`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-25_000i16, &client_key);
+    /// let b = FheInt16::encrypt(3_000i16, &client_key);
+    ///
+    /// let result = (&a).mul_high(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, ((-25_000i32 * 3_000i32) >> 16) as i16);
+    /// ```
+    fn mul_high(self, rhs: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mul_high_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+                FheInt::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_high yet")
+            }
+        })
+    }
+}
+
+impl<Id> MulHigh<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    /// Returns the most significant half of the full product of two [FheInt].
+    ///
+    /// See [MulHigh::mul_high] for the reference version
+    fn mul_high(self, rhs: &Self) -> Self::Output {
+        <&Self as MulHigh<&Self>>::mul_high(&self, rhs)
+    }
+}
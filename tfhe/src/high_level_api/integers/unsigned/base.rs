@@ -10,7 +10,7 @@ use crate::high_level_api::integers::signed::{FheInt, FheIntId};
 use crate::high_level_api::integers::IntegerId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::Tagged;
-use crate::high_level_api::{global_state, Device};
+use crate::high_level_api::{global_state, Device, NoiseInfo};
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::integer::server_key::MatchValues;
@@ -326,6 +326,42 @@ where
         self.ciphertext.on_cpu().is_trivial()
     }
 
+    /// Returns the degree and noise of the blocks of the ciphertext
+    ///
+    /// The variance of the noise of the blocks can be estimated from the parameters, see
+    /// [BlockNoiseInfo::estimated_variance](crate::BlockNoiseInfo::estimated_variance).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::ciphertext::NoiseLevel;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let parameters = client_key.computation_parameters();
+    ///
+    /// let a = FheUint16::encrypt(1u16, &client_key);
+    /// let noise_info = a.noise_info();
+    /// assert!(!noise_info.carry_propagation_needed());
+    /// for block in noise_info.blocks() {
+    ///     assert_eq!(block.noise_level(), NoiseLevel::NOMINAL);
+    ///     assert_eq!(
+    ///         block.estimated_variance(parameters),
+    ///         Some(parameters.encryption_noise_variance())
+    ///     );
+    /// }
+    ///
+    /// let trivial = FheUint16::encrypt_trivial(1u16);
+    /// for block in trivial.noise_info().blocks() {
+    ///     assert_eq!(block.noise_level(), NoiseLevel::ZERO);
+    /// }
+    /// ```
+    pub fn noise_info(&self) -> NoiseInfo {
+        NoiseInfo::from_blocks(&self.ciphertext.on_cpu().blocks)
+    }
+
     /// Sums multiple ciphertexts together.
     ///
     /// This is much more efficient than manually calling the `+` operator, thus
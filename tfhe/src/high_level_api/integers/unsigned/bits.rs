@@ -0,0 +1,335 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheUintId, IntegerId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::{FheBool, FheUint, FheUint8, FheUint8Id};
+use rayon::prelude::*;
+
+impl<Id> FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Returns the bits of the value, least significant bit first
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0b1011_0010u8;
+    /// let a = FheUint8::encrypt(msg, &client_key);
+    ///
+    /// let bits = a.to_bits();
+    /// assert_eq!(bits.len(), 8);
+    /// for (i, bit) in bits.iter().enumerate() {
+    ///     let decrypted: bool = bit.decrypt(&client_key);
+    ///     assert_eq!(decrypted, (msg >> i) & 1 == 1);
+    /// }
+    /// ```
+    pub fn to_bits(&self) -> Vec<FheBool> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => cpu_key
+                .pbs_key()
+                .to_bits_parallelized(&*self.ciphertext.on_cpu())
+                .into_iter()
+                .map(|bit| FheBool::new(bit, cpu_key.tag.clone()))
+                .collect(),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support to_bits yet")
+            }
+        })
+    }
+
+    /// Builds a value from its bits, least significant bit first
+    ///
+    /// If there are less bits than the number of bits of `Self`,
+    /// the missing most significant bits are 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more bits than the number of bits of `Self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let bits = [true, false, true, true].map(|bit| FheBool::encrypt(bit, &client_key));
+    ///
+    /// let a = FheUint8::from_bits(&bits);
+    /// let decrypted: u8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b1101);
+    /// ```
+    #[track_caller]
+    pub fn from_bits(bits: &[FheBool]) -> Self {
+        assert!(
+            bits.len() <= Id::num_bits(),
+            "Cannot build a {} bits integer from {} bits",
+            Id::num_bits(),
+            bits.len()
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let bits = bits
+                    .iter()
+                    .map(|bit| bit.ciphertext.on_cpu().into_owned())
+                    .collect::<Vec<BooleanBlock>>();
+                let ct: RadixCiphertext = sks.from_bits_parallelized(&bits);
+                let ct = sks.cast_to_unsigned(ct, Id::num_blocks(cpu_key.message_modulus()));
+                Self::new(ct, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support from_bits yet")
+            }
+        })
+    }
+
+    /// Returns the bytes of the value, least significant byte first
+    ///
+    /// If the number of bits of `Self` is not a multiple of 8,
+    /// the most significant bits of the last byte are 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let msg = 0x1234_5678u32;
+    /// let a = FheUint32::encrypt(msg, &client_key);
+    ///
+    /// let bytes = a.to_le_bytes();
+    /// let decrypted = bytes
+    ///     .iter()
+    ///     .map(|byte| byte.decrypt(&client_key))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(decrypted, msg.to_le_bytes());
+    /// ```
+    pub fn to_le_bytes(&self) -> Vec<FheUint8> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let byte_num_blocks = FheUint8Id::num_blocks(cpu_key.message_modulus());
+
+                let mut ct = self.ciphertext.on_cpu().into_owned();
+                if !ct.block_carries_are_empty() {
+                    sks.full_propagate_parallelized(&mut ct);
+                }
+
+                let num_bits = Id::num_bits();
+                (0..num_bits.div_ceil(8))
+                    .into_par_iter()
+                    .map(|i| {
+                        let range = 8 * i..(8 * (i + 1)).min(num_bits);
+                        let byte = sks
+                            .unchecked_scalar_bitslice_parallelized(&ct, range)
+                            .unwrap();
+                        let byte = sks.cast_to_unsigned(byte, byte_num_blocks);
+                        FheUint8::new(byte, cpu_key.tag.clone())
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support to_le_bytes yet")
+            }
+        })
+    }
+
+    /// Builds a value from its bytes, least significant byte first
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bytes is not `ceil(num_bits / 8)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let bytes = [0x78u8, 0x56, 0x34, 0x12].map(|byte| FheUint8::encrypt(byte, &client_key));
+    ///
+    /// let a = FheUint32::from_le_bytes(&bytes);
+    /// let decrypted: u32 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0x1234_5678);
+    /// ```
+    #[track_caller]
+    pub fn from_le_bytes(bytes: &[FheUint8]) -> Self {
+        assert_eq!(
+            bytes.len(),
+            Id::num_bits().div_ceil(8),
+            "Invalid number of bytes for a {} bits integer",
+            Id::num_bits()
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+
+                let blocks = bytes
+                    .par_iter()
+                    .flat_map_iter(|byte| {
+                        let mut byte = byte.ciphertext.on_cpu().into_owned();
+                        if !byte.block_carries_are_empty() {
+                            sks.full_propagate_parallelized(&mut byte);
+                        }
+                        byte.blocks
+                    })
+                    .collect::<Vec<_>>();
+
+                let ct = sks.cast_to_unsigned(
+                    RadixCiphertext::from(blocks),
+                    Id::num_blocks(cpu_key.message_modulus()),
+                );
+                Self::new(ct, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support from_le_bytes yet")
+            }
+        })
+    }
+
+    /// Concatenates `hi` on top of `lo`
+    ///
+    /// The result encrypts `(hi << LoId::num_bits()) + lo`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self` has less bits than `hi` and `lo` together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let hi = FheUint16::encrypt(0x1234u16, &client_key);
+    /// let lo = FheUint16::encrypt(0x5678u16, &client_key);
+    ///
+    /// let a = FheUint32::concat(&hi, &lo);
+    /// let decrypted: u32 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0x1234_5678);
+    /// ```
+    #[track_caller]
+    pub fn concat<HiId, LoId>(hi: &FheUint<HiId>, lo: &FheUint<LoId>) -> Self
+    where
+        HiId: FheUintId,
+        LoId: FheUintId,
+    {
+        assert!(
+            HiId::num_bits() + LoId::num_bits() <= Id::num_bits(),
+            "Cannot concatenate a {} bits and a {} bits integers into a {} bits integer",
+            HiId::num_bits(),
+            LoId::num_bits(),
+            Id::num_bits()
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let ct = sks.concat_parallelized(&hi.ciphertext.on_cpu(), &lo.ciphertext.on_cpu());
+                let ct = sks.cast_to_unsigned(ct, Id::num_blocks(cpu_key.message_modulus()));
+                Self::new(ct, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support concat yet")
+            }
+        })
+    }
+
+    /// Splits the value at the given bit index
+    ///
+    /// Returns `(lo, hi)` where `lo` holds the bits `[0, bit)` and `hi` the bits
+    /// `[bit, num_bits)` of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit` is greater than the number of bits of `Self`,
+    /// or if `LoId` or `HiId` do not have enough bits to hold their part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint32, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint32::encrypt(0x1234_5678u32, &client_key);
+    ///
+    /// let (lo, hi): (FheUint8, FheUint32) = a.split_at(8);
+    /// let lo: u8 = lo.decrypt(&client_key);
+    /// let hi: u32 = hi.decrypt(&client_key);
+    /// assert_eq!(lo, 0x78);
+    /// assert_eq!(hi, 0x12_3456);
+    ///
+    /// let (lo, hi): (FheUint16, FheUint16) = a.split_at(16);
+    /// let lo: u16 = lo.decrypt(&client_key);
+    /// let hi: u16 = hi.decrypt(&client_key);
+    /// assert_eq!(lo, 0x5678);
+    /// assert_eq!(hi, 0x1234);
+    /// ```
+    #[track_caller]
+    pub fn split_at<LoId, HiId>(&self, bit: usize) -> (FheUint<LoId>, FheUint<HiId>)
+    where
+        LoId: FheUintId,
+        HiId: FheUintId,
+    {
+        assert!(
+            bit <= Id::num_bits(),
+            "Cannot split a {} bits integer at bit {bit}",
+            Id::num_bits()
+        );
+        assert!(
+            bit <= LoId::num_bits() && Id::num_bits() - bit <= HiId::num_bits(),
+            "Cannot split a {} bits integer at bit {bit} into {} and {} bits integers",
+            Id::num_bits(),
+            LoId::num_bits(),
+            HiId::num_bits()
+        );
+
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let (lo, hi) = sks.split_at_bit_parallelized(&self.ciphertext.on_cpu(), bit);
+                let (lo, hi) = rayon::join(
+                    || sks.cast_to_unsigned(lo, LoId::num_blocks(cpu_key.message_modulus())),
+                    || sks.cast_to_unsigned(hi, HiId::num_blocks(cpu_key.message_modulus())),
+                );
+                (
+                    FheUint::new(lo, cpu_key.tag.clone()),
+                    FheUint::new(hi, cpu_key.tag.clone()),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support split_at yet")
+            }
+        })
+    }
+}
@@ -13,6 +13,7 @@ pub(in crate::high_level_api) use compressed::CompressedRadixCiphertext;
 pub(in crate::high_level_api) use inner::{RadixCiphertext, RadixCiphertextVersionOwned};
 
 mod base;
+mod bits;
 mod compressed;
 mod static_;
 
@@ -20,6 +21,10 @@ mod encrypt;
 mod inner;
 mod ops;
 mod overflowing_ops;
+mod saturating_ops;
 pub(crate) mod scalar_ops;
 #[cfg(test)]
 mod tests;
+mod widening_ops;
+#[cfg(feature = "experimental")]
+mod wopbs;
@@ -10,8 +10,8 @@ use crate::high_level_api::global_state::with_thread_local_cuda_streams;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::traits::{
-    DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    DivRem, FheEq, FheMax, FheMin, FheOrd, Isqrt, ModPow, Pow, RotateLeft, RotateLeftAssign,
+    RotateRight, RotateRightAssign,
 };
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
@@ -644,6 +644,217 @@ where
     }
 }
 
+impl<Id, Id2> Pow<&FheUint<Id2>> for FheUint<Id>
+where
+    Id: FheUintId,
+    Id2: FheUintId,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: &FheUint<Id2>) -> Self::Output {
+        <&Self as Pow<&FheUint<Id2>>>::pow(&self, exponent)
+    }
+}
+
+impl<Id, Id2> Pow<&FheUint<Id2>> for &FheUint<Id>
+where
+    Id: FheUintId,
+    Id2: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Raises a [FheUint] to the power of an encrypted exponent
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint8::encrypt(9u8, &client_key);
+    ///
+    /// let result = (&a).pow(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3u16.wrapping_pow(9));
+    /// ```
+    fn pow(self, exponent: &FheUint<Id2>) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .pow_parallelized(&*self.ciphertext.on_cpu(), &*exponent.ciphertext.on_cpu());
+                FheUint::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+}
+
+impl<Id> Pow<u32> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    fn pow(self, exponent: u32) -> Self::Output {
+        <&Self as Pow<u32>>::pow(&self, exponent)
+    }
+}
+
+impl<Id> Pow<u32> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Raises a [FheUint] to the power of a clear exponent
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(7u16, &client_key);
+    ///
+    /// let result = (&a).pow(5);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 7u16.wrapping_pow(5));
+    /// ```
+    fn pow(self, exponent: u32) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), exponent);
+                FheUint::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+}
+
+impl<Id, Id2> ModPow<&FheUint<Id2>, &Self> for FheUint<Id>
+where
+    Id: FheUintId,
+    Id2: FheUintId,
+{
+    type Output = Self;
+
+    fn modpow(self, exponent: &FheUint<Id2>, modulus: &Self) -> Self::Output {
+        <&Self as ModPow<&FheUint<Id2>, &Self>>::modpow(&self, exponent, modulus)
+    }
+}
+
+impl<Id, Id2> ModPow<&FheUint<Id2>, &FheUint<Id>> for &FheUint<Id>
+where
+    Id: FheUintId,
+    Id2: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Computes `self^exponent mod modulus`
+    ///
+    /// Contrary to using [Pow] followed by `%`, intermediate results are reduced,
+    /// so the result is correct even when `self^exponent` does not fit in a [FheUint].
+    ///
+    /// # Notes
+    ///
+    /// When the modulus is 0, the result is `self^exponent`, wrapping around on overflow.
+    ///
+    /// This behaviour should not be relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let base = FheUint16::encrypt(4u16, &client_key);
+    /// let exponent = FheUint16::encrypt(13u16, &client_key);
+    /// let modulus = FheUint16::encrypt(497u16, &client_key);
+    ///
+    /// let result = (&base).modpow(&exponent, &modulus);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 445);
+    /// ```
+    fn modpow(self, exponent: &FheUint<Id2>, modulus: &FheUint<Id>) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key.pbs_key().modpow_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*exponent.ciphertext.on_cpu(),
+                    &*modulus.ciphertext.on_cpu(),
+                );
+                FheUint::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support modpow yet");
+            }
+        })
+    }
+}
+
+impl<Id> Isqrt for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// let result = a.isqrt();
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 31);
+    /// ```
+    fn isqrt(&self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner_result = cpu_key
+                    .pbs_key()
+                    .isqrt_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(inner_result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support isqrt yet");
+            }
+        })
+    }
+}
+
 // Ciphertext/Ciphertext operators
 macro_rules! generic_integer_impl_operation (
     (
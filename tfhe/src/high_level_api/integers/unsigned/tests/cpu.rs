@@ -395,6 +395,30 @@ fn test_ilog2() {
     super::test_case_ilog2(&client_key);
 }
 
+#[test]
+fn test_pow_isqrt() {
+    let client_key = setup_default_cpu();
+    super::test_case_pow_isqrt(&client_key);
+}
+
+#[test]
+fn test_saturating_ops() {
+    let client_key = setup_default_cpu();
+    super::test_case_saturating_ops(&client_key);
+}
+
+#[test]
+fn test_widening_mul_mul_high() {
+    let client_key = setup_default_cpu();
+    super::test_case_widening_mul_mul_high(&client_key);
+}
+
+#[test]
+fn test_bits_and_bytes() {
+    let client_key = setup_default_cpu();
+    super::test_case_bits_and_bytes(&client_key);
+}
+
 #[test]
 fn test_is_even_is_odd() {
     let client_key = setup_default_cpu();
@@ -604,3 +628,186 @@ fn test_compressed_cpk_encrypt_cast_compute_hl() {
     let clear: u64 = mul.decrypt(&client_key);
     assert_eq!(clear, (input_msg * multiplier) % modulus);
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_wopbs_map() {
+    use crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64)
+            .enable_wopbs(WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+            .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    // An S-box like lookup, that is not expressible using the usual operators
+    let sbox: Vec<u8> = {
+        let mut table = (0..=u8::MAX).collect::<Vec<_>>();
+        table.shuffle(&mut rng);
+        table
+    };
+
+    let clear_a = rng.gen::<u8>();
+    let mut a = FheUint8::encrypt(clear_a, &client_key);
+
+    let b = a.map(|x| u64::from(sbox[x as usize]));
+    let decrypted: u8 = b.decrypt(&client_key);
+    assert_eq!(decrypted, sbox[clear_a as usize]);
+
+    a.apply(|x| x * x);
+    let decrypted: u8 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_a));
+
+    let clear_c = rng.gen::<u16>();
+    let c = FheUint16::encrypt(clear_c, &client_key);
+
+    let d = c.map(|x| x.rotate_left(3) ^ 0xBEEF);
+    let decrypted: u16 = d.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        (u64::from(clear_c).rotate_left(3) ^ 0xBEEF) as u16
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_wopbs_key_raw_parts() {
+    use crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64)
+            .enable_wopbs(WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+            .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    // The WoP-PBS key has no compressed version
+    assert!(CompressedServerKey::try_new(&client_key).is_err());
+
+    let (key, cpk_ksk_material, compression_key, decompression_key, wopbs_key, tag) =
+        server_key.into_raw_parts();
+    assert!(wopbs_key.is_some());
+    let server_key = crate::ServerKey::from_raw_parts(
+        key,
+        cpk_ksk_material,
+        compression_key,
+        decompression_key,
+        wopbs_key,
+        tag,
+    );
+    set_server_key(server_key);
+
+    let clear_a = rand::random::<u8>();
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = a.map(|x| x ^ 0x5A);
+    let decrypted: u8 = b.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a ^ 0x5A);
+}
+
+#[test]
+fn test_noise_info() {
+    use crate::core_crypto::commons::dispersion::Variance;
+    use crate::core_crypto::prelude::DynamicDistribution;
+    use crate::shortint::ciphertext::NoiseLevel;
+    use crate::NoiseInfo;
+
+    // Variance on the torus of a noise distribution, for the native modulus
+    fn distribution_variance(distribution: DynamicDistribution<u64>) -> f64 {
+        match distribution {
+            DynamicDistribution::Gaussian(gaussian) => gaussian.std * gaussian.std,
+            DynamicDistribution::TUniform(t_uniform) => {
+                let bound = 2.0f64.powi(t_uniform.bound_log2() as i32);
+                (2.0 * bound * bound + 1.0) / 6.0 / 2.0f64.powi(128)
+            }
+        }
+    }
+
+    fn assert_variance_eq(variance: Option<Variance>, expected: f64) {
+        let variance = variance.unwrap().0;
+        assert!(
+            (variance - expected).abs() <= expected * 1e-9,
+            "{variance} != {expected}"
+        );
+    }
+
+    for params in [
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS_GAUSSIAN_2M64,
+    ] {
+        let config = ConfigBuilder::with_custom_parameters(params).build();
+        let (client_key, server_key) = generate_keys(config);
+        let (integer_server_key, ..) = server_key.clone().into_raw_parts();
+        set_server_key(server_key);
+
+        let parameters = client_key.computation_parameters();
+        let encryption_variance = distribution_variance(match params.encryption_key_choice {
+            EncryptionKeyChoice::Big => params.glwe_noise_distribution,
+            EncryptionKeyChoice::Small => params.lwe_noise_distribution,
+        });
+        let nominal_variance = parameters.nominal_noise_variance().unwrap().0;
+
+        // Fresh encryptions have the encryption noise of the parameters
+        let a = FheUint8::encrypt(3u8, &client_key);
+        let noise_info = a.noise_info();
+        assert_eq!(noise_info.blocks().len(), 4);
+        assert!(!noise_info.carry_propagation_needed());
+        for block in noise_info.blocks() {
+            assert_eq!(block.noise_level(), NoiseLevel::NOMINAL);
+            assert_variance_eq(block.estimated_variance(parameters), encryption_variance);
+        }
+
+        // The origin of the noise of deserialized ciphertexts is unknown
+        let deserialized: FheUint8 =
+            bincode::deserialize(&bincode::serialize(&a).unwrap()).unwrap();
+        for block in deserialized.noise_info().blocks() {
+            assert_eq!(block.noise_level(), NoiseLevel::NOMINAL);
+            assert!(block.estimated_variance(parameters).is_none());
+        }
+
+        // Additions without carry propagation increase the noise and fill the carries
+        let (raw_a, id, tag) = a.into_raw_parts();
+        let raw_sum = integer_server_key.unchecked_add(&raw_a, &raw_a);
+        let sum = FheUint8::from_raw_parts(raw_sum, id, tag);
+        let noise_info = sum.noise_info();
+        assert!(noise_info.carry_propagation_needed());
+        let first_block = &noise_info.blocks()[0];
+        assert_eq!(first_block.noise_level(), NoiseLevel::NOMINAL * 2);
+        assert_eq!(first_block.degree().get(), 6);
+        assert_variance_eq(
+            first_block.estimated_variance(parameters),
+            2.0 * encryption_variance,
+        );
+
+        // The output of a PBS has the noise given by the formulas for the parameters
+        let shortint_key = &integer_server_key.key;
+        let lut = shortint_key.generate_lookup_table(|x| x);
+        let bootstrapped = shortint_key.apply_lookup_table(&raw_a.blocks[0], &lut);
+        let mixed = shortint_key.unchecked_add(&bootstrapped, &raw_a.blocks[1]);
+        let noise_info = NoiseInfo::from_blocks(&[bootstrapped, mixed]);
+        assert_eq!(noise_info.blocks()[0].noise_level(), NoiseLevel::NOMINAL);
+        assert_variance_eq(
+            noise_info.blocks()[0].estimated_variance(parameters),
+            nominal_variance,
+        );
+        assert_eq!(
+            noise_info.blocks()[1].noise_level(),
+            NoiseLevel::NOMINAL * 2
+        );
+        assert_variance_eq(
+            noise_info.blocks()[1].estimated_variance(parameters),
+            nominal_variance + encryption_variance,
+        );
+
+        // The carry propagation bootstraps the blocks
+        let result = &sum + &sum;
+        let noise_info = result.noise_info();
+        assert!(!noise_info.carry_propagation_needed());
+        for block in noise_info.blocks() {
+            assert!(block.noise_level() <= NoiseLevel::NOMINAL);
+        }
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted, 12);
+    }
+}
@@ -567,3 +567,110 @@ fn test_case_is_even_is_odd(cks: &ClientKey) {
         );
     }
 }
+
+fn test_case_pow_isqrt(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..3 {
+        let clear_a = rng.gen::<u16>();
+        let clear_b = rng.gen::<u8>();
+        let clear_m = rng.gen_range(1..=u16::MAX);
+        let a = crate::FheUint16::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+        let m = crate::FheUint16::encrypt(clear_m, cks);
+
+        let pow: u16 = (&a).pow(&b).decrypt(cks);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b.into()));
+
+        let pow: u16 = (&a).pow(u32::from(clear_b)).decrypt(cks);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b.into()));
+
+        let modpow: u16 = (&a).modpow(&b, &m).decrypt(cks);
+        let expected = (0..clear_b).fold(1u64 % u64::from(clear_m), |acc, _| {
+            acc * u64::from(clear_a) % u64::from(clear_m)
+        });
+        assert_eq!(u64::from(modpow), expected);
+
+        let isqrt: u16 = a.isqrt().decrypt(cks);
+        assert!(u32::from(isqrt).pow(2) <= u32::from(clear_a));
+        assert!((u32::from(isqrt) + 1).pow(2) > u32::from(clear_a));
+    }
+}
+
+fn test_case_saturating_ops(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    // Values close to the bounds so that saturation happens
+    let cases = [(u8::MAX, 1u8), (0, 1), (16, 16), (rng.gen(), rng.gen())];
+    for (clear_a, clear_b) in cases {
+        let a = FheUint8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+
+        let result: u8 = (&a).saturating_add(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+
+        let result: u8 = (&a).saturating_sub(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+
+        let result: u8 = a.saturating_mul(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+fn test_case_widening_mul_mul_high(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    let cases = [(u8::MAX, u8::MAX), (0, 1), (rng.gen(), rng.gen())];
+    for (clear_a, clear_b) in cases {
+        let a = FheUint8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+        let expected = u16::from(clear_a) * u16::from(clear_b);
+
+        let result: u16 = (&a).widening_mul(&b).decrypt(cks);
+        assert_eq!(result, expected);
+
+        let result: u8 = a.mul_high(&b).decrypt(cks);
+        assert_eq!(result, (expected >> 8) as u8);
+    }
+}
+
+fn test_case_bits_and_bytes(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    let clear = rng.gen::<u32>();
+    let a = FheUint32::encrypt(clear, cks);
+
+    let bits = a.to_bits();
+    assert_eq!(bits.len(), 32);
+    for (i, bit) in bits.iter().enumerate() {
+        let decrypted: bool = bit.decrypt(cks);
+        assert_eq!(decrypted, (clear >> i) & 1 == 1);
+    }
+    let from_bits: u32 = FheUint32::from_bits(&bits).decrypt(cks);
+    assert_eq!(from_bits, clear);
+    let from_low_bits: u32 = FheUint32::from_bits(&bits[..12]).decrypt(cks);
+    assert_eq!(from_low_bits, clear & 0xFFF);
+
+    let bytes = a.to_le_bytes();
+    let decrypted = bytes
+        .iter()
+        .map(|byte| byte.decrypt(cks))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted, clear.to_le_bytes());
+    let from_bytes: u32 = FheUint32::from_le_bytes(&bytes).decrypt(cks);
+    assert_eq!(from_bytes, clear);
+
+    let (lo, hi): (FheUint8, FheUint32) = a.split_at(8);
+    let lo: u8 = lo.decrypt(cks);
+    let hi: u32 = hi.decrypt(cks);
+    assert_eq!(lo, clear as u8);
+    assert_eq!(hi, clear >> 8);
+
+    // Not aligned on a block boundary
+    let (lo, hi): (FheUint8, FheUint32) = a.split_at(7);
+    let lo: u8 = lo.decrypt(cks);
+    let hi: u32 = hi.decrypt(cks);
+    assert_eq!(lo, (clear & 0x7F) as u8);
+    assert_eq!(hi, clear >> 7);
+
+    let b = FheUint32::encrypt(rng.gen::<u32>(), cks);
+    let clear_b: u32 = b.decrypt(cks);
+    let concatenated: u64 = FheUint64::concat(&a, &b).decrypt(cks);
+    assert_eq!(concatenated, (u64::from(clear) << 32) | u64::from(clear_b));
+}
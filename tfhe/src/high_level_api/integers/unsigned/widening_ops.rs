@@ -0,0 +1,122 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::prelude::{MulHigh, WideningMul};
+use crate::{
+    FheUint, FheUint1024Id, FheUint128Id, FheUint12Id, FheUint16Id, FheUint2048Id, FheUint256Id,
+    FheUint2Id, FheUint32Id, FheUint4Id, FheUint512Id, FheUint64Id, FheUint6Id, FheUint8Id,
+};
+
+/// Computes the full product of lhs and rhs into a FheUint with twice as many bits
+fn widening_mul_impl<Id, WideId>(lhs: &FheUint<Id>, rhs: &FheUint<Id>) -> FheUint<WideId>
+where
+    Id: FheUintId,
+    WideId: FheUintId,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let result = cpu_key
+                .pbs_key()
+                .widening_mul_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+            FheUint::new(result, cpu_key.tag.clone())
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support widening_mul yet")
+        }
+    })
+}
+
+macro_rules! impl_widening_mul {
+    ($($id:ty => $wide_id:ty),* $(,)?) => {
+        $(
+            impl WideningMul<Self> for &FheUint<$id> {
+                type Output = FheUint<$wide_id>;
+
+                fn widening_mul(self, rhs: Self) -> Self::Output {
+                    widening_mul_impl(self, rhs)
+                }
+            }
+
+            impl WideningMul<&Self> for FheUint<$id> {
+                type Output = FheUint<$wide_id>;
+
+                fn widening_mul(self, rhs: &Self) -> Self::Output {
+                    widening_mul_impl(&self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+// Each type is widened to the type with twice as many bits, when it exists
+impl_widening_mul!(
+    FheUint2Id => FheUint4Id,
+    FheUint4Id => FheUint8Id,
+    FheUint6Id => FheUint12Id,
+    FheUint8Id => FheUint16Id,
+    FheUint16Id => FheUint32Id,
+    FheUint32Id => FheUint64Id,
+    FheUint64Id => FheUint128Id,
+    FheUint128Id => FheUint256Id,
+    FheUint256Id => FheUint512Id,
+    FheUint512Id => FheUint1024Id,
+    FheUint1024Id => FheUint2048Id,
+);
+
+impl<Id> MulHigh<Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Returns the most significant half of the full product of two [FheUint].
+    ///
+    /// For example, with [FheUint32](crate::FheUint32) this is
+    /// `((a as u64 * b as u64) >> 32) as u32`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(50_000u16, &client_key);
+    /// let b = FheUint16::encrypt(3_000u16, &client_key);
+    ///
+    /// let result = (&a).mul_high(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, ((50_000u32 * 3_000u32) >> 16) as u16);
+    /// ```
+    fn mul_high(self, rhs: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mul_high_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+                FheUint::new(result, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_high yet")
+            }
+        })
+    }
+}
+
+impl<Id> MulHigh<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Returns the most significant half of the full product of two [FheUint].
+    ///
+    /// See [MulHigh::mul_high] for the reference version
+    fn mul_high(self, rhs: &Self) -> Self::Output {
+        <&Self as MulHigh<&Self>>::mul_high(&self, rhs)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::FheBootstrap;
+use crate::{
+    FheUint, FheUint10Id, FheUint12Id, FheUint14Id, FheUint16Id, FheUint2Id, FheUint4Id,
+    FheUint6Id, FheUint8Id,
+};
+
+/// Evaluates a function over the whole encrypted integer using a WoP-PBS
+fn map_impl<Id, F>(ct: &FheUint<Id>, func: F) -> FheUint<Id>
+where
+    Id: FheUintId,
+    F: Fn(u64) -> u64,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => {
+            let wopbs_key = cpu_key.wopbs_key().expect(
+                "No WoP-PBS key available, it must be enabled using ConfigBuilder::enable_wopbs",
+            );
+            let ct =
+                wopbs_key.keyswitch_to_wopbs_params(cpu_key.pbs_key(), &*ct.ciphertext.on_cpu());
+            let lut = wopbs_key.generate_lut_radix(&ct, func);
+            let ct = wopbs_key.wopbs(&ct, &lut);
+            let ct = wopbs_key.keyswitch_to_pbs_params(&ct);
+            FheUint::new(ct, cpu_key.tag.clone())
+        }
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support FheBootstrap yet")
+        }
+    })
+}
+
+// The look-up table evaluated by the WoP-PBS has 2^num_bits entries, so it is only implemented
+// for integers of at most 16 bits
+macro_rules! impl_fhe_bootstrap {
+    ($($id:ty),* $(,)?) => {
+        $(
+            impl FheBootstrap for FheUint<$id> {
+                /// Evaluates a function over the whole encrypted integer.
+                ///
+                /// The function is evaluated using a WoP-PBS (without padding programmable
+                /// bootstrapping), and its result is reduced modulo 2^num_bits.
+                ///
+                /// # Panics
+                ///
+                /// If the WoP-PBS key was not generated, see `ConfigBuilder::enable_wopbs`
+                ///
+                /// # Example
+                ///
+                /// ```rust
+                /// use tfhe::prelude::*;
+                /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+                /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+                /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+                ///
+                /// let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64)
+                ///     .enable_wopbs(WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+                /// let (client_key, server_key) = generate_keys(config);
+                /// set_server_key(server_key);
+                ///
+                /// let a = FheUint8::encrypt(23u8, &client_key);
+                ///
+                /// let result = a.map(|x| x * x + 1);
+                /// let result: u8 = result.decrypt(&client_key);
+                /// assert_eq!(result, 23u8.wrapping_mul(23).wrapping_add(1));
+                /// ```
+                fn map<F: Fn(u64) -> u64>(&self, func: F) -> Self {
+                    map_impl(self, func)
+                }
+
+                /// Evaluates a function over the whole encrypted integer, in place.
+                ///
+                /// See [Self::map].
+                fn apply<F: Fn(u64) -> u64>(&mut self, func: F) {
+                    *self = map_impl(self, func);
+                }
+            }
+        )*
+    };
+}
+
+impl_fhe_bootstrap!(
+    FheUint2Id,
+    FheUint4Id,
+    FheUint6Id,
+    FheUint8Id,
+    FheUint10Id,
+    FheUint12Id,
+    FheUint14Id,
+    FheUint16Id,
+);
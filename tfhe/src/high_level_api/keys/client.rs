@@ -114,6 +114,10 @@ impl ClientKey {
     }
 
     /// Generates a new CompressedServerKey
+    ///
+    /// # Panic
+    ///
+    /// This will panic if WoP-PBS is enabled, see [CompressedServerKey::new]
     pub fn generate_compressed_server_key(&self) -> CompressedServerKey {
         CompressedServerKey::new(self)
     }
@@ -7,13 +7,15 @@ use crate::integer::compression_keys::{
     DecompressionKey,
 };
 use crate::integer::public_key::CompactPublicKey;
+use crate::integer::wopbs::WopbsKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::ciphertext::MaxDegree;
 use crate::shortint::key_switching_key::KeySwitchingKeyConformanceParams;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::parameters::{
     CompactPublicKeyEncryptionParameters, ShortintKeySwitchingParameters,
 };
-use crate::shortint::{EncryptionKeyChoice, MessageModulus, PBSParameters};
+use crate::shortint::{EncryptionKeyChoice, MessageModulus, PBSParameters, WopbsParameters};
 use crate::{Config, Error};
 use serde::{Deserialize, Serialize};
 use tfhe_csprng::seeders::Seed;
@@ -30,6 +32,7 @@ pub(crate) struct IntegerConfig {
         crate::shortint::parameters::ShortintKeySwitchingParameters,
     )>,
     pub(crate) compression_parameters: Option<CompressionParameters>,
+    pub(crate) wopbs_parameters: Option<WopbsParameters>,
 }
 
 impl IntegerConfig {
@@ -44,6 +47,7 @@ impl IntegerConfig {
             block_parameters,
             dedicated_compact_public_key_parameters,
             compression_parameters: None,
+            wopbs_parameters: None,
         }
     }
 
@@ -51,6 +55,11 @@ impl IntegerConfig {
         self.compression_parameters = Some(compression_parameters);
     }
 
+    #[cfg(feature = "experimental")]
+    pub fn enable_wopbs(&mut self, wopbs_parameters: WopbsParameters) {
+        self.wopbs_parameters = Some(wopbs_parameters);
+    }
+
     pub fn public_key_encryption_parameters(
         &self,
     ) -> Result<crate::shortint::parameters::CompactPublicKeyEncryptionParameters, crate::Error>
@@ -76,6 +85,7 @@ impl Default for IntegerConfig {
             block_parameters: params,
             dedicated_compact_public_key_parameters: None,
             compression_parameters: None,
+            wopbs_parameters: None,
         }
     }
 }
@@ -91,6 +101,7 @@ pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) dedicated_compact_private_key: Option<CompactPrivateKey>,
     pub(crate) compression_key: Option<CompressionPrivateKeys>,
+    pub(crate) wopbs_parameters: Option<WopbsParameters>,
 }
 
 impl IntegerClientKey {
@@ -116,6 +127,7 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            wopbs_parameters: config.wopbs_parameters,
         }
     }
 
@@ -131,6 +143,7 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            wopbs_parameters: _,
         } = self;
         (key, dedicated_compact_private_key, compression_key)
     }
@@ -170,6 +183,7 @@ impl IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            wopbs_parameters: None,
         }
     }
 
@@ -199,6 +213,7 @@ impl From<IntegerConfig> for IntegerClientKey {
             key,
             dedicated_compact_private_key,
             compression_key,
+            wopbs_parameters: config.wopbs_parameters,
         }
     }
 }
@@ -215,6 +230,7 @@ pub struct IntegerServerKey {
         Option<crate::integer::key_switching_key::KeySwitchingKeyMaterial>,
     pub(crate) compression_key: Option<CompressionKey>,
     pub(crate) decompression_key: Option<DecompressionKey>,
+    pub(crate) wopbs_key: Option<WopbsKey>,
 }
 
 impl IntegerServerKey {
@@ -246,11 +262,27 @@ impl IntegerServerKey {
 
                     build_helper.into()
                 });
+
+        #[cfg(feature = "experimental")]
+        let wopbs_key = client_key
+            .wopbs_parameters
+            .as_ref()
+            .map(|params| WopbsKey::new_wopbs_key(cks, &base_integer_key, params));
+        #[cfg(not(feature = "experimental"))]
+        let wopbs_key = {
+            assert!(
+                client_key.wopbs_parameters.is_none(),
+                "Generating a WoP-PBS key requires the experimental feature"
+            );
+            None
+        };
+
         Self {
             key: base_integer_key,
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            wopbs_key,
         }
     }
 
@@ -258,6 +290,11 @@ impl IntegerServerKey {
         &self.key
     }
 
+    #[cfg(feature = "experimental")]
+    pub(in crate::high_level_api) fn wopbs_key(&self) -> Option<&WopbsKey> {
+        self.wopbs_key.as_ref()
+    }
+
     pub(in crate::high_level_api) fn cpk_casting_key(
         &self,
     ) -> Option<crate::integer::key_switching_key::KeySwitchingKeyView> {
@@ -273,6 +310,13 @@ impl IntegerServerKey {
     pub(in crate::high_level_api) fn message_modulus(&self) -> MessageModulus {
         self.key.message_modulus()
     }
+
+    #[cfg(feature = "strings")]
+    pub(in crate::high_level_api) fn string_key(
+        &self,
+    ) -> crate::strings::ServerKey<&crate::integer::ServerKey> {
+        crate::strings::ServerKey::new(&self.key)
+    }
 }
 
 #[cfg(feature = "gpu")]
@@ -296,6 +340,18 @@ pub struct IntegerCompressedServerKey {
 
 impl IntegerCompressedServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
+        Self::try_new(client_key).expect("Incompatible parameters")
+    }
+
+    pub(in crate::high_level_api) fn try_new(client_key: &IntegerClientKey) -> Result<Self, Error> {
+        // There is no compressed version of the WoP-PBS key, a compressed server key
+        // would silently lose it
+        if client_key.wopbs_parameters.is_some() {
+            return Err(Error::new(
+                "A compressed server key cannot be generated when WoP-PBS is enabled".to_string(),
+            ));
+        }
+
         let cks = &client_key.key;
 
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(cks);
@@ -327,12 +383,12 @@ impl IntegerCompressedServerKey {
                     (Some(compression_keys), Some(decompression_keys))
                 });
 
-        Self {
+        Ok(Self {
             key,
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
-        }
+        })
     }
 
     pub fn into_raw_parts(
@@ -385,6 +441,8 @@ impl IntegerCompressedServerKey {
             ),
             compression_key,
             decompression_key,
+            // Compressed keys are never generated with WoP-PBS enabled
+            wopbs_key: None,
         }
     }
 }
@@ -473,6 +531,7 @@ pub struct IntegerServerKeyConformanceParams {
         ShortintKeySwitchingParameters,
     )>,
     pub compression_param: Option<CompressionParameters>,
+    pub wopbs_param: Option<WopbsParameters>,
 }
 
 impl From<Config> for IntegerServerKeyConformanceParams {
@@ -481,6 +540,7 @@ impl From<Config> for IntegerServerKeyConformanceParams {
             sk_param: value.inner.block_parameters,
             cpk_param: value.inner.dedicated_compact_public_key_parameters,
             compression_param: value.inner.compression_parameters,
+            wopbs_param: value.inner.wopbs_parameters,
         }
     }
 }
@@ -539,6 +599,7 @@ impl ParameterSetConformant for IntegerServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            wopbs_key,
         } = self;
 
         let cpk_key_switching_key_material_is_ok = match (
@@ -570,9 +631,27 @@ impl ParameterSetConformant for IntegerServerKey {
             _ => return false,
         };
 
+        // The WoP-PBS key embeds a copy of the PBS server key which must match the main one
+        let wopbs_is_ok = match (wopbs_key.as_ref(), parameter_set.wopbs_param.as_ref()) {
+            (None, None) => true,
+            (Some(wopbs_key), Some(wopbs_param)) => {
+                let wopbs_key: &crate::shortint::wopbs::WopbsKey = wopbs_key.as_ref();
+                let expected_max_degree = MaxDegree::integer_radix_server_key(
+                    parameter_set.sk_param.message_modulus(),
+                    parameter_set.sk_param.carry_modulus(),
+                );
+                wopbs_key.param == *wopbs_param
+                    && wopbs_key
+                        .pbs_server_key
+                        .is_conformant(&(parameter_set.sk_param, expected_max_degree))
+            }
+            _ => return false,
+        };
+
         key.is_conformant(&parameter_set.sk_param)
             && cpk_key_switching_key_material_is_ok
             && compression_is_ok
+            && wopbs_is_ok
     }
 }
 
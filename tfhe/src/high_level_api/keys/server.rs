@@ -12,6 +12,7 @@ use crate::integer::compression_keys::{
     CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
 };
 use crate::integer::parameters::IntegerCompactCiphertextListExpansionMode;
+use crate::integer::wopbs::WopbsKey;
 use crate::named::Named;
 use crate::prelude::Tagged;
 use crate::shortint::MessageModulus;
@@ -51,6 +52,7 @@ impl ServerKey {
         Option<crate::integer::key_switching_key::KeySwitchingKeyMaterial>,
         Option<CompressionKey>,
         Option<DecompressionKey>,
+        Option<WopbsKey>,
         Tag,
     ) {
         let IntegerServerKey {
@@ -58,6 +60,7 @@ impl ServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            wopbs_key,
         } = (*self.key).clone();
 
         (
@@ -65,6 +68,7 @@ impl ServerKey {
             cpk_key_switching_key_material,
             compression_key,
             decompression_key,
+            wopbs_key,
             self.tag,
         )
     }
@@ -76,6 +80,7 @@ impl ServerKey {
         >,
         compression_key: Option<CompressionKey>,
         decompression_key: Option<DecompressionKey>,
+        wopbs_key: Option<WopbsKey>,
         tag: Tag,
     ) -> Self {
         Self {
@@ -84,6 +89,7 @@ impl ServerKey {
                 cpk_key_switching_key_material,
                 compression_key,
                 decompression_key,
+                wopbs_key,
             }),
             tag,
         }
@@ -93,6 +99,11 @@ impl ServerKey {
         self.key.pbs_key()
     }
 
+    #[cfg(feature = "experimental")]
+    pub(in crate::high_level_api) fn wopbs_key(&self) -> Option<&crate::integer::wopbs::WopbsKey> {
+        self.key.wopbs_key()
+    }
+
     pub(in crate::high_level_api) fn cpk_casting_key(
         &self,
     ) -> Option<crate::integer::key_switching_key::KeySwitchingKeyView> {
@@ -103,6 +114,13 @@ impl ServerKey {
         self.key.message_modulus()
     }
 
+    #[cfg(feature = "strings")]
+    pub(in crate::high_level_api) fn string_key(
+        &self,
+    ) -> crate::strings::ServerKey<&crate::integer::ServerKey> {
+        self.key.string_key()
+    }
+
     pub(in crate::high_level_api) fn integer_compact_ciphertext_list_expansion_mode(
         &self,
     ) -> IntegerCompactCiphertextListExpansionMode {
@@ -205,6 +223,12 @@ pub struct CompressedServerKey {
 }
 
 impl CompressedServerKey {
+    /// Creates a CompressedServerKey, derived from the given client key
+    ///
+    /// # Panic
+    ///
+    /// This will panic if WoP-PBS is enabled in the client key's config,
+    /// as the WoP-PBS key has no compressed version
     pub fn new(keys: &ClientKey) -> Self {
         Self {
             integer_key: IntegerCompressedServerKey::new(&keys.key),
@@ -212,6 +236,13 @@ impl CompressedServerKey {
         }
     }
 
+    pub fn try_new(keys: &ClientKey) -> Result<Self, crate::Error> {
+        IntegerCompressedServerKey::try_new(&keys.key).map(|integer_key| Self {
+            integer_key,
+            tag: keys.tag.clone(),
+        })
+    }
+
     pub fn into_raw_parts(
         self,
     ) -> (
@@ -411,6 +442,7 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: None,
+                wopbs_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -430,6 +462,7 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: Some(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64),
+                wopbs_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -453,6 +486,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                wopbs_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -487,6 +521,7 @@ mod test {
                     sk_param,
                     cpk_param: None,
                     compression_param: None,
+                    wopbs_param: None,
                 };
 
                 assert!(!sk.is_conformant(&conformance_params));
@@ -513,6 +548,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                wopbs_param: None,
             };
 
             assert!(!sk.is_conformant(&conformance_params));
@@ -535,6 +571,7 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: None,
+                wopbs_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -555,6 +592,7 @@ mod test {
                 sk_param,
                 cpk_param: None,
                 compression_param: Some(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64),
+                wopbs_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -578,6 +616,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                wopbs_param: None,
             };
 
             assert!(sk.is_conformant(&conformance_params));
@@ -612,6 +651,7 @@ mod test {
                     sk_param,
                     cpk_param: None,
                     compression_param: None,
+                    wopbs_param: None,
                 };
 
                 assert!(!sk.is_conformant(&conformance_params));
@@ -638,6 +678,7 @@ mod test {
                 sk_param,
                 cpk_param: Some((cpk_params, casting_params)),
                 compression_param: None,
+                wopbs_param: None,
             };
 
             assert!(!sk.is_conformant(&conformance_params));
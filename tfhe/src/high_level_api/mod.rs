@@ -51,6 +51,14 @@ pub use crate::integer::server_key::MatchValues;
 pub use config::{Config, ConfigBuilder};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 
+pub use fixed::{
+    FheFixed, FheFixedI16F16, FheFixedI16F16Id, FheFixedI32F32, FheFixedI32F32Id, FheFixedI8F8,
+    FheFixedI8F8Id, FheFixedId,
+};
+pub use float::{
+    FheFloat, FheFloat16, FheFloat16Id, FheFloat32, FheFloat32Id, FheFloat64, FheFloat64Id,
+    FheFloatId,
+};
 pub use integers::{CompressedFheInt, CompressedFheUint, FheInt, FheUint, IntegerId};
 #[cfg(feature = "gpu")]
 pub use keys::CudaServerKey;
@@ -96,6 +104,9 @@ pub use compressed_ciphertext_list::{
     CompressedCiphertextList, CompressedCiphertextListBuilder, HlCompressible, HlExpandable,
 };
 
+pub use noise_info::{BlockNoiseInfo, NoiseInfo};
+#[cfg(feature = "strings")]
+pub use strings::{ClearString, FheAsciiString, FheStringIsEmpty, FheStringLen};
 pub use tag::Tag;
 pub use traits::FheId;
 
@@ -103,9 +114,14 @@ mod booleans;
 mod compressed_ciphertext_list;
 mod config;
 mod errors;
+mod fixed;
+mod float;
 mod global_state;
 mod integers;
 mod keys;
+mod noise_info;
+#[cfg(feature = "strings")]
+mod strings;
 mod traits;
 mod utils;
 
@@ -117,8 +133,10 @@ mod tag;
 pub(in crate::high_level_api) mod details;
 /// The tfhe prelude.
 pub mod prelude;
+#[cfg(feature = "threshold")]
+pub mod threshold;
 #[cfg(feature = "zk-pok")]
-mod zk;
+pub(crate) mod zk;
 
 /// Devices supported by tfhe-rs
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -162,4 +180,5 @@ pub enum FheTypes {
     Int128 = 27,
     Int160 = 28,
     Int256 = 29,
+    AsciiString = 30,
 }
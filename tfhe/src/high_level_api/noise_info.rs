@@ -0,0 +1,94 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::{Ciphertext, PBSParameters};
+
+/// The degree and noise of a block of a ciphertext
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlockNoiseInfo {
+    degree: Degree,
+    noise_level: NoiseLevel,
+}
+
+impl BlockNoiseInfo {
+    /// Returns the degree of the block, i.e. the maximum value its message and carry can hold
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    /// Returns the noise level of the block
+    ///
+    /// A fresh block or a block output by a PBS has a [`NoiseLevel::NOMINAL`], the noise level
+    /// then grows with the linear operations done on the block until the next PBS.
+    pub fn noise_level(&self) -> NoiseLevel {
+        self.noise_level
+    }
+
+    /// Returns the estimated variance of the noise of the block, for the parameters it was
+    /// encrypted with, see
+    /// [ClientKey::computation_parameters](crate::ClientKey::computation_parameters).
+    ///
+    /// A block freshly encrypted with the client key has the variance of the encryption noise of
+    /// the parameters, and a block output by a PBS the variance given by the noise formulas for the
+    /// noise distributions (Gaussian or TUniform) of the parameters. The linear operations done
+    /// since then sum these variances as tracked by the noise level.
+    ///
+    /// `None` is returned if the origin of the noise is unknown, e.g. for blocks encrypted with a
+    /// public key or deserialized, or if the variance of a PBS output cannot be estimated for the
+    /// parameters (multi bit parameters).
+    pub fn estimated_variance(&self, parameters: PBSParameters) -> Option<Variance> {
+        if self.noise_level == NoiseLevel::UNKNOWN {
+            return None;
+        }
+
+        let encryption_level = self.noise_level.encryption_level()?;
+        let bootstrapped_level = self.noise_level.get().saturating_sub(encryption_level);
+
+        let mut variance = encryption_level as f64 * parameters.encryption_noise_variance().0;
+        if bootstrapped_level != 0 {
+            variance += bootstrapped_level as f64 * parameters.nominal_noise_variance()?.0;
+        }
+
+        Some(Variance(variance))
+    }
+}
+
+/// The degree and noise of the blocks of a ciphertext
+///
+/// This allows to reason about where the PBS happen in a computation, see
+/// [FheUint::noise_info](crate::FheUint::noise_info).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoiseInfo {
+    blocks: Vec<BlockNoiseInfo>,
+    carry_propagation_needed: bool,
+}
+
+impl NoiseInfo {
+    /// Returns the information of each block, the least significant block first
+    pub fn blocks(&self) -> &[BlockNoiseInfo] {
+        &self.blocks
+    }
+
+    /// Returns true if the carries of some blocks are not empty
+    ///
+    /// In that case, the next operation on the ciphertext starts by propagating the carries, i.e.
+    /// a PBS on each block.
+    pub fn carry_propagation_needed(&self) -> bool {
+        self.carry_propagation_needed
+    }
+
+    pub(in crate::high_level_api) fn from_blocks(blocks: &[Ciphertext]) -> Self {
+        let carry_propagation_needed = !blocks.iter().all(Ciphertext::carry_is_empty);
+        let blocks = blocks
+            .iter()
+            .map(|block| BlockNoiseInfo {
+                degree: block.degree,
+                noise_level: block.noise_level(),
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            blocks,
+            carry_propagation_needed,
+        }
+    }
+}
@@ -9,9 +9,12 @@
 pub use crate::high_level_api::traits::{
     BitSlice, CiphertextList, DivRem, FheBootstrap, FheDecrypt, FheEncrypt, FheEq, FheKeyswitch,
     FheMax, FheMin, FheNumberConstant, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
-    FheTryTrivialEncrypt, IfThenElse, OverflowingAdd, OverflowingMul, OverflowingSub, RotateLeft,
-    RotateLeftAssign, RotateRight, RotateRightAssign, Tagged,
+    FheTryTrivialEncrypt, IfThenElse, Isqrt, ModPow, MulHigh, OverflowingAdd, OverflowingMul,
+    OverflowingSub, Pow, RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign,
+    SaturatingAdd, SaturatingMul, SaturatingSub, Tagged, WideningMul,
 };
+#[cfg(feature = "strings")]
+pub use crate::high_level_api::traits::{FheEqIgnoreCase, FheStringMatching};
 
 pub use crate::conformance::ParameterSetConformant;
 pub use crate::core_crypto::prelude::{CastFrom, CastInto};
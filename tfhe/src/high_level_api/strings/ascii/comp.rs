@@ -0,0 +1,303 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{FheEq, FheEqIgnoreCase, FheOrd};
+use crate::strings::ciphertext::{ClearString, GenericPatternRef};
+use crate::{FheAsciiString, FheBool};
+
+impl FheEq<&Self> for FheAsciiString {
+    /// Test for equality between two [FheAsciiString]
+    ///
+    /// Padding is not taken into account, i.e. two strings with the same chars
+    /// but a different number of padding nulls are equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("alice", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt_with_padding("bob", 2, &client_key).unwrap();
+    ///
+    /// let result = a.eq(&b);
+    /// assert!(!result.decrypt(&client_key));
+    ///
+    /// let result = a.ne(&b);
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    fn eq(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .eq(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ne(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl FheEq<&ClearString> for FheAsciiString {
+    /// Test for equality between a [FheAsciiString] and a [ClearString]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("alice", &client_key).unwrap();
+    ///
+    /// let result = a.eq(&ClearString::new("alice".into()));
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    fn eq(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .eq(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn ne(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ne(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl FheOrd<&Self> for FheAsciiString {
+    /// Lexicographic comparisons between two [FheAsciiString]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("apple", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt("banana", &client_key).unwrap();
+    ///
+    /// assert!(a.lt(&b).decrypt(&client_key));
+    /// assert!(a.le(&b).decrypt(&client_key));
+    /// assert!(!a.gt(&b).decrypt(&client_key));
+    /// assert!(!a.ge(&b).decrypt(&client_key));
+    /// ```
+    fn lt(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .lt(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn le(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .le(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn gt(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .gt(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn ge(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ge(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl FheOrd<&ClearString> for FheAsciiString {
+    /// Lexicographic comparisons between a [FheAsciiString] and a [ClearString]
+    fn lt(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .lt(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn le(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .le(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn gt(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .gt(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn ge(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ge(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl FheEqIgnoreCase<&Self> for FheAsciiString {
+    /// Test for equality between two [FheAsciiString], ignoring ASCII case
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("Alice", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt("aLiCe", &client_key).unwrap();
+    ///
+    /// let result = a.eq_ignore_case(&b);
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    fn eq_ignore_case(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .eq_ignore_case(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl FheEqIgnoreCase<&ClearString> for FheAsciiString {
+    fn eq_ignore_case(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .eq_ignore_case(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
@@ -0,0 +1,119 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::FheStringMatching;
+use crate::strings::ciphertext::{ClearString, GenericPatternRef};
+use crate::{FheAsciiString, FheBool};
+
+impl FheStringMatching<&Self> for FheAsciiString {
+    /// Returns whether `other` is a substring, a prefix or a suffix of `self`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ClearString, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let email = FheAsciiString::try_encrypt("alice@zama.ai", &client_key).unwrap();
+    /// let domain = FheAsciiString::try_encrypt("@zama.ai", &client_key).unwrap();
+    ///
+    /// assert!(email.ends_with(&domain).decrypt(&client_key));
+    /// assert!(email
+    ///     .contains(&ClearString::new("@".into()))
+    ///     .decrypt(&client_key));
+    /// assert!(!email.starts_with(&domain).decrypt(&client_key));
+    /// ```
+    fn contains(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .contains(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn starts_with(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .starts_with(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn ends_with(&self, other: &Self) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ends_with(&self.inner, GenericPatternRef::Enc(&other.inner));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl FheStringMatching<&ClearString> for FheAsciiString {
+    fn contains(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .contains(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn starts_with(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .starts_with(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    fn ends_with(&self, other: &ClearString) -> FheBool {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key
+                    .string_key()
+                    .ends_with(&self.inner, GenericPatternRef::Clear(other));
+                FheBool::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
@@ -0,0 +1,182 @@
+mod comp;
+mod contains;
+mod no_pattern;
+
+pub use no_pattern::{FheStringIsEmpty, FheStringLen};
+
+use crate::high_level_api::backward_compatibility::strings::FheAsciiStringVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::named::Named;
+use crate::prelude::{CiphertextList, FheDecrypt, FheTryEncrypt, FheTryTrivialEncrypt, Tagged};
+use crate::strings::ciphertext::FheString;
+use crate::{ClientKey, CompactCiphertextList, CompactPublicKey, Tag};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// An encrypted ASCII string
+///
+/// The string may be padded with trailing encrypted null characters,
+/// which allows to hide its actual length.
+///
+/// Operations use the server key set via [crate::set_server_key].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+/// // Adding padding hides the actual length of the string
+/// let b = FheAsciiString::try_encrypt_with_padding("tfhe-rs", 3, &client_key).unwrap();
+///
+/// let are_equal = a.eq(&b);
+/// assert!(are_equal.decrypt(&client_key));
+///
+/// let decrypted: String = b.decrypt(&client_key);
+/// assert_eq!(decrypted, "tfhe-rs");
+/// ```
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(FheAsciiStringVersions)]
+pub struct FheAsciiString {
+    pub(in crate::high_level_api) inner: FheString,
+    pub(crate) tag: Tag,
+}
+
+impl Named for FheAsciiString {
+    const NAME: &'static str = "high_level_api::FheAsciiString";
+}
+
+impl Tagged for FheAsciiString {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+fn check_is_ascii(str: &str) -> crate::Result<()> {
+    if !str.is_ascii() || str.contains('\0') {
+        return Err(crate::Error::new(
+            "Input is not an ASCII string or contains null characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl FheAsciiString {
+    pub(in crate::high_level_api) fn new(inner: FheString, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+
+    pub fn into_raw_parts(self) -> (FheString, Tag) {
+        (self.inner, self.tag)
+    }
+
+    pub fn from_raw_parts(inner: FheString, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+
+    /// Returns whether the string is padded with trailing null characters
+    pub fn is_padded(&self) -> bool {
+        self.inner.is_padded()
+    }
+
+    /// Encrypts the string and appends `padding` encrypted null characters to it.
+    ///
+    /// The padding hides the actual length of the string.
+    pub fn try_encrypt_with_padding(
+        str: impl AsRef<str>,
+        padding: u32,
+        key: &ClientKey,
+    ) -> crate::Result<Self> {
+        let str = str.as_ref();
+        check_is_ascii(str)?;
+
+        let inner = crate::strings::ClientKey::new(&key.key.key).encrypt_ascii(str, Some(padding));
+        Ok(Self::new(inner, key.tag.clone()))
+    }
+
+    /// Encrypts the string, padding it with encrypted null characters so that
+    /// the encrypted string always has `size` characters.
+    ///
+    /// Returns an error if the string is longer than `size`.
+    pub fn try_encrypt_with_fixed_size(
+        str: impl AsRef<str>,
+        size: u32,
+        key: &ClientKey,
+    ) -> crate::Result<Self> {
+        let str = str.as_ref();
+        let len = u32::try_from(str.len())
+            .map_err(|_| crate::Error::new("String is too long".to_string()))?;
+        if len > size {
+            return Err(crate::Error::new(format!(
+                "The string has {len} chars which is more than the requested size ({size})"
+            )));
+        }
+
+        Self::try_encrypt_with_padding(str, size - len, key)
+    }
+}
+
+impl<'a> FheTryEncrypt<&'a str, ClientKey> for FheAsciiString {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &'a str, key: &ClientKey) -> Result<Self, Self::Error> {
+        check_is_ascii(value)?;
+
+        let inner = crate::strings::ClientKey::new(&key.key.key).encrypt_ascii(value, None);
+        Ok(Self::new(inner, key.tag.clone()))
+    }
+}
+
+/// Encrypts the string with a [CompactPublicKey]
+///
+/// The string is encrypted in a [CompactCiphertextList] which is then expanded,
+/// if the public key parameters require a casting, the server key must be set.
+impl<'a> FheTryEncrypt<&'a str, CompactPublicKey> for FheAsciiString {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &'a str, key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        CompactCiphertextList::builder(key)
+            .push_string(value)?
+            .build()
+            .expand()?
+            .get(0)?
+            .ok_or_else(|| {
+                crate::Error::new("Internal error, missing encrypted string".to_string())
+            })
+    }
+}
+
+impl<'a> FheTryTrivialEncrypt<&'a str> for FheAsciiString {
+    type Error = crate::Error;
+
+    fn try_encrypt_trivial(value: &'a str) -> Result<Self, Self::Error> {
+        check_is_ascii(value)?;
+
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = FheString::trivial(&cpu_key.string_key(), value);
+                Ok(Self::new(inner, cpu_key.tag.clone()))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::Error::new(
+                "Cuda devices do not support strings".to_string(),
+            )),
+        })
+    }
+}
+
+impl FheDecrypt<String> for FheAsciiString {
+    /// Decrypts the string, removing any padding
+    fn decrypt(&self, key: &ClientKey) -> String {
+        crate::strings::ClientKey::new(&key.key.key).decrypt_ascii(&self.inner)
+    }
+}
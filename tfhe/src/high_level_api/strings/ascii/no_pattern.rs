@@ -0,0 +1,168 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::IntegerId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::strings::server_key::{
+    FheStringIsEmpty as InnerFheStringIsEmpty, FheStringLen as InnerFheStringLen,
+};
+use crate::{FheAsciiString, FheBool, FheUint16, FheUint16Id};
+
+/// Length of a [FheAsciiString]
+///
+/// The length of a string without padding is known in clear,
+/// for a padded string it is computed homomorphically.
+pub enum FheStringLen {
+    NoPadding(u16),
+    Padding(FheUint16),
+}
+
+/// Emptiness of a [FheAsciiString]
+///
+/// Whether a string without padding is empty is known in clear,
+/// for a padded string it is computed homomorphically.
+pub enum FheStringIsEmpty {
+    NoPadding(bool),
+    Padding(FheBool),
+}
+
+impl FheAsciiString {
+    /// Returns the number of chars of the string, padding excluded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString, FheStringLen};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("tfhe", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt_with_padding("tfhe", 4, &client_key).unwrap();
+    ///
+    /// match a.len() {
+    ///     FheStringLen::NoPadding(len) => assert_eq!(len, 4),
+    ///     FheStringLen::Padding(_) => panic!("Unexpected padding"),
+    /// }
+    ///
+    /// match b.len() {
+    ///     FheStringLen::NoPadding(_) => panic!("Unexpected no padding"),
+    ///     FheStringLen::Padding(len) => {
+    ///         let len: u16 = len.decrypt(&client_key);
+    ///         assert_eq!(len, 4);
+    ///     }
+    /// }
+    /// ```
+    pub fn len(&self) -> FheStringLen {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => match cpu_key.string_key().len(&self.inner) {
+                InnerFheStringLen::NoPadding(len) => FheStringLen::NoPadding(len as u16),
+                InnerFheStringLen::Padding(len) => {
+                    let sk = cpu_key.pbs_key();
+                    let len =
+                        sk.cast_to_unsigned(len, FheUint16Id::num_blocks(sk.message_modulus()));
+                    FheStringLen::Padding(FheUint16::new(len, cpu_key.tag.clone()))
+                }
+            },
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    /// Returns whether the string is empty, padding excluded
+    pub fn is_empty(&self) -> FheStringIsEmpty {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => match cpu_key.string_key().is_empty(&self.inner) {
+                InnerFheStringIsEmpty::NoPadding(is_empty) => FheStringIsEmpty::NoPadding(is_empty),
+                InnerFheStringIsEmpty::Padding(is_empty) => {
+                    FheStringIsEmpty::Padding(FheBool::new(is_empty, cpu_key.tag.clone()))
+                }
+            },
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    /// Returns a new string with all ASCII chars converted to lowercase
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("TFHE-rs", &client_key).unwrap();
+    ///
+    /// let lowercase: String = a.to_lowercase().decrypt(&client_key);
+    /// assert_eq!(lowercase, "tfhe-rs");
+    ///
+    /// let uppercase: String = a.to_uppercase().decrypt(&client_key);
+    /// assert_eq!(uppercase, "TFHE-RS");
+    /// ```
+    pub fn to_lowercase(&self) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().to_lowercase(&self.inner);
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+
+    /// Returns a new string with all ASCII chars converted to uppercase
+    pub fn to_uppercase(&self) -> Self {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().to_uppercase(&self.inner);
+                Self::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
+
+impl std::ops::Add<&FheAsciiString> for &FheAsciiString {
+    type Output = FheAsciiString;
+
+    /// Concatenates two strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt_with_padding("Hello, ", 1, &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt("world!", &client_key).unwrap();
+    ///
+    /// let c: String = (&a + &b).decrypt(&client_key);
+    /// assert_eq!(c, "Hello, world!");
+    /// ```
+    fn add(self, rhs: &FheAsciiString) -> Self::Output {
+        global_state::with_internal_keys(|keys| match keys {
+            InternalServerKey::Cpu(cpu_key) => {
+                let inner = cpu_key.string_key().concat(&self.inner, &rhs.inner);
+                FheAsciiString::new(inner, cpu_key.tag.clone())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support strings")
+            }
+        })
+    }
+}
@@ -0,0 +1,6 @@
+pub use crate::strings::ciphertext::ClearString;
+pub use ascii::{FheAsciiString, FheStringIsEmpty, FheStringLen};
+
+mod ascii;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,213 @@
+use crate::prelude::*;
+use crate::safe_serialization::{safe_deserialize, safe_serialize};
+use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::{
+    generate_keys, set_server_key, ClearString, ClientKey, CompactCiphertextList, CompactPublicKey,
+    CompressedCiphertextListBuilder, ConfigBuilder, FheAsciiString, FheStringLen, FheUint32,
+    ServerKey, Tag,
+};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    client_key
+}
+
+#[test]
+fn test_ascii_string_encrypt_decrypt() {
+    let client_key = setup_default_cpu();
+
+    for padding in [0, 1, 3] {
+        let string =
+            FheAsciiString::try_encrypt_with_padding("tfhe-rs", padding, &client_key).unwrap();
+        assert_eq!(string.is_padded(), padding != 0);
+
+        let decrypted: String = string.decrypt(&client_key);
+        assert_eq!(decrypted, "tfhe-rs");
+    }
+
+    let string = FheAsciiString::try_encrypt_with_fixed_size("tfhe", 8, &client_key).unwrap();
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe");
+    assert!(FheAsciiString::try_encrypt_with_fixed_size("tfhe-rs", 4, &client_key).is_err());
+
+    let string = FheAsciiString::try_encrypt_trivial("trivial").unwrap();
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "trivial");
+
+    // Non ASCII strings and strings with null chars are rejected
+    assert!(FheAsciiString::try_encrypt("café", &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("a\0b", &client_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_public_key_encrypt() {
+    let client_key = setup_default_cpu();
+    let public_key = CompactPublicKey::new(&client_key);
+
+    let string = FheAsciiString::try_encrypt("tfhe-rs", &public_key).unwrap();
+    assert!(!string.is_padded());
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe-rs");
+
+    assert!(FheAsciiString::try_encrypt("café", &public_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_operations() {
+    let client_key = setup_default_cpu();
+
+    let a = FheAsciiString::try_encrypt_with_padding("Hello", 2, &client_key).unwrap();
+    let b = FheAsciiString::try_encrypt("hello", &client_key).unwrap();
+    let clear = ClearString::new("Hello".into());
+
+    assert!(!a.eq(&b).decrypt(&client_key));
+    assert!(a.ne(&b).decrypt(&client_key));
+    assert!(a.eq(&clear).decrypt(&client_key));
+    assert!(a.eq_ignore_case(&b).decrypt(&client_key));
+    assert!(a.lt(&b).decrypt(&client_key));
+    assert!(b.ge(&clear).decrypt(&client_key));
+
+    assert!(a
+        .contains(&ClearString::new("ell".into()))
+        .decrypt(&client_key));
+    assert!(b
+        .starts_with(&ClearString::new("he".into()))
+        .decrypt(&client_key));
+    assert!(!b.ends_with(&a).decrypt(&client_key));
+
+    match a.len() {
+        FheStringLen::NoPadding(_) => panic!("Expected a padded string"),
+        FheStringLen::Padding(len) => {
+            let len: u16 = len.decrypt(&client_key);
+            assert_eq!(len, 5);
+        }
+    }
+
+    let upper: String = b.to_uppercase().decrypt(&client_key);
+    assert_eq!(upper, "HELLO");
+
+    let concatenated: String = (&a + &b).decrypt(&client_key);
+    assert_eq!(concatenated, "Hellohello");
+}
+
+#[test]
+fn test_ascii_string_tag_propagation() {
+    let config = ConfigBuilder::default().build();
+    let mut client_key = ClientKey::generate(config);
+    client_key.tag_mut().set_u64(42);
+
+    let server_key = ServerKey::new(&client_key);
+    set_server_key(server_key);
+
+    let a = FheAsciiString::try_encrypt("tag", &client_key).unwrap();
+    assert_eq!(a.tag(), client_key.tag());
+
+    let b = a.to_lowercase();
+    assert_eq!(b.tag(), client_key.tag());
+
+    let c = a.eq(&b);
+    assert_eq!(c.tag(), client_key.tag());
+
+    let d = FheAsciiString::try_encrypt_trivial("trivial").unwrap();
+    assert_eq!(d.tag(), client_key.tag());
+    assert_ne!(d.tag(), &Tag::default());
+}
+
+#[test]
+fn test_ascii_string_safe_serialization() {
+    let client_key = setup_default_cpu();
+
+    let string = FheAsciiString::try_encrypt_with_padding("serialize", 2, &client_key).unwrap();
+
+    let mut buffer = vec![];
+    safe_serialize(&string, &mut buffer, 1 << 30).unwrap();
+    let string: FheAsciiString = safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+
+    assert!(string.is_padded());
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "serialize");
+}
+
+#[test]
+fn test_ascii_string_compressed_list() {
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+            .build();
+
+    let client_key = ClientKey::generate(config);
+    let server_key = ServerKey::new(&client_key);
+    set_server_key(server_key);
+
+    let a = FheAsciiString::try_encrypt("tfhe", &client_key).unwrap();
+    let b = FheAsciiString::try_encrypt_with_padding("padded", 3, &client_key).unwrap();
+    let c = FheAsciiString::try_encrypt("", &client_key).unwrap();
+    let d = FheUint32::encrypt(17u32, &client_key);
+
+    let compressed_list = CompressedCiphertextListBuilder::new()
+        .push(a)
+        .push(b)
+        .push(c)
+        .push(d)
+        .build()
+        .unwrap();
+
+    let a: FheAsciiString = compressed_list.get(0).unwrap().unwrap();
+    let b: FheAsciiString = compressed_list.get(1).unwrap().unwrap();
+    let c: FheAsciiString = compressed_list.get(2).unwrap().unwrap();
+
+    assert!(!a.is_padded());
+    assert!(b.is_padded());
+
+    let a: String = a.decrypt(&client_key);
+    assert_eq!(a, "tfhe");
+    let b: String = b.decrypt(&client_key);
+    assert_eq!(b, "padded");
+    let c: String = c.decrypt(&client_key);
+    assert_eq!(c, "");
+
+    // Incorrect types
+    assert!(compressed_list.get::<FheUint32>(0).is_err());
+    assert!(compressed_list.get::<FheAsciiString>(3).is_err());
+}
+
+#[test]
+fn test_ascii_string_compact_list() {
+    let config = ConfigBuilder::default().build();
+
+    let client_key = ClientKey::generate(config);
+    let server_key = ServerKey::new(&client_key);
+    let public_key = CompactPublicKey::new(&client_key);
+    set_server_key(server_key);
+
+    let compact_list = CompactCiphertextList::builder(&public_key)
+        .push_string("tfhe")
+        .unwrap()
+        .push_string_with_padding("padded", 2)
+        .unwrap()
+        .push(17u32)
+        .build_packed();
+
+    assert!(CompactCiphertextList::builder(&public_key)
+        .push_string("café")
+        .is_err());
+
+    let expander = compact_list.expand().unwrap();
+
+    let a: FheAsciiString = expander.get(0).unwrap().unwrap();
+    let b: FheAsciiString = expander.get(1).unwrap().unwrap();
+
+    assert!(!a.is_padded());
+    assert!(b.is_padded());
+
+    let a: String = a.decrypt(&client_key);
+    assert_eq!(a, "tfhe");
+    let b: String = b.decrypt(&client_key);
+    assert_eq!(b, "padded");
+
+    // Incorrect types
+    assert!(expander.get::<FheUint32>(0).is_err());
+    assert!(expander.get::<FheAsciiString>(2).is_err());
+}
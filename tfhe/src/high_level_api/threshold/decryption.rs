@@ -0,0 +1,265 @@
+use super::check_party_indices;
+use super::key_generation::SecretKeyShare;
+use crate::core_crypto::commons::math::random::RandomGenerator;
+use crate::core_crypto::experimental::algorithms::{
+    combine_lwe_partial_decryptions, partial_decrypt_lwe_ciphertext,
+};
+use crate::core_crypto::prelude::*;
+use crate::high_level_api::backward_compatibility::threshold::PartialDecryptionVersions;
+use crate::high_level_api::integers::{FheUint, FheUintId};
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::{PBSOrder, ShortintParameterSet};
+use tfhe_versionable::Versionize;
+
+/// The partial decryption of a ciphertext by a party: one value per block of the ciphertext.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(PartialDecryptionVersions)]
+pub struct PartialDecryption {
+    party_index: usize,
+    num_parties: usize,
+    values: Vec<u64>,
+}
+
+impl PartialDecryption {
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+}
+
+/// Returns the scaling factor of the messages in the blocks, the first bit being the padding bit
+fn block_delta(block: &crate::shortint::Ciphertext) -> u64 {
+    (1u64 << 63) / (block.message_modulus.0 * block.carry_modulus.0)
+}
+
+/// Statistical security, in bits, with which the smudging noise must hide the noise of the
+/// ciphertexts
+const SMUDGING_STATISTICAL_SECURITY_BITS: u32 = 40;
+
+/// Returns the largest smudging bound for which the smudging noises of all the parties stay below
+/// a sixteenth of the scaling factor, not to impact the decoding of the blocks
+fn max_smudging_bound_log2_for_delta(delta: u64, num_parties: usize) -> u32 {
+    // num_parties * 2^(bound_log2 + 1) <= delta / 16
+    (delta / 16 / num_parties as u64)
+        .checked_ilog2()
+        .map_or(0, |log2| log2.saturating_sub(1))
+}
+
+impl SecretKeyShare {
+    /// Returns the largest smudging bound that can be used in [Self::partial_decrypt] for the
+    /// parameters and number of parties of the share.
+    ///
+    /// This is the recommended bound: the larger the bound, the better the noise of the ciphertext
+    /// and thus the secret key shares are hidden, see also [Self::min_smudging_bound_log2].
+    pub fn max_smudging_bound_log2(&self) -> u32 {
+        let delta = (1u64 << 63)
+            / (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0);
+        max_smudging_bound_log2_for_delta(delta, self.num_parties)
+    }
+
+    /// Returns an upper bound of the variance of the noise of a block, on the torus, or `None` if
+    /// the noise of the block is unknown
+    fn block_noise_variance_upper_bound(&self, block: &crate::shortint::Ciphertext) -> Option<f64> {
+        let noise_level = block.noise_level();
+        if noise_level == NoiseLevel::UNKNOWN {
+            return None;
+        }
+
+        // A fresh encryption under the compact public key accumulates about one noise of the
+        // parameters per coefficient of the encryption key
+        let encryption_lwe_dimension =
+            ShortintParameterSet::from(self.parameters).encryption_lwe_dimension();
+        let fresh_variance =
+            (encryption_lwe_dimension.0 + 1) as f64 * self.parameters.encryption_noise_variance().0;
+        let nominal_variance = self.parameters.nominal_noise_variance()?.0;
+        // With TUniform parameters the noises of the key shares sum to up to 4 times the variance
+        // of the parameters
+        let key_noise_factor = 4.0;
+
+        Some(key_noise_factor * noise_level.get() as f64 * fresh_variance.max(nominal_variance))
+    }
+
+    /// Returns the smallest smudging bound that can be used in [Self::partial_decrypt] for the
+    /// ciphertext: the smudging noise must be 2^40 times larger than the noise of the ciphertext
+    /// for the secret key shares to be statistically hidden.
+    ///
+    /// Returns an error if the noise of the ciphertext is unknown.
+    pub fn min_smudging_bound_log2<Id: FheUintId>(
+        &self,
+        ciphertext: &FheUint<Id>,
+    ) -> crate::Result<u32> {
+        let ciphertext = ciphertext.ciphertext.on_cpu();
+
+        let mut max_variance = 0f64;
+        for block in ciphertext.blocks.iter() {
+            let Some(variance) = self.block_noise_variance_upper_bound(block) else {
+                return Err(crate::Error::new(
+                    "The noise of the ciphertext is unknown, it must be bootstrapped before being \
+                    decrypted"
+                        .to_string(),
+                ));
+            };
+            max_variance = max_variance.max(variance);
+        }
+
+        // Standard deviation of the noise for the native modulus 2^64
+        let std_dev = max_variance.sqrt() * 2.0f64.powi(u64::BITS as i32);
+
+        Ok(std_dev.max(1.0).log2().ceil() as u32 + SMUDGING_STATISTICAL_SECURITY_BITS)
+    }
+
+    /// Computes the partial decryption of the ciphertext by the party.
+    ///
+    /// A smudging noise drawn uniformly in [-2^smudging_bound_log2, 2^smudging_bound_log2] is
+    /// added to each block, to hide the secret key share. The bound must be at least
+    /// [Self::min_smudging_bound_log2] for the noise of the ciphertext to be statistically hidden,
+    /// and at most [Self::max_smudging_bound_log2] for the blocks to be decrypted correctly, an
+    /// error is returned otherwise.
+    ///
+    /// This requires noise-flooding parameters, whose scaling factor leaves room for a smudging
+    /// noise 2^40 times larger than the noise of the ciphertexts: an error is returned for
+    /// parameters that cannot provide that gap, which is the case of the predefined parameters.
+    pub fn partial_decrypt<Id: FheUintId>(
+        &self,
+        ciphertext: &FheUint<Id>,
+        smudging_bound_log2: u32,
+    ) -> crate::Result<PartialDecryption> {
+        let min_smudging_bound_log2 = self.min_smudging_bound_log2(ciphertext)?;
+
+        if let Some(block) = ciphertext.ciphertext.on_cpu().blocks.first() {
+            let max_smudging_bound_log2 =
+                max_smudging_bound_log2_for_delta(block_delta(block), self.num_parties);
+
+            if min_smudging_bound_log2 > max_smudging_bound_log2 {
+                return Err(crate::Error::new(format!(
+                    "The parameters cannot hide the noise of the ciphertext with \
+                    {SMUDGING_STATISTICAL_SECURITY_BITS} bits of statistical security: a \
+                    smudging bound of at least 2^{min_smudging_bound_log2} is required, but at \
+                    most 2^{max_smudging_bound_log2} allows to decrypt blocks with {} parties",
+                    self.num_parties
+                )));
+            }
+
+            if smudging_bound_log2 > max_smudging_bound_log2 {
+                return Err(crate::Error::new(format!(
+                    "Smudging bound 2^{smudging_bound_log2} is too large to decrypt blocks with \
+                    {} parties",
+                    self.num_parties
+                )));
+            }
+        }
+
+        if smudging_bound_log2 < min_smudging_bound_log2 {
+            return Err(crate::Error::new(format!(
+                "Smudging bound 2^{smudging_bound_log2} is too small to hide the noise of the \
+                ciphertext, at least 2^{min_smudging_bound_log2} is required"
+            )));
+        }
+
+        Ok(self.partial_decrypt_unchecked(ciphertext, smudging_bound_log2))
+    }
+
+    /// Computes the partial decryption of the ciphertext without checking the smudging bound
+    pub(super) fn partial_decrypt_unchecked<Id: FheUintId>(
+        &self,
+        ciphertext: &FheUint<Id>,
+        smudging_bound_log2: u32,
+    ) -> PartialDecryption {
+        let ciphertext = ciphertext.ciphertext.on_cpu();
+        let mut seeder = new_seeder();
+        let mut generator = RandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+        let smudging_noise_distribution = TUniform::<u64>::new(smudging_bound_log2);
+
+        let values = ciphertext
+            .blocks
+            .iter()
+            .map(|block| match block.pbs_order {
+                PBSOrder::KeyswitchBootstrap => partial_decrypt_lwe_ciphertext(
+                    &self.glwe_secret_key.as_lwe_secret_key(),
+                    &block.ct,
+                    smudging_noise_distribution,
+                    &mut generator,
+                ),
+                PBSOrder::BootstrapKeyswitch => partial_decrypt_lwe_ciphertext(
+                    &self.lwe_secret_key,
+                    &block.ct,
+                    smudging_noise_distribution,
+                    &mut generator,
+                ),
+            })
+            .collect();
+
+        PartialDecryption {
+            party_index: self.party_index,
+            num_parties: self.num_parties,
+            values,
+        }
+    }
+}
+
+/// Combines the [PartialDecryption] of all the parties to decrypt the ciphertext.
+pub fn combine_partial_decryptions<Clear, Id>(
+    ciphertext: &FheUint<Id>,
+    partial_decryptions: &[PartialDecryption],
+) -> crate::Result<Clear>
+where
+    Id: FheUintId,
+    Clear: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    let num_parties = partial_decryptions
+        .first()
+        .map_or(0, |partial_decryption| partial_decryption.num_parties);
+    if partial_decryptions
+        .iter()
+        .any(|partial_decryption| partial_decryption.num_parties != num_parties)
+    {
+        return Err(crate::Error::new(
+            "Partial decryptions were computed for different numbers of parties".to_string(),
+        ));
+    }
+    check_party_indices(
+        partial_decryptions
+            .iter()
+            .map(|partial_decryption| partial_decryption.party_index),
+        num_parties,
+    )?;
+
+    let ciphertext = ciphertext.ciphertext.on_cpu();
+    let blocks = &ciphertext.blocks;
+
+    if partial_decryptions
+        .iter()
+        .any(|partial_decryption| partial_decryption.values.len() != blocks.len())
+    {
+        return Err(crate::Error::new(
+            "Partial decryptions do not match the number of blocks of the ciphertext".to_string(),
+        ));
+    }
+
+    let Some(first_block) = blocks.first() else {
+        return Ok(Clear::ZERO);
+    };
+
+    let bits_in_block = first_block.message_modulus.0.ilog2();
+    let mut recomposer = BlockRecomposer::<Clear>::new(bits_in_block);
+
+    for (block_index, block) in blocks.iter().enumerate() {
+        let block_partial_decryptions = partial_decryptions
+            .iter()
+            .map(|partial_decryption| partial_decryption.values[block_index])
+            .collect::<Vec<_>>();
+        let decrypted = combine_lwe_partial_decryptions(&block.ct, &block_partial_decryptions).0;
+
+        // Decoding, as in the decryption of the message and carry of a block
+        let delta = block_delta(block);
+        let rounding = (decrypted & (delta >> 1)) << 1;
+        let decoded = decrypted.wrapping_add(rounding) / delta;
+
+        if !recomposer.add_unmasked(decoded) {
+            // End of Clear::BITS reached no need to try more recomposition
+            break;
+        }
+    }
+
+    Ok(recomposer.value())
+}
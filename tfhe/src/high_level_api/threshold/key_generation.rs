@@ -0,0 +1,405 @@
+use super::{check_config, check_party_indices};
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_add_assign, slice_wrapping_sub_assign,
+};
+use crate::core_crypto::commons::math::random::RandomGenerator;
+use crate::core_crypto::prelude::*;
+use crate::high_level_api::backward_compatibility::threshold::{
+    BeaverTripleShareVersions, MaskedOpeningVersions, SecretKeyShareVersions,
+};
+use crate::high_level_api::config::Config;
+use crate::shortint::PBSParameters;
+use tfhe_versionable::Versionize;
+
+/// Number of secret key elements generated by the protocol: the LWE secret key elements followed
+/// by the GLWE secret key elements
+fn secret_key_len(parameters: &PBSParameters) -> usize {
+    parameters.lwe_dimension().0
+        + parameters
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(parameters.polynomial_size())
+            .0
+}
+
+fn new_random_generator() -> RandomGenerator<DefaultRandomGenerator> {
+    let mut seeder = new_seeder();
+    RandomGenerator::new(seeder.seed())
+}
+
+/// The additive shares of a Beaver triple (u, v, w = u * v), received by one party.
+///
+/// Each element of the vectors is an independent triple, one triple being consumed per secret
+/// key element and per round of the key generation protocol.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(BeaverTripleShareVersions)]
+pub struct BeaverTripleShare {
+    party_index: usize,
+    round: usize,
+    u: Vec<u64>,
+    v: Vec<u64>,
+    w: Vec<u64>,
+}
+
+impl BeaverTripleShare {
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
+
+/// Generates the shares of the Beaver triples used in a round of the key generation protocol,
+/// the share of index `i` being sent to the party of index `i`.
+///
+/// This is run by the dealer, which must be trusted not to collude with any party.
+pub fn generate_beaver_triple_shares(
+    config: Config,
+    num_parties: usize,
+    round: usize,
+) -> crate::Result<Vec<BeaverTripleShare>> {
+    let parameters = check_config(&config)?;
+    if num_parties < 2 {
+        return Err(crate::Error::new(
+            "Threshold protocols require at least 2 parties".to_string(),
+        ));
+    }
+
+    let len = secret_key_len(&parameters);
+    let mut generator = new_random_generator();
+
+    let mut u = vec![0u64; len];
+    let mut v = vec![0u64; len];
+    generator.fill_slice_with_random_uniform(&mut u);
+    generator.fill_slice_with_random_uniform(&mut v);
+    let w = u
+        .iter()
+        .zip(v.iter())
+        .map(|(u, v)| u.wrapping_mul(*v))
+        .collect::<Vec<_>>();
+
+    // The last party gets the difference between the triple and the random shares of the others
+    let mut last_share = BeaverTripleShare {
+        party_index: num_parties - 1,
+        round,
+        u,
+        v,
+        w,
+    };
+    let mut shares = Vec::with_capacity(num_parties);
+
+    for party_index in 0..num_parties - 1 {
+        let mut share = BeaverTripleShare {
+            party_index,
+            round,
+            u: vec![0u64; len],
+            v: vec![0u64; len],
+            w: vec![0u64; len],
+        };
+
+        for (random_values, last_values) in [
+            (&mut share.u, &mut last_share.u),
+            (&mut share.v, &mut last_share.v),
+            (&mut share.w, &mut last_share.w),
+        ] {
+            generator.fill_slice_with_random_uniform(random_values.as_mut_slice());
+            slice_wrapping_sub_assign(last_values, random_values);
+        }
+
+        shares.push(share);
+    }
+
+    shares.push(last_share);
+    Ok(shares)
+}
+
+/// The masked values opened by a party during a round of the key generation protocol, to be
+/// broadcast to all the other parties.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(MaskedOpeningVersions)]
+pub struct MaskedOpening {
+    party_index: usize,
+    round: usize,
+    d: Vec<u64>,
+    e: Vec<u64>,
+}
+
+impl MaskedOpening {
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
+
+/// The state of a party during the key generation protocol.
+///
+/// Each party samples a random binary vector, the secret key being the XOR of the vectors of
+/// all parties. The parties hold additive shares of the XOR of the vectors of the first parties,
+/// and during the round `j` (for j in 1..num_parties) they compute additive shares of its XOR with
+/// the vector of the party `j` using x ^ y = x + y - 2xy, the product being computed with a Beaver
+/// triple.
+///
+/// Each round goes as follows:
+/// - each party receives its [BeaverTripleShare] of the round from the dealer;
+/// - each party broadcasts its [MaskedOpening] computed with [Self::masked_opening];
+/// - once it has received the openings of all parties, each party calls [Self::apply_round].
+///
+/// After the `num_parties - 1` rounds, [Self::into_secret_key_share] returns the share of the
+/// party.
+#[derive(Clone)]
+pub struct KeyGenerationParty {
+    party_index: usize,
+    num_parties: usize,
+    parameters: PBSParameters,
+    binary_vector: Vec<u64>,
+    xor_share: Vec<u64>,
+    completed_rounds: usize,
+}
+
+impl KeyGenerationParty {
+    /// Creates the state of the party of index `party_index`, sampling its random binary vector.
+    pub fn new(config: Config, party_index: usize, num_parties: usize) -> crate::Result<Self> {
+        let parameters = check_config(&config)?;
+        if num_parties < 2 {
+            return Err(crate::Error::new(
+                "Threshold protocols require at least 2 parties".to_string(),
+            ));
+        }
+        if party_index >= num_parties {
+            return Err(crate::Error::new(format!(
+                "Invalid party index {party_index} for {num_parties} parties"
+            )));
+        }
+
+        let len = secret_key_len(&parameters);
+        let mut binary_vector = vec![0u64; len];
+        new_random_generator().fill_slice_with_random_uniform_binary(&mut binary_vector);
+
+        // Before the first round, the party 0 holds the whole XOR of its own vector
+        let xor_share = if party_index == 0 {
+            binary_vector.clone()
+        } else {
+            vec![0u64; len]
+        };
+
+        Ok(Self {
+            party_index,
+            num_parties,
+            parameters,
+            binary_vector,
+            xor_share,
+            completed_rounds: 0,
+        })
+    }
+
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+
+    /// Returns the index of the round in progress, in 1..num_parties
+    pub fn current_round(&self) -> usize {
+        self.completed_rounds + 1
+    }
+
+    fn check_triple_share(&self, triple_share: &BeaverTripleShare) -> crate::Result<()> {
+        if self.completed_rounds + 1 >= self.num_parties {
+            return Err(crate::Error::new(
+                "All the rounds of the key generation protocol are completed".to_string(),
+            ));
+        }
+
+        let len = self.xor_share.len();
+        if triple_share.party_index != self.party_index
+            || triple_share.round != self.current_round()
+            || [&triple_share.u, &triple_share.v, &triple_share.w]
+                .iter()
+                .any(|values| values.len() != len)
+        {
+            return Err(crate::Error::new(format!(
+                "Beaver triple share is not for party {} in round {}",
+                self.party_index,
+                self.current_round()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the vector of the party is XORed in the current round
+    fn is_round_input_party(&self) -> bool {
+        self.party_index == self.current_round()
+    }
+
+    /// Computes the masked values to broadcast in the current round.
+    pub fn masked_opening(&self, triple_share: &BeaverTripleShare) -> crate::Result<MaskedOpening> {
+        self.check_triple_share(triple_share)?;
+
+        let mut d = self.xor_share.clone();
+        slice_wrapping_sub_assign(&mut d, &triple_share.u);
+
+        let mut e = triple_share
+            .v
+            .iter()
+            .map(|v| v.wrapping_neg())
+            .collect::<Vec<_>>();
+        if self.is_round_input_party() {
+            slice_wrapping_add_assign(&mut e, &self.binary_vector);
+        }
+
+        Ok(MaskedOpening {
+            party_index: self.party_index,
+            round: self.current_round(),
+            d,
+            e,
+        })
+    }
+
+    /// Completes the current round, given the openings broadcast by all the parties.
+    pub fn apply_round(
+        &mut self,
+        triple_share: &BeaverTripleShare,
+        openings: &[MaskedOpening],
+    ) -> crate::Result<()> {
+        self.check_triple_share(triple_share)?;
+        check_party_indices(
+            openings.iter().map(|opening| opening.party_index),
+            self.num_parties,
+        )?;
+
+        let len = self.xor_share.len();
+        if openings.iter().any(|opening| {
+            opening.round != self.current_round()
+                || opening.d.len() != len
+                || opening.e.len() != len
+        }) {
+            return Err(crate::Error::new(format!(
+                "Masked openings are not all for round {}",
+                self.current_round()
+            )));
+        }
+
+        let mut d = vec![0u64; len];
+        let mut e = vec![0u64; len];
+        for opening in openings {
+            slice_wrapping_add_assign(&mut d, &opening.d);
+            slice_wrapping_add_assign(&mut e, &opening.e);
+        }
+
+        // Share of the product: w + d.v + e.u (+ d.e for the party 0)
+        let is_leading_party = self.party_index == 0;
+        let product_share = (0..len).map(|i| {
+            let mut z = triple_share.w[i]
+                .wrapping_add(d[i].wrapping_mul(triple_share.v[i]))
+                .wrapping_add(e[i].wrapping_mul(triple_share.u[i]));
+            if is_leading_party {
+                z = z.wrapping_add(d[i].wrapping_mul(e[i]));
+            }
+            z
+        });
+
+        // The vector XORed in this round is only known by its party, the others hold a zero share
+        let is_round_input_party = self.is_round_input_party();
+        for ((x, &b), z) in self
+            .xor_share
+            .iter_mut()
+            .zip(self.binary_vector.iter())
+            .zip(product_share)
+        {
+            let y = if is_round_input_party { b } else { 0 };
+            *x = x.wrapping_add(y).wrapping_sub(z.wrapping_mul(2));
+        }
+
+        self.completed_rounds += 1;
+        Ok(())
+    }
+
+    /// Returns the secret key share of the party, once all the rounds have been completed.
+    pub fn into_secret_key_share(self) -> crate::Result<SecretKeyShare> {
+        if self.completed_rounds + 1 != self.num_parties {
+            return Err(crate::Error::new(format!(
+                "The key generation protocol is not completed, {} out of {} rounds were run",
+                self.completed_rounds,
+                self.num_parties - 1
+            )));
+        }
+
+        let lwe_dimension = self.parameters.lwe_dimension();
+        let mut xor_share = self.xor_share;
+        let glwe_elements = xor_share.split_off(lwe_dimension.0);
+
+        Ok(SecretKeyShare {
+            party_index: self.party_index,
+            num_parties: self.num_parties,
+            parameters: self.parameters,
+            lwe_secret_key: LweSecretKey::from_container(xor_share),
+            glwe_secret_key: GlweSecretKey::from_container(
+                glwe_elements,
+                self.parameters.polynomial_size(),
+            ),
+        })
+    }
+}
+
+/// The share of the client key held by a party: additive shares of the LWE and GLWE secret keys.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SecretKeyShareVersions)]
+pub struct SecretKeyShare {
+    pub(super) party_index: usize,
+    pub(super) num_parties: usize,
+    pub(super) parameters: PBSParameters,
+    pub(super) lwe_secret_key: LweSecretKeyOwned<u64>,
+    pub(super) glwe_secret_key: GlweSecretKeyOwned<u64>,
+}
+
+impl SecretKeyShare {
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+
+    pub fn num_parties(&self) -> usize {
+        self.num_parties
+    }
+
+    pub fn parameters(&self) -> PBSParameters {
+        self.parameters
+    }
+}
+
+/// Runs the key generation protocol between `num_parties` parties simulated in the current
+/// process, the Beaver triples being generated locally.
+///
+/// This is meant for tests and experiments: in a deployment, each party runs its
+/// [KeyGenerationParty] on its own, the messages being exchanged over the network.
+pub fn generate_secret_key_shares<C: Into<Config>>(
+    config: C,
+    num_parties: usize,
+) -> crate::Result<Vec<SecretKeyShare>> {
+    let config = config.into();
+
+    let mut parties = (0..num_parties)
+        .map(|party_index| KeyGenerationParty::new(config, party_index, num_parties))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    for round in 1..num_parties {
+        let triple_shares = generate_beaver_triple_shares(config, num_parties, round)?;
+
+        let openings = parties
+            .iter()
+            .zip(triple_shares.iter())
+            .map(|(party, triple_share)| party.masked_opening(triple_share))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        for (party, triple_share) in parties.iter_mut().zip(triple_shares.iter()) {
+            party.apply_round(triple_share, &openings)?;
+        }
+    }
+
+    parties
+        .into_iter()
+        .map(KeyGenerationParty::into_secret_key_share)
+        .collect()
+}
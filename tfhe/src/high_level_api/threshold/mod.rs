@@ -0,0 +1,200 @@
+//! Threshold key generation and decryption.
+//!
+//! This module allows `N` parties to jointly generate the keys of the high level API, and to
+//! jointly decrypt ciphertexts, without any single party ever holding the full client key.
+//!
+//! Each party holds a [SecretKeyShare]: additive shares (modulo 2^64) of the binary secret keys.
+//! The shares are generated by a protocol run among the parties (see [KeyGenerationParty]), in
+//! which each party samples a random binary key, the secret key being the XOR of all of them.
+//! Converting the XOR into additive shares requires multiplications, which are done using Beaver
+//! triples provided by a dealer (see [generate_beaver_triple_shares]).
+//!
+//! From their secret key shares, the parties generate [ServerKeyShare] and [CompactPublicKeyShare]
+//! which are summed to obtain the [ServerKey](crate::ServerKey) and the
+//! [CompactPublicKey](crate::CompactPublicKey). All parties must use the same common seed, from
+//! which the masks of the keys are drawn.
+//!
+//! Ciphertexts are decrypted by combining a [PartialDecryption] of each party, a smudging noise
+//! being added to each partial decryption to hide the secret key shares.
+//!
+//! # Security model
+//!
+//! Parties are assumed to be honest-but-curious: they follow the protocols, but may try to learn
+//! information from the messages they receive. The dealer generating Beaver triples must not
+//! collude with any party, and must not see the messages exchanged between parties. The common
+//! seed should be agreed upon once all parties have committed to their secret key shares, for
+//! example using a commit-and-reveal protocol.
+//!
+//! The noise of the parameters is split between the parties: each party adds to its key shares a
+//! noise whose variance is the one of the parameters divided by the number of parties, so that the
+//! combined keys have the noise of the parameters. As the bound of a TUniform distribution can only
+//! be lowered by powers of 2, the combined keys may have up to 4 times the variance of the
+//! parameters in that case, which increases the failure probability. As a party knows its own
+//! noise, the secret key is hidden from it by the noise of the other parties only: the parties must
+//! not collude, and the parameters should leave enough security margin for the noise of
+//! `num_parties - 1` parties to protect the key.
+//!
+//! The smudging noise of the partial decryptions must hide the noise of the ciphertext, and thus
+//! the secret key shares, with 40 bits of statistical security: its bound must be at least
+//! [SecretKeyShare::min_smudging_bound_log2]. It must also be at most
+//! [SecretKeyShare::max_smudging_bound_log2] for the ciphertexts to be decrypted correctly. Only
+//! noise-flooding parameters, whose scaling factor is much larger than the noise of the
+//! ciphertexts, leave room for such a smudging noise: the predefined parameters do not, and the
+//! partial decryption returns an error for them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! use tfhe::threshold::{
+//!     combine_compact_public_key_shares, combine_server_key_shares, generate_secret_key_shares,
+//! };
+//! use tfhe::{set_server_key, CompactCiphertextList, ConfigBuilder, FheUint8, Seed};
+//!
+//! let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS).build();
+//! // Local simulation of the key generation protocol between 3 parties
+//! let secret_key_shares = generate_secret_key_shares(config, 3).unwrap();
+//!
+//! // The seed must be the same for all parties
+//! let common_seed = Seed(0x5eed);
+//! let server_key_shares = secret_key_shares
+//!     .iter()
+//!     .map(|share| share.generate_server_key_share(common_seed))
+//!     .collect::<Vec<_>>();
+//! let public_key_shares = secret_key_shares
+//!     .iter()
+//!     .map(|share| share.generate_compact_public_key_share(common_seed))
+//!     .collect::<Vec<_>>();
+//!
+//! let server_key = combine_server_key_shares(&server_key_shares).unwrap();
+//! let public_key = combine_compact_public_key_shares(&public_key_shares).unwrap();
+//! set_server_key(server_key);
+//!
+//! let expanded = CompactCiphertextList::builder(&public_key)
+//!     .push(27u8)
+//!     .push(100u8)
+//!     .build()
+//!     .expand()
+//!     .unwrap();
+//! let a: FheUint8 = expanded.get(0).unwrap().unwrap();
+//! let b: FheUint8 = expanded.get(1).unwrap().unwrap();
+//!
+//! let c = &a + &b;
+//!
+//! // These parameters do not leave room for a smudging noise hiding the noise of the ciphertext
+//! let share = &secret_key_shares[0];
+//! assert!(share.min_smudging_bound_log2(&c).unwrap() > share.max_smudging_bound_log2());
+//! assert!(share
+//!     .partial_decrypt(&c, share.max_smudging_bound_log2())
+//!     .is_err());
+//! ```
+
+mod decryption;
+mod key_generation;
+mod public_key_shares;
+#[cfg(test)]
+mod tests;
+
+pub use decryption::{combine_partial_decryptions, PartialDecryption};
+pub use key_generation::{
+    generate_beaver_triple_shares, generate_secret_key_shares, BeaverTripleShare,
+    KeyGenerationParty, MaskedOpening, SecretKeyShare,
+};
+pub use public_key_shares::{
+    combine_compact_public_key_shares, combine_server_key_shares, CompactPublicKeyShare,
+    ServerKeyShare,
+};
+
+use crate::core_crypto::prelude::{DynamicDistribution, Gaussian, StandardDev};
+use crate::high_level_api::config::Config;
+use crate::shortint::PBSParameters;
+
+/// Checks that the parameters of the config are supported by the threshold protocols, and returns
+/// the block parameters.
+fn check_config(config: &Config) -> crate::Result<PBSParameters> {
+    let integer_config = &config.inner;
+
+    if integer_config
+        .dedicated_compact_public_key_parameters
+        .is_some()
+        || integer_config.compression_parameters.is_some()
+        || integer_config.wopbs_parameters.is_some()
+    {
+        return Err(crate::Error::new(
+            "Threshold key generation only supports the block parameters, dedicated compact \
+            public key, compression and wopbs parameters are not supported"
+                .to_string(),
+        ));
+    }
+
+    let parameters = integer_config.block_parameters;
+    if !matches!(parameters, PBSParameters::PBS(_)) {
+        return Err(crate::Error::new(
+            "Threshold key generation only supports classic PBS parameters".to_string(),
+        ));
+    }
+
+    if !parameters.ciphertext_modulus().is_native_modulus() {
+        return Err(crate::Error::new(
+            "Threshold key generation only supports the native ciphertext modulus".to_string(),
+        ));
+    }
+
+    Ok(parameters)
+}
+
+/// Checks that the parties indices of a set of shares are exactly 0..num_parties
+fn check_party_indices(
+    party_indices: impl Iterator<Item = usize>,
+    num_parties: usize,
+) -> crate::Result<()> {
+    let mut seen = vec![false; num_parties];
+    let mut count = 0;
+
+    for party_index in party_indices {
+        if party_index >= num_parties || seen[party_index] {
+            return Err(crate::Error::new(format!(
+                "Invalid or duplicated party index {party_index} for {num_parties} parties"
+            )));
+        }
+        seen[party_index] = true;
+        count += 1;
+    }
+
+    if count != num_parties {
+        return Err(crate::Error::new(format!(
+            "Expected shares from {num_parties} parties, got {count}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns the noise distribution of the key shares of a party, so that the sum of the noises of
+/// all the parties has the variance of `distribution`.
+///
+/// As the bound of a TUniform distribution can only be lowered by powers of 2, the variance of
+/// the sum is never smaller than the one of `distribution`, but may be up to 4 times larger in
+/// that case.
+fn party_noise_distribution(
+    distribution: DynamicDistribution<u64>,
+    num_parties: usize,
+) -> DynamicDistribution<u64> {
+    match distribution {
+        DynamicDistribution::Gaussian(gaussian) => {
+            DynamicDistribution::Gaussian(Gaussian::from_standard_dev(
+                StandardDev(gaussian.std / (num_parties as f64).sqrt()),
+                gaussian.mean,
+            ))
+        }
+        // The variance of a TUniform distribution is about 4^bound_log2 / 3, lowering the bound
+        // by k divides it by 4^k which must be at most the number of parties
+        DynamicDistribution::TUniform(t_uniform) => {
+            let bound_log2_decrease = num_parties.ilog2() / 2;
+            DynamicDistribution::new_t_uniform(
+                t_uniform.bound_log2().saturating_sub(bound_log2_decrease),
+            )
+        }
+    }
+}
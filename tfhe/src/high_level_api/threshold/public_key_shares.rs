@@ -0,0 +1,267 @@
+use super::key_generation::SecretKeyShare;
+use super::{check_party_indices, party_noise_distribution};
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::core_crypto::experimental::algorithms::{
+    generate_lwe_bootstrap_key_share, generate_lwe_compact_public_key_share,
+    generate_lwe_keyswitch_key_share,
+};
+use crate::core_crypto::prelude::*;
+use crate::high_level_api::backward_compatibility::threshold::{
+    CompactPublicKeyShareVersions, ServerKeyShareVersions,
+};
+use crate::shortint::ciphertext::MaxDegree;
+use crate::shortint::parameters::CompactPublicKeyEncryptionParameters;
+use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::{EncryptionKeyChoice, PBSParameters};
+use crate::{CompactPublicKey, ServerKey, Tag};
+use tfhe_versionable::Versionize;
+
+/// The share of the [ServerKey] generated by a party.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(ServerKeyShareVersions)]
+pub struct ServerKeyShare {
+    party_index: usize,
+    num_parties: usize,
+    parameters: PBSParameters,
+    common_seed: u128,
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: LweBootstrapKeyOwned<u64>,
+}
+
+impl ServerKeyShare {
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+}
+
+/// The share of the [CompactPublicKey] generated by a party.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(CompactPublicKeyShareVersions)]
+pub struct CompactPublicKeyShare {
+    party_index: usize,
+    num_parties: usize,
+    parameters: PBSParameters,
+    common_seed: u128,
+    key: LweCompactPublicKeyOwned<u64>,
+}
+
+impl CompactPublicKeyShare {
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+}
+
+impl SecretKeyShare {
+    fn is_leading_party(&self) -> bool {
+        self.party_index == 0
+    }
+
+    /// Generates the share of the [ServerKey] of the party.
+    ///
+    /// The `common_seed` must be the same for all the parties, the masks of the key being drawn
+    /// from it. The noise of the share has the variance of the parameters divided by the number
+    /// of parties.
+    pub fn generate_server_key_share(&self, common_seed: Seed) -> ServerKeyShare {
+        let parameters = self.parameters;
+        let ciphertext_modulus = parameters.ciphertext_modulus();
+        let mut seeder = new_seeder();
+        let mut generator =
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(common_seed, seeder.as_mut());
+
+        let big_lwe_secret_key = self.glwe_secret_key.as_lwe_secret_key();
+
+        let mut key_switching_key = LweKeyswitchKeyOwned::new(
+            0u64,
+            parameters.ks_base_log(),
+            parameters.ks_level(),
+            big_lwe_secret_key.lwe_dimension(),
+            self.lwe_secret_key.lwe_dimension(),
+            ciphertext_modulus,
+        );
+        generate_lwe_keyswitch_key_share(
+            &big_lwe_secret_key,
+            &self.lwe_secret_key,
+            &mut key_switching_key,
+            self.is_leading_party(),
+            party_noise_distribution(parameters.lwe_noise_distribution(), self.num_parties),
+            &mut generator,
+        );
+
+        let mut bootstrapping_key = LweBootstrapKeyOwned::new(
+            0u64,
+            parameters.glwe_dimension().to_glwe_size(),
+            parameters.polynomial_size(),
+            parameters.pbs_base_log(),
+            parameters.pbs_level(),
+            parameters.lwe_dimension(),
+            ciphertext_modulus,
+        );
+        generate_lwe_bootstrap_key_share(
+            &self.lwe_secret_key,
+            &self.glwe_secret_key,
+            &mut bootstrapping_key,
+            self.is_leading_party(),
+            party_noise_distribution(parameters.glwe_noise_distribution(), self.num_parties),
+            &mut generator,
+        );
+
+        ServerKeyShare {
+            party_index: self.party_index,
+            num_parties: self.num_parties,
+            parameters,
+            common_seed: common_seed.0,
+            key_switching_key,
+            bootstrapping_key,
+        }
+    }
+
+    /// Generates the share of the [CompactPublicKey] of the party.
+    ///
+    /// The `common_seed` must be the same for all the parties, the mask of the key being drawn
+    /// from it. The noise of the share has the variance of the parameters divided by the number
+    /// of parties.
+    pub fn generate_compact_public_key_share(&self, common_seed: Seed) -> CompactPublicKeyShare {
+        let parameters = self.parameters;
+        let mut seeder = new_seeder();
+        let mut generator =
+            EncryptionRandomGenerator::<DefaultRandomGenerator>::new(common_seed, seeder.as_mut());
+
+        let (encryption_key, noise_distribution) = match parameters.encryption_key_choice() {
+            EncryptionKeyChoice::Big => (
+                self.glwe_secret_key.as_lwe_secret_key(),
+                parameters.glwe_noise_distribution(),
+            ),
+            EncryptionKeyChoice::Small => (
+                self.lwe_secret_key.as_view(),
+                parameters.lwe_noise_distribution(),
+            ),
+        };
+
+        let mut key = LweCompactPublicKeyOwned::new(
+            0u64,
+            encryption_key.lwe_dimension(),
+            parameters.ciphertext_modulus(),
+        );
+        generate_lwe_compact_public_key_share(
+            &encryption_key,
+            &mut key,
+            self.is_leading_party(),
+            party_noise_distribution(noise_distribution, self.num_parties),
+            &mut generator,
+        );
+
+        CompactPublicKeyShare {
+            party_index: self.party_index,
+            num_parties: self.num_parties,
+            parameters,
+            common_seed: common_seed.0,
+            key,
+        }
+    }
+}
+
+/// Checks that a set of key shares was generated by all the parties, with the same parameters
+/// and common seed
+fn check_key_shares<'a>(
+    shares: impl Iterator<Item = (usize, usize, &'a PBSParameters, u128)> + Clone,
+) -> crate::Result<()> {
+    let Some((_, num_parties, parameters, common_seed)) = shares.clone().next() else {
+        return Err(crate::Error::new("No key share to combine".to_string()));
+    };
+
+    if shares
+        .clone()
+        .any(|(_, other_num_parties, other_parameters, other_seed)| {
+            other_num_parties != num_parties
+                || other_parameters != parameters
+                || other_seed != common_seed
+        })
+    {
+        return Err(crate::Error::new(
+            "Key shares were generated with different parameters or common seeds".to_string(),
+        ));
+    }
+
+    check_party_indices(shares.map(|(party_index, ..)| party_index), num_parties)
+}
+
+/// Combines the [ServerKeyShare] of all the parties into the [ServerKey].
+pub fn combine_server_key_shares(shares: &[ServerKeyShare]) -> crate::Result<ServerKey> {
+    check_key_shares(shares.iter().map(|share| {
+        (
+            share.party_index,
+            share.num_parties,
+            &share.parameters,
+            share.common_seed,
+        )
+    }))?;
+
+    let parameters = shares[0].parameters;
+    let mut key_switching_key = shares[0].key_switching_key.clone();
+    let mut bootstrapping_key = shares[0].bootstrapping_key.clone();
+    for share in &shares[1..] {
+        slice_wrapping_add_assign(key_switching_key.as_mut(), share.key_switching_key.as_ref());
+        slice_wrapping_add_assign(bootstrapping_key.as_mut(), share.bootstrapping_key.as_ref());
+    }
+
+    let mut fourier_bootstrapping_key = FourierLweBootstrapKey::new(
+        bootstrapping_key.input_lwe_dimension(),
+        bootstrapping_key.glwe_size(),
+        bootstrapping_key.polynomial_size(),
+        bootstrapping_key.decomposition_base_log(),
+        bootstrapping_key.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(
+        &bootstrapping_key,
+        &mut fourier_bootstrapping_key,
+    );
+
+    let shortint_key = crate::shortint::ServerKey::from_raw_parts(
+        key_switching_key,
+        ShortintBootstrappingKey::Classic(fourier_bootstrapping_key),
+        parameters.message_modulus(),
+        parameters.carry_modulus(),
+        MaxDegree::from_msg_carry_modulus(parameters.message_modulus(), parameters.carry_modulus()),
+        parameters.max_noise_level(),
+        parameters.ciphertext_modulus(),
+        parameters.encryption_key_choice().into(),
+    );
+
+    Ok(ServerKey::from_raw_parts(
+        crate::integer::ServerKey::from_raw_parts(shortint_key),
+        None,
+        None,
+        None,
+        None,
+        Tag::default(),
+    ))
+}
+
+/// Combines the [CompactPublicKeyShare] of all the parties into the [CompactPublicKey].
+pub fn combine_compact_public_key_shares(
+    shares: &[CompactPublicKeyShare],
+) -> crate::Result<CompactPublicKey> {
+    check_key_shares(shares.iter().map(|share| {
+        (
+            share.party_index,
+            share.num_parties,
+            &share.parameters,
+            share.common_seed,
+        )
+    }))?;
+
+    let encryption_parameters: CompactPublicKeyEncryptionParameters =
+        shares[0].parameters.try_into()?;
+    let mut key = shares[0].key.clone();
+    for share in &shares[1..] {
+        slice_wrapping_add_assign(key.as_mut(), share.key.as_ref());
+    }
+
+    let shortint_key =
+        crate::shortint::CompactPublicKey::from_raw_parts(key, encryption_parameters);
+
+    Ok(CompactPublicKey::from_raw_parts(
+        crate::integer::public_key::CompactPublicKey::from_raw_parts(shortint_key),
+        Tag::default(),
+    ))
+}
@@ -0,0 +1,244 @@
+use super::*;
+use crate::high_level_api::prelude::*;
+use crate::shortint::parameters::*;
+use crate::{set_server_key, CompactCiphertextList, ConfigBuilder, FheUint16, FheUint8, Seed};
+use rand::prelude::*;
+
+const NUM_PARTIES: usize = 3;
+
+fn threshold_decrypt<Clear>(
+    secret_key_shares: &[SecretKeyShare],
+    ciphertext: &crate::FheUint<impl crate::high_level_api::integers::FheUintId>,
+) -> Clear
+where
+    Clear: crate::integer::block_decomposition::RecomposableFrom<u64>
+        + crate::core_crypto::prelude::UnsignedNumeric,
+{
+    // The parameters used in the tests do not leave room for a smudging noise statistically
+    // hiding the noise of the ciphertexts, the largest bound is used to check the decryption
+    let partial_decryptions = secret_key_shares
+        .iter()
+        .map(|share| share.partial_decrypt_unchecked(ciphertext, share.max_smudging_bound_log2()))
+        .collect::<Vec<_>>();
+    combine_partial_decryptions(ciphertext, &partial_decryptions).unwrap()
+}
+
+fn threshold_key_generation_and_decryption(parameters: ClassicPBSParameters) {
+    let config = ConfigBuilder::with_custom_parameters(parameters).build();
+    let secret_key_shares = generate_secret_key_shares(config, NUM_PARTIES).unwrap();
+
+    let common_seed = Seed(thread_rng().gen());
+    let server_key_shares = secret_key_shares
+        .iter()
+        .map(|share| share.generate_server_key_share(common_seed))
+        .collect::<Vec<_>>();
+    let public_key_shares = secret_key_shares
+        .iter()
+        .map(|share| share.generate_compact_public_key_share(common_seed))
+        .collect::<Vec<_>>();
+
+    let server_key = combine_server_key_shares(&server_key_shares).unwrap();
+    let public_key = combine_compact_public_key_shares(&public_key_shares).unwrap();
+    set_server_key(server_key);
+
+    let mut rng = thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u16>();
+        let clear_b = rng.gen::<u8>();
+
+        let expanded = CompactCiphertextList::builder(&public_key)
+            .push(clear_a)
+            .push(clear_b)
+            .build()
+            .expand()
+            .unwrap();
+        let a: FheUint16 = expanded.get(0).unwrap().unwrap();
+        let b: FheUint8 = expanded.get(1).unwrap().unwrap();
+
+        let decrypted: u16 = threshold_decrypt(&secret_key_shares, &a);
+        assert_eq!(decrypted, clear_a);
+
+        let c = &a * &FheUint16::cast_from(b.clone()) + &a;
+        let expected = clear_a
+            .wrapping_mul(u16::from(clear_b))
+            .wrapping_add(clear_a);
+        let decrypted: u16 = threshold_decrypt(&secret_key_shares, &c);
+        assert_eq!(decrypted, expected);
+
+        let d = &b ^ 0x5au8;
+        let decrypted: u8 = threshold_decrypt(&secret_key_shares, &d);
+        assert_eq!(decrypted, clear_b ^ 0x5a);
+    }
+}
+
+#[test]
+fn test_threshold_key_generation_and_decryption_ks_pbs() {
+    threshold_key_generation_and_decryption(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
+#[test]
+fn test_threshold_key_generation_and_decryption_pbs_ks() {
+    threshold_key_generation_and_decryption(
+        PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS_GAUSSIAN_2M64,
+    );
+}
+
+fn lwe_secret_key_share(share: &SecretKeyShare) -> &[u64] {
+    share.lwe_secret_key.as_ref()
+}
+
+fn glwe_secret_key_share(share: &SecretKeyShare) -> &[u64] {
+    share.glwe_secret_key.as_ref()
+}
+
+#[test]
+fn test_threshold_secret_key_shares_sum_to_binary_keys() {
+    let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS).build();
+
+    for num_parties in [2, 4] {
+        let secret_key_shares = generate_secret_key_shares(config, num_parties).unwrap();
+        assert_eq!(secret_key_shares.len(), num_parties);
+
+        let sum_shares = |key: fn(&SecretKeyShare) -> &[u64]| {
+            let mut sum = key(&secret_key_shares[0]).to_vec();
+            for share in &secret_key_shares[1..] {
+                for (sum, element) in sum.iter_mut().zip(key(share)) {
+                    *sum = sum.wrapping_add(*element);
+                }
+            }
+            sum
+        };
+
+        for key in [
+            lwe_secret_key_share as fn(&SecretKeyShare) -> &[u64],
+            glwe_secret_key_share,
+        ] {
+            let sum = sum_shares(key);
+            assert!(sum.iter().all(|element| *element <= 1));
+            // The shares themselves are uniformly random
+            assert!(secret_key_shares
+                .iter()
+                .all(|share| key(share).iter().any(|element| *element > 1)));
+        }
+    }
+}
+
+#[test]
+fn test_threshold_invalid_inputs() {
+    let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS).build();
+
+    assert!(generate_secret_key_shares(config, 1).is_err());
+    assert!(KeyGenerationParty::new(config, 2, 2).is_err());
+
+    let unsupported_config = ConfigBuilder::with_custom_parameters(
+        PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    )
+    .build();
+    assert!(generate_secret_key_shares(unsupported_config, 2).is_err());
+
+    let secret_key_shares = generate_secret_key_shares(config, 2).unwrap();
+
+    // Missing or duplicated shares
+    let public_key_shares = secret_key_shares
+        .iter()
+        .map(|share| share.generate_compact_public_key_share(Seed(0)))
+        .collect::<Vec<_>>();
+    assert!(combine_compact_public_key_shares(&public_key_shares[..1]).is_err());
+    assert!(combine_compact_public_key_shares(&[
+        public_key_shares[0].clone(),
+        public_key_shares[0].clone()
+    ])
+    .is_err());
+
+    // Shares generated from different common seeds
+    let other_seed_share = secret_key_shares[1].generate_compact_public_key_share(Seed(1));
+    assert!(
+        combine_compact_public_key_shares(&[public_key_shares[0].clone(), other_seed_share])
+            .is_err()
+    );
+
+    let public_key = combine_compact_public_key_shares(&public_key_shares).unwrap();
+    let expanded = CompactCiphertextList::builder(&public_key)
+        .push(42u8)
+        .build()
+        .expand()
+        .unwrap();
+    let a: FheUint8 = expanded.get(0).unwrap().unwrap();
+
+    // The smudging noise cannot both hide the noise of the ciphertext and keep the decoding
+    // correct with these parameters
+    let max_smudging_bound_log2 = secret_key_shares[0].max_smudging_bound_log2();
+    let min_smudging_bound_log2 = secret_key_shares[0].min_smudging_bound_log2(&a).unwrap();
+    assert!(min_smudging_bound_log2 > max_smudging_bound_log2);
+    for smudging_bound_log2 in [
+        max_smudging_bound_log2,
+        max_smudging_bound_log2 + 1,
+        min_smudging_bound_log2,
+    ] {
+        assert!(secret_key_shares[0]
+            .partial_decrypt(&a, smudging_bound_log2)
+            .is_err());
+    }
+
+    // The noise of a trivial ciphertext is zero, the smudging noise only needs 40 bits
+    let server_key_shares = secret_key_shares
+        .iter()
+        .map(|share| share.generate_server_key_share(Seed(0)))
+        .collect::<Vec<_>>();
+    set_server_key(combine_server_key_shares(&server_key_shares).unwrap());
+    let trivial = FheUint8::encrypt_trivial(42u8);
+    assert_eq!(
+        secret_key_shares[0]
+            .min_smudging_bound_log2(&trivial)
+            .unwrap(),
+        40
+    );
+    assert!(secret_key_shares[0].partial_decrypt(&trivial, 39).is_err());
+    assert!(secret_key_shares[0]
+        .partial_decrypt(&trivial, max_smudging_bound_log2 + 1)
+        .is_err());
+
+    let partial_decryptions = secret_key_shares
+        .iter()
+        .map(|share| {
+            share
+                .partial_decrypt(&trivial, max_smudging_bound_log2)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(combine_partial_decryptions::<u8, _>(&trivial, &partial_decryptions[..1]).is_err());
+    let decrypted: u8 = combine_partial_decryptions(&trivial, &partial_decryptions).unwrap();
+    assert_eq!(decrypted, 42);
+}
+
+#[test]
+fn test_threshold_party_noise_distribution() {
+    // The variance of the noise of the parameters is split between the parties
+    let gaussian = DynamicDistribution::new_gaussian_from_std_dev(StandardDev(2.0f64.powi(-40)));
+    for num_parties in [2, 3, 16] {
+        let party_variance = party_noise_distribution(gaussian, num_parties)
+            .gaussian_variance()
+            .0;
+        let expected_variance = gaussian.gaussian_variance().0 / num_parties as f64;
+        assert!((party_variance - expected_variance).abs() <= expected_variance * 1e-9);
+    }
+
+    let t_uniform = DynamicDistribution::new_t_uniform(41);
+    for (num_parties, expected_bound_log2) in [(2, 41), (4, 40), (5, 40), (16, 39), (17, 39)] {
+        let DynamicDistribution::TUniform(party_t_uniform) =
+            party_noise_distribution(t_uniform, num_parties)
+        else {
+            panic!("Expected a TUniform distribution");
+        };
+        assert_eq!(party_t_uniform.bound_log2(), expected_bound_log2);
+    }
+
+    // The summed variance of the noises of the parties is never below the one of the parameters
+    let modulus = 2.0f64.powi(64);
+    for num_parties in 2..=64 {
+        let party_variance = party_noise_distribution(t_uniform, num_parties)
+            .variance(modulus)
+            .0;
+        assert!(num_parties as f64 * party_variance >= t_uniform.variance(modulus).0);
+    }
+}
@@ -100,6 +100,20 @@ pub trait FheOrd<Rhs = Self> {
     fn ge(&self, other: Rhs) -> FheBool;
 }
 
+/// Trait for fully homomorphic equality test ignoring ASCII case.
+#[cfg(feature = "strings")]
+pub trait FheEqIgnoreCase<Rhs = Self> {
+    fn eq_ignore_case(&self, other: Rhs) -> FheBool;
+}
+
+/// Trait for fully homomorphic pattern matching on strings.
+#[cfg(feature = "strings")]
+pub trait FheStringMatching<Rhs> {
+    fn contains(&self, other: Rhs) -> FheBool;
+    fn starts_with(&self, other: Rhs) -> FheBool;
+    fn ends_with(&self, other: Rhs) -> FheBool;
+}
+
 pub trait FheMin<Rhs = Self> {
     type Output;
 
@@ -160,6 +174,24 @@ pub trait DivRem<Rhs = Self> {
     fn div_rem(self, amount: Rhs) -> Self::Output;
 }
 
+pub trait Pow<Rhs> {
+    type Output;
+
+    fn pow(self, exponent: Rhs) -> Self::Output;
+}
+
+pub trait ModPow<Exponent, Modulus = Exponent> {
+    type Output;
+
+    fn modpow(self, exponent: Exponent, modulus: Modulus) -> Self::Output;
+}
+
+pub trait Isqrt {
+    type Output;
+
+    fn isqrt(&self) -> Self::Output;
+}
+
 pub trait IfThenElse<Ciphertext> {
     fn if_then_else(&self, ct_then: &Ciphertext, ct_else: &Ciphertext) -> Ciphertext;
     fn select(&self, ct_when_true: &Ciphertext, ct_when_false: &Ciphertext) -> Ciphertext {
@@ -188,6 +220,54 @@ pub trait OverflowingMul<Rhs> {
     fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
 }
 
+pub trait SaturatingAdd<Rhs> {
+    type Output;
+
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SaturatingSub<Rhs> {
+    type Output;
+
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SaturatingMul<Rhs> {
+    type Output;
+
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplication whose result has twice as many bits as its inputs, so that it cannot overflow
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(200u8, &client_key);
+/// let b = FheUint8::encrypt(150u8, &client_key);
+///
+/// let result: FheUint16 = (&a).widening_mul(&b);
+/// let result: u16 = result.decrypt(&client_key);
+/// assert_eq!(result, 200u16 * 150u16);
+/// ```
+pub trait WideningMul<Rhs> {
+    type Output;
+
+    fn widening_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait MulHigh<Rhs> {
+    type Output;
+
+    fn mul_high(self, rhs: Rhs) -> Self::Output;
+}
+
 pub trait BitSlice<Bounds> {
     type Output;
 
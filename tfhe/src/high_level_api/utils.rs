@@ -43,6 +43,10 @@ impl<Id: FheUintId> Expandable for FheUint<Id> {
                 "Tried to expand a FheUint{} while a FheBool is stored in this slot",
                 Id::num_bits(),
             ))),
+            DataKind::String { .. } => Err(crate::Error::new(format!(
+                "Tried to expand a FheUint{} while a string is stored in this slot",
+                Id::num_bits(),
+            ))),
         }
     }
 }
@@ -78,6 +82,10 @@ impl<Id: FheIntId> Expandable for FheInt<Id> {
                 "Tried to expand a FheUint{} while a FheBool is stored in this slot",
                 Id::num_bits(),
             ))),
+            DataKind::String { .. } => Err(crate::Error::new(format!(
+                "Tried to expand a FheInt{} while a string is stored in this slot",
+                Id::num_bits(),
+            ))),
         }
     }
 }
@@ -105,6 +113,53 @@ impl Expandable for FheBool {
                 // The expander will be responsible for setting the correct tag
                 Ok(Self::new(boolean_block, Tag::default()))
             }
+            DataKind::String { .. } => Err(crate::Error::new(
+                "Tried to expand a FheBool while a string is stored in this slot".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "strings")]
+impl Expandable for crate::FheAsciiString {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        match kind {
+            DataKind::Unsigned(_) => {
+                let stored_num_bits = num_bits_of_blocks(&blocks) as usize;
+                Err(crate::Error::new(format!(
+                    "Tried to expand a FheAsciiString while a FheUint{stored_num_bits} is stored in this slot",
+                )))
+            }
+            DataKind::Signed(_) => {
+                let stored_num_bits = num_bits_of_blocks(&blocks) as usize;
+                Err(crate::Error::new(format!(
+                    "Tried to expand a FheAsciiString while a FheInt{stored_num_bits} is stored in this slot",
+                )))
+            }
+            DataKind::Boolean => Err(crate::Error::new(
+                "Tried to expand a FheAsciiString while a FheBool is stored in this slot"
+                    .to_string(),
+            )),
+            DataKind::String { padded, .. } => {
+                if let Some(first_block) = blocks.first() {
+                    let num_blocks_per_char =
+                        crate::strings::ciphertext::num_ascii_blocks(first_block.message_modulus);
+                    if blocks.len() % num_blocks_per_char != 0 {
+                        return Err(crate::Error::new(format!(
+                            "Invalid number of blocks for a string, \
+                            expected a multiple of {num_blocks_per_char}, got {}",
+                            blocks.len()
+                        )));
+                    }
+                }
+
+                let inner = crate::strings::ciphertext::FheString::from_uint(
+                    crate::integer::RadixCiphertext::from(blocks),
+                    padded,
+                );
+                // The expander will be responsible for setting the correct tag
+                Ok(Self::new(inner, Tag::default()))
+            }
         }
     }
 }
@@ -1,6 +1,15 @@
-use crate::core_crypto::prelude::LweCiphertextCount;
-use crate::zk::CompactPkeCrs;
-use crate::{Config, Error};
+use crate::core_crypto::prelude::{CastInto, LweCiphertextCount, UnsignedNumeric};
+use crate::high_level_api::integers::{FheUint, FheUintId};
+use crate::integer::block_decomposition::{
+    BlockDecomposer, BlockRecomposer, DecomposableInto, RecomposableFrom,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{MessageModulus, PBSOrder};
+use crate::zk::{
+    CompactPkeCrs, CompactPublicKeyProof, CompactPublicKeyProofCrs, DecryptionProof,
+    DecryptionProofCrs, ZkComputeLoad, ZkVerificationOutcome,
+};
+use crate::{ClientKey, CompactPublicKey, Config, Error};
 
 impl CompactPkeCrs {
     /// Create a new `CompactPkeCrs` from a `Config` object.
@@ -32,3 +41,279 @@ impl CompactPkeCrs {
         Ok(crs)
     }
 }
+
+impl DecryptionProofCrs {
+    /// Create a new `DecryptionProofCrs` from a `Config` object.
+    ///
+    /// The compact public key is used as the commitment to the secret key of the proofs, so the
+    /// config must not use dedicated compact public key parameters.
+    pub fn from_config(config: Config) -> crate::Result<Self> {
+        if config
+            .inner
+            .dedicated_compact_public_key_parameters
+            .is_some()
+        {
+            return Err(Error::new(
+                "Decryption proofs are not supported with dedicated compact public key parameters"
+                    .to_string(),
+            ));
+        }
+
+        Self::from_shortint_params(config.public_key_encryption_parameters()?)
+    }
+}
+
+impl CompactPublicKeyProofCrs {
+    /// Create a new `CompactPublicKeyProofCrs` from a `Config` object, to prove that the compact
+    /// public keys of this config are well formed.
+    pub fn from_config(config: Config) -> crate::Result<Self> {
+        Self::from_shortint_params(config.public_key_encryption_parameters()?)
+    }
+}
+
+/// Decompose a plaintext into the cleartexts of `num_blocks` blocks, return `None` if it does
+/// not fit in the blocks
+fn decompose_plaintext<Clear>(
+    plaintext: Clear,
+    message_modulus: MessageModulus,
+    num_blocks: usize,
+) -> Option<Vec<u64>>
+where
+    Clear: DecomposableInto<u64>,
+{
+    let mut decomposer = BlockDecomposer::new(plaintext, message_modulus.0.ilog2());
+    let cleartexts = (0..num_blocks)
+        .map(|_| decomposer.next().map_or(0, CastInto::cast_into))
+        .collect();
+
+    decomposer
+        .all(|block| block == Clear::ZERO)
+        .then_some(cleartexts)
+}
+
+/// Decrypt a [FheUint] and prove that the decryption is correct.
+///
+/// The proof can be verified with [verify_decryption], using only the [CompactPublicKey] of the
+/// client key as a commitment to its secret key. The public key must have been generated from
+/// the client key, without dedicated compact public key parameters, see
+/// [DecryptionProofCrs::from_config].
+///
+/// The blocks of the ciphertext must have empty carries, which is the case of the outputs of
+/// the operations of the high level API, and their noise must be below a quarter of the scaling
+/// factor of the messages.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+/// use tfhe::zk::{prove_decryption, verify_decryption, DecryptionProofCrs};
+/// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheUint8};
+///
+/// let config =
+///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).build();
+/// let crs = DecryptionProofCrs::from_config(config).unwrap();
+/// let (client_key, server_key) = generate_keys(config);
+/// let public_key = CompactPublicKey::new(&client_key);
+/// set_server_key(server_key);
+/// let metadata = b"TFHE-rs";
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+/// let result = a + b;
+///
+/// let (clear, proof): (u8, _) =
+///     prove_decryption(&result, &client_key, &public_key, &crs, metadata).unwrap();
+/// assert_eq!(clear, 127);
+///
+/// assert!(verify_decryption(&result, clear, &proof, &crs, &public_key, metadata).is_valid());
+/// assert!(verify_decryption(&result, 126u8, &proof, &crs, &public_key, metadata).is_invalid());
+/// ```
+pub fn prove_decryption<Clear, Id>(
+    ciphertext: &FheUint<Id>,
+    client_key: &ClientKey,
+    public_key: &CompactPublicKey,
+    crs: &DecryptionProofCrs,
+    metadata: &[u8],
+) -> crate::Result<(Clear, DecryptionProof)>
+where
+    Clear: RecomposableFrom<u64> + DecomposableInto<u64> + UnsignedNumeric,
+    Id: FheUintId,
+{
+    if client_key.key.dedicated_compact_private_key.is_some() {
+        return Err(Error::new(
+            "Decryption proofs are not supported with dedicated compact public key parameters"
+                .to_string(),
+        ));
+    }
+
+    let ciphertext = ciphertext.ciphertext.on_cpu();
+    let shortint_key = &client_key.key.key.key;
+    let message_modulus = shortint_key.parameters.message_modulus();
+
+    let pbs_order = ciphertext
+        .blocks
+        .first()
+        .map_or(PBSOrder::KeyswitchBootstrap, |block| block.pbs_order);
+    if ciphertext
+        .blocks
+        .iter()
+        .any(|block| block.pbs_order != pbs_order)
+    {
+        return Err(Error::new(
+            "All the blocks of the ciphertext must be encrypted under the same key".to_string(),
+        ));
+    }
+    let lwe_secret_key = match pbs_order {
+        PBSOrder::KeyswitchBootstrap => shortint_key.large_lwe_secret_key(),
+        PBSOrder::BootstrapKeyswitch => shortint_key.small_lwe_secret_key(),
+    };
+
+    let lwe_ciphertexts = ciphertext
+        .blocks
+        .iter()
+        .map(|block| block.ct.as_view())
+        .collect::<Vec<_>>();
+
+    let (cleartexts, proof) = ShortintEngine::with_thread_local_mut(|engine| {
+        crs.prove(
+            &lwe_ciphertexts,
+            &public_key.key.key.key.key,
+            &lwe_secret_key,
+            metadata,
+            ZkComputeLoad::Proof,
+            &mut engine.random_generator,
+        )
+    })?;
+
+    if cleartexts
+        .iter()
+        .any(|&cleartext| cleartext >= message_modulus.0)
+    {
+        return Err(Error::new(
+            "The blocks of the ciphertext must have empty carries to prove its decryption"
+                .to_string(),
+        ));
+    }
+
+    let mut recomposer = BlockRecomposer::<Clear>::new(message_modulus.0.ilog2());
+    for &cleartext in &cleartexts {
+        if !recomposer.add_unmasked(cleartext) {
+            break;
+        }
+    }
+    let plaintext = recomposer.value();
+
+    // The verifier decomposes the plaintext, so it must hold the value of all the blocks
+    if decompose_plaintext(plaintext, message_modulus, cleartexts.len()).as_ref()
+        != Some(&cleartexts)
+    {
+        return Err(Error::new(
+            "The decrypted value does not fit in the requested clear type".to_string(),
+        ));
+    }
+
+    Ok((plaintext, proof))
+}
+
+/// Verify that a [FheUint] decrypts to `plaintext`, using the proof generated by
+/// [prove_decryption] and the [CompactPublicKey] of the client key.
+pub fn verify_decryption<Clear, Id>(
+    ciphertext: &FheUint<Id>,
+    plaintext: Clear,
+    proof: &DecryptionProof,
+    crs: &DecryptionProofCrs,
+    public_key: &CompactPublicKey,
+    metadata: &[u8],
+) -> ZkVerificationOutcome
+where
+    Clear: DecomposableInto<u64> + UnsignedNumeric,
+    Id: FheUintId,
+{
+    let ciphertext = ciphertext.ciphertext.on_cpu();
+
+    let Some(cleartexts) = decompose_plaintext(
+        plaintext,
+        public_key.parameters().message_modulus,
+        ciphertext.blocks.len(),
+    ) else {
+        return ZkVerificationOutcome::Invalid;
+    };
+
+    let lwe_ciphertexts = ciphertext
+        .blocks
+        .iter()
+        .map(|block| block.ct.as_view())
+        .collect::<Vec<_>>();
+
+    crs.verify(
+        &lwe_ciphertexts,
+        &cleartexts,
+        &public_key.key.key.key.key,
+        proof,
+        metadata,
+    )
+}
+
+/// Prove that a [CompactPublicKey] was honestly generated from the [ClientKey], with a binary
+/// secret key and a bounded noise, see [CompactPublicKeyProofCrs].
+///
+/// This allows a third party receiving the public key to check that it is well formed with
+/// [verify_compact_public_key], without learning anything about the client key. The server key
+/// is not covered by the proof.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::safe_serialization::{safe_deserialize, safe_serialize};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+/// use tfhe::zk::{
+///     prove_compact_public_key, verify_compact_public_key, CompactPublicKeyProof,
+///     CompactPublicKeyProofCrs,
+/// };
+/// use tfhe::{ClientKey, CompactPublicKey, ConfigBuilder};
+///
+/// let config =
+///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).build();
+/// let crs = CompactPublicKeyProofCrs::from_config(config).unwrap();
+/// let client_key = ClientKey::generate(config);
+/// let public_key = CompactPublicKey::new(&client_key);
+///
+/// let proof = prove_compact_public_key(&public_key, &client_key, &crs).unwrap();
+///
+/// let mut serialized_proof = Vec::new();
+/// safe_serialize(&proof, &mut serialized_proof, 1 << 20).unwrap();
+/// let proof: CompactPublicKeyProof =
+///     safe_deserialize(serialized_proof.as_slice(), 1 << 20).unwrap();
+///
+/// assert!(verify_compact_public_key(&public_key, &proof, &crs).is_valid());
+/// ```
+pub fn prove_compact_public_key(
+    public_key: &CompactPublicKey,
+    client_key: &ClientKey,
+    crs: &CompactPublicKeyProofCrs,
+) -> crate::Result<CompactPublicKeyProof> {
+    let lwe_secret_key = match client_key.key.dedicated_compact_private_key.as_ref() {
+        Some((compact_private_key, _)) => compact_private_key.key.key(),
+        None => client_key.key.key.key.encryption_key_and_noise().0,
+    };
+
+    ShortintEngine::with_thread_local_mut(|engine| {
+        crs.prove(
+            &public_key.key.key.key.key,
+            &lwe_secret_key,
+            ZkComputeLoad::Proof,
+            &mut engine.random_generator,
+        )
+    })
+}
+
+/// Verify that a [CompactPublicKey] is well formed, using the proof generated by
+/// [prove_compact_public_key].
+pub fn verify_compact_public_key(
+    public_key: &CompactPublicKey,
+    proof: &CompactPublicKeyProof,
+    crs: &CompactPublicKeyProofCrs,
+) -> ZkVerificationOutcome {
+    crs.verify(&public_key.key.key.key.key, proof)
+}
@@ -2,6 +2,8 @@ use std::convert::Infallible;
 
 use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 
+#[cfg(feature = "zk-pok")]
+use crate::integer::ciphertext::ProvenRanges;
 use crate::integer::ciphertext::{
     BaseCrtCiphertext, BaseRadixCiphertext, BaseSignedRadixCiphertext, CompactCiphertextList,
     CompressedCiphertextList, CompressedModulusSwitchedRadixCiphertext,
@@ -55,15 +57,62 @@ pub enum CompactCiphertextListVersions {
     V1(CompactCiphertextList),
 }
 
+#[cfg(feature = "zk-pok")]
+#[derive(Version)]
+pub struct ProvenCompactCiphertextListV0 {
+    ct_list: crate::shortint::ciphertext::ProvenCompactCiphertextList,
+    info: Vec<DataKind>,
+}
+
+#[cfg(feature = "zk-pok")]
+impl Upgrade<ProvenCompactCiphertextList> for ProvenCompactCiphertextListV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<ProvenCompactCiphertextList, Self::Error> {
+        Ok(ProvenCompactCiphertextList {
+            ct_list: self.ct_list,
+            info: self.info,
+            proven_ranges: None,
+        })
+    }
+}
+
 #[cfg(feature = "zk-pok")]
 #[derive(VersionsDispatch)]
 pub enum ProvenCompactCiphertextListVersions {
-    V0(ProvenCompactCiphertextList),
+    V0(ProvenCompactCiphertextListV0),
+    V1(ProvenCompactCiphertextList),
+}
+
+#[cfg(feature = "zk-pok")]
+#[derive(VersionsDispatch)]
+pub enum ProvenRangesVersions {
+    V0(ProvenRanges),
+}
+
+#[derive(Version)]
+pub enum DataKindV0 {
+    Unsigned(usize),
+    Signed(usize),
+    Boolean,
+}
+
+impl Upgrade<DataKind> for DataKindV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<DataKind, Self::Error> {
+        match self {
+            Self::Unsigned(num_blocks) => Ok(DataKind::Unsigned(num_blocks)),
+            Self::Signed(num_blocks) => Ok(DataKind::Signed(num_blocks)),
+            Self::Boolean => Ok(DataKind::Boolean),
+        }
+    }
 }
 
 #[derive(VersionsDispatch)]
 pub enum DataKindVersions {
-    V0(DataKind),
+    V0(DataKindV0),
+    V1(DataKind),
 }
 
 #[derive(VersionsDispatch)]
@@ -3,7 +3,9 @@ use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
 use crate::core_crypto::prelude::Numeric;
 use crate::integer::backward_compatibility::ciphertext::CompactCiphertextListVersions;
 #[cfg(feature = "zk-pok")]
-use crate::integer::backward_compatibility::ciphertext::ProvenCompactCiphertextListVersions;
+use crate::integer::backward_compatibility::ciphertext::{
+    ProvenCompactCiphertextListVersions, ProvenRangesVersions,
+};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::encryption::{create_clear_radix_block_iterator, KnowsMessageModulus};
 use crate::integer::parameters::CompactCiphertextListConformanceParams;
@@ -21,7 +23,12 @@ use crate::shortint::parameters::{
 };
 use crate::shortint::{CarryModulus, Ciphertext, MessageModulus};
 #[cfg(feature = "zk-pok")]
-use crate::zk::{CompactPkeCrs, CompactPkeZkScheme, ZkComputeLoad, ZkVerificationOutcome};
+use crate::zk::{
+    CompactPkeCrs, CompactPkeZkScheme, MessageBitsOpening, WeightedMessageBit,
+    ZkBatchVerificationOutcome, ZkComputeLoad, ZkVerificationOutcome,
+};
+#[cfg(feature = "zk-pok")]
+use std::ops::RangeInclusive;
 
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -279,6 +286,48 @@ impl CompactCiphertextListBuilder {
         self
     }
 
+    /// Pushes an ASCII string followed by `padding` null chars.
+    ///
+    /// An empty string is always pushed with at least one null char,
+    /// so that it is not dropped from the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not ASCII or contains null chars.
+    #[cfg(feature = "strings")]
+    pub fn push_string_with_padding(&mut self, string: &str, padding: u32) -> &mut Self {
+        assert!(
+            string.is_ascii() && !string.contains('\0'),
+            "Input is not an ASCII string or contains null characters"
+        );
+        let padding = if string.is_empty() {
+            padding.max(1)
+        } else {
+            padding
+        };
+
+        let message_modulus = self.pk.key.message_modulus();
+        let num_blocks_per_char = crate::strings::ciphertext::num_ascii_blocks(message_modulus);
+
+        // Chars are stored from the last one to the first one, so that the list
+        // has the same layout as `FheString::into_uint`
+        let n = self.messages.len();
+        for byte in std::iter::repeat(0u8)
+            .take(padding as usize)
+            .chain(string.bytes().rev())
+        {
+            let decomposer =
+                create_clear_radix_block_iterator(byte, message_modulus, num_blocks_per_char);
+            self.messages.extend(decomposer);
+        }
+
+        self.info.push(DataKind::String {
+            num_blocks: self.messages.len() - n,
+            padded: padding != 0,
+        });
+        self
+    }
+
     pub fn build(&self) -> CompactCiphertextList {
         let ct_list = self.pk.key.encrypt_slice(self.messages.as_slice());
         CompactCiphertextList {
@@ -326,6 +375,7 @@ impl CompactCiphertextListBuilder {
         Ok(ProvenCompactCiphertextList {
             ct_list,
             info: self.info.clone(),
+            proven_ranges: None,
         })
     }
 
@@ -336,6 +386,27 @@ impl CompactCiphertextListBuilder {
         metadata: &[u8],
         load: ZkComputeLoad,
     ) -> crate::Result<ProvenCompactCiphertextList> {
+        let (ct_list, _) = self.prove_packed(crs, metadata, load, &[])?;
+        Ok(ProvenCompactCiphertextList {
+            ct_list,
+            info: self.info.clone(),
+            proven_ranges: None,
+        })
+    }
+
+    /// Encrypts and proves the packed messages, along with the openings of the linear
+    /// combinations of their bits in `combinations`
+    #[cfg(feature = "zk-pok")]
+    fn prove_packed(
+        &self,
+        crs: &CompactPkeCrs,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        combinations: &[Vec<WeightedMessageBit>],
+    ) -> crate::Result<(
+        crate::shortint::ciphertext::ProvenCompactCiphertextList,
+        Vec<MessageBitsOpening>,
+    )> {
         if self.pk.key.parameters.carry_modulus.0 < self.pk.key.parameters.message_modulus.0 {
             return Err(crate::Error::new(
                 "In order to build a packed ProvenCompactCiphertextList, \
@@ -350,20 +421,227 @@ impl CompactCiphertextListBuilder {
             .chunks(2)
             .map(|two_values| (two_values.get(1).copied().unwrap_or(0) * msg_mod) + two_values[0])
             .collect::<Vec<_>>();
-        let ct_list = self.pk.key.encrypt_and_prove_slice(
-            packed_messages.as_slice(),
-            crs,
-            metadata,
-            load,
-            msg_mod * msg_mod,
-        )?;
+        self.pk
+            .key
+            .encrypt_and_prove_slice_with_message_bits_openings(
+                packed_messages.as_slice(),
+                crs,
+                metadata,
+                load,
+                msg_mod * msg_mod,
+                combinations,
+            )
+    }
+
+    /// Builds a packed list with a proof of encryption, along with proofs that each value pushed
+    /// in the builder lies in the corresponding range of `ranges`.
+    ///
+    /// The values must be unsigned integers or booleans of at most 64 bits, and the parameters
+    /// must have `CarryModulus == MessageModulus`. The ranges are proven over the encrypted
+    /// values, see [ProvenRanges], and are checked by [ProvenCompactCiphertextList::verify].
+    #[cfg(feature = "zk-pok")]
+    pub fn build_with_proof_packed_and_ranges(
+        &self,
+        crs: &CompactPkeCrs,
+        ranges: &[RangeInclusive<u64>],
+        metadata: &[u8],
+        load: ZkComputeLoad,
+    ) -> crate::Result<ProvenCompactCiphertextList> {
+        let message_modulus = self.pk.key.parameters.message_modulus;
+        if self.pk.key.parameters.carry_modulus.0 != message_modulus.0 {
+            return Err(crate::Error::new(
+                "Range proofs require parameters with CarryModulus == MessageModulus".to_string(),
+            ));
+        }
+
+        if ranges.len() != self.info.len() {
+            return Err(crate::Error::new(format!(
+                "Expected one range per value, got {} ranges for {} values",
+                ranges.len(),
+                self.info.len()
+            )));
+        }
+
+        let values_bits = packed_values_bits(&self.info, message_modulus).ok_or_else(|| {
+            crate::Error::new(
+                "Range proofs are only supported for unsigned integers and booleans \
+                of at most 64 bits"
+                    .to_string(),
+            )
+        })?;
+
+        let bits_per_message = packed_message_bits(message_modulus);
+        let mut blocks = self.messages.as_slice();
+        let mut aux_messages = Vec::new();
+        let mut aux_combinations = Vec::with_capacity(2 * ranges.len());
+        for ((kind, value_bits), range) in self.info.iter().zip(&values_bits).zip(ranges) {
+            let (value_blocks, remaining_blocks) = blocks.split_at(kind.num_blocks());
+            blocks = remaining_blocks;
+
+            // The blocks of the value are little endian
+            let value = value_blocks.iter().rev().fold(0u64, |value, &block| {
+                (value << value_bits_per_block(message_modulus)) | block
+            });
+
+            let (min, max) = (*range.start(), *range.end());
+            if !range.contains(&value) || !is_valid_range(*kind, min, max) {
+                return Err(crate::Error::new(format!(
+                    "Cannot prove that the value {value} of kind {kind:?} lies in [{min}, {max}]"
+                )));
+            }
+
+            let num_aux_messages = range_num_aux_messages(min, max, bits_per_message);
+            for aux_value in [value - min, max - value] {
+                aux_messages.extend((0..num_aux_messages).map(|i| {
+                    aux_value
+                        .checked_shr((i * bits_per_message) as u32)
+                        .unwrap_or(0)
+                        % (1 << bits_per_message)
+                }));
+            }
+
+            let (low_bits, high_bits) = range_aux_bits(
+                aux_messages.len() - 2 * num_aux_messages,
+                num_aux_messages,
+                bits_per_message,
+            );
+            aux_combinations.push(low_bits);
+            aux_combinations.push(high_bits);
+        }
+
+        let (ct_list, values_openings) = self.prove_packed(crs, metadata, load, &values_bits)?;
+
+        let msg_mod = message_modulus.0;
+        let (aux_list, aux_openings) = self
+            .pk
+            .key
+            .encrypt_and_prove_slice_with_message_bits_openings(
+                aux_messages.as_slice(),
+                crs,
+                metadata,
+                load,
+                msg_mod * msg_mod,
+                &aux_combinations,
+            )?;
+
+        // The bits of each value are in both relations of its range
+        let openings: Vec<MessageBitsOpening> = values_openings
+            .iter()
+            .flat_map(|&value_opening| [value_opening; 2])
+            .zip(aux_openings)
+            .map(|(value_opening, aux_opening)| [value_opening, aux_opening].into_iter().sum())
+            .collect();
+
         Ok(ProvenCompactCiphertextList {
             ct_list,
             info: self.info.clone(),
+            proven_ranges: Some(ProvenRanges {
+                ranges: ranges
+                    .iter()
+                    .map(|range| (*range.start(), *range.end()))
+                    .collect(),
+                aux_list,
+                openings,
+            }),
         })
     }
 }
 
+/// Number of bits of a message in a packed list, or in the auxiliary list of [ProvenRanges]
+#[cfg(feature = "zk-pok")]
+fn packed_message_bits(message_modulus: MessageModulus) -> usize {
+    2 * value_bits_per_block(message_modulus) as usize
+}
+
+#[cfg(feature = "zk-pok")]
+fn value_bits_per_block(message_modulus: MessageModulus) -> u32 {
+    message_modulus.0.ilog2()
+}
+
+/// Returns, for each value of a packed list, the bits of the messages of the list weighted so
+/// that their sum is the value.
+///
+/// Returns `None` if a value is not an unsigned integer or a boolean of at most 64 bits.
+#[cfg(feature = "zk-pok")]
+fn packed_values_bits(
+    info: &[DataKind],
+    message_modulus: MessageModulus,
+) -> Option<Vec<Vec<WeightedMessageBit>>> {
+    let block_bits = value_bits_per_block(message_modulus) as usize;
+    let mut first_block = 0;
+
+    info.iter()
+        .map(|&kind| {
+            let num_blocks = kind.num_blocks();
+            let value_first_block = first_block;
+            first_block += num_blocks;
+
+            if !matches!(kind, DataKind::Unsigned(_) | DataKind::Boolean)
+                || num_blocks * block_bits > u64::BITS as usize
+            {
+                return None;
+            }
+
+            let value_bits = (0..num_blocks)
+                .flat_map(|i| {
+                    let block = value_first_block + i;
+                    (0..block_bits).map(move |bit| WeightedMessageBit {
+                        // Blocks are packed by two, the second one in the high bits of the message
+                        message_index: block / 2,
+                        bit_index: (block % 2) * block_bits + bit,
+                        weight: 1 << (i * block_bits + bit),
+                    })
+                })
+                .collect();
+
+            Some(value_bits)
+        })
+        .collect()
+}
+
+/// Booleans are expanded to 0 or 1 whatever the bits of their block, so their range must be
+/// included in [0, 1] to match the expanded value
+#[cfg(feature = "zk-pok")]
+fn is_valid_range(kind: DataKind, min: u64, max: u64) -> bool {
+    min <= max && (kind != DataKind::Boolean || max <= 1)
+}
+
+/// Number of messages of the auxiliary list used to encrypt `x - min` and `max - x` each
+#[cfg(feature = "zk-pok")]
+fn range_num_aux_messages(min: u64, max: u64, bits_per_message: usize) -> usize {
+    let width_bits = (u64::BITS - (max - min).leading_zeros()) as usize;
+    width_bits.div_ceil(bits_per_message).max(1)
+}
+
+/// Returns the weighted bits of the auxiliary messages of a value in the relations
+/// `x - (x - min) = min` and `x + (max - x) = max`
+///
+/// `x - min` is encrypted in the `num_aux_messages` messages starting at `first_aux_message`,
+/// followed by `max - x`.
+#[cfg(feature = "zk-pok")]
+fn range_aux_bits(
+    first_aux_message: usize,
+    num_aux_messages: usize,
+    bits_per_message: usize,
+) -> (Vec<WeightedMessageBit>, Vec<WeightedMessageBit>) {
+    let aux_bits = |first_message: usize, sign: i128| -> Vec<WeightedMessageBit> {
+        (0..num_aux_messages)
+            .flat_map(|i| {
+                (0..bits_per_message).map(move |bit| WeightedMessageBit {
+                    message_index: first_message + i,
+                    bit_index: bit,
+                    weight: sign << (i * bits_per_message + bit),
+                })
+            })
+            .collect()
+    };
+
+    (
+        aux_bits(first_aux_message, -1),
+        aux_bits(first_aux_message + num_aux_messages, 1),
+    )
+}
+
 pub struct CompactCiphertextListExpander {
     expanded_blocks: Vec<Ciphertext>,
     info: Vec<DataKind>,
@@ -770,6 +1048,25 @@ pub struct ProvenCompactCiphertextList {
     // Integers stored can have a heterogeneous number of blocks and signedness
     // We store this info to safeguard the expansion
     pub(crate) info: Vec<DataKind>,
+    pub(crate) proven_ranges: Option<ProvenRanges>,
+}
+
+/// Proofs that each value of a [ProvenCompactCiphertextList] lies in a range
+///
+/// For a value `x` in `[min, max]`, `x - min` and `max - x` are encrypted and proven in an
+/// auxiliary list. The openings then prove, over the messages committed by the proofs of both
+/// lists, that `x - (x - min) = min` and `x + (max - x) = max`. As the bits of the auxiliary
+/// values are proven to be bits, both are non-negative which gives `min <= x <= max`.
+///
+/// The auxiliary list is never expanded.
+#[cfg(feature = "zk-pok")]
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(ProvenRangesVersions)]
+pub struct ProvenRanges {
+    pub(crate) ranges: Vec<(u64, u64)>,
+    pub(crate) aux_list: crate::shortint::ciphertext::ProvenCompactCiphertextList,
+    // Two openings per value, for the relations with min then max
+    pub(crate) openings: Vec<MessageBitsOpening>,
 }
 
 #[cfg(feature = "zk-pok")]
@@ -778,13 +1075,248 @@ impl ProvenCompactCiphertextList {
         CompactCiphertextListBuilder::new(pk)
     }
 
+    /// Verifies the proofs of encryption of the list, and the proofs that its values lie in their
+    /// ranges if the list was built with
+    /// [CompactCiphertextListBuilder::build_with_proof_packed_and_ranges]
     pub fn verify(
         &self,
         crs: &CompactPkeCrs,
         public_key: &CompactPublicKey,
         metadata: &[u8],
     ) -> ZkVerificationOutcome {
-        self.ct_list.verify(crs, &public_key.key, metadata)
+        if self
+            .ct_list
+            .verify(crs, &public_key.key, metadata)
+            .is_invalid()
+        {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        self.verify_ranges(crs, public_key, metadata)
+    }
+
+    /// Verifies a batch of lists generated with the same metadata, as [Self::verify] does
+    ///
+    /// The proofs of encryption of the lists, and of the auxiliary lists of their ranges, are
+    /// verified together, see [CompactPkeCrs::verify_batch]. The openings of the ranges are then
+    /// checked list by list. The indices returned when the batch is invalid are the ones of the
+    /// invalid lists.
+    pub fn verify_batch(
+        lists: &[&Self],
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+    ) -> ZkBatchVerificationOutcome {
+        // The auxiliary lists are verified along with the lists they belong to
+        let (list_indices, shortint_lists): (Vec<_>, Vec<_>) = lists
+            .iter()
+            .enumerate()
+            .flat_map(|(list_index, list)| {
+                std::iter::once(&list.ct_list)
+                    .chain(
+                        list.proven_ranges
+                            .as_ref()
+                            .map(|proven_ranges| &proven_ranges.aux_list),
+                    )
+                    .map(move |shortint_list| (list_index, (shortint_list, metadata)))
+            })
+            .unzip();
+
+        let outcome = crate::shortint::ciphertext::ProvenCompactCiphertextList::verify_batch(
+            &shortint_lists,
+            crs,
+            &public_key.key,
+        );
+
+        let mut invalid_lists = outcome
+            .invalid_indices()
+            .iter()
+            .map(|&i| list_indices[i])
+            .collect::<Vec<_>>();
+        invalid_lists.dedup();
+
+        let invalid_ranges = lists
+            .par_iter()
+            .enumerate()
+            .filter(|(list_index, _)| !invalid_lists.contains(list_index))
+            .filter_map(|(list_index, list)| {
+                list.verify_range_openings(crs, public_key)
+                    .is_invalid()
+                    .then_some(list_index)
+            })
+            .collect::<Vec<_>>();
+
+        invalid_lists.extend(invalid_ranges);
+        invalid_lists.sort_unstable();
+
+        if invalid_lists.is_empty() {
+            ZkBatchVerificationOutcome::Valid
+        } else {
+            ZkBatchVerificationOutcome::Invalid(invalid_lists)
+        }
+    }
+
+    /// Returns the ranges of the values of the list, if it was built with
+    /// [CompactCiphertextListBuilder::build_with_proof_packed_and_ranges]
+    ///
+    /// The ranges are only guaranteed once the list has been verified.
+    pub fn ranges(&self) -> Option<Vec<RangeInclusive<u64>>> {
+        self.proven_ranges.as_ref().map(|proven_ranges| {
+            proven_ranges
+                .ranges
+                .iter()
+                .map(|&(min, max)| min..=max)
+                .collect()
+        })
+    }
+
+    /// Verifies the proofs of encryption and the proofs of the ranges of the auxiliary list, then
+    /// the openings binding the ranges to the values of the list.
+    ///
+    /// Lists without ranges are valid.
+    fn verify_ranges(
+        &self,
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+    ) -> ZkVerificationOutcome {
+        let Some(proven_ranges) = self.proven_ranges.as_ref() else {
+            return ZkVerificationOutcome::Valid;
+        };
+
+        if proven_ranges
+            .aux_list
+            .verify(crs, &public_key.key, metadata)
+            .is_invalid()
+        {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        self.verify_range_openings(crs, public_key)
+    }
+
+    /// Verifies that the openings of the ranges hold over the messages committed by the proofs
+    /// of the list and of its auxiliary list, which must have been verified beforehand
+    fn verify_range_openings(
+        &self,
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+    ) -> ZkVerificationOutcome {
+        let Some(proven_ranges) = self.proven_ranges.as_ref() else {
+            return ZkVerificationOutcome::Valid;
+        };
+
+        let message_modulus = public_key.key.parameters.message_modulus;
+        if !self.is_packed()
+            || public_key.key.parameters.carry_modulus.0 != message_modulus.0
+            || proven_ranges.ranges.len() != self.info.len()
+            || proven_ranges.openings.len() != 2 * self.info.len()
+        {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        let Some(values_bits) = packed_values_bits(&self.info, message_modulus) else {
+            return ZkVerificationOutcome::Invalid;
+        };
+
+        let bits_per_message = packed_message_bits(message_modulus);
+        let mut num_aux_messages = 0;
+        for (((kind, value_bits), &(min, max)), openings) in self
+            .info
+            .iter()
+            .zip(&values_bits)
+            .zip(&proven_ranges.ranges)
+            .zip(proven_ranges.openings.chunks_exact(2))
+        {
+            if !is_valid_range(*kind, min, max) {
+                return ZkVerificationOutcome::Invalid;
+            }
+
+            let value_num_aux_messages = range_num_aux_messages(min, max, bits_per_message);
+            let (low_bits, high_bits) =
+                range_aux_bits(num_aux_messages, value_num_aux_messages, bits_per_message);
+            num_aux_messages += 2 * value_num_aux_messages;
+
+            for ((aux_bits, bound), opening) in [(low_bits, min), (high_bits, max)]
+                .into_iter()
+                .zip(openings)
+            {
+                let (Some(value_terms), Some(aux_terms)) = (
+                    self.ct_list.message_bits_terms(value_bits),
+                    proven_ranges.aux_list.message_bits_terms(&aux_bits),
+                ) else {
+                    return ZkVerificationOutcome::Invalid;
+                };
+
+                let terms = value_terms
+                    .iter()
+                    .chain(&aux_terms)
+                    .map(|(ct_list, proof, bits)| (*ct_list, *proof, bits.as_slice()))
+                    .collect::<Vec<_>>();
+
+                if crs
+                    .verify_message_bits_relation(
+                        opening,
+                        &terms,
+                        &public_key.key.key,
+                        i128::from(bound),
+                    )
+                    .is_invalid()
+                {
+                    return ZkVerificationOutcome::Invalid;
+                }
+            }
+        }
+
+        // The auxiliary list has no other message
+        if proven_ranges.aux_list.ciphertext_count() != num_aux_messages {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        ZkVerificationOutcome::Valid
+    }
+
+    /// Verifies the list then expands it, checking that the ranges proven for its values are
+    /// included in `ranges`, one per value.
+    ///
+    /// See [Self::verify_and_expand].
+    pub fn verify_and_expand_with_ranges(
+        &self,
+        crs: &CompactPkeCrs,
+        ranges: &[RangeInclusive<u64>],
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+        expansion_mode: IntegerCompactCiphertextListExpansionMode<'_>,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        self.check_ranges_are_included_in(ranges)?;
+        self.verify_and_expand(crs, public_key, metadata, expansion_mode)
+    }
+
+    /// Checks that the ranges declared for the values of the list are included in `ranges`
+    pub(crate) fn check_ranges_are_included_in(
+        &self,
+        ranges: &[RangeInclusive<u64>],
+    ) -> crate::Result<()> {
+        let Some(proven_ranges) = self.proven_ranges.as_ref() else {
+            return Err(crate::Error::new(
+                "This compact list has no proven ranges".to_string(),
+            ));
+        };
+
+        if ranges.len() != proven_ranges.ranges.len()
+            || !proven_ranges
+                .ranges
+                .iter()
+                .zip(ranges)
+                .all(|(&(min, max), range)| range.contains(&min) && range.contains(&max))
+        {
+            return Err(crate::Error::new(
+                "The proven ranges of this compact list are not included in the expected ranges"
+                    .to_string(),
+            ));
+        }
+
+        Ok(())
     }
 
     pub fn verify_and_expand(
@@ -807,6 +1339,10 @@ impl ProvenCompactCiphertextList {
             )));
         }
 
+        if self.verify_ranges(crs, public_key, metadata).is_invalid() {
+            return Err(crate::ErrorKind::InvalidZkProof.into());
+        }
+
         let expanded_blocks = match expansion_mode {
             IntegerCompactCiphertextListExpansionMode::CastAndUnpackIfNecessary(
                 key_switching_key_view,
@@ -1033,7 +1569,11 @@ impl ParameterSetConformant for ProvenCompactCiphertextList {
     type ParameterSet = IntegerProvenCompactCiphertextListConformanceParams;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
-        let Self { ct_list, info } = self;
+        let Self {
+            ct_list,
+            info,
+            proven_ranges,
+        } = self;
 
         let total_expected_num_blocks: usize = info.iter().map(|a| a.num_blocks()).sum();
 
@@ -1049,7 +1589,38 @@ impl ParameterSetConformant for ProvenCompactCiphertextList {
             zk_scheme: parameter_set.zk_scheme,
         };
 
-        ct_list.is_conformant(&a)
+        if !ct_list.is_conformant(&a) {
+            return false;
+        }
+
+        let Some(ProvenRanges {
+            ranges,
+            aux_list,
+            openings,
+        }) = proven_ranges
+        else {
+            return true;
+        };
+
+        if parameter_set.carry_modulus.0 != parameter_set.message_modulus.0
+            || ranges.len() != info.len()
+            || openings.len() != 2 * info.len()
+            || ranges.iter().any(|(min, max)| min > max)
+            || !openings.iter().all(MessageBitsOpening::is_usable)
+        {
+            return false;
+        }
+
+        let bits_per_message = packed_message_bits(parameter_set.message_modulus);
+        let total_expected_aux_count = ranges
+            .iter()
+            .map(|&(min, max)| 2 * range_num_aux_messages(min, max, bits_per_message))
+            .sum();
+
+        aux_list.is_conformant(&ProvenCompactCiphertextListConformanceParams {
+            total_expected_lwe_count: total_expected_aux_count,
+            ..a
+        })
     }
 }
 
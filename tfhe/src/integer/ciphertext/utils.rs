@@ -12,12 +12,18 @@ pub enum DataKind {
     /// The held value is a number of radix blocks.
     Signed(usize),
     Boolean,
+    /// The held value is the number of radix blocks of the string (all chars
+    /// included) and whether the string is padded with trailing nulls.
+    String {
+        num_blocks: usize,
+        padded: bool,
+    },
 }
 
 impl DataKind {
     pub fn num_blocks(self) -> usize {
         match self {
-            Self::Unsigned(n) | Self::Signed(n) => n,
+            Self::Unsigned(n) | Self::Signed(n) | Self::String { num_blocks: n, .. } => n,
             Self::Boolean => 1,
         }
     }
@@ -48,6 +54,12 @@ where
             (DataKind::Signed(_), false) => Err(crate::Error::new(
                 "Tried to expand an unsigned radix while a signed radix is stored".to_string(),
             )),
+            (DataKind::String { .. }, _) => {
+                let signed_or_unsigned_str = if T::IS_SIGNED { "signed" } else { "unsigned" };
+                Err(crate::Error::new(format!(
+                    "Tried to expand a {signed_or_unsigned_str} radix while a string is stored"
+                )))
+            }
         }
     }
 }
@@ -62,6 +74,9 @@ impl Expandable for BooleanBlock {
                 "Tried to expand a boolean block while a signed radix was stored".to_string(),
             )),
             DataKind::Boolean => Ok(Self::new_unchecked(blocks[0].clone())),
+            DataKind::String { .. } => Err(crate::Error::new(
+                "Tried to expand a boolean block while a string was stored".to_string(),
+            )),
         }
     }
 }
@@ -43,6 +43,12 @@ where
             (DataKind::Signed(_), false) => Err(crate::Error::new(
                 "Tried to expand an unsigned radix while a signed radix is stored".to_string(),
             )),
+            (DataKind::String { .. }, _) => {
+                let signed_or_unsigned_str = if T::IS_SIGNED { "signed" } else { "unsigned" };
+                Err(crate::Error::new(format!(
+                    "Tried to expand a {signed_or_unsigned_str} radix while a string is stored"
+                )))
+            }
         }
     }
 }
@@ -57,6 +63,9 @@ impl CudaExpandable for CudaBooleanBlock {
                 "Tried to expand a boolean block while a signed radix was stored".to_string(),
             )),
             DataKind::Boolean => Ok(Self::from_cuda_radix_ciphertext(blocks)),
+            DataKind::String { .. } => Err(crate::Error::new(
+                "Tried to expand a boolean block while a string was stored".to_string(),
+            )),
         }
     }
 }
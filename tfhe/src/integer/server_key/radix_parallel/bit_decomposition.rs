@@ -0,0 +1,295 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the bits of the ciphertext, least significant bit first
+    ///
+    /// The returned vector has `num_blocks * log2(message_modulus)` elements.
+    ///
+    /// - The block carries of the input must be empty
+    pub fn unchecked_to_bits_parallelized<T>(&self, ct: &T) -> Vec<BooleanBlock>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        BitExtractor::new(ct.blocks(), self, bits_per_block)
+            .extract_all_bits()
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect()
+    }
+
+    /// Returns the bits of the ciphertext, least significant bit first
+    ///
+    /// See [Self::to_bits_parallelized]
+    pub fn smart_to_bits_parallelized<T>(&self, ct: &mut T) -> Vec<BooleanBlock>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_to_bits_parallelized(ct)
+    }
+
+    /// Returns the bits of the ciphertext, least significant bit first
+    ///
+    /// The returned vector has `num_blocks * log2(message_modulus)` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg = 0b1011_0010u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let bits = sks.to_bits_parallelized(&ct);
+    /// assert_eq!(bits.len(), 8);
+    ///
+    /// // Decrypt:
+    /// for (i, bit) in bits.iter().enumerate() {
+    ///     assert_eq!(cks.decrypt_bool(bit), (msg >> i) & 1 == 1);
+    /// }
+    /// ```
+    pub fn to_bits_parallelized<T>(&self, ct: &T) -> Vec<BooleanBlock>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_to_bits_parallelized(ct)
+        } else {
+            let mut tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            self.unchecked_to_bits_parallelized(&tmp_ct)
+        }
+    }
+
+    /// Builds a ciphertext from its bits, least significant bit first
+    ///
+    /// The returned ciphertext has `ceil(bits.len() / log2(message_modulus))` blocks,
+    /// the missing most significant bits of the last block are 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg = 0b101_1001u8;
+    ///
+    /// let bits = (0..7)
+    ///     .map(|i| cks.encrypt_bool((msg >> i) & 1 == 1))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct: RadixCiphertext = sks.from_bits_parallelized(&bits);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct);
+    /// assert_eq!(res, msg);
+    /// ```
+    pub fn from_bits_parallelized<T>(&self, bits: &[BooleanBlock]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+
+        let blocks = bits
+            .par_chunks(bits_per_block)
+            .map(|chunk| {
+                let mut block = chunk[0].as_ref().clone();
+                if chunk.len() == 1 {
+                    return block;
+                }
+
+                // Each bit is moved to its position in the block, the result
+                // is then bootstrapped to get a block with a nominal noise level
+                for (i, bit) in chunk.iter().enumerate().skip(1) {
+                    let mut shifted_bit = bit.as_ref().clone();
+                    self.key
+                        .unchecked_scalar_left_shift_assign(&mut shifted_bit, i as u8);
+                    self.key.unchecked_add_assign(&mut block, &shifted_bit);
+                }
+                self.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
+
+    /// Concatenates the blocks of `hi` on top of the blocks of `lo`
+    ///
+    /// The result has `hi.blocks.len() + lo.blocks.len()` blocks and encrypts
+    /// `(hi << lo_num_bits) + lo` where `lo_num_bits` is the number of bits of `lo`.
+    ///
+    /// - The block carries of the inputs must be empty
+    pub fn unchecked_concat_parallelized(
+        &self,
+        hi: &RadixCiphertext,
+        lo: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut blocks = Vec::with_capacity(hi.blocks.len() + lo.blocks.len());
+        blocks.extend_from_slice(&lo.blocks);
+        blocks.extend_from_slice(&hi.blocks);
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Concatenates the blocks of `hi` on top of the blocks of `lo`
+    ///
+    /// The result has `hi.blocks.len() + lo.blocks.len()` blocks and encrypts
+    /// `(hi << lo_num_bits) + lo` where `lo_num_bits` is the number of bits of `lo`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let hi = 0xABu8;
+    /// let lo = 0xCDu8;
+    ///
+    /// let ct_hi = cks.encrypt(hi);
+    /// let ct_lo = cks.encrypt(lo);
+    ///
+    /// let ct_res = sks.concat_parallelized(&ct_hi, &ct_lo);
+    ///
+    /// // Decrypt:
+    /// let res: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 0xABCD);
+    /// ```
+    pub fn concat_parallelized(
+        &self,
+        hi: &RadixCiphertext,
+        lo: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut tmp_hi;
+        let mut tmp_lo;
+
+        let (hi, lo) = match (hi.block_carries_are_empty(), lo.block_carries_are_empty()) {
+            (true, true) => (hi, lo),
+            (true, false) => {
+                tmp_lo = lo.clone();
+                self.full_propagate_parallelized(&mut tmp_lo);
+                (hi, &tmp_lo)
+            }
+            (false, true) => {
+                tmp_hi = hi.clone();
+                self.full_propagate_parallelized(&mut tmp_hi);
+                (&tmp_hi, lo)
+            }
+            (false, false) => {
+                tmp_hi = hi.clone();
+                tmp_lo = lo.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_hi),
+                    || self.full_propagate_parallelized(&mut tmp_lo),
+                );
+                (&tmp_hi, &tmp_lo)
+            }
+        };
+
+        self.unchecked_concat_parallelized(hi, lo)
+    }
+
+    /// Splits the ciphertext at the given bit index
+    ///
+    /// Returns `(lo, hi)` where `lo` encrypts the bits `[0, bit)` and `hi` the bits
+    /// `[bit, num_bits)` of the input.
+    /// When `bit` is a multiple of the number of bits per block, no PBS is needed.
+    ///
+    /// - The block carries of the input must be empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit` is greater than the number of bits of the input.
+    pub fn unchecked_split_at_bit_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        bit: usize,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let num_bits = bits_per_block * ct.blocks.len();
+        assert!(
+            bit <= num_bits,
+            "Cannot split a ciphertext of {num_bits} bits at bit {bit}"
+        );
+
+        if bit % bits_per_block == 0 {
+            let (lo, hi) = ct.blocks.split_at(bit / bits_per_block);
+            return (
+                RadixCiphertext::from(lo.to_vec()),
+                RadixCiphertext::from(hi.to_vec()),
+            );
+        }
+
+        // The bit is not block aligned so 0 < bit < num_bits, and both ranges are valid
+        let (lo, hi) = rayon::join(
+            || self.unchecked_scalar_bitslice_parallelized(ct, 0..bit),
+            || self.unchecked_scalar_bitslice_parallelized(ct, bit..num_bits),
+        );
+        (lo.unwrap(), hi.unwrap())
+    }
+
+    /// Splits the ciphertext at the given bit index
+    ///
+    /// Returns `(lo, hi)` where `lo` encrypts the bits `[0, bit)` and `hi` the bits
+    /// `[bit, num_bits)` of the input.
+    /// When `bit` is a multiple of the number of bits per block, no PBS is needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit` is greater than the number of bits of the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg = 0xABCDu16;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (ct_lo, ct_hi) = sks.split_at_bit_parallelized(&ct, 5);
+    ///
+    /// // Decrypt:
+    /// let lo: u16 = cks.decrypt(&ct_lo);
+    /// let hi: u16 = cks.decrypt(&ct_hi);
+    /// assert_eq!(lo, msg & 0b1_1111);
+    /// assert_eq!(hi, msg >> 5);
+    /// ```
+    pub fn split_at_bit_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        bit: usize,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        if ct.block_carries_are_empty() {
+            self.unchecked_split_at_bit_parallelized(ct, bit)
+        } else {
+            let mut tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            self.unchecked_split_at_bit_parallelized(&tmp_ct, bit)
+        }
+    }
+}
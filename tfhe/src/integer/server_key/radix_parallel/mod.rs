@@ -1,5 +1,6 @@
 mod abs;
 mod add;
+mod bit_decomposition;
 mod bit_extractor;
 mod bitwise_op;
 mod block_shift;
@@ -9,7 +10,10 @@ mod div_mod;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod oblivious_access;
+mod pow;
 mod rotate;
+mod saturating;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_comparison;
@@ -877,3 +877,219 @@ impl ServerKey {
         (full_result, overflowed)
     }
 }
+
+impl ServerKey {
+    /// Extends the ciphertext to twice its number of blocks,
+    /// using the sign bit for signed ciphertexts and zeros otherwise
+    fn extend_to_double_width<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let target_num_blocks = 2 * ct.blocks().len();
+        if T::IS_SIGNED {
+            let extended = self.cast_to_signed(ct.clone(), target_num_blocks);
+            T::from_blocks(extended.blocks)
+        } else {
+            let extended = self.cast_to_unsigned(ct.clone(), target_num_blocks);
+            T::from_blocks(extended.blocks)
+        }
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts encrypting integer values.
+    ///
+    /// The result has twice as many blocks as the inputs, so it cannot overflow.
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - lhs and rhs must have the same number of blocks
+    pub fn unchecked_widening_mul_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "lhs and rhs must have the same number of blocks ({} vs {})",
+            lhs.blocks().len(),
+            rhs.blocks().len()
+        );
+
+        let (lhs, rhs) = rayon::join(
+            || self.extend_to_double_width(lhs),
+            || self.extend_to_double_width(rhs),
+        );
+        self.unchecked_mul_parallelized(&lhs, &rhs)
+    }
+
+    /// Computes homomorphically the most significant half of the full product
+    /// of two ciphertexts encrypting integer values.
+    ///
+    /// The result has the same number of blocks as the inputs.
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - lhs and rhs must have the same number of blocks
+    pub fn unchecked_mul_high_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = lhs.blocks().len();
+        let mut blocks = self
+            .unchecked_widening_mul_parallelized(lhs, rhs)
+            .into_blocks();
+        T::from_blocks(blocks.split_off(num_blocks))
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts encrypting integer values.
+    ///
+    /// See [Self::widening_mul_parallelized]
+    pub fn smart_widening_mul_parallelized<T>(&self, lhs: &mut T, rhs: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(rhs);
+                }
+            },
+        );
+
+        self.unchecked_widening_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the most significant half of the full product
+    /// of two ciphertexts encrypting integer values.
+    ///
+    /// See [Self::mul_high_parallelized]
+    pub fn smart_mul_high_parallelized<T>(&self, lhs: &mut T, rhs: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(rhs);
+                }
+            },
+        );
+
+        self.unchecked_mul_high_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts encrypting integer values.
+    ///
+    /// The result has twice as many blocks as the inputs, so it cannot overflow.
+    /// Signed ciphertexts are sign extended, unsigned ones are zero extended.
+    ///
+    /// # Panics
+    ///
+    /// Panics if lhs and rhs do not have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let clear_1 = 170u8;
+    /// let clear_2 = 6u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically the full multiplication
+    /// let ct_res = sks.widening_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// assert_eq!(ct_res.blocks().len(), 2 * num_blocks);
+    /// // Decrypt
+    /// let res: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(u16::from(clear_1) * u16::from(clear_2), res);
+    /// ```
+    pub fn widening_mul_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_widening_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the most significant half of the full product
+    /// of two ciphertexts encrypting integer values.
+    ///
+    /// In other words, for 8-bit ciphertexts, it is like doing
+    /// `((a as u16 * b as u16) >> 8) as u8`
+    ///
+    /// # Panics
+    ///
+    /// Panics if lhs and rhs do not have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let clear_1 = -100i8;
+    /// let clear_2 = 77i8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_signed(clear_1);
+    /// let ctxt_2 = cks.encrypt_signed(clear_2);
+    ///
+    /// // Compute homomorphically the high part of the multiplication
+    /// let ct_res = sks.mul_high_parallelized(&ctxt_1, &ctxt_2);
+    /// // Decrypt
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(((i16::from(clear_1) * i16::from(clear_2)) >> 8) as i8, res);
+    /// ```
+    pub fn mul_high_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = lhs.blocks().len();
+        let mut blocks = self.widening_mul_parallelized(lhs, rhs).into_blocks();
+        T::from_blocks(blocks.split_off(num_blocks))
+    }
+}
@@ -0,0 +1,385 @@
+use crate::core_crypto::prelude::UnsignedInteger;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+use crate::prelude::CastInto;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the number of positions of a collection of length `len`
+    /// that the encrypted index can point to
+    fn num_reachable_positions(&self, index: &RadixCiphertext, len: usize) -> usize {
+        let num_index_bits = self.message_modulus().0.ilog2() as usize * index.blocks.len();
+        if num_index_bits >= usize::BITS as usize {
+            len
+        } else {
+            len.min(1 << num_index_bits)
+        }
+    }
+
+    /// Computes the one-hot vector of selectors for the given encrypted index
+    ///
+    /// The i-th selector encrypts `true` if and only if `index == i`
+    fn compute_index_selectors(&self, index: &RadixCiphertext, len: usize) -> Vec<BooleanBlock> {
+        let num_positions = self.num_reachable_positions(index, len) as u64;
+        self.compute_equality_selectors(index, (0..num_positions).into_par_iter())
+    }
+
+    //===========================================================
+    //  Unchecked
+    //===========================================================
+
+    /// Returns the element of the slice at the encrypted `index`
+    ///
+    /// - If the index is out of bounds, the returned value is 0
+    ///
+    /// # Panics
+    ///
+    /// - If the slice is empty or if its elements do not have the same number of blocks
+    /// - If the carry modulus is smaller than the message modulus
+    pub fn unchecked_oblivious_read_parallelized<T>(&self, cts: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!cts.is_empty(), "Cannot read from an empty slice");
+        let num_blocks = cts[0].blocks().len();
+        assert!(
+            cts.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All ciphertexts of the slice must have the same number of blocks"
+        );
+
+        let selectors = self.compute_index_selectors(index, cts.len());
+        if selectors.is_empty() {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        // At most one selector is true, so at most one of the products is non-zero
+        let products = cts
+            .par_iter()
+            .zip(selectors.par_iter())
+            .map(|(ct, selector)| {
+                let mut ct = ct.clone();
+                self.zero_out_if_condition_is_false(&mut ct, &selector.0);
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = self.sum_one_hot_vector(products);
+        result
+            .blocks_mut()
+            .par_iter_mut()
+            .for_each(|block| self.key.message_extract_assign(block));
+        result
+    }
+
+    /// Returns the element of the clear table at the encrypted `index`,
+    /// as a radix ciphertext of `num_blocks` blocks
+    ///
+    /// - If the index is out of bounds, the returned value is 0
+    /// - Table values that do not fit in `num_blocks` blocks are truncated
+    ///
+    /// # Panics
+    ///
+    /// - If the carry modulus is smaller than the message modulus
+    pub fn unchecked_oblivious_read_clear_parallelized<Clear>(
+        &self,
+        table: &[Clear],
+        index: &RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
+    {
+        let selectors = self.compute_index_selectors(index, table.len());
+        if selectors.is_empty() {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        let possible_results = self.create_possible_results(
+            num_blocks,
+            selectors.into_par_iter().zip(table.par_iter().copied()),
+        );
+        let result: RadixCiphertext = self.aggregate_one_hot_vector(possible_results);
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Replaces the element of the slice at the encrypted `index` by `value`
+    ///
+    /// - If the index is out of bounds, the slice is left unchanged
+    ///
+    /// # Panics
+    ///
+    /// - If the elements of the slice and the value do not have the same number of blocks
+    /// - If the carry modulus is smaller than the message modulus
+    pub fn unchecked_oblivious_write_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &RadixCiphertext,
+        value: &T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            cts.iter()
+                .all(|ct| ct.blocks().len() == value.blocks().len()),
+            "All ciphertexts of the slice must have the same number of blocks as the value"
+        );
+
+        let selectors = self.compute_index_selectors(index, cts.len());
+
+        cts.par_iter_mut()
+            .zip(selectors.par_iter())
+            .for_each(|(ct, selector)| {
+                *ct = self.unchecked_if_then_else_parallelized(selector, value, ct);
+            });
+    }
+
+    //===========================================================
+    //  Smart
+    //===========================================================
+
+    /// Returns the element of the slice at the encrypted `index`
+    ///
+    /// See [Self::oblivious_read_parallelized]
+    pub fn smart_oblivious_read_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &mut RadixCiphertext,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !index.block_carries_are_empty() {
+            self.full_propagate_parallelized(index);
+        }
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_oblivious_read_parallelized(cts, index)
+    }
+
+    /// Returns the element of the clear table at the encrypted `index`
+    ///
+    /// See [Self::oblivious_read_clear_parallelized]
+    pub fn smart_oblivious_read_clear_parallelized<Clear>(
+        &self,
+        table: &[Clear],
+        index: &mut RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
+    {
+        if !index.block_carries_are_empty() {
+            self.full_propagate_parallelized(index);
+        }
+
+        self.unchecked_oblivious_read_clear_parallelized(table, index, num_blocks)
+    }
+
+    /// Replaces the element of the slice at the encrypted `index` by `value`
+    ///
+    /// See [Self::oblivious_write_parallelized]
+    pub fn smart_oblivious_write_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &mut RadixCiphertext,
+        value: &mut T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                if !index.block_carries_are_empty() {
+                    self.full_propagate_parallelized(index);
+                }
+            },
+            || {
+                if !value.block_carries_are_empty() {
+                    self.full_propagate_parallelized(value);
+                }
+            },
+        );
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_oblivious_write_parallelized(cts, index, value);
+    }
+
+    //===========================================================
+    //  Default
+    //===========================================================
+
+    /// Returns the element of the slice at the encrypted `index`
+    ///
+    /// The access pattern does not depend on the index: all the elements
+    /// of the slice are touched.
+    ///
+    /// - If the index is out of bounds, the returned value is 0
+    ///
+    /// # Panics
+    ///
+    /// - If the slice is empty or if its elements do not have the same number of blocks
+    /// - If the carry modulus is smaller than the message modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let clears = [12u8, 34, 56, 78];
+    /// let cts = clears.iter().map(|&x| cks.encrypt(x)).collect::<Vec<_>>();
+    ///
+    /// let index = cks.encrypt(2u8);
+    /// let ct_res = sks.oblivious_read_parallelized(&cts, &index);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 56);
+    /// ```
+    pub fn oblivious_read_parallelized<T>(&self, cts: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+        let mut tmp_index;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        self.unchecked_oblivious_read_parallelized(cts, index)
+    }
+
+    /// Returns the element of the clear table at the encrypted `index`,
+    /// as a radix ciphertext of `num_blocks` blocks
+    ///
+    /// - If the index is out of bounds, the returned value is 0
+    /// - Table values that do not fit in `num_blocks` blocks are truncated
+    ///
+    /// # Panics
+    ///
+    /// - If the carry modulus is smaller than the message modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// // A small S-box
+    /// let sbox = [0xCu8, 0x5, 0x6, 0xB, 0x9, 0x0, 0xA, 0xD];
+    ///
+    /// let index = cks.encrypt(3u8);
+    /// let ct_res = sks.oblivious_read_clear_parallelized(&sbox, &index, num_blocks);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0xB);
+    /// ```
+    pub fn oblivious_read_clear_parallelized<Clear>(
+        &self,
+        table: &[Clear],
+        index: &RadixCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64> + CastInto<usize>,
+    {
+        let mut tmp_index;
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        self.unchecked_oblivious_read_clear_parallelized(table, index, num_blocks)
+    }
+
+    /// Replaces the element of the slice at the encrypted `index` by `value`
+    ///
+    /// The access pattern does not depend on the index: all the elements
+    /// of the slice are rewritten.
+    ///
+    /// - If the index is out of bounds, the slice keeps its values
+    ///
+    /// # Panics
+    ///
+    /// - If the elements of the slice and the value do not have the same number of blocks
+    /// - If the carry modulus is smaller than the message modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let clears = [12u8, 34, 56, 78];
+    /// let mut cts = clears.iter().map(|&x| cks.encrypt(x)).collect::<Vec<_>>();
+    ///
+    /// let index = cks.encrypt(1u8);
+    /// let value = cks.encrypt(99u8);
+    /// sks.oblivious_write_parallelized(&mut cts, &index, &value);
+    ///
+    /// let dec = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u8>>();
+    /// assert_eq!(dec, vec![12, 99, 56, 78]);
+    /// ```
+    pub fn oblivious_write_parallelized<T>(&self, cts: &mut [T], index: &RadixCiphertext, value: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_index;
+        let mut tmp_value;
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        let value = if value.block_carries_are_empty() {
+            value
+        } else {
+            tmp_value = value.clone();
+            self.full_propagate_parallelized(&mut tmp_value);
+            &tmp_value
+        };
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_oblivious_write_parallelized(cts, index, value);
+    }
+}
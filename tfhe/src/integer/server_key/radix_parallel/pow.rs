@@ -0,0 +1,519 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use crate::shortint::ciphertext::Degree;
+
+impl ServerKey {
+    fn bits_of_exponent(&self, exponent: &RadixCiphertext) -> Vec<BooleanBlock> {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        BitExtractor::new(exponent.blocks.as_slice(), self, bits_per_block)
+            .extract_all_bits()
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect()
+    }
+
+    /// Right-to-left square-and-multiply with encrypted exponent bits
+    ///
+    /// `mul` must return a ciphertext with clean carries.
+    ///
+    /// The result is the product of the `power_i` for which the i-th bit of the exponent is set,
+    /// where `power_0` is `base` and `power_i+1 = mul(power_i, power_i)`.
+    /// The squaring giving the next power is done in parallel with the multiplication
+    /// of the result by the current (selected) power.
+    fn square_and_multiply<T, F>(&self, base: T, exponent: &RadixCiphertext, mul: F) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> T + Sync,
+    {
+        let num_blocks = base.blocks().len();
+        let one: T = self.create_trivial_radix(1u64, num_blocks);
+
+        let exponent_bits = self.bits_of_exponent(exponent);
+        let Some(last_index) = exponent_bits.len().checked_sub(1) else {
+            return one;
+        };
+
+        let mut power = base;
+        let mut result = None;
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let factor = self.unchecked_if_then_else_parallelized(bit, &power, &one);
+
+            let (new_result, next_power) = rayon::join(
+                || match &result {
+                    Some(result) => mul(result, &factor),
+                    None => factor,
+                },
+                || (i != last_index).then(|| mul(&power, &power)),
+            );
+
+            result = Some(new_result);
+            if let Some(next_power) = next_power {
+                power = next_power;
+            }
+        }
+
+        result.unwrap()
+    }
+
+    //==============================================================================================
+    //  Unchecked
+    //==============================================================================================
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// For signed ciphertexts, negative numbers have no square root, the result will be 0.
+    ///
+    /// See [Self::isqrt_parallelized] for an example
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_isqrt_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.blocks().is_empty() {
+            return ct.clone();
+        }
+
+        // Once negative values are replaced by 0, signed values
+        // can be handled as unsigned ones
+        let ct = if T::IS_SIGNED {
+            self.unchecked_scalar_max_parallelized(ct, 0u64)
+        } else {
+            ct.clone()
+        };
+        let num_blocks = ct.blocks().len();
+        let mut remainder = RadixCiphertext::from_blocks(ct.into_blocks());
+
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let num_bits = bits_per_block * num_blocks;
+
+        // Digit-by-digit algorithm, one bit of the result is found per iteration:
+        //
+        // let mut one = 1 << highest_even_bit;
+        // while one != 0 {
+        //     if remainder >= result + one {
+        //         remainder -= result + one;
+        //         result = (result >> 1) + one;
+        //     } else {
+        //         result >>= 1;
+        //     }
+        //     one >>= 2;
+        // }
+        //
+        // The bit of `one` is never set in `result` nor in `result >> 1`, so
+        // the additions only need to set a bit of a single block.
+        let mut result: RadixCiphertext = self.create_trivial_zero_radix(num_blocks);
+        let highest_even_bit = (num_bits - 1) & !1;
+        for bit_index in (0..=highest_even_bit).rev().step_by(2) {
+            let block_index = bit_index / bits_per_block;
+            let bit_in_block = bit_index % bits_per_block;
+
+            // The bit is clear, so it is set by a scalar addition which needs no PBS
+            let mut candidate = result.clone();
+            let block = &mut candidate.blocks[block_index];
+            let degree =
+                (block.degree.get() + (1 << bit_in_block)).min(self.message_modulus().0 - 1);
+            self.key
+                .unchecked_scalar_add_assign(block, 1u8 << bit_in_block);
+            // As the bit was clear, the result still fits in the message space
+            block.degree = Degree::new(degree);
+
+            let (difference, borrowed) =
+                self.unchecked_unsigned_overflowing_sub_parallelized(&remainder, &candidate);
+
+            let (new_remainder, mut shifted_result) = rayon::join(
+                || self.unchecked_if_then_else_parallelized(&borrowed, &remainder, &difference),
+                || self.unchecked_scalar_right_shift_parallelized(&result, 1u32),
+            );
+
+            let set_bit_if_lut = self.key.generate_lookup_table_bivariate(|x, borrowed| {
+                if borrowed == 0 {
+                    x | (1 << bit_in_block)
+                } else {
+                    x
+                }
+            });
+            shifted_result.blocks[block_index] = self.key.unchecked_apply_lookup_table_bivariate(
+                &shifted_result.blocks[block_index],
+                &borrowed.0,
+                &set_bit_if_lut,
+            );
+
+            remainder = new_remainder;
+            result = shifted_result;
+        }
+
+        T::from_blocks(result.blocks)
+    }
+
+    /// Raises `base` to the power of `exponent`, wrapping around on overflow.
+    ///
+    /// See [Self::pow_parallelized] for an example
+    ///
+    /// Expects both inputs to have clean carries
+    pub fn unchecked_pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.square_and_multiply(base.clone(), exponent, |lhs, rhs| {
+            self.unchecked_mul_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Raises `base` to the power of a clear `exponent`, wrapping around on overflow.
+    ///
+    /// See [Self::scalar_pow_parallelized] for an example
+    ///
+    /// Expects base to have clean carries
+    pub fn unchecked_scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if exponent == 0 {
+            return self.create_trivial_radix(1u64, base.blocks().len());
+        }
+
+        // Left-to-right square-and-multiply, as the exponent is clear
+        // only the multiplications for bits that are set are done
+        let mut result = base.clone();
+        for i in (0..exponent.ilog2()).rev() {
+            result = self.unchecked_mul_parallelized(&result, &result);
+            if (exponent >> i) & 1 == 1 {
+                result = self.unchecked_mul_parallelized(&result, base);
+            }
+        }
+
+        result
+    }
+
+    /// Computes `base^exponent mod modulus`
+    ///
+    /// Intermediate products are computed on twice the number of blocks,
+    /// so that they do not overflow before being reduced.
+    ///
+    /// See [Self::modpow_parallelized] for an example
+    ///
+    /// Expects all inputs to have clean carries
+    pub fn unchecked_modpow_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = base.blocks.len();
+        assert_eq!(
+            modulus.blocks.len(),
+            num_blocks,
+            "base and modulus must have the same number of blocks"
+        );
+
+        let wide_modulus = self.extend_radix_with_trivial_zero_blocks_msb(modulus, num_blocks);
+        let mul_mod = |lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            let (lhs, rhs) = rayon::join(
+                || self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks),
+                || self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks),
+            );
+            let product = self.unchecked_mul_parallelized(&lhs, &rhs);
+            let remainder = self.unchecked_rem_parallelized(&product, &wide_modulus);
+            self.trim_radix_blocks_msb(&remainder, num_blocks)
+        };
+
+        let (reduced_base, is_modulus_one) = rayon::join(
+            || self.unchecked_rem_parallelized(base, modulus),
+            || self.unchecked_scalar_eq_parallelized(modulus, 1u64),
+        );
+
+        let mut result = self.square_and_multiply(reduced_base, exponent, mul_mod);
+        // When no multiplication happened (e.g. exponent is 0) the result is 1,
+        // which is not reduced when the modulus is 1
+        self.zero_out_if_condition_equals(&mut result, &is_modulus_one.0, 1);
+        result
+    }
+
+    //==============================================================================================
+    //  Smart
+    //==============================================================================================
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// For signed ciphertexts, negative numbers have no square root, the result will be 0.
+    ///
+    /// See [Self::isqrt_parallelized] for an example
+    pub fn smart_isqrt_parallelized<T>(&self, ct: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.unchecked_isqrt_parallelized(ct)
+    }
+
+    /// Raises `base` to the power of `exponent`, wrapping around on overflow.
+    ///
+    /// See [Self::pow_parallelized] for an example
+    pub fn smart_pow_parallelized<T>(&self, base: &mut T, exponent: &mut RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                if !base.block_carries_are_empty() {
+                    self.full_propagate_parallelized(base);
+                }
+            },
+            || {
+                if !exponent.block_carries_are_empty() {
+                    self.full_propagate_parallelized(exponent);
+                }
+            },
+        );
+
+        self.unchecked_pow_parallelized(base, exponent)
+    }
+
+    /// Raises `base` to the power of a clear `exponent`, wrapping around on overflow.
+    ///
+    /// See [Self::scalar_pow_parallelized] for an example
+    pub fn smart_scalar_pow_parallelized<T>(&self, base: &mut T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !base.block_carries_are_empty() {
+            self.full_propagate_parallelized(base);
+        }
+
+        self.unchecked_scalar_pow_parallelized(base, exponent)
+    }
+
+    /// Computes `base^exponent mod modulus`
+    ///
+    /// See [Self::modpow_parallelized] for an example
+    pub fn smart_modpow_parallelized(
+        &self,
+        base: &mut RadixCiphertext,
+        exponent: &mut RadixCiphertext,
+        modulus: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        for ct in [&mut *base, &mut *exponent, &mut *modulus] {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(ct);
+            }
+        }
+
+        self.unchecked_modpow_parallelized(base, exponent, modulus)
+    }
+
+    //==============================================================================================
+    //  Default
+    //==============================================================================================
+
+    /// Returns the square root of the number, rounded down.
+    ///
+    /// For signed ciphertexts, negative numbers have no square root, the result will be 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg = 200u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 14);
+    /// ```
+    pub fn isqrt_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        self.unchecked_isqrt_parallelized(ct)
+    }
+
+    /// Raises `base` to the power of `exponent`, wrapping around on overflow.
+    ///
+    /// The exponent is always unsigned, the number of multiplications done
+    /// is proportional to its number of bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let base = -3i8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct_base = cks.encrypt_signed(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(exponent.into()));
+    /// ```
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_base;
+        let mut tmp_exponent;
+
+        let (base, exponent) = match (
+            base.block_carries_are_empty(),
+            exponent.block_carries_are_empty(),
+        ) {
+            (true, true) => (base, exponent),
+            (true, false) => {
+                tmp_exponent = exponent.clone();
+                self.full_propagate_parallelized(&mut tmp_exponent);
+                (base, &tmp_exponent)
+            }
+            (false, true) => {
+                tmp_base = base.clone();
+                self.full_propagate_parallelized(&mut tmp_base);
+                (&tmp_base, exponent)
+            }
+            (false, false) => {
+                tmp_base = base.clone();
+                tmp_exponent = exponent.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_base),
+                    || self.full_propagate_parallelized(&mut tmp_exponent),
+                );
+                (&tmp_base, &tmp_exponent)
+            }
+        };
+
+        self.unchecked_pow_parallelized(base, exponent)
+    }
+
+    /// Raises `base` to the power of a clear `exponent`, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let base = 7u8;
+    ///
+    /// let ct = cks.encrypt(base);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct, 3);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(3));
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp = base.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        self.unchecked_scalar_pow_parallelized(base, exponent)
+    }
+
+    /// Computes `base^exponent mod modulus`
+    ///
+    /// # Notes
+    ///
+    /// When the modulus is 0, the result is `base^exponent`, wrapping around on overflow.
+    ///
+    /// This behaviour should not be relied on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let (base, exponent, modulus) = (123u8, 45u8, 211u8);
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    /// let ct_modulus = cks.encrypt(modulus);
+    ///
+    /// let ct_res = sks.modpow_parallelized(&ct_base, &ct_exponent, &ct_modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// let expected = (0..exponent).fold(1u64, |acc, _| acc * u64::from(base) % u64::from(modulus));
+    /// assert_eq!(u64::from(res), expected);
+    /// ```
+    pub fn modpow_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut tmp_base;
+        let mut tmp_exponent;
+        let mut tmp_modulus;
+
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp_base = base.clone();
+            self.full_propagate_parallelized(&mut tmp_base);
+            &tmp_base
+        };
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            tmp_exponent = exponent.clone();
+            self.full_propagate_parallelized(&mut tmp_exponent);
+            &tmp_exponent
+        };
+        let modulus = if modulus.block_carries_are_empty() {
+            modulus
+        } else {
+            tmp_modulus = modulus.clone();
+            self.full_propagate_parallelized(&mut tmp_modulus);
+            &tmp_modulus
+        };
+
+        self.unchecked_modpow_parallelized(base, exponent, modulus)
+    }
+}
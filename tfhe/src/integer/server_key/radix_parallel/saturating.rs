@@ -0,0 +1,514 @@
+use crate::integer::{
+    BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey, SignedRadixCiphertext,
+};
+
+impl ServerKey {
+    /// Returns a boolean block encrypting `true` if the (clean) signed ciphertext is negative
+    fn sign_bit_is_set(&self, ct: &SignedRadixCiphertext) -> BooleanBlock {
+        let half = self.message_modulus().0 / 2;
+        let lut = self.key.generate_lookup_table(|x| u64::from(x >= half));
+        let last_block = ct
+            .blocks
+            .last()
+            .expect("Cannot get the sign of an empty ciphertext");
+        BooleanBlock::new_unchecked(self.key.apply_lookup_table(last_block, &lut))
+    }
+
+    /// Returns an encryption of the minimum signed value if `is_negative` encrypts `true`,
+    /// otherwise an encryption of the maximum signed value
+    fn signed_saturation_value(
+        &self,
+        is_negative: &BooleanBlock,
+        num_blocks: usize,
+    ) -> SignedRadixCiphertext {
+        let msg_mod = self.message_modulus().0;
+        let half = msg_mod / 2;
+
+        // Only two distinct blocks are needed: the low blocks are all 0s (min) or all
+        // (msg_mod - 1) (max), the last block holds the sign bit
+        let low_lut = self
+            .key
+            .generate_lookup_table(|x| if x == 1 { 0 } else { msg_mod - 1 });
+        let high_lut = self
+            .key
+            .generate_lookup_table(|x| if x == 1 { half } else { half - 1 });
+
+        let (low_block, high_block) = rayon::join(
+            || self.key.apply_lookup_table(&is_negative.0, &low_lut),
+            || self.key.apply_lookup_table(&is_negative.0, &high_lut),
+        );
+
+        let mut blocks = vec![low_block; num_blocks - 1];
+        blocks.push(high_block);
+        SignedRadixCiphertext::from(blocks)
+    }
+
+    /// Replaces the signed result by the saturation value when `overflowed` encrypts `true`
+    fn signed_saturate(
+        &self,
+        result: &SignedRadixCiphertext,
+        overflowed: &BooleanBlock,
+        saturate_to_min: &BooleanBlock,
+    ) -> SignedRadixCiphertext {
+        let saturation_value = self.signed_saturation_value(saturate_to_min, result.blocks.len());
+        self.unchecked_if_then_else_parallelized(overflowed, &saturation_value, result)
+    }
+
+    //===========================================================
+    //  Unchecked
+    //===========================================================
+
+    /// Computes homomorphically the addition of two unsigned ciphertexts,
+    /// saturating at the maximum value instead of overflowing
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - The result is returned in a new ciphertext
+    pub fn unchecked_unsigned_saturating_add_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the name number of blocks ({} vs {})",
+            lhs.blocks.len(),
+            rhs.blocks.len()
+        );
+        if lhs.blocks.is_empty() {
+            return lhs.clone();
+        }
+
+        let mut result = lhs.clone();
+        let overflowed = self.overflowing_add_assign_with_carry(&mut result, rhs, None);
+
+        let max: RadixCiphertext = self.create_trivial_max_radix(lhs.blocks.len());
+        self.unchecked_if_then_else_parallelized(&overflowed, &max, &result)
+    }
+
+    /// Computes homomorphically the subtraction of two unsigned ciphertexts,
+    /// saturating at zero instead of overflowing
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - The result is returned in a new ciphertext
+    pub fn unchecked_unsigned_saturating_sub_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut result, overflowed) =
+            self.unchecked_unsigned_overflowing_sub_parallelized(lhs, rhs);
+        self.zero_out_if_condition_equals(&mut result, &overflowed.0, 1);
+        result
+    }
+
+    /// Computes homomorphically the multiplication of two unsigned ciphertexts,
+    /// saturating at the maximum value instead of overflowing
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - The result is returned in a new ciphertext
+    pub fn unchecked_unsigned_saturating_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (result, overflowed) = self.unchecked_unsigned_overflowing_mul_parallelized(lhs, rhs);
+
+        let max: RadixCiphertext = self.create_trivial_max_radix(result.blocks.len());
+        self.unchecked_if_then_else_parallelized(&overflowed, &max, &result)
+    }
+
+    /// Computes homomorphically the addition of two signed ciphertexts,
+    /// saturating at the minimum or maximum value instead of overflowing
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - The result is returned in a new ciphertext
+    pub fn unchecked_signed_saturating_add_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        // An addition can only overflow if both operands have the same sign,
+        // which is then the direction of the overflow
+        let ((result, overflowed), lhs_is_negative) = rayon::join(
+            || self.unchecked_signed_overflowing_add_parallelized(lhs, rhs),
+            || self.sign_bit_is_set(lhs),
+        );
+        self.signed_saturate(&result, &overflowed, &lhs_is_negative)
+    }
+
+    /// Computes homomorphically the subtraction of two signed ciphertexts,
+    /// saturating at the minimum or maximum value instead of overflowing
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - The result is returned in a new ciphertext
+    pub fn unchecked_signed_saturating_sub_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        // A subtraction can only overflow if the operands have different signs,
+        // the direction of the overflow is then given by the sign of lhs
+        let ((result, overflowed), lhs_is_negative) = rayon::join(
+            || self.unchecked_signed_overflowing_sub_parallelized(lhs, rhs),
+            || self.sign_bit_is_set(lhs),
+        );
+        self.signed_saturate(&result, &overflowed, &lhs_is_negative)
+    }
+
+    /// Computes homomorphically the multiplication of two signed ciphertexts,
+    /// saturating at the minimum or maximum value instead of overflowing
+    ///
+    /// - The block carries of the inputs must be empty
+    /// - The result is returned in a new ciphertext
+    pub fn unchecked_signed_saturating_mul_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        // When the multiplication overflows, none of the operands is zero,
+        // so the sign of the true result is the xor of the operands' signs
+        let ((result, overflowed), result_is_negative) = rayon::join(
+            || self.signed_overflowing_mul_parallelized(lhs, rhs),
+            || {
+                let (lhs_is_negative, rhs_is_negative) =
+                    rayon::join(|| self.sign_bit_is_set(lhs), || self.sign_bit_is_set(rhs));
+                self.boolean_bitxor(&lhs_is_negative, &rhs_is_negative)
+            },
+        );
+        self.signed_saturate(&result, &overflowed, &result_is_negative)
+    }
+
+    //===========================================================
+    //  Smart
+    //===========================================================
+
+    /// Computes homomorphically the saturating addition of two unsigned ciphertexts
+    ///
+    /// See [Self::unsigned_saturating_add_parallelized]
+    pub fn smart_unsigned_saturating_add_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.full_propagate_if_needed(lhs, rhs);
+        self.unchecked_unsigned_saturating_add_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the saturating subtraction of two unsigned ciphertexts
+    ///
+    /// See [Self::unsigned_saturating_sub_parallelized]
+    pub fn smart_unsigned_saturating_sub_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.full_propagate_if_needed(lhs, rhs);
+        self.unchecked_unsigned_saturating_sub_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the saturating multiplication of two unsigned ciphertexts
+    ///
+    /// See [Self::unsigned_saturating_mul_parallelized]
+    pub fn smart_unsigned_saturating_mul_parallelized(
+        &self,
+        lhs: &mut RadixCiphertext,
+        rhs: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.full_propagate_if_needed(lhs, rhs);
+        self.unchecked_unsigned_saturating_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the saturating addition of two signed ciphertexts
+    ///
+    /// See [Self::signed_saturating_add_parallelized]
+    pub fn smart_signed_saturating_add_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.full_propagate_if_needed(lhs, rhs);
+        self.unchecked_signed_saturating_add_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the saturating subtraction of two signed ciphertexts
+    ///
+    /// See [Self::signed_saturating_sub_parallelized]
+    pub fn smart_signed_saturating_sub_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.full_propagate_if_needed(lhs, rhs);
+        self.unchecked_signed_saturating_sub_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the saturating multiplication of two signed ciphertexts
+    ///
+    /// See [Self::signed_saturating_mul_parallelized]
+    pub fn smart_signed_saturating_mul_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &mut SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.full_propagate_if_needed(lhs, rhs);
+        self.unchecked_signed_saturating_mul_parallelized(lhs, rhs)
+    }
+
+    //===========================================================
+    //  Default
+    //===========================================================
+
+    /// Computes homomorphically the addition of two unsigned ciphertexts,
+    /// saturating at the maximum value instead of overflowing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn unsigned_saturating_add_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.with_clean_operands(lhs, rhs, |lhs, rhs| {
+            self.unchecked_unsigned_saturating_add_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the subtraction of two unsigned ciphertexts,
+    /// saturating at zero instead of overflowing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg1 = 100u8;
+    /// let msg2 = 200u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn unsigned_saturating_sub_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.with_clean_operands(lhs, rhs, |lhs, rhs| {
+            self.unchecked_unsigned_saturating_sub_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the multiplication of two unsigned ciphertexts,
+    /// saturating at the maximum value instead of overflowing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg1 = 20u8;
+    /// let msg2 = 30u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn unsigned_saturating_mul_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        self.with_clean_operands(lhs, rhs, |lhs, rhs| {
+            self.unchecked_unsigned_saturating_mul_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the addition of two signed ciphertexts,
+    /// saturating at the minimum or maximum value instead of overflowing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn signed_saturating_add_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.with_clean_operands(lhs, rhs, |lhs, rhs| {
+            self.unchecked_signed_saturating_add_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the subtraction of two signed ciphertexts,
+    /// saturating at the minimum or maximum value instead of overflowing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg1 = 100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn signed_saturating_sub_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.with_clean_operands(lhs, rhs, |lhs, rhs| {
+            self.unchecked_signed_saturating_sub_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the multiplication of two signed ciphertexts,
+    /// saturating at the minimum or maximum value instead of overflowing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64, num_blocks);
+    ///
+    /// let msg1 = -20i8;
+    /// let msg2 = 30i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn signed_saturating_mul_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.with_clean_operands(lhs, rhs, |lhs, rhs| {
+            self.unchecked_signed_saturating_mul_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Propagates the carries of both operands if they are not empty
+    fn full_propagate_if_needed<T>(&self, lhs: &mut T, rhs: &mut T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                if !lhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(lhs);
+                }
+            },
+            || {
+                if !rhs.block_carries_are_empty() {
+                    self.full_propagate_parallelized(rhs);
+                }
+            },
+        );
+    }
+
+    /// Calls `func` with operands that have empty carries, cloning and propagating
+    /// the inputs only if needed
+    fn with_clean_operands<T, F>(&self, lhs: &T, rhs: &T, func: F) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: FnOnce(&T, &T) -> T,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        func(lhs, rhs)
+    }
+}
@@ -9,7 +9,9 @@ pub(crate) mod test_div_rem;
 pub(crate) mod test_ilog2;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+pub(crate) mod test_pow;
 pub(crate) mod test_rotate;
+pub(crate) mod test_saturating;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
 pub(crate) mod test_scalar_comparison;
@@ -21,6 +23,7 @@ pub(crate) mod test_scalar_sub;
 pub(crate) mod test_shift;
 pub(crate) mod test_sub;
 pub(crate) mod test_vector_comparisons;
+pub(crate) mod test_widening_mul;
 
 use crate::core_crypto::prelude::SignedInteger;
 use crate::integer::keycache::KEY_CACHE;
@@ -0,0 +1,136 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_signed::{signed_mul_under_modulus, NB_CTXT};
+use crate::integer::server_key::radix_parallel::tests_unsigned::test_pow::clear_isqrt;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{
+    IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey, SignedRadixCiphertext,
+};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use crate::shortint::PBSParameters;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_signed_default_isqrt {
+    // Uses subtraction with borrow and many-luts, so 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_signed_default_pow {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+
+fn integer_signed_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::isqrt_parallelized);
+    signed_default_isqrt_test(param, executor);
+}
+
+fn integer_signed_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::pow_parallelized);
+    signed_default_pow_test(param, executor);
+}
+
+pub(crate) fn signed_default_isqrt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a SignedRadixCiphertext, SignedRadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let input_values = [0i64, -1, -modulus, modulus - 1]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| rng.gen_range(-modulus..modulus)))
+        .collect::<Vec<_>>();
+
+    for clear in input_values {
+        let ctxt = cks.encrypt_signed(clear);
+
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check, msg: {clear}");
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        // Negative values have no square root, 0 is returned
+        let expected_result = clear_isqrt(clear.max(0) as u64) as i64;
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt of {clear}, \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn signed_default_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a RadixCiphertext),
+        SignedRadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..nb_tests_smaller {
+        let clear_base = rng.gen_range(-modulus..modulus);
+        let clear_exponent = rng.gen_range(0..2 * modulus as u64);
+
+        let ctxt_base = cks.encrypt_signed(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent));
+        let tmp = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let expected_result = (0..clear_exponent).fold(1i64, |acc, _| {
+            signed_mul_under_modulus(acc, clear_base, modulus)
+        });
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
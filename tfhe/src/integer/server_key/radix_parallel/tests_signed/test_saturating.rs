@@ -0,0 +1,130 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_signed::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_signed_unchecked_saturating_add);
+create_parameterized_test!(integer_signed_unchecked_saturating_sub);
+create_parameterized_test!(integer_signed_unchecked_saturating_mul);
+create_parameterized_test!(integer_signed_default_saturating_add);
+create_parameterized_test!(integer_signed_default_saturating_sub);
+create_parameterized_test!(integer_signed_default_saturating_mul);
+
+fn integer_signed_unchecked_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_signed_saturating_add_parallelized);
+    signed_saturating_op_test(param, executor, |a, b| a + b);
+}
+
+fn integer_signed_unchecked_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_signed_saturating_sub_parallelized);
+    signed_saturating_op_test(param, executor, |a, b| a - b);
+}
+
+fn integer_signed_unchecked_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_signed_saturating_mul_parallelized);
+    signed_saturating_op_test(param, executor, |a, b| a * b);
+}
+
+fn integer_signed_default_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::signed_saturating_add_parallelized);
+    signed_saturating_op_test(param, executor, |a, b| a + b);
+}
+
+fn integer_signed_default_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::signed_saturating_sub_parallelized);
+    signed_saturating_op_test(param, executor, |a, b| a - b);
+}
+
+fn integer_signed_default_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::signed_saturating_mul_parallelized);
+    signed_saturating_op_test(param, executor, |a, b| a * b);
+}
+
+/// Tests a saturating operation against the exact result of `clear_op`
+/// clamped to `[-modulus, modulus)`
+pub(crate) fn signed_saturating_op_test<P, T, F>(param: P, mut executor: T, clear_op: F)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a SignedRadixCiphertext),
+        SignedRadixCiphertext,
+    >,
+    F: Fn(i64, i64) -> i64,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let edge_cases = [
+        (0, 0),
+        (modulus - 1, 1),
+        (-modulus, -1),
+        (-modulus, modulus - 1),
+        (modulus - 1, -modulus),
+        (-modulus, -modulus),
+    ];
+    let random_cases = (0..nb_tests).map(|_| {
+        (
+            rng.gen_range(-modulus..modulus),
+            rng.gen_range(-modulus..modulus),
+        )
+    });
+
+    for (clear_0, clear_1) in edge_cases.into_iter().chain(random_cases) {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let expected_result = clear_op(clear_0, clear_1).clamp(-modulus, modulus - 1);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid saturating result for {clear_0} and {clear_1}, \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_signed::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_signed_unchecked_widening_mul);
+create_parameterized_test!(integer_signed_unchecked_mul_high);
+create_parameterized_test!(integer_signed_default_widening_mul);
+create_parameterized_test!(integer_signed_default_mul_high);
+
+fn integer_signed_unchecked_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(
+        &ServerKey::unchecked_widening_mul_parallelized::<SignedRadixCiphertext>,
+    );
+    signed_widening_mul_test(param, executor, false);
+}
+
+fn integer_signed_unchecked_mul_high<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(
+        &ServerKey::unchecked_mul_high_parallelized::<SignedRadixCiphertext>,
+    );
+    signed_widening_mul_test(param, executor, true);
+}
+
+fn integer_signed_default_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::widening_mul_parallelized::<SignedRadixCiphertext>);
+    signed_widening_mul_test(param, executor, false);
+}
+
+fn integer_signed_default_mul_high<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::mul_high_parallelized::<SignedRadixCiphertext>);
+    signed_widening_mul_test(param, executor, true);
+}
+
+/// Tests the full product of two ciphertexts, or only its high half when `high_only` is true
+pub(crate) fn signed_widening_mul_test<P, T>(param: P, mut executor: T, high_only: bool)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a SignedRadixCiphertext),
+        SignedRadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let expected_num_blocks = if high_only { NB_CTXT } else { 2 * NB_CTXT };
+
+    let edge_cases = [
+        (0, 0),
+        (modulus - 1, -1),
+        (-modulus, -1),
+        (-modulus, modulus - 1),
+        (-modulus, -modulus),
+    ];
+    let random_cases = (0..nb_tests).map(|_| {
+        (
+            rng.gen_range(-modulus..modulus),
+            rng.gen_range(-modulus..modulus),
+        )
+    });
+
+    for (clear_0, clear_1) in edge_cases.into_iter().chain(random_cases) {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+        assert_eq!(ct_res.blocks.len(), expected_num_blocks);
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let full_product = clear_0 * clear_1;
+        let expected_result = if high_only {
+            // The full range is 2 * modulus, this is an arithmetic shift
+            full_product.div_euclid(2 * modulus)
+        } else {
+            full_product
+        };
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid widening mul result for {clear_0} * {clear_1}, \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
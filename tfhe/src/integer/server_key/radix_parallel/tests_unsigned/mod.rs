@@ -1,5 +1,6 @@
 mod modulus_switch_compression;
 pub(crate) mod test_add;
+pub(crate) mod test_bit_decomposition;
 pub(crate) mod test_bitwise_op;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
@@ -8,7 +9,10 @@ pub(crate) mod test_div_mod;
 pub(crate) mod test_ilog2;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+pub(crate) mod test_oblivious_access;
+pub(crate) mod test_pow;
 pub(crate) mod test_rotate;
+pub(crate) mod test_saturating;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
 pub(crate) mod test_scalar_comparison;
@@ -23,6 +27,7 @@ pub(crate) mod test_sub;
 pub(crate) mod test_sum;
 pub(crate) mod test_vector_comparisons;
 pub(crate) mod test_vector_find;
+pub(crate) mod test_widening_mul;
 
 use super::tests_cases_unsigned::*;
 use crate::core_crypto::prelude::UnsignedInteger;
@@ -555,6 +560,8 @@ impl<T> NotTuple for &mut crate::integer::ciphertext::BaseSignedRadixCiphertext<
 
 impl<T> NotTuple for &Vec<T> {}
 
+impl<T> NotTuple for &[T] {}
+
 /// For unary operations
 ///
 /// Note, we need to `NotTuple` constraint to avoid conflicts with binary or ternary operations
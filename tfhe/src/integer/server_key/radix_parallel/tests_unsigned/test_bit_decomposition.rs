@@ -0,0 +1,229 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, panic_if_any_block_is_not_clean, unsigned_modulus,
+    CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_unchecked_to_bits);
+create_parameterized_test!(integer_default_to_bits);
+create_parameterized_test!(integer_default_from_bits);
+create_parameterized_test!(integer_default_concat);
+create_parameterized_test!(integer_unchecked_split_at_bit);
+create_parameterized_test!(integer_default_split_at_bit);
+
+fn integer_unchecked_to_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_to_bits_parallelized::<RadixCiphertext>);
+    to_bits_test(param, executor);
+}
+
+fn integer_default_to_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::to_bits_parallelized::<RadixCiphertext>);
+    to_bits_test(param, executor);
+}
+
+fn integer_default_from_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::from_bits_parallelized::<RadixCiphertext>);
+    from_bits_test(param, executor);
+}
+
+fn integer_default_concat<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::concat_parallelized);
+    concat_test(param, executor);
+}
+
+fn integer_unchecked_split_at_bit<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_split_at_bit_parallelized);
+    split_at_bit_test(param, executor);
+}
+
+fn integer_default_split_at_bit<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::split_at_bit_parallelized);
+    split_at_bit_test(param, executor);
+}
+
+pub(crate) fn to_bits_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, Vec<BooleanBlock>>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let num_bits = modulus.ilog2() as usize;
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen_range(0..modulus);
+        let ctxt = cks.encrypt(clear);
+
+        let bits = executor.execute(&ctxt);
+        assert_eq!(bits.len(), num_bits);
+
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(
+                cks.decrypt_bool(bit),
+                (clear >> i) & 1 == 1,
+                "Invalid bit {i} for {clear}"
+            );
+        }
+    }
+}
+
+pub(crate) fn from_bits_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a [BooleanBlock], RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let bits_per_block = cks.parameters().message_modulus().0.ilog2() as usize;
+    let max_num_bits = NB_CTXT * bits_per_block;
+
+    for _ in 0..nb_tests {
+        let num_bits = rng.gen_range(1..=max_num_bits);
+        let clear = rng.gen_range(0..(1u64 << num_bits));
+        let bits = (0..num_bits)
+            .map(|i| cks.encrypt_bool((clear >> i) & 1 == 1))
+            .collect::<Vec<_>>();
+
+        let ct_res = executor.execute(&bits);
+        assert_eq!(ct_res.blocks.len(), num_bits.div_ceil(bits_per_block));
+        panic_if_any_block_is_not_clean(&ct_res, &cks);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, clear,
+            "Invalid from_bits result for {num_bits} bits"
+        );
+    }
+}
+
+pub(crate) fn concat_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_hi = rng.gen_range(0..modulus);
+        let clear_lo = rng.gen_range(0..modulus);
+
+        let ctxt_hi = cks.encrypt(clear_hi);
+        let ctxt_lo = cks.encrypt(clear_lo);
+
+        let ct_res = executor.execute((&ctxt_hi, &ctxt_lo));
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted,
+            clear_hi * modulus + clear_lo,
+            "Invalid concatenation of {clear_hi} and {clear_lo}"
+        );
+    }
+}
+
+pub(crate) fn split_at_bit_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, usize), (RadixCiphertext, RadixCiphertext)>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let num_bits = modulus.ilog2() as usize;
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen_range(0..modulus);
+        let bit = rng.gen_range(0..=num_bits);
+
+        let ctxt = cks.encrypt(clear);
+
+        let (ct_lo, ct_hi) = executor.execute((&ctxt, bit));
+
+        let lo: u64 = if ct_lo.blocks.is_empty() {
+            0
+        } else {
+            cks.decrypt(&ct_lo)
+        };
+        let hi: u64 = if ct_hi.blocks.is_empty() {
+            0
+        } else {
+            cks.decrypt(&ct_hi)
+        };
+        assert_eq!(
+            lo,
+            clear % (1 << bit),
+            "Invalid low part of {clear} split at {bit}"
+        );
+        assert_eq!(
+            hi,
+            clear >> bit,
+            "Invalid high part of {clear} split at {bit}"
+        );
+    }
+}
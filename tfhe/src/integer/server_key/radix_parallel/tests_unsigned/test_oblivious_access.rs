@@ -0,0 +1,256 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, panic_if_any_block_is_not_clean_or_trivial, unsigned_modulus,
+    CpuFunctionExecutor, MAX_VEC_LEN, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_unchecked_oblivious_read);
+create_parameterized_test!(integer_unchecked_oblivious_read_clear);
+create_parameterized_test!(integer_unchecked_oblivious_write);
+
+create_parameterized_test!(integer_default_oblivious_read);
+create_parameterized_test!(integer_default_oblivious_read_clear);
+create_parameterized_test!(integer_default_oblivious_write);
+
+fn integer_unchecked_oblivious_read<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(
+        &ServerKey::unchecked_oblivious_read_parallelized::<RadixCiphertext>,
+    );
+    oblivious_read_test_case(param, executor, false);
+}
+
+fn integer_unchecked_oblivious_read_clear<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_oblivious_read_clear_parallelized::<u64>);
+    oblivious_read_clear_test_case(param, executor, false);
+}
+
+fn integer_unchecked_oblivious_write<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(
+        &ServerKey::unchecked_oblivious_write_parallelized::<RadixCiphertext>,
+    );
+    oblivious_write_test_case(param, executor, false);
+}
+
+fn integer_default_oblivious_read<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::oblivious_read_parallelized::<RadixCiphertext>);
+    oblivious_read_test_case(param, executor, true);
+}
+
+fn integer_default_oblivious_read_clear<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::oblivious_read_clear_parallelized::<u64>);
+    oblivious_read_clear_test_case(param, executor, true);
+}
+
+fn integer_default_oblivious_write<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::oblivious_write_parallelized::<RadixCiphertext>);
+    oblivious_write_test_case(param, executor, true);
+}
+
+/// Encrypts the index, when `with_carries` is true, the returned ciphertext
+/// has non-empty carries (the encrypted value is still `clear_index`)
+fn encrypt_index(
+    cks: &RadixClientKey,
+    sks: &ServerKey,
+    clear_index: u64,
+    modulus: u64,
+    with_carries: bool,
+    rng: &mut ThreadRng,
+) -> RadixCiphertext {
+    if with_carries {
+        let offset = rng.gen_range(1..modulus);
+        let mut ct = cks.encrypt((clear_index + modulus - offset) % modulus);
+        sks.unchecked_scalar_add_assign(&mut ct, offset);
+        ct
+    } else {
+        cks.encrypt(clear_index)
+    }
+}
+
+pub(crate) fn oblivious_read_test_case<P, T>(param: P, mut executor: T, with_carries: bool)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a [RadixCiphertext], &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        // Also read past the end of the slice, which must return 0
+        let clear_index = rng.gen_range(0..(num_values as u64 + 2).min(modulus));
+        let index = encrypt_index(&cks, &sks, clear_index, modulus, with_carries, &mut rng);
+
+        let result = executor.execute((&cts, &index));
+        let result_2 = executor.execute((&cts, &index));
+        assert_eq!(result, result_2, "Failed determinism check");
+        panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+        let expected = clears.get(clear_index as usize).copied().unwrap_or(0);
+        let decrypted: u64 = cks.decrypt(&result);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid oblivious read at index {clear_index} of {clears:?}"
+        );
+    }
+}
+
+pub(crate) fn oblivious_read_clear_test_case<P, T>(param: P, mut executor: T, with_carries: bool)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a [u64], &'a RadixCiphertext, usize), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // empty table
+    {
+        let index = cks.encrypt(rng.gen_range(0..modulus));
+        let result = executor.execute((&[], &index, NB_CTXT));
+        assert!(result.is_trivial());
+        let decrypted: u64 = cks.decrypt(&result);
+        assert_eq!(decrypted, 0);
+    }
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let table = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+
+        let clear_index = rng.gen_range(0..(num_values as u64 + 2).min(modulus));
+        let index = encrypt_index(&cks, &sks, clear_index, modulus, with_carries, &mut rng);
+
+        let result = executor.execute((&table, &index, NB_CTXT));
+        let result_2 = executor.execute((&table, &index, NB_CTXT));
+        assert_eq!(result, result_2, "Failed determinism check");
+        assert_eq!(result.blocks.len(), NB_CTXT);
+        panic_if_any_block_is_not_clean_or_trivial(&result, &cks);
+
+        let expected = table.get(clear_index as usize).copied().unwrap_or(0);
+        let decrypted: u64 = cks.decrypt(&result);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid oblivious read at index {clear_index} of {table:?}"
+        );
+    }
+}
+
+pub(crate) fn oblivious_write_test_case<P, T>(param: P, mut executor: T, with_carries: bool)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut [RadixCiphertext],
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let mut cts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        // Also write past the end of the slice, which must leave it unchanged
+        let clear_index = rng.gen_range(0..(num_values as u64 + 2).min(modulus));
+        let index = encrypt_index(&cks, &sks, clear_index, modulus, with_carries, &mut rng);
+
+        let clear_value = rng.gen_range(0..modulus);
+        let value = cks.encrypt(clear_value);
+
+        executor.execute((&mut cts, &index, &value));
+
+        if let Some(clear) = clears.get_mut(clear_index as usize) {
+            *clear = clear_value;
+        }
+        for ct in &cts {
+            panic_if_any_block_is_not_clean_or_trivial(ct, &cks);
+        }
+        let decrypted = cts
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decrypted, clears,
+            "Invalid oblivious write of {clear_value} at index {clear_index}"
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_isqrt {
+    // Uses subtraction with borrow and many-luts, so 1_1 parameters are not supported
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_default_pow {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+    PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+create_parameterized_test!(integer_default_scalar_pow);
+create_parameterized_test!(integer_default_modpow {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64
+});
+
+fn integer_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::isqrt_parallelized);
+    default_isqrt_test(param, executor);
+}
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::pow_parallelized);
+    default_pow_test(param, executor);
+}
+
+fn integer_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::scalar_pow_parallelized);
+    default_scalar_pow_test(param, executor);
+}
+
+fn integer_default_modpow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::modpow_parallelized);
+    default_modpow_test(param, executor);
+}
+
+pub(crate) fn clear_isqrt(value: u64) -> u64 {
+    // Search the largest root such that root * root <= value
+    let (mut low, mut high) = (0u64, 1u64 << 32);
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if mid * mid <= value {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+fn clear_modpow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut power = base % modulus;
+    let mut exponent = exponent;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = result * power % modulus;
+        }
+        power = power * power % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+pub(crate) fn default_isqrt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    let input_values = [0u64, 1, modulus - 1]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| rng.gen::<u64>() % modulus))
+        .collect::<Vec<_>>();
+
+    for clear in input_values {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check, msg: {clear}");
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_isqrt(clear);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt of {clear}, \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_base = rng.gen::<u64>() % modulus;
+        let clear_exponent = rng.gen::<u64>() % modulus;
+
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent));
+        let tmp = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_base.wrapping_pow(clear_exponent as u32) % modulus;
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_scalar_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u32), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for clear_exponent in [0u32, 1]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| rng.gen_range(2..64)))
+    {
+        let clear_base = rng.gen::<u64>() % modulus;
+
+        let ctxt_base = cks.encrypt(clear_base);
+
+        let ct_res = executor.execute((&ctxt_base, clear_exponent));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_base.wrapping_pow(clear_exponent) % modulus;
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_modpow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    // A modulus of 1 gives 0, even for a null exponent
+    let cases = [(rng.gen::<u64>() % modulus, 0u64, 1u64)]
+        .into_iter()
+        .chain((0..nb_tests_smaller).map(|_| {
+            (
+                rng.gen::<u64>() % modulus,
+                rng.gen::<u64>() % modulus,
+                rng.gen_range(1..modulus),
+            )
+        }))
+        .collect::<Vec<_>>();
+
+    for (clear_base, clear_exponent, clear_modulus) in cases {
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+        let ctxt_modulus = cks.encrypt(clear_modulus);
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent, &ctxt_modulus));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_modpow(clear_base, clear_exponent, clear_modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.modpow({clear_exponent}, {clear_modulus}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_unchecked_saturating_add);
+create_parameterized_test!(integer_unchecked_saturating_sub);
+create_parameterized_test!(integer_unchecked_saturating_mul);
+create_parameterized_test!(integer_default_saturating_add);
+create_parameterized_test!(integer_default_saturating_sub);
+create_parameterized_test!(integer_default_saturating_mul);
+
+fn integer_unchecked_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_unsigned_saturating_add_parallelized);
+    saturating_op_test(param, executor, u64::checked_add);
+}
+
+fn integer_unchecked_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_unsigned_saturating_sub_parallelized);
+    saturating_op_test(param, executor, u64::checked_sub);
+}
+
+fn integer_unchecked_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_unsigned_saturating_mul_parallelized);
+    saturating_op_test(param, executor, u64::checked_mul);
+}
+
+fn integer_default_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_saturating_add_parallelized);
+    saturating_op_test(param, executor, u64::checked_add);
+}
+
+fn integer_default_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_saturating_sub_parallelized);
+    saturating_op_test(param, executor, u64::checked_sub);
+}
+
+fn integer_default_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_saturating_mul_parallelized);
+    saturating_op_test(param, executor, u64::checked_mul);
+}
+
+/// Tests a saturating operation against the result of `clear_op` clamped to `[0, modulus)`,
+/// `clear_op` returns None only when the result is below 0
+pub(crate) fn saturating_op_test<P, T, F>(param: P, mut executor: T, clear_op: F)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+    F: Fn(u64, u64) -> Option<u64>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    let edge_cases = [
+        (0, 0),
+        (modulus - 1, 1),
+        (1, modulus - 1),
+        (modulus - 1, modulus - 1),
+    ];
+    let random_cases =
+        (0..nb_tests).map(|_| (rng.gen_range(0..modulus), rng.gen_range(0..modulus)));
+
+    for (clear_0, clear_1) in edge_cases.into_iter().chain(random_cases) {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_op(clear_0, clear_1).map_or(0, |r| r.min(modulus - 1));
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid saturating result for {clear_0} and {clear_1}, \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_unchecked_widening_mul);
+create_parameterized_test!(integer_unchecked_mul_high);
+create_parameterized_test!(integer_default_widening_mul);
+create_parameterized_test!(integer_default_mul_high);
+
+fn integer_unchecked_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(
+        &ServerKey::unchecked_widening_mul_parallelized::<RadixCiphertext>,
+    );
+    widening_mul_test(param, executor, false);
+}
+
+fn integer_unchecked_mul_high<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::unchecked_mul_high_parallelized::<RadixCiphertext>);
+    widening_mul_test(param, executor, true);
+}
+
+fn integer_default_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::widening_mul_parallelized::<RadixCiphertext>);
+    widening_mul_test(param, executor, false);
+}
+
+fn integer_default_mul_high<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::mul_high_parallelized::<RadixCiphertext>);
+    widening_mul_test(param, executor, true);
+}
+
+/// Tests the full product of two ciphertexts, or only its high half when `high_only` is true
+pub(crate) fn widening_mul_test<P, T>(param: P, mut executor: T, high_only: bool)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    let expected_num_blocks = if high_only { NB_CTXT } else { 2 * NB_CTXT };
+
+    let edge_cases = [(0, 0), (modulus - 1, 1), (modulus - 1, modulus - 1)];
+    let random_cases =
+        (0..nb_tests).map(|_| (rng.gen_range(0..modulus), rng.gen_range(0..modulus)));
+
+    for (clear_0, clear_1) in edge_cases.into_iter().chain(random_cases) {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+        assert_eq!(ct_res.blocks.len(), expected_num_blocks);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let full_product = clear_0 * clear_1;
+        let expected_result = if high_only {
+            full_product / modulus
+        } else {
+            full_product
+        };
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid widening mul result for {clear_0} * {clear_1}, \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
@@ -1067,7 +1067,7 @@ impl ServerKey {
     /// otherwise it will be 0.
     ///
     /// Requires ct to have empty carries
-    fn compute_equality_selectors<T, Iter, Clear>(
+    pub(super) fn compute_equality_selectors<T, Iter, Clear>(
         &self,
         ct: &T,
         possible_input_values: Iter,
@@ -1139,7 +1139,7 @@ impl ServerKey {
     ///
     /// - Resulting radix ciphertexts have their block packed, thus they will have ceil (numb_blocks
     ///   / 2) elements
-    fn create_possible_results<T, Iter, Clear>(
+    pub(super) fn create_possible_results<T, Iter, Clear>(
         &self,
         num_blocks: usize,
         possible_outputs: Iter,
@@ -1210,7 +1210,32 @@ impl ServerKey {
     /// The elements in the one hot vector have their block packed.
     ///
     /// The returned result has non packed blocks
-    fn aggregate_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
+    pub(super) fn aggregate_one_hot_vector<T>(&self, one_hot_vector: Vec<T>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let result = self.sum_one_hot_vector(one_hot_vector);
+
+        let unpacked_blocks = result
+            .blocks()
+            .par_iter()
+            .flat_map(|block| -> [Ciphertext; 2] {
+                rayon::join(
+                    || self.key.message_extract(block),
+                    || self.key.carry_extract(block),
+                )
+                .into()
+            })
+            .collect::<Vec<_>>();
+        T::from_blocks(unpacked_blocks)
+    }
+
+    /// Sums a one-hot vector of radix ciphertexts
+    /// (i.e. at most one of the vector element is non-zero).
+    ///
+    /// The blocks of the returned result are not cleaned, a PBS has to be applied on
+    /// each of them before doing further computations.
+    pub(super) fn sum_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
     where
         T: IntegerRadixCiphertext,
     {
@@ -1255,19 +1280,7 @@ impl ServerKey {
                 self.unchecked_add_assign(&mut result, r);
             }
         }
-
-        let unpacked_blocks = result
-            .blocks()
-            .par_iter()
-            .flat_map(|block| -> [Ciphertext; 2] {
-                rayon::join(
-                    || self.key.message_extract(block),
-                    || self.key.carry_extract(block),
-                )
-                .into()
-            })
-            .collect::<Vec<_>>();
-        T::from_blocks(unpacked_blocks)
+        result
     }
 
     /// Only keeps at most one Ciphertext that encrypts 1
@@ -6,13 +6,22 @@
 #[cfg(all(test, feature = "experimental"))]
 mod test;
 
+use crate::integer::backward_compatibility::wopbs::WopbsKeyVersions;
 use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(WopbsKeyVersions)]
 pub struct WopbsKey {
     wopbs_key: crate::shortint::wopbs::WopbsKey,
 }
 
+impl AsRef<crate::shortint::wopbs::WopbsKey> for WopbsKey {
+    fn as_ref(&self) -> &crate::shortint::wopbs::WopbsKey {
+        &self.wopbs_key
+    }
+}
+
 #[cfg(feature = "experimental")]
 pub use experimental::*;
 
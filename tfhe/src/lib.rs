@@ -127,6 +127,9 @@ mod test_user_docs;
 #[cfg(feature = "strings")]
 pub mod strings;
 
+#[cfg(feature = "hash")]
+pub mod hash;
+
 #[cfg(feature = "integer")]
 /// cbindgen:ignore
 pub(crate) mod high_level_api;
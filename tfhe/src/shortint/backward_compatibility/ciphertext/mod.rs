@@ -14,8 +14,8 @@ pub enum MaxNoiseLevelVersions {
 }
 
 #[derive(VersionsDispatch)]
-pub enum NoiseLevelVersions {
-    V0(NoiseLevel),
+pub enum SerializableNoiseLevelVersions {
+    V0(SerializableNoiseLevel),
 }
 
 #[derive(VersionsDispatch)]
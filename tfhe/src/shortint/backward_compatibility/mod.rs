@@ -7,3 +7,4 @@ pub mod list_compression;
 pub mod parameters;
 pub mod public_key;
 pub mod server_key;
+pub mod wopbs;
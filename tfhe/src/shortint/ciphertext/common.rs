@@ -50,7 +50,7 @@ impl MaxNoiseLevel {
     }
 
     pub const fn validate(&self, noise_level: NoiseLevel) -> Result<(), CheckError> {
-        if noise_level.0 > self.0 {
+        if noise_level.level > self.0 {
             return Err(CheckError::NoiseTooBig {
                 noise_level,
                 max_noise_level: *self,
@@ -61,28 +61,105 @@ impl MaxNoiseLevel {
 }
 
 /// This tracks the amount of noise in a ciphertext.
-#[derive(
-    Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize, Versionize,
-)]
-#[versionize(NoiseLevelVersions)]
-pub struct NoiseLevel(u64);
+///
+/// The level also records, when it is known, how much of it comes from fresh encryptions with the
+/// client key, the rest coming from PBS outputs. This origin is not serialized and is ignored when
+/// comparing noise levels.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Versionize)]
+#[serde(from = "SerializableNoiseLevel", into = "SerializableNoiseLevel")]
+#[versionize(convert = "SerializableNoiseLevel")]
+pub struct NoiseLevel {
+    level: u64,
+    // Part of the level coming from fresh encryptions, None if the origin of the noise is unknown
+    encryption_level: Option<u64>,
+}
 
 impl NoiseLevel {
-    pub const NOMINAL: Self = Self(1);
-    pub const ZERO: Self = Self(0);
+    pub const NOMINAL: Self = Self {
+        level: 1,
+        encryption_level: None,
+    };
+    pub const ZERO: Self = Self {
+        level: 0,
+        encryption_level: Some(0),
+    };
     // As a safety measure the unknown noise level is set to the max value
-    pub const UNKNOWN: Self = Self(u64::MAX);
+    pub const UNKNOWN: Self = Self {
+        level: u64::MAX,
+        encryption_level: None,
+    };
+    /// The [`Self::NOMINAL`] level of a fresh encryption with the client key
+    pub(crate) const ENCRYPTED: Self = Self {
+        level: 1,
+        encryption_level: Some(1),
+    };
+    /// The [`Self::NOMINAL`] level of the output of a PBS
+    pub(crate) const BOOTSTRAPPED: Self = Self {
+        level: 1,
+        encryption_level: Some(0),
+    };
 }
 
 impl NoiseLevel {
     pub fn get(&self) -> u64 {
-        self.0
+        self.level
+    }
+
+    /// Returns the part of the level coming from fresh encryptions with the client key, the rest
+    /// coming from PBS outputs, or `None` if the origin of the noise is unknown
+    pub(crate) fn encryption_level(&self) -> Option<u64> {
+        self.encryption_level
+    }
+}
+
+impl PartialEq for NoiseLevel {
+    fn eq(&self, other: &Self) -> bool {
+        self.level == other.level
+    }
+}
+
+impl Eq for NoiseLevel {}
+
+impl PartialOrd for NoiseLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NoiseLevel {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.level.cmp(&other.level)
+    }
+}
+
+/// The serialized form of a [`NoiseLevel`], without the origin of the noise
+#[derive(Copy, Clone, Serialize, Deserialize, Versionize)]
+#[serde(rename = "NoiseLevel")]
+#[versionize(SerializableNoiseLevelVersions)]
+pub struct SerializableNoiseLevel(u64);
+
+impl From<NoiseLevel> for SerializableNoiseLevel {
+    fn from(value: NoiseLevel) -> Self {
+        Self(value.level)
+    }
+}
+
+impl From<SerializableNoiseLevel> for NoiseLevel {
+    fn from(value: SerializableNoiseLevel) -> Self {
+        Self {
+            level: value.0,
+            encryption_level: None,
+        }
     }
 }
 
 impl std::ops::AddAssign for NoiseLevel {
     fn add_assign(&mut self, rhs: Self) {
-        self.0 = self.0.saturating_add(rhs.0);
+        self.level = self.level.saturating_add(rhs.level);
+        self.encryption_level = self
+            .encryption_level
+            .zip(rhs.encryption_level)
+            .map(|(lhs, rhs)| lhs.saturating_add(rhs));
     }
 }
 
@@ -97,7 +174,10 @@ impl std::ops::Add for NoiseLevel {
 
 impl std::ops::MulAssign<u64> for NoiseLevel {
     fn mul_assign(&mut self, rhs: u64) {
-        self.0 = self.0.saturating_mul(rhs);
+        self.level = self.level.saturating_mul(rhs);
+        self.encryption_level = self
+            .encryption_level
+            .map(|encryption_level| encryption_level.saturating_mul(rhs));
     }
 }
 
@@ -256,11 +336,15 @@ mod tests {
 
         let mut rng = thread_rng();
 
-        assert_eq!(NoiseLevel::UNKNOWN.0, u64::MAX);
+        assert_eq!(NoiseLevel::UNKNOWN.get(), u64::MAX);
 
         let max_noise_level = NoiseLevel::UNKNOWN;
         let random_addend = rng.gen::<u64>();
-        let add = max_noise_level + NoiseLevel(random_addend);
+        let add = max_noise_level
+            + NoiseLevel {
+                level: random_addend,
+                encryption_level: Some(0),
+            };
         assert_eq!(add, NoiseLevel::UNKNOWN);
 
         let random_positive_multiplier = rng.gen_range(1u64..=u64::MAX);
@@ -268,6 +352,25 @@ mod tests {
         assert_eq!(mul, NoiseLevel::UNKNOWN);
     }
 
+    #[test]
+    fn test_noise_level_origin() {
+        let level = NoiseLevel::ENCRYPTED * 3 + NoiseLevel::BOOTSTRAPPED * 2 + NoiseLevel::ZERO;
+        assert_eq!(level, NoiseLevel::NOMINAL * 5);
+        assert_eq!(level.encryption_level(), Some(3));
+
+        // The origin is lost as soon as a noise of unknown origin is added
+        let level = level + NoiseLevel::NOMINAL;
+        assert_eq!(level.get(), 6);
+        assert_eq!(level.encryption_level(), None);
+
+        // The origin is not serialized
+        let serialized = bincode::serialize(&NoiseLevel::ENCRYPTED).unwrap();
+        assert_eq!(serialized, bincode::serialize(&1u64).unwrap());
+        let deserialized: NoiseLevel = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, NoiseLevel::NOMINAL);
+        assert_eq!(deserialized.encryption_level(), None);
+    }
+
     #[test]
     fn test_max_noise_level_from_msg_carry_modulus() {
         let max_noise_level =
@@ -1,7 +1,9 @@
 use super::Degree;
 use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
 use crate::core_crypto::algorithms::verify_lwe_compact_ciphertext_list;
-use crate::core_crypto::prelude::{LweCiphertextCount, LweCiphertextListParameters};
+use crate::core_crypto::prelude::{
+    LweCiphertextCount, LweCiphertextListParameters, LweCompactCiphertextListOwned,
+};
 use crate::shortint::backward_compatibility::ciphertext::ProvenCompactCiphertextListVersions;
 use crate::shortint::ciphertext::CompactCiphertextList;
 use crate::shortint::parameters::{
@@ -11,7 +13,8 @@ use crate::shortint::parameters::{
 };
 use crate::shortint::{Ciphertext, CompactPublicKey};
 use crate::zk::{
-    CompactPkeCrs, CompactPkeProof, CompactPkeZkScheme, ZkMSBZeroPaddingBitCount,
+    CompactPkeCrs, CompactPkeProof, CompactPkeZkScheme, CompactPublicKeyProofCrs,
+    DecryptionProofCrs, WeightedMessageBit, ZkBatchVerificationOutcome, ZkMSBZeroPaddingBitCount,
     ZkVerificationOutcome,
 };
 use rayon::prelude::*;
@@ -92,6 +95,56 @@ impl CompactPkeCrs {
     }
 }
 
+impl DecryptionProofCrs {
+    /// Construct the CRS to prove the decryption of ciphertexts encrypted with the compact public
+    /// key of the given parameters
+    pub fn from_shortint_params<P, E>(params: P) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+
+        let mut plaintext_modulus = params.message_modulus.0 * params.carry_modulus.0;
+        // Our plaintext modulus does not take into account the bit of padding
+        plaintext_modulus *= 2;
+
+        crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                params.encryption_lwe_dimension,
+                plaintext_modulus,
+                &mut engine.random_generator,
+            )
+        })
+    }
+}
+
+impl CompactPublicKeyProofCrs {
+    /// Construct the CRS to prove that compact public keys of the given parameters are well
+    /// formed
+    pub fn from_shortint_params<P, E>(params: P) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+
+        if !params.ciphertext_modulus.is_native_modulus() {
+            return Err(crate::Error::from(
+                "Compact public key proofs only support the native ciphertext modulus",
+            ));
+        }
+
+        crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                params.encryption_lwe_dimension,
+                params.encryption_noise_distribution,
+                &mut engine.random_generator,
+            )
+        })
+    }
+}
+
 /// A List of CompactCiphertext with their zero-knowledge proofs
 ///
 /// The proofs can only be generated during the encryption with a [CompactPublicKey]
@@ -227,6 +280,90 @@ impl ProvenCompactCiphertextList {
         }
     }
 
+    /// Verifies a batch of lists, each with the metadata used to generate its proofs
+    ///
+    /// The proofs of all the lists are verified together, see [CompactPkeCrs::verify_batch]. The
+    /// indices returned when the batch is invalid are the ones of the invalid lists.
+    pub fn verify_batch(
+        lists: &[(&Self, &[u8])],
+        crs: &CompactPkeCrs,
+        public_key: &CompactPublicKey,
+    ) -> ZkBatchVerificationOutcome {
+        let (list_indices, proven_lists): (Vec<_>, Vec<_>) = lists
+            .iter()
+            .enumerate()
+            .flat_map(|(list_index, &(list, metadata))| {
+                list.proved_lists
+                    .iter()
+                    .map(move |(ct_list, proof)| (list_index, (&ct_list.ct_list, proof, metadata)))
+            })
+            .unzip();
+
+        match crs.verify_batch(&proven_lists, &public_key.key) {
+            ZkBatchVerificationOutcome::Valid => ZkBatchVerificationOutcome::Valid,
+            ZkBatchVerificationOutcome::Invalid(indices) => {
+                let mut invalid_lists = indices
+                    .into_iter()
+                    .map(|i| list_indices[i])
+                    .collect::<Vec<_>>();
+                invalid_lists.dedup();
+                ZkBatchVerificationOutcome::Invalid(invalid_lists)
+            }
+        }
+    }
+
+    /// Splits a linear combination of the message bits of the whole list into the terms expected
+    /// by [CompactPkeCrs::verify_message_bits_relation], one per proof with bits in the
+    /// combination.
+    ///
+    /// Returns `None` if the combination has bits of messages that are not in the list.
+    pub(crate) fn message_bits_terms(
+        &self,
+        combination: &[WeightedMessageBit],
+    ) -> Option<
+        Vec<(
+            &LweCompactCiphertextListOwned<u64>,
+            &CompactPkeProof,
+            Vec<WeightedMessageBit>,
+        )>,
+    > {
+        let message_count = self.ciphertext_count();
+        if combination
+            .iter()
+            .any(|bit| bit.message_index >= message_count)
+        {
+            return None;
+        }
+
+        let mut list_start = 0;
+        let terms = self
+            .proved_lists
+            .iter()
+            .filter_map(|(ct_list, proof)| {
+                let list_messages =
+                    list_start..list_start + ct_list.ct_list.lwe_ciphertext_count().0;
+                list_start = list_messages.end;
+
+                let list_combination = combination
+                    .iter()
+                    .filter(|bit| list_messages.contains(&bit.message_index))
+                    .map(|&bit| WeightedMessageBit {
+                        message_index: bit.message_index - list_messages.start,
+                        ..bit
+                    })
+                    .collect::<Vec<_>>();
+
+                (!list_combination.is_empty()).then_some((
+                    &ct_list.ct_list,
+                    proof,
+                    list_combination,
+                ))
+            })
+            .collect();
+
+        Some(terms)
+    }
+
     pub fn proof_size(&self) -> usize {
         self.proved_lists.len() * core::mem::size_of::<CompactPkeProof>()
     }
@@ -113,7 +113,7 @@ impl ShortintEngine {
         Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
-            NoiseLevel::NOMINAL,
+            NoiseLevel::ENCRYPTED,
             message_modulus,
             CarryModulus(carry_modulus),
             params_op_order,
@@ -151,7 +151,7 @@ impl ShortintEngine {
         Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
-            NoiseLevel::NOMINAL,
+            NoiseLevel::ENCRYPTED,
             message_modulus,
             carry_modulus,
             params_op_order,
@@ -200,7 +200,7 @@ impl ShortintEngine {
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: params_op_order,
-            noise_level: NoiseLevel::NOMINAL,
+            noise_level: NoiseLevel::ENCRYPTED,
         }
     }
 
@@ -230,7 +230,7 @@ impl ShortintEngine {
                 client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0
                     - 1,
             ),
-            NoiseLevel::NOMINAL,
+            NoiseLevel::ENCRYPTED,
             client_key.parameters.message_modulus(),
             client_key.parameters.carry_modulus(),
             params_op_order,
@@ -268,7 +268,7 @@ impl ShortintEngine {
         Ciphertext::new(
             ct,
             Degree::new(client_key.parameters.message_modulus().0 - 1),
-            NoiseLevel::NOMINAL,
+            NoiseLevel::ENCRYPTED,
             client_key.parameters.message_modulus(),
             client_key.parameters.carry_modulus(),
             params_op_order,
@@ -309,7 +309,7 @@ impl ShortintEngine {
             message_modulus: client_key.parameters.message_modulus(),
             carry_modulus: client_key.parameters.carry_modulus(),
             pbs_order: params_op_order,
-            noise_level: NoiseLevel::NOMINAL,
+            noise_level: NoiseLevel::ENCRYPTED,
         }
     }
 
@@ -341,7 +341,7 @@ impl ShortintEngine {
         Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
-            NoiseLevel::NOMINAL,
+            NoiseLevel::ENCRYPTED,
             message_modulus,
             carry_modulus,
             params_op_order,
@@ -379,7 +379,7 @@ impl ShortintEngine {
             message_modulus,
             carry_modulus,
             pbs_order: params_op_order,
-            noise_level: NoiseLevel::NOMINAL,
+            noise_level: NoiseLevel::ENCRYPTED,
         }
     }
 }
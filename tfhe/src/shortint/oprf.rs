@@ -150,7 +150,7 @@ impl ServerKey {
         Ciphertext {
             ct,
             degree: Degree::new(p - 1),
-            noise_level: NoiseLevel::NOMINAL,
+            noise_level: NoiseLevel::BOOTSTRAPPED,
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
             pbs_order: self.pbs_order,
@@ -28,6 +28,7 @@ pub mod coverage_parameters;
 pub mod key_switching;
 pub mod list_compression;
 pub mod multi_bit;
+mod noise_estimation;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub mod parameters_wopbs_only;
@@ -0,0 +1,202 @@
+#![allow(clippy::excessive_precision)]
+
+//! Estimation of the noise of ciphertexts from the parameters.
+//!
+//! The formulas are the ones of [`noise_formulas`](crate::core_crypto::commons::noise_formulas),
+//! where the variance of the noise of the keys is the one of their actual noise distribution
+//! instead of the minimal variance for 132 bits of security. This way they also apply to
+//! parameters using TUniform noise.
+
+use super::{EncryptionKeyChoice, PBSParameters};
+use crate::core_crypto::commons::dispersion::Variance;
+
+// The PBS output variance, with `bsk_variance` the variance of the noise of the bootstrapping key
+fn pbs_variance(
+    input_lwe_dimension: f64,
+    output_glwe_dimension: f64,
+    output_polynomial_size: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+    bsk_variance: f64,
+    modulus: f64,
+) -> f64 {
+    input_lwe_dimension
+        * (2.06537277069845e-33
+            * decomposition_base.powf(2.0)
+            * decomposition_level_count
+            * output_polynomial_size.powf(2.0)
+            * (output_glwe_dimension + 1.0)
+            + (1_f64 / 3.0)
+                * decomposition_level_count
+                * output_polynomial_size
+                * bsk_variance
+                * ((1_f64 / 4.0) * decomposition_base.powf(2.0) + 0.5)
+                * (output_glwe_dimension + 1.0)
+            + (1_f64 / 12.0) * modulus.powf(-2.0)
+            + (1_f64 / 2.0)
+                * output_glwe_dimension
+                * output_polynomial_size
+                * (0.0208333333333333 * modulus.powf(-2.0)
+                    + 0.0416666666666667
+                        * decomposition_base.powf(-2.0 * decomposition_level_count))
+            + (1_f64 / 24.0) * decomposition_base.powf(-2.0 * decomposition_level_count))
+}
+
+// The variance added by a keyswitch, with `ksk_variance` the variance of the noise of the
+// keyswitching key
+fn keyswitch_additive_variance(
+    input_lwe_dimension: f64,
+    decomposition_base: f64,
+    decomposition_level_count: f64,
+    ksk_variance: f64,
+    modulus: f64,
+) -> f64 {
+    (1_f64 / 3.0)
+        * decomposition_level_count
+        * input_lwe_dimension
+        * ksk_variance
+        * ((1_f64 / 4.0) * decomposition_base.powf(2.0) + 0.5)
+        + input_lwe_dimension
+            * (0.0208333333333333 * modulus.powf(-2.0)
+                + 0.0416666666666667 * decomposition_base.powf(-2.0 * decomposition_level_count))
+}
+
+impl PBSParameters {
+    fn modulus_as_f64(&self) -> f64 {
+        let ciphertext_modulus = self.ciphertext_modulus();
+        if ciphertext_modulus.is_native_modulus() {
+            2.0f64.powi(u64::BITS as i32)
+        } else {
+            ciphertext_modulus.get_custom_modulus() as f64
+        }
+    }
+
+    /// Returns the variance of the noise of a ciphertext encrypted with the client key.
+    pub fn encryption_noise_variance(&self) -> Variance {
+        let modulus = self.modulus_as_f64();
+
+        match self.encryption_key_choice() {
+            EncryptionKeyChoice::Big => self.glwe_noise_distribution().variance(modulus),
+            EncryptionKeyChoice::Small => self.lwe_noise_distribution().variance(modulus),
+        }
+    }
+
+    /// Returns the estimated variance of the noise of a ciphertext output by a PBS, i.e. with a
+    /// [`NoiseLevel::NOMINAL`](super::NoiseLevel::NOMINAL).
+    ///
+    /// The formulas are only available for the classic PBS, `None` is returned for multi bit
+    /// parameters.
+    pub fn nominal_noise_variance(&self) -> Option<Variance> {
+        let Self::PBS(params) = self else {
+            return None;
+        };
+
+        let modulus = self.modulus_as_f64();
+
+        let pbs_variance = pbs_variance(
+            params.lwe_dimension.0 as f64,
+            params.glwe_dimension.0 as f64,
+            params.polynomial_size.0 as f64,
+            2.0f64.powi(params.pbs_base_log.0 as i32),
+            params.pbs_level.0 as f64,
+            params.glwe_noise_distribution.variance(modulus).0,
+            modulus,
+        );
+
+        match params.encryption_key_choice {
+            EncryptionKeyChoice::Big => Some(Variance(pbs_variance)),
+            // The ciphertexts are keyswitched after the PBS
+            EncryptionKeyChoice::Small => {
+                let keyswitch_variance = keyswitch_additive_variance(
+                    params
+                        .glwe_dimension
+                        .to_equivalent_lwe_dimension(params.polynomial_size)
+                        .0 as f64,
+                    2.0f64.powi(params.ks_base_log.0 as i32),
+                    params.ks_level.0 as f64,
+                    params.lwe_noise_distribution.variance(modulus).0,
+                    modulus,
+                );
+                Some(Variance(pbs_variance + keyswitch_variance))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::noise_formulas::lwe_keyswitch::keyswitch_additive_variance_132_bits_security_gaussian;
+    use crate::core_crypto::commons::noise_formulas::lwe_programmable_bootstrap::pbs_variance_132_bits_security_gaussian;
+    use crate::core_crypto::commons::noise_formulas::secure_noise::{
+        minimal_glwe_variance_for_132_bits_security_gaussian,
+        minimal_lwe_variance_for_132_bits_security_gaussian,
+    };
+    use crate::core_crypto::prelude::DynamicDistribution;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS_GAUSSIAN_2M64, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    };
+
+    #[test]
+    fn test_noise_variance_with_132_bits_security_noise() {
+        let modulus = 2.0f64.powi(64);
+
+        for mut params in [
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS_GAUSSIAN_2M64,
+        ] {
+            // With the minimal noise for 132 bits of security, the estimate is the one of the
+            // autogenerated formulas
+            let glwe_variance = minimal_glwe_variance_for_132_bits_security_gaussian(
+                params.glwe_dimension,
+                params.polynomial_size,
+                modulus,
+            );
+            let lwe_variance =
+                minimal_lwe_variance_for_132_bits_security_gaussian(params.lwe_dimension, modulus);
+            params.glwe_noise_distribution = DynamicDistribution::new_gaussian(glwe_variance);
+            params.lwe_noise_distribution = DynamicDistribution::new_gaussian(lwe_variance);
+
+            let mut expected_nominal_variance = pbs_variance_132_bits_security_gaussian(
+                params.lwe_dimension,
+                params.glwe_dimension,
+                params.polynomial_size,
+                params.pbs_base_log,
+                params.pbs_level,
+                modulus,
+            )
+            .0;
+            let expected_encryption_variance = match params.encryption_key_choice {
+                EncryptionKeyChoice::Big => glwe_variance.0,
+                EncryptionKeyChoice::Small => {
+                    expected_nominal_variance +=
+                        keyswitch_additive_variance_132_bits_security_gaussian(
+                            params
+                                .glwe_dimension
+                                .to_equivalent_lwe_dimension(params.polynomial_size),
+                            params.lwe_dimension,
+                            params.ks_base_log,
+                            params.ks_level,
+                            modulus,
+                        )
+                        .0;
+                    lwe_variance.0
+                }
+            };
+
+            let params = PBSParameters::PBS(params);
+            for (variance, expected) in [
+                (
+                    params.nominal_noise_variance().unwrap().0,
+                    expected_nominal_variance,
+                ),
+                (
+                    params.encryption_noise_variance().0,
+                    expected_encryption_variance,
+                ),
+            ] {
+                assert!((variance - expected).abs() <= expected * 1e-9);
+            }
+        }
+    }
+}
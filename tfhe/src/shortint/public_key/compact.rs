@@ -17,7 +17,9 @@ use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::compact_public_key_only::CompactPublicKeyEncryptionParameters;
 use crate::shortint::{CarryModulus, ClientKey, MessageModulus};
 #[cfg(feature = "zk-pok")]
-use crate::zk::{CompactPkeCrs, ZkComputeLoad};
+use crate::zk::{
+    CompactPkeCrs, CompactPkeZkScheme, MessageBitsOpening, WeightedMessageBit, ZkComputeLoad,
+};
 use crate::Error;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
@@ -375,10 +377,56 @@ impl CompactPublicKey {
         load: ZkComputeLoad,
         encryption_modulus: u64,
     ) -> crate::Result<ProvenCompactCiphertextList> {
+        self.encrypt_and_prove_slice_with_message_bits_openings(
+            messages,
+            crs,
+            metadata,
+            load,
+            encryption_modulus,
+            &[],
+        )
+        .map(|(proven_list, _)| proven_list)
+    }
+
+    /// Same as [Self::encrypt_and_prove_slice], also returning one opening per linear combination
+    /// of the message bits in `combinations`.
+    ///
+    /// The message indices of the combinations are indices in `messages`. As the messages may be
+    /// split between several proofs, the openings of each proof are summed, see
+    /// [ProvenCompactCiphertextList::message_bits_terms].
+    #[cfg(feature = "zk-pok")]
+    pub(crate) fn encrypt_and_prove_slice_with_message_bits_openings(
+        &self,
+        messages: &[u64],
+        crs: &CompactPkeCrs,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        encryption_modulus: u64,
+        combinations: &[Vec<WeightedMessageBit>],
+    ) -> crate::Result<(ProvenCompactCiphertextList, Vec<MessageBitsOpening>)> {
         let plaintext_modulus = self.parameters.message_modulus.0 * self.parameters.carry_modulus.0;
         let delta = (1u64 << 63) / plaintext_modulus;
         assert!(encryption_modulus <= plaintext_modulus);
 
+        if !combinations.is_empty() {
+            if crs.scheme_version() != CompactPkeZkScheme::V2 {
+                return Err(crate::Error::new(
+                    "Openings of message bits require a CRS of the V2 zk scheme".to_string(),
+                ));
+            }
+
+            let bits_per_message = plaintext_modulus.ilog2() as usize;
+            if combinations
+                .iter()
+                .flatten()
+                .any(|bit| bit.message_index >= messages.len() || bit.bit_index >= bits_per_message)
+            {
+                return Err(crate::Error::new(
+                    "Linear combination of bits that are not message bits of the list".to_string(),
+                ));
+            }
+        }
+
         // This is the maximum number of lwe that can share the same mask in lwe compact pk
         // encryption
         let max_ciphertext_per_bin = self.key.lwe_dimension().0;
@@ -389,7 +437,33 @@ impl CompactPublicKey {
 
         let num_lists = messages.len().div_ceil(message_chunk_size);
         let mut proved_lists = Vec::with_capacity(num_lists);
-        for message_chunk in messages.chunks(message_chunk_size) {
+        let mut openings = vec![Vec::new(); combinations.len()];
+        for (chunk_index, message_chunk) in messages.chunks(message_chunk_size).enumerate() {
+            // The combinations restricted to the messages of this chunk, along with their indices
+            let chunk_start = chunk_index * message_chunk_size;
+            let chunk_messages = chunk_start..chunk_start + message_chunk.len();
+            let (combination_indices, chunk_combinations): (Vec<_>, Vec<_>) = combinations
+                .iter()
+                .enumerate()
+                .filter_map(|(combination_index, combination)| {
+                    let chunk_combination = combination
+                        .iter()
+                        .filter(|bit| chunk_messages.contains(&bit.message_index))
+                        .map(|&bit| WeightedMessageBit {
+                            message_index: bit.message_index - chunk_start,
+                            ..bit
+                        })
+                        .collect::<Vec<_>>();
+
+                    (!chunk_combination.is_empty())
+                        .then_some((combination_index, chunk_combination))
+                })
+                .unzip();
+            let chunk_combinations = chunk_combinations
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<_>>();
+
             let mut ct_list = LweCompactCiphertextListOwned::new(
                 0u64,
                 self.key.lwe_dimension().to_lwe_size(),
@@ -401,10 +475,10 @@ impl CompactPublicKey {
 
             // No parallelism allowed
             #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
-            let proof = {
-                use crate::core_crypto::prelude::encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key;
+            let (proof, chunk_openings) = {
+                use crate::core_crypto::algorithms::lwe_encryption::encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings;
                 ShortintEngine::with_thread_local_mut(|engine| {
-                    encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+                    encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings(
                         &self.key,
                         &mut ct_list,
                         &message_chunk,
@@ -417,16 +491,17 @@ impl CompactPublicKey {
                         crs,
                         metadata,
                         load,
+                        &chunk_combinations,
                     )
                 })
             }?;
 
             // Parallelism allowed  /
             #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
-            let proof = {
-                use crate::core_crypto::prelude::par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key;
+            let (proof, chunk_openings) = {
+                use crate::core_crypto::algorithms::lwe_encryption::par_encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings;
                 ShortintEngine::with_thread_local_mut(|engine| {
-                    par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+                    par_encrypt_and_prove_lwe_compact_ciphertext_list_with_message_bits_openings(
                         &self.key,
                         &mut ct_list,
                         &message_chunk,
@@ -439,6 +514,7 @@ impl CompactPublicKey {
                         crs,
                         metadata,
                         load,
+                        &chunk_combinations,
                     )
                 })
             }?;
@@ -453,9 +529,18 @@ impl CompactPublicKey {
             };
 
             proved_lists.push((ciphertext, proof));
+            for (combination_index, opening) in combination_indices.into_iter().zip(chunk_openings)
+            {
+                openings[combination_index].push(opening);
+            }
         }
 
-        Ok(ProvenCompactCiphertextList { proved_lists })
+        let openings = openings
+            .into_iter()
+            .map(|openings| openings.into_iter().sum())
+            .collect();
+
+        Ok((ProvenCompactCiphertextList { proved_lists }, openings))
     }
 
     pub fn size_elements(&self) -> usize {
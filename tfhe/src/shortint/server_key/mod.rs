@@ -844,7 +844,7 @@ impl ServerKey {
         });
 
         ct.degree = acc.degree;
-        ct.set_noise_level(NoiseLevel::NOMINAL, self.max_noise_level);
+        ct.set_noise_level(NoiseLevel::BOOTSTRAPPED, self.max_noise_level);
     }
 
     /// Compute a keyswitch and programmable bootstrap applying several functions on an input
@@ -1327,7 +1327,7 @@ impl ServerKey {
             );
 
             output_shortint_ct.degree = *output_degree;
-            output_shortint_ct.set_noise_level(NoiseLevel::NOMINAL, self.max_noise_level);
+            output_shortint_ct.set_noise_level(NoiseLevel::BOOTSTRAPPED, self.max_noise_level);
             outputs.push(output_shortint_ct);
         }
 
@@ -1378,7 +1378,7 @@ impl ServerKey {
             );
 
             output_shortint_ct.degree = *output_degree;
-            output_shortint_ct.set_noise_level(NoiseLevel::NOMINAL, self.max_noise_level);
+            output_shortint_ct.set_noise_level(NoiseLevel::BOOTSTRAPPED, self.max_noise_level);
             outputs.push(output_shortint_ct);
         }
 
@@ -9,15 +9,17 @@
 
 use crate::core_crypto::entities::*;
 
+use crate::shortint::backward_compatibility::wopbs::WopbsKeyVersions;
 use crate::shortint::{ServerKey, WopbsParameters};
 use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
 
 #[cfg(all(test, feature = "experimental"))]
 mod test;
 
 // Struct for WoPBS based on the private functional packing keyswitch.
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[cfg_attr(tfhe_lints, allow(tfhe_lints::serialize_without_versionize))]
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(WopbsKeyVersions)]
 pub struct WopbsKey {
     //Key for the private functional keyswitch
     pub wopbs_server_key: ServerKey,
@@ -0,0 +1,35 @@
+use std::convert::Infallible;
+
+use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
+
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+
+#[derive(VersionsDispatch)]
+pub enum FheAsciiCharVersions {
+    V0(FheAsciiChar),
+}
+
+#[derive(Version)]
+pub struct FheStringV0 {
+    enc_string: Vec<FheAsciiChar>,
+    padded: bool,
+}
+
+impl Upgrade<FheString> for FheStringV0 {
+    type Error = Infallible;
+
+    fn upgrade(self) -> Result<FheString, Self::Error> {
+        // Strings could only be encrypted from ASCII before UTF-8 support
+        Ok(FheString {
+            enc_string: self.enc_string,
+            padded: self.padded,
+            utf8: false,
+        })
+    }
+}
+
+#[derive(VersionsDispatch)]
+pub enum FheStringVersions {
+    V0(FheStringV0),
+    V1(FheString),
+}
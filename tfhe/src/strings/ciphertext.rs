@@ -5,23 +5,35 @@ use crate::integer::{
     ServerKey as IntegerServerKey,
 };
 use crate::shortint::MessageModulus;
+use crate::strings::backward_compatibility::{FheAsciiCharVersions, FheStringVersions};
 use crate::strings::client_key::EncU16;
 use crate::strings::N;
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 use rayon::slice::ParallelSlice;
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
+use tfhe_versionable::Versionize;
 
 /// Represents a encrypted ASCII character.
-#[derive(Clone)]
+///
+/// In strings encrypted from UTF-8 (see [`ClientKey::encrypt_utf8`]) it holds a single byte of the
+/// UTF-8 encoding, so that non-ASCII characters span several [`FheAsciiChar`]s.
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(FheAsciiCharVersions)]
 pub struct FheAsciiChar {
     pub enc_char: RadixCiphertext,
 }
 
 /// Represents a encrypted string made up of [`FheAsciiChar`]s.
-#[derive(Clone)]
+///
+/// The `utf8` flag tells whether the string may contain multi-byte UTF-8 characters, in which case
+/// its number of characters is not the number of [`FheAsciiChar`]s.
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(FheStringVersions)]
 pub struct FheString {
     pub enc_string: Vec<FheAsciiChar>,
     pub padded: bool,
+    pub utf8: bool,
 }
 
 // For str functions that require unsigned integers as arguments
@@ -45,6 +57,20 @@ impl ClearString {
         Self { str }
     }
 
+    /// Creates a new clear pattern from a UTF-8 string, to be used with strings encrypted from
+    /// UTF-8 (see [`ClientKey::encrypt_utf8`]).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the string contains null characters "\0" or if its UTF-8
+    /// encoding is longer than 32 bytes.
+    pub fn new_utf8(str: String) -> Self {
+        assert!(!str.contains('\0'));
+        assert!(str.len() <= N);
+
+        Self { str }
+    }
+
     pub fn str(&self) -> &str {
         &self.str
     }
@@ -124,6 +150,31 @@ impl FheString {
         client_key.encrypt_ascii(str, padding)
     }
 
+    #[cfg(test)]
+    pub fn new_trivial_utf8<T: Borrow<IntegerClientKey>>(
+        client_key: &ClientKey<T>,
+        str: &str,
+        padding: Option<u32>,
+    ) -> Self {
+        client_key.trivial_encrypt_utf8(str, padding)
+    }
+
+    /// Constructs a new `FheString` from a plaintext UTF-8 string, a [`ClientKey`] and an optional
+    /// padding length.
+    ///
+    /// Utilizes [`ClientKey::encrypt_utf8`] for the encryption.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the provided string contains null characters.
+    pub fn new_utf8<T: Borrow<IntegerClientKey>>(
+        client_key: &ClientKey<T>,
+        str: &str,
+        padding: Option<u32>,
+    ) -> Self {
+        client_key.encrypt_utf8(str, padding)
+    }
+
     #[cfg(test)]
     pub fn print_trivial(&self) {
         print!("pad: {}, chars: [", self.padded);
@@ -141,11 +192,13 @@ impl FheString {
         println!("]");
     }
 
+    /// Trivially encrypts a UTF-8 string (ASCII being a subset of it), one byte per
+    /// [`FheAsciiChar`].
     pub fn trivial<T: Borrow<IntegerServerKey> + Sync>(
         server_key: &ServerKey<T>,
         str: &str,
     ) -> Self {
-        assert!(str.is_ascii() & !str.contains('\0'));
+        assert!(!str.contains('\0'));
 
         let server_key2 = server_key.inner();
 
@@ -159,6 +212,7 @@ impl FheString {
         Self {
             enc_string,
             padded: false,
+            utf8: !str.is_ascii(),
         }
     }
 
@@ -182,13 +236,22 @@ impl FheString {
         self.padded = to;
     }
 
+    pub fn is_utf8(&self) -> bool {
+        self.utf8
+    }
+
+    pub fn set_is_utf8(&mut self, to: bool) {
+        self.utf8 = to;
+    }
+
     // Converts a `RadixCiphertext` to a `FheString`, building a `FheAsciiChar` for each
-    // num_ascii_blocks blocks.
+    // num_ascii_blocks blocks. The result is not flagged as UTF-8, see `set_is_utf8`.
     pub fn from_uint(uint: RadixCiphertext, padded: bool) -> Self {
         if uint.blocks().is_empty() {
             return Self {
                 enc_string: vec![],
                 padded,
+                utf8: false,
             };
         }
 
@@ -210,7 +273,11 @@ impl FheString {
             })
             .collect();
 
-        Self { enc_string, padded }
+        Self {
+            enc_string,
+            padded,
+            utf8: false,
+        }
     }
 
     // Converts a `FheString` to a `RadixCiphertext`, taking 4 blocks for each `FheAsciiChar`.
@@ -252,11 +319,12 @@ impl FheString {
         Self {
             enc_string: vec![],
             padded: false,
+            utf8: false,
         }
     }
 }
 
-pub(super) fn num_ascii_blocks(message_modulus: MessageModulus) -> usize {
+pub(crate) fn num_ascii_blocks(message_modulus: MessageModulus) -> usize {
     let message_modulus = message_modulus.0;
 
     assert!(message_modulus.is_power_of_two());
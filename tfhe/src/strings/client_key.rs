@@ -45,18 +45,30 @@ where
 {
     #[cfg(test)]
     pub fn trivial_encrypt_ascii(&self, str: &str, padding: Option<u32>) -> FheString {
-        let ck = self.inner.borrow();
-
         assert!(str.is_ascii() & !str.contains('\0'));
 
+        self.trivial_encrypt_bytes(str.as_bytes(), padding, false)
+    }
+
+    #[cfg(test)]
+    pub fn trivial_encrypt_utf8(&self, str: &str, padding: Option<u32>) -> FheString {
+        assert!(!str.contains('\0'));
+
+        self.trivial_encrypt_bytes(str.as_bytes(), padding, true)
+    }
+
+    #[cfg(test)]
+    fn trivial_encrypt_bytes(&self, bytes: &[u8], padding: Option<u32>, utf8: bool) -> FheString {
+        let ck = self.inner.borrow();
+
         let padded = padding.is_some_and(|p| p != 0);
 
         let num_blocks = self.num_ascii_blocks();
 
-        let mut enc_string: Vec<_> = str
-            .bytes()
+        let mut enc_string: Vec<_> = bytes
+            .iter()
             .map(|char| FheAsciiChar {
-                enc_char: ck.create_trivial_radix(char, num_blocks),
+                enc_char: ck.create_trivial_radix(*char, num_blocks),
             })
             .collect();
 
@@ -69,7 +81,11 @@ where
             enc_string.extend(null);
         }
 
-        FheString { enc_string, padded }
+        FheString {
+            enc_string,
+            padded,
+            utf8,
+        }
     }
 
     /// Encrypts an ASCII string, optionally padding it with the specified amount of 0s, and returns
@@ -80,18 +96,38 @@ where
     /// This function will panic if the provided string is not ASCII or contains null characters
     /// "\0".
     pub fn encrypt_ascii(&self, str: &str, padding: Option<u32>) -> FheString {
-        let ck = self.inner.borrow();
-
         assert!(str.is_ascii() & !str.contains('\0'));
 
+        self.encrypt_bytes(str.as_bytes(), padding, false)
+    }
+
+    /// Encrypts a UTF-8 string, optionally padding it with the specified amount of 0s, and returns
+    /// an [`FheString`].
+    ///
+    /// Each byte of the UTF-8 encoding is encrypted separately, so a non-ASCII character is made
+    /// up of 2 to 4 [`FheAsciiChar`]s.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the provided string contains null characters "\0".
+    pub fn encrypt_utf8(&self, str: &str, padding: Option<u32>) -> FheString {
+        assert!(!str.contains('\0'));
+
+        // The string is flagged as UTF-8 even if it is ASCII, so that the flag doesn't reveal it
+        self.encrypt_bytes(str.as_bytes(), padding, true)
+    }
+
+    fn encrypt_bytes(&self, bytes: &[u8], padding: Option<u32>, utf8: bool) -> FheString {
+        let ck = self.inner.borrow();
+
         let padded = padding.is_some_and(|p| p != 0);
 
         let num_blocks = self.num_ascii_blocks();
 
-        let mut enc_string: Vec<_> = str
-            .bytes()
+        let mut enc_string: Vec<_> = bytes
+            .iter()
             .map(|char| FheAsciiChar {
-                enc_char: ck.encrypt_radix(char, num_blocks),
+                enc_char: ck.encrypt_radix(*char, num_blocks),
             })
             .collect();
 
@@ -104,7 +140,11 @@ where
             enc_string.extend(null);
         }
 
-        FheString { enc_string, padded }
+        FheString {
+            enc_string,
+            padded,
+            utf8,
+        }
     }
 
     fn num_ascii_blocks(&self) -> usize {
@@ -125,6 +165,24 @@ where
     /// This function will panic if the decrypted string is not ASCII or the `FheString` padding
     /// flag doesn't match the actual string.
     pub fn decrypt_ascii(&self, enc_str: &FheString) -> String {
+        let bytes = self.decrypt_bytes(enc_str);
+
+        String::from_utf8(bytes).unwrap()
+    }
+
+    /// Decrypts a `FheString`, removes any padding and returns the UTF-8 string.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the decrypted bytes are not valid UTF-8 or the `FheString`
+    /// padding flag doesn't match the actual string.
+    pub fn decrypt_utf8(&self, enc_str: &FheString) -> String {
+        let bytes = self.decrypt_bytes(enc_str);
+
+        String::from_utf8(bytes).expect("DECRYPTED STRING IS NOT VALID UTF-8")
+    }
+
+    fn decrypt_bytes(&self, enc_str: &FheString) -> Vec<u8> {
         let ck = self.inner.borrow();
 
         let padded_flag = enc_str.is_padded();
@@ -161,7 +219,7 @@ where
             )
         }
 
-        String::from_utf8(bytes).unwrap()
+        bytes
     }
 
     #[cfg(test)]
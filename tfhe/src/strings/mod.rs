@@ -1,5 +1,7 @@
+pub mod backward_compatibility;
 pub mod ciphertext;
 pub mod client_key;
+pub mod regex;
 pub mod server_key;
 
 mod char_iter;
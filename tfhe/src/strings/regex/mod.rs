@@ -0,0 +1,389 @@
+//! Clear regular expressions that can be matched against encrypted strings.
+//!
+//! A [`Regex`] is compiled in the clear into an automaton without epsilon transitions (Glushkov
+//! construction), in which each state is a position of the regex holding a class of bytes. The
+//! automaton is then evaluated homomorphically over the chars of an encrypted string, see
+//! [`ServerKey::is_match`](crate::strings::ServerKey::is_match) and
+//! [`ServerKey::find_regex`](crate::strings::ServerKey::find_regex).
+mod parser;
+
+use parser::{Ast, Parser};
+use std::collections::BTreeSet;
+
+/// A set of non null bytes, stored as sorted, disjoint and non adjacent inclusive ranges.
+///
+/// The null byte is never part of a class as it is the padding char of encrypted strings.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct ByteClass {
+    ranges: Vec<(u8, u8)>,
+}
+
+impl ByteClass {
+    fn from_members(members: &[bool; 256]) -> Self {
+        let mut ranges: Vec<(u8, u8)> = vec![];
+
+        for byte in 1..=u8::MAX {
+            if !members[byte as usize] {
+                continue;
+            }
+
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == byte => *end = byte,
+                _ => ranges.push((byte, byte)),
+            }
+        }
+
+        Self { ranges }
+    }
+
+    fn members(&self) -> [bool; 256] {
+        let mut members = [false; 256];
+
+        for &(start, end) in &self.ranges {
+            for byte in start..=end {
+                members[byte as usize] = true;
+            }
+        }
+
+        members
+    }
+
+    fn empty() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    fn from_range(start: u8, end: u8) -> Self {
+        let mut members = [false; 256];
+        for byte in start..=end {
+            members[byte as usize] = true;
+        }
+
+        Self::from_members(&members)
+    }
+
+    fn from_byte(byte: u8) -> Self {
+        Self::from_range(byte, byte)
+    }
+
+    fn any_except_newline() -> Self {
+        Self::from_byte(b'\n').negate()
+    }
+
+    fn digit() -> Self {
+        Self::from_range(b'0', b'9')
+    }
+
+    fn word() -> Self {
+        Self::from_range(b'a', b'z')
+            .union(&Self::from_range(b'A', b'Z'))
+            .union(&Self::digit())
+            .union(&Self::from_byte(b'_'))
+    }
+
+    fn space() -> Self {
+        // Tab, line feed, vertical tab, form feed and carriage return are contiguous
+        Self::from_range(b'\t', b'\r').union(&Self::from_byte(b' '))
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        let mut members = self.members();
+
+        for (member, other_member) in members.iter_mut().zip(other.members()) {
+            *member |= other_member;
+        }
+
+        Self::from_members(&members)
+    }
+
+    fn negate(&self) -> Self {
+        let mut members = self.members();
+
+        for member in &mut members {
+            *member = !*member;
+        }
+
+        Self::from_members(&members)
+    }
+
+    fn single_byte(&self) -> Option<u8> {
+        match self.ranges.as_slice() {
+            [(start, end)] if start == end => Some(*start),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn ranges(&self) -> &[(u8, u8)] {
+        &self.ranges
+    }
+}
+
+/// A regular expression, matched byte by byte against encrypted strings.
+///
+/// The supported syntax is:
+/// - literal ASCII chars and escaped punctuation (e.g. `\.`), `\n`, `\t`, `\r`
+/// - the `.` wildcard (any char except a new line)
+/// - classes such as `[a-z_]` or `[^0-9]`, and the `\d`, `\w`, `\s` shorthands (and their `\D`,
+///   `\W`, `\S` negations) which follow the ASCII definitions
+/// - concatenation, alternation `|` and groups `(...)`
+/// - the `*`, `+` and `?` repetitions, and bounded repetitions `{n}`, `{n,}` and `{n,m}`
+/// - the `^` and `$` anchors, only at the very start and at the very end of the regex
+///
+/// # Examples
+///
+/// ```rust
+/// use tfhe::strings::regex::Regex;
+///
+/// let regex = Regex::new(r"^[a-z0-9._]+@[a-z0-9]+\.(com|org)$").unwrap();
+/// assert_eq!(regex.as_str(), r"^[a-z0-9._]+@[a-z0-9]+\.(com|org)$");
+///
+/// assert!(Regex::new("(ab").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Regex {
+    pattern: String,
+    // The distinct byte classes of the regex
+    classes: Vec<ByteClass>,
+    // For each position, the index of its class in `classes`
+    position_classes: Vec<usize>,
+    // The positions that can match the first char of a match
+    first: Vec<usize>,
+    // The positions that can match the last char of a match
+    last: Vec<usize>,
+    // For each position, the positions that can match the next char
+    follow: Vec<Vec<usize>>,
+    // Whether the regex matches the empty string
+    nullable: bool,
+    anchored_start: bool,
+    anchored_end: bool,
+}
+
+impl Regex {
+    /// Compiles a regular expression, returning an error if its syntax is invalid or not
+    /// supported.
+    pub fn new(pattern: &str) -> crate::Result<Self> {
+        if !pattern.is_ascii() {
+            return Err(crate::Error::new(
+                "Only ASCII regular expressions are supported".to_string(),
+            ));
+        }
+
+        let mut inner = pattern;
+
+        let anchored_start = inner.starts_with('^');
+        if anchored_start {
+            inner = &inner[1..];
+        }
+
+        // The trailing '$' is an anchor if it is not escaped i.e. preceded by an even number of
+        // backslashes
+        let anchored_end = inner.strip_suffix('$').is_some_and(|rest| {
+            let backslashes = rest.bytes().rev().take_while(|c| *c == b'\\').count();
+            backslashes % 2 == 0
+        });
+        if anchored_end {
+            inner = &inner[..inner.len() - 1];
+        }
+
+        let ast = Parser::new(inner).parse()?;
+
+        let mut builder = GlushkovBuilder::default();
+        let linearized = builder.add(&ast);
+
+        let mut classes: Vec<ByteClass> = vec![];
+        let position_classes = builder
+            .positions
+            .into_iter()
+            .map(|class| {
+                classes.iter().position(|c| *c == class).unwrap_or_else(|| {
+                    classes.push(class);
+                    classes.len() - 1
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            classes,
+            position_classes,
+            first: linearized.first,
+            last: linearized.last,
+            follow: builder
+                .follow
+                .into_iter()
+                .map(|follow| follow.into_iter().collect())
+                .collect(),
+            nullable: linearized.nullable,
+            anchored_start,
+            anchored_end,
+        })
+    }
+
+    /// Returns the source of the regular expression
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    pub(crate) fn classes(&self) -> &[ByteClass] {
+        &self.classes
+    }
+
+    pub(crate) fn num_positions(&self) -> usize {
+        self.position_classes.len()
+    }
+
+    pub(crate) fn class_of(&self, position: usize) -> usize {
+        self.position_classes[position]
+    }
+
+    pub(crate) fn first(&self) -> &[usize] {
+        &self.first
+    }
+
+    pub(crate) fn is_last(&self, position: usize) -> bool {
+        self.last.contains(&position)
+    }
+
+    pub(crate) fn follow(&self, position: usize) -> &[usize] {
+        &self.follow[position]
+    }
+
+    pub(crate) fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    pub(crate) fn is_anchored_start(&self) -> bool {
+        self.anchored_start
+    }
+
+    pub(crate) fn is_anchored_end(&self) -> bool {
+        self.anchored_end
+    }
+}
+
+impl std::str::FromStr for Regex {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+// Properties of a sub expression of the regex
+struct Linearized {
+    nullable: bool,
+    first: Vec<usize>,
+    last: Vec<usize>,
+}
+
+impl Linearized {
+    fn empty() -> Self {
+        Self {
+            nullable: true,
+            first: vec![],
+            last: vec![],
+        }
+    }
+}
+
+#[derive(Default)]
+struct GlushkovBuilder {
+    positions: Vec<ByteClass>,
+    follow: Vec<BTreeSet<usize>>,
+}
+
+impl GlushkovBuilder {
+    fn add(&mut self, ast: &Ast) -> Linearized {
+        match ast {
+            Ast::Empty => Linearized::empty(),
+            Ast::Class(class) => {
+                let position = self.positions.len();
+                self.positions.push(class.clone());
+                self.follow.push(BTreeSet::new());
+
+                Linearized {
+                    nullable: false,
+                    first: vec![position],
+                    last: vec![position],
+                }
+            }
+            Ast::Concat(items) => items.iter().fold(Linearized::empty(), |acc, item| {
+                let next = self.add(item);
+                self.concat(acc, next)
+            }),
+            Ast::Alternation(branches) => {
+                let mut result = Linearized {
+                    nullable: false,
+                    first: vec![],
+                    last: vec![],
+                };
+
+                for branch in branches {
+                    let branch = self.add(branch);
+
+                    result.nullable |= branch.nullable;
+                    result.first.extend(branch.first);
+                    result.last.extend(branch.last);
+                }
+
+                result
+            }
+            Ast::Repeat { ast, min, max } => {
+                let mut result = Linearized::empty();
+
+                // Each repetition is a copy of the expression with its own positions. An
+                // unbounded repetition ends with a copy looping back on itself
+                let num_copies = match max {
+                    Some(_) => *min,
+                    None => min.saturating_sub(1),
+                };
+
+                for _ in 0..num_copies {
+                    let copy = self.add(ast);
+                    result = self.concat(result, copy);
+                }
+
+                match max {
+                    Some(max) => {
+                        for _ in *min..*max {
+                            let mut copy = self.add(ast);
+                            copy.nullable = true;
+                            result = self.concat(result, copy);
+                        }
+                    }
+                    None => {
+                        let mut copy = self.add(ast);
+                        for &last in &copy.last {
+                            self.follow[last].extend(&copy.first);
+                        }
+                        copy.nullable |= *min == 0;
+                        result = self.concat(result, copy);
+                    }
+                }
+
+                result
+            }
+        }
+    }
+
+    fn concat(&mut self, lhs: Linearized, rhs: Linearized) -> Linearized {
+        for &last in &lhs.last {
+            self.follow[last].extend(&rhs.first);
+        }
+
+        let mut first = lhs.first;
+        if lhs.nullable {
+            first.extend(&rhs.first);
+        }
+
+        let mut last = rhs.last;
+        if rhs.nullable {
+            last.extend(&lhs.last);
+        }
+
+        Linearized {
+            nullable: lhs.nullable && rhs.nullable,
+            first,
+            last,
+        }
+    }
+}
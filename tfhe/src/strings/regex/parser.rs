@@ -0,0 +1,282 @@
+use super::ByteClass;
+
+// Upper bound on the bounds of a repetition, as a bounded repetition is compiled by duplicating
+// the repeated expression
+const MAX_REPETITION: u32 = 128;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Ast {
+    Empty,
+    Class(ByteClass),
+    Concat(Vec<Ast>),
+    Alternation(Vec<Ast>),
+    Repeat {
+        ast: Box<Ast>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+pub(super) struct Parser<'a> {
+    pattern: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(pattern: &'a str) -> Self {
+        Self {
+            pattern: pattern.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    pub(super) fn parse(mut self) -> crate::Result<Ast> {
+        let ast = self.parse_alternation()?;
+
+        match self.peek() {
+            Some(b')') => Err(self.error("unopened group")),
+            Some(c) => Err(self.error(&format!("unexpected '{}'", c as char))),
+            None => Ok(ast),
+        }
+    }
+
+    fn error(&self, msg: &str) -> crate::Error {
+        crate::Error::new(format!("Invalid regex at position {}: {msg}", self.pos))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.pattern.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        let is_eaten = self.peek() == Some(c);
+        if is_eaten {
+            self.pos += 1;
+        }
+        is_eaten
+    }
+
+    fn parse_alternation(&mut self) -> crate::Result<Ast> {
+        let mut branches = vec![self.parse_concat()?];
+
+        while self.eat(b'|') {
+            branches.push(self.parse_concat()?);
+        }
+
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Ast::Alternation(branches)
+        })
+    }
+
+    fn parse_concat(&mut self) -> crate::Result<Ast> {
+        let mut items = vec![];
+
+        while let Some(c) = self.peek() {
+            if c == b'|' || c == b')' {
+                break;
+            }
+
+            let atom = self.parse_atom()?;
+            items.push(self.parse_quantifiers(atom)?);
+        }
+
+        Ok(match items.len() {
+            0 => Ast::Empty,
+            1 => items.pop().unwrap(),
+            _ => Ast::Concat(items),
+        })
+    }
+
+    fn parse_quantifiers(&mut self, mut ast: Ast) -> crate::Result<Ast> {
+        loop {
+            let (min, max) = match self.peek() {
+                Some(b'*') => (0, None),
+                Some(b'+') => (1, None),
+                Some(b'?') => (0, Some(1)),
+                Some(b'{') => {
+                    self.pos += 1;
+                    ast = self.parse_bounds(ast)?;
+                    continue;
+                }
+                _ => return Ok(ast),
+            };
+            self.pos += 1;
+
+            ast = Ast::Repeat {
+                ast: Box::new(ast),
+                min,
+                max,
+            };
+        }
+    }
+
+    // Parses `n}`, `n,}` or `n,m}`, the opening brace being already consumed
+    fn parse_bounds(&mut self, ast: Ast) -> crate::Result<Ast> {
+        let min = self.parse_number()?;
+
+        let max = if self.eat(b',') {
+            if self.peek() == Some(b'}') {
+                None
+            } else {
+                Some(self.parse_number()?)
+            }
+        } else {
+            Some(min)
+        };
+
+        if !self.eat(b'}') {
+            return Err(self.error("expected '}'"));
+        }
+
+        if max.is_some_and(|max| max < min) {
+            return Err(self.error("invalid repetition bounds"));
+        }
+
+        if min.max(max.unwrap_or(0)) > MAX_REPETITION {
+            return Err(self.error(&format!(
+                "repetition bounds must be at most {MAX_REPETITION}"
+            )));
+        }
+
+        Ok(Ast::Repeat {
+            ast: Box::new(ast),
+            min,
+            max,
+        })
+    }
+
+    fn parse_number(&mut self) -> crate::Result<u32> {
+        let start = self.pos;
+
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+
+        std::str::from_utf8(&self.pattern[start..self.pos])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| self.error("expected a number"))
+    }
+
+    fn parse_atom(&mut self) -> crate::Result<Ast> {
+        let Some(c) = self.next() else {
+            return Err(self.error("unexpected end of regex"));
+        };
+
+        match c {
+            b'(' => {
+                // Groups are not capturing, so non-capturing groups are the same
+                if self.eat(b'?') && !self.eat(b':') {
+                    return Err(self.error("unsupported group flags"));
+                }
+
+                let ast = self.parse_alternation()?;
+
+                if !self.eat(b')') {
+                    return Err(self.error("unclosed group"));
+                }
+
+                Ok(ast)
+            }
+            b'[' => self.parse_class().map(Ast::Class),
+            b'.' => Ok(Ast::Class(ByteClass::any_except_newline())),
+            b'\\' => self.parse_escape().map(Ast::Class),
+            b'*' | b'+' | b'?' | b'{' => Err(self.error("repetition operator without an operand")),
+            b'^' | b'$' => {
+                Err(self
+                    .error("anchors are only supported at the start and at the end of the regex"))
+            }
+            c => Ok(Ast::Class(ByteClass::from_byte(c))),
+        }
+    }
+
+    // Parses the char following a '\'
+    fn parse_escape(&mut self) -> crate::Result<ByteClass> {
+        let Some(c) = self.next() else {
+            return Err(self.error("unexpected end of regex after '\\'"));
+        };
+
+        let class = match c {
+            b'd' => ByteClass::digit(),
+            b'w' => ByteClass::word(),
+            b's' => ByteClass::space(),
+            b'D' => ByteClass::digit().negate(),
+            b'W' => ByteClass::word().negate(),
+            b'S' => ByteClass::space().negate(),
+            b'n' => ByteClass::from_byte(b'\n'),
+            b't' => ByteClass::from_byte(b'\t'),
+            b'r' => ByteClass::from_byte(b'\r'),
+            c if c.is_ascii_punctuation() => ByteClass::from_byte(c),
+            c => {
+                return Err(self.error(&format!("unsupported escape '\\{}'", c as char)));
+            }
+        };
+
+        Ok(class)
+    }
+
+    // Parses a class (e.g. `[a-z_]` or `[^0-9]`), the opening bracket being already consumed
+    fn parse_class(&mut self) -> crate::Result<ByteClass> {
+        let negated = self.eat(b'^');
+
+        let mut class = ByteClass::empty();
+        let mut is_first = true;
+
+        loop {
+            let Some(c) = self.next() else {
+                return Err(self.error("unclosed class"));
+            };
+
+            // A ']' right after the opening bracket is a literal
+            if c == b']' && !is_first {
+                break;
+            }
+            is_first = false;
+
+            let item = if c == b'\\' {
+                self.parse_escape()?
+            } else {
+                ByteClass::from_byte(c)
+            };
+
+            // Ranges, a '-' before the closing bracket is a literal
+            let is_range = self.peek() == Some(b'-')
+                && self.pattern.get(self.pos + 1).is_some_and(|c| *c != b']');
+
+            if is_range {
+                self.pos += 1;
+
+                let start = item
+                    .single_byte()
+                    .ok_or_else(|| self.error("invalid range start"))?;
+
+                let end = match self.next() {
+                    Some(b'\\') => self.parse_escape()?.single_byte(),
+                    Some(c) => Some(c),
+                    None => None,
+                }
+                .ok_or_else(|| self.error("invalid range end"))?;
+
+                if end < start {
+                    return Err(self.error("invalid range bounds"));
+                }
+
+                class = class.union(&ByteClass::from_range(start, end));
+            } else {
+                class = class.union(&item);
+            }
+        }
+
+        Ok(if negated { class.negate() } else { class })
+    }
+}
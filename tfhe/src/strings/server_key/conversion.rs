@@ -0,0 +1,257 @@
+use crate::integer::prelude::*;
+use crate::integer::server_key::MatchValues;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// Parses an encrypted string as an unsigned 64 bits integer in the given radix, returning
+    /// the encrypted value and a boolean indicating whether the string was a valid number.
+    ///
+    /// The string follows the same format as for [`u64::from_str_radix`]: an optional `+` sign
+    /// followed by at least one digit, digits greater than 9 being letters (either lowercase or
+    /// uppercase). The string is invalid if it contains other chars or if the value overflows.
+    ///
+    /// If the string is not valid, the returned value is 0.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = FheString::new(&ck, "+4f", Some(2));
+    ///
+    /// let (value, is_valid) = sk.parse_u64(&enc_s, 16);
+    ///
+    /// let value = ck.inner().decrypt_radix::<u64>(&value);
+    /// let is_valid = ck.inner().decrypt_bool(&is_valid);
+    ///
+    /// assert!(is_valid);
+    /// assert_eq!(value, 0x4f);
+    /// ```
+    pub fn parse_u64(&self, str: &FheString, radix: u32) -> (RadixCiphertext, BooleanBlock) {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range from 2 to 36, got {radix}"
+        );
+
+        let sk = self.inner();
+
+        let num_blocks = sk.num_blocks_to_represent_unsigned_value(u64::MAX);
+
+        let digits = MatchValues::new(
+            (b'0'..=b'9')
+                .chain(b'a'..=b'z')
+                .chain(b'A'..=b'Z')
+                .filter_map(|byte| {
+                    let digit = (byte as char).to_digit(radix)?;
+                    Some((u64::from(byte), u64::from(digit)))
+                })
+                .collect(),
+        )
+        .expect("Input bytes are unique");
+
+        // For each char, its digit value, whether it is a digit and whether it is skipped when
+        // computing the value i.e. a padding null or the leading '+'
+        let chars: Vec<_> = str
+            .chars()
+            .par_iter()
+            .enumerate()
+            .map(|(i, char)| {
+                let ((digit, is_digit), (is_plus, is_null)) = rayon::join(
+                    || sk.match_value_parallelized(char.ciphertext(), &digits),
+                    || {
+                        rayon::join(
+                            || (i == 0).then(|| sk.scalar_eq_parallelized(char.ciphertext(), b'+')),
+                            || {
+                                str.is_padded()
+                                    .then(|| sk.scalar_eq_parallelized(char.ciphertext(), 0u8))
+                            },
+                        )
+                    },
+                );
+
+                let is_skipped = match (is_plus, is_null) {
+                    (Some(is_plus), Some(is_null)) => Some(sk.boolean_bitor(&is_plus, &is_null)),
+                    (is_plus, is_null) => is_plus.or(is_null),
+                };
+
+                (sk.cast_to_unsigned(digit, num_blocks), is_digit, is_skipped)
+            })
+            .collect();
+
+        let mut value = sk.create_trivial_zero_radix(num_blocks);
+        let mut overflowed = sk.create_trivial_boolean_block(false);
+        let mut is_valid = sk.create_trivial_boolean_block(true);
+        let mut has_digit = sk.create_trivial_boolean_block(false);
+
+        let max_before_mul = u64::MAX / u64::from(radix);
+
+        for (digit, is_digit, is_skipped) in chars {
+            let ((new_value, new_overflowed), (new_is_valid, new_has_digit)) = rayon::join(
+                || {
+                    let (will_overflow, mut shifted) = rayon::join(
+                        || sk.scalar_gt_parallelized(&value, max_before_mul),
+                        || sk.scalar_mul_parallelized(&value, u64::from(radix)),
+                    );
+
+                    let add_overflow =
+                        sk.unsigned_overflowing_add_assign_parallelized(&mut shifted, &digit);
+
+                    let mut step_overflowed = sk.boolean_bitor(&will_overflow, &add_overflow);
+
+                    // Skipped chars neither change the value nor make it overflow
+                    match &is_skipped {
+                        Some(is_skipped) => {
+                            let is_not_skipped = sk.boolean_bitnot(is_skipped);
+                            sk.boolean_bitand_assign(&mut step_overflowed, &is_not_skipped);
+
+                            rayon::join(
+                                || sk.if_then_else_parallelized(is_skipped, &value, &shifted),
+                                || sk.boolean_bitor(&overflowed, &step_overflowed),
+                            )
+                        }
+                        None => (shifted, sk.boolean_bitor(&overflowed, &step_overflowed)),
+                    }
+                },
+                || {
+                    let is_ok = match &is_skipped {
+                        Some(is_skipped) => sk.boolean_bitor(&is_digit, is_skipped),
+                        None => is_digit.clone(),
+                    };
+
+                    rayon::join(
+                        || sk.boolean_bitand(&is_valid, &is_ok),
+                        || sk.boolean_bitor(&has_digit, &is_digit),
+                    )
+                },
+            );
+
+            value = new_value;
+            overflowed = new_overflowed;
+            is_valid = new_is_valid;
+            has_digit = new_has_digit;
+        }
+
+        sk.boolean_bitnot_assign(&mut overflowed);
+        sk.boolean_bitand_assign(&mut is_valid, &has_digit);
+        sk.boolean_bitand_assign(&mut is_valid, &overflowed);
+
+        let value = sk.if_then_else_parallelized(
+            &is_valid,
+            &value,
+            &sk.create_trivial_zero_radix(num_blocks),
+        );
+
+        (value, is_valid)
+    }
+
+    /// Returns the decimal representation of an encrypted unsigned integer, as an encrypted
+    /// string without leading zeros.
+    ///
+    /// As the number of digits is not known in the clear, the string is padded with nulls up to
+    /// the number of digits of the greatest value the integer can hold.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the integer has more than 64 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let enc_n = ck.encrypt_radix(1234u16, 8);
+    ///
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let enc_s = sk.to_decimal_string(&enc_n);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&enc_s), "1234");
+    /// ```
+    pub fn to_decimal_string(&self, n: &RadixCiphertext) -> FheString {
+        let sk = self.inner();
+
+        let num_bits = n.blocks().len() as u32 * sk.message_modulus().0.ilog2();
+        assert!(
+            num_bits <= 64,
+            "integers of more than 64 bits are not supported, got {num_bits} bits"
+        );
+
+        if num_bits == 0 {
+            return FheString::trivial(self, "0");
+        }
+
+        let max_value = u64::MAX >> (64 - num_bits);
+        let num_digits = max_value.to_string().len() as u32;
+
+        // Digits from the most significant one, and whether each of them is a leading zero i.e.
+        // the integer is lower than the power of 10 of this digit (the least significant digit is
+        // never a leading zero, so that 0 is written "0")
+        let (digits, leading_zeros): (Vec<_>, Vec<_>) = (0..num_digits)
+            .into_par_iter()
+            .rev()
+            .map(|power| {
+                let power_of_10 = 10u64.pow(power);
+
+                let (digit, is_leading_zero) = rayon::join(
+                    || {
+                        let quotient = if power == 0 {
+                            n.clone()
+                        } else {
+                            sk.scalar_div_parallelized(n, power_of_10)
+                        };
+
+                        let digit = sk.scalar_rem_parallelized(&quotient, 10u64);
+                        let mut digit = sk.cast_to_unsigned(digit, self.num_ascii_blocks());
+                        sk.scalar_add_assign_parallelized(&mut digit, b'0');
+
+                        FheAsciiChar { enc_char: digit }
+                    },
+                    || {
+                        (power != 0)
+                            .then(|| sk.scalar_lt_parallelized(n, power_of_10).into_radix(16, sk))
+                    },
+                );
+
+                (digit, is_leading_zero)
+            })
+            .unzip();
+
+        let digits = FheString {
+            enc_string: digits,
+            padded: false,
+            utf8: false,
+        };
+
+        let leading_zeros: Vec<_> = leading_zeros.into_iter().flatten().collect();
+
+        let Some(num_leading_zeros) = sk.sum_ciphertexts_parallelized(leading_zeros.iter()) else {
+            // There's a single digit
+            return digits;
+        };
+
+        // Shifting the leading zeros out leaves nulls at the end
+        let mut result = self.left_shift_chars(&digits, &num_leading_zeros);
+        result.append_null(self);
+
+        result
+    }
+}
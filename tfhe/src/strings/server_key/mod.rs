@@ -1,7 +1,11 @@
 mod comp;
+mod conversion;
 mod no_patterns;
 mod pattern;
+mod regex;
+mod substring;
 mod trim;
+mod utf8;
 
 pub use trim::split_ascii_whitespace;
 
@@ -34,8 +38,8 @@ where
     }
 }
 
-// With no padding, the length is just the vector's length (clear result). With padding it requires
-// homomorphically counting the non zero elements (encrypted result).
+// With no padding, the length is just the vector's length (clear result). With padding, or if the
+// string is UTF-8, it requires homomorphically counting the chars (encrypted result).
 pub enum FheStringLen {
     NoPadding(usize),
     Padding(RadixCiphertext),
@@ -251,6 +255,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         let true_is_padded = true_ct.is_padded();
         let false_is_padded = false_ct.is_padded();
+        let is_utf8 = true_ct.is_utf8() || false_ct.is_utf8();
 
         let true_ct_uint = true_ct.into_uint();
         let false_ct_uint = false_ct.into_uint();
@@ -258,6 +263,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         let result_uint = sk.if_then_else_parallelized(condition, &true_ct_uint, &false_ct_uint);
 
         let mut result = FheString::from_uint(result_uint, false);
+        result.set_is_utf8(is_utf8);
 
         match (true_is_padded, false_is_padded) {
             (true, true) => {
@@ -312,7 +318,10 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             &shifted,
         );
 
-        FheString::from_uint(result, false)
+        let mut result = FheString::from_uint(result, false);
+        result.set_is_utf8(str.is_utf8());
+
+        result
     }
 
     fn right_shift_chars(&self, str: &FheString, shift: &RadixCiphertext) -> FheString {
@@ -343,7 +352,10 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             &shifted,
         );
 
-        FheString::from_uint(result, false)
+        let mut result = FheString::from_uint(result, false);
+        result.set_is_utf8(str.is_utf8());
+
+        result
     }
 }
 
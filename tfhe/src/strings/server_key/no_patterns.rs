@@ -7,11 +7,14 @@ use rayon::prelude::*;
 use std::borrow::Borrow;
 
 impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
-    /// Returns the length of an encrypted string as an `FheStringLen` enum.
+    /// Returns the number of characters of an encrypted string as an `FheStringLen` enum.
     ///
-    /// If the encrypted string has no padding, the length is the clear length of the char vector.
-    /// If there is padding, the length is calculated homomorphically and returned as an
-    /// encrypted `RadixCiphertext`.
+    /// If the encrypted string has no padding and is not UTF-8, the length is the clear length of
+    /// the char vector. Otherwise the length is calculated homomorphically and returned as an
+    /// encrypted `RadixCiphertext`, as the number of bytes making up each UTF-8 character is not
+    /// known in the clear.
+    ///
+    /// The number of bytes is given by [`ServerKey::byte_len`].
     ///
     /// # Examples
     ///
@@ -46,8 +49,33 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     ///         assert_eq!(length, 5)
     ///     }
     /// }
+    ///
+    /// let enc_utf8 = FheString::new_utf8(&ck, "héllo 世界", None);
+    ///
+    /// match sk.len(&enc_utf8) {
+    ///     FheStringLen::NoPadding(_) => panic!("Unexpected clear length"),
+    ///     FheStringLen::Padding(ciphertext) => {
+    ///         let length = ck.inner().decrypt_radix::<u32>(&ciphertext);
+    ///         assert_eq!(length, 8)
+    ///     }
+    /// }
     /// ```
     pub fn len(&self, str: &FheString) -> FheStringLen {
+        if str.is_utf8() {
+            FheStringLen::Padding(self.count_utf8_chars(str))
+        } else {
+            self.byte_len(str)
+        }
+    }
+
+    /// Returns the number of bytes of an encrypted string as an `FheStringLen` enum.
+    ///
+    /// If the encrypted string has no padding, the length is the clear length of the char vector.
+    /// If there is padding, the length is calculated homomorphically and returned as an
+    /// encrypted `RadixCiphertext`.
+    ///
+    /// For strings that are not UTF-8 this is the same as [`ServerKey::len`].
+    pub fn byte_len(&self, str: &FheString) -> FheStringLen {
         let sk = self.inner();
 
         if str.is_padded() {
@@ -277,7 +305,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             || self.to_lowercase(lhs),
             || match rhs {
                 GenericPatternRef::Clear(rhs) => {
-                    GenericPattern::Clear(ClearString::new(rhs.str().to_lowercase()))
+                    GenericPattern::Clear(ClearString::new_utf8(rhs.str().to_lowercase()))
                 }
                 GenericPatternRef::Enc(rhs) => GenericPattern::Enc(self.to_lowercase(rhs)),
             },
@@ -316,7 +344,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         let mut result = lhs.clone();
 
-        match self.len(lhs) {
+        match self.byte_len(lhs) {
             // No homomorphic operation required if the lhs is not padded
             FheStringLen::NoPadding(_) => {
                 result.chars_vec().extend_from_slice(rhs.chars());
@@ -339,6 +367,8 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             }
         }
 
+        result.set_is_utf8(lhs.is_utf8() || rhs.is_utf8());
+
         result
     }
 
@@ -219,7 +219,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             IsMatch::Clear(val) => {
                 // val = true if pattern is empty, in which the last match index = str.len()
                 let index = if val {
-                    match self.len(str) {
+                    match self.byte_len(str) {
                         FheStringLen::Padding(cipher_len) => cipher_len,
                         FheStringLen::NoPadding(len) => sk.create_trivial_radix(len as u32, 16),
                     }
@@ -270,7 +270,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
                 // The non padded str case was handled thanks to + 1 in the ext_iter
                 if str.is_padded() && padded_pat_is_empty.is_some() {
-                    let str_true_len = match self.len(str) {
+                    let str_true_len = match self.byte_len(str) {
                         FheStringLen::Padding(cipher_len) => cipher_len,
                         FheStringLen::NoPadding(len) => sk.create_trivial_radix(len as u32, 16),
                     };
@@ -102,13 +102,13 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             || {
                 rayon::join(
                     || self.is_empty(&trivial_or_enc_from),
-                    || self.len(&trivial_or_enc_from),
+                    || self.byte_len(&trivial_or_enc_from),
                 )
             },
             || {
                 rayon::join(
-                    || self.len(result),
-                    || match self.len(to) {
+                    || self.byte_len(result),
+                    || match self.byte_len(to) {
                         FheStringLen::Padding(enc_val) => enc_val,
                         FheStringLen::NoPadding(val) => sk.create_trivial_radix(val as u32, 16),
                     },
@@ -24,7 +24,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         let (mut shift_right, real_pat_len) = rayon::join(
             || sk.sub_parallelized(&str_len, index),
-            || match self.len(&trivial_or_enc_pat) {
+            || match self.byte_len(&trivial_or_enc_pat) {
                 FheStringLen::Padding(enc_val) => enc_val,
                 FheStringLen::NoPadding(val) => sk.create_trivial_radix(val as u32, 16),
             },
@@ -224,7 +224,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     ) -> SplitInternal {
         let sk = self.inner();
 
-        let mut max_counter = match self.len(str) {
+        let mut max_counter = match self.byte_len(str) {
             FheStringLen::Padding(enc_val) => enc_val,
             FheStringLen::NoPadding(val) => sk.create_trivial_radix(val as u32, 16),
         };
@@ -288,7 +288,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             panic!("Only Split or SplitInclusive")
         }
 
-        let max_counter = match self.len(str) {
+        let max_counter = match self.byte_len(str) {
             FheStringLen::Padding(enc_val) => enc_val,
             FheStringLen::NoPadding(val) => sk.create_trivial_radix(val as u32, 16),
         };
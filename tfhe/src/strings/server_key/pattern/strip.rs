@@ -162,7 +162,7 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         let (starts_with, real_pat_len) = rayon::join(
             || self.starts_with(str, pat),
-            || match self.len(&trivial_or_enc_pat) {
+            || match self.byte_len(&trivial_or_enc_pat) {
                 FheStringLen::Padding(enc_val) => enc_val,
                 FheStringLen::NoPadding(val) => sk.create_trivial_radix(val as u32, 16),
             },
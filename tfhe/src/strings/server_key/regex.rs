@@ -0,0 +1,288 @@
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::FheString;
+use crate::strings::regex::{ByteClass, Regex};
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+// A condition known in the clear, or encrypted
+#[derive(Clone, Copy)]
+enum Condition<'a> {
+    True,
+    False,
+    Enc(&'a BooleanBlock),
+}
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    fn byte_in_class(&self, char: &RadixCiphertext, class: &ByteClass) -> BooleanBlock {
+        let sk = self.inner();
+
+        let in_ranges: Vec<_> = class
+            .ranges()
+            .par_iter()
+            .map(|&(start, end)| match (start, end) {
+                // The null byte is never in a class, so this is any non null byte
+                (1, u8::MAX) => sk.scalar_ne_parallelized(char, 0u8),
+                (start, u8::MAX) => sk.scalar_ge_parallelized(char, start),
+                (start, end) if start == end => sk.scalar_eq_parallelized(char, start),
+                (start, end) => {
+                    let (ge_start, le_end) = rayon::join(
+                        || sk.scalar_ge_parallelized(char, start),
+                        || sk.scalar_le_parallelized(char, end),
+                    );
+
+                    sk.boolean_bitand(&ge_start, &le_end)
+                }
+            })
+            .collect();
+
+        in_ranges
+            .into_iter()
+            .reduce(|lhs, rhs| sk.boolean_bitor(&lhs, &rhs))
+            .unwrap_or_else(|| sk.create_trivial_boolean_block(false))
+    }
+
+    fn bitor_options(
+        &self,
+        lhs: Option<BooleanBlock>,
+        rhs: Option<&BooleanBlock>,
+    ) -> Option<BooleanBlock> {
+        let sk = self.inner();
+
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(sk.boolean_bitor(&lhs, rhs)),
+            (Some(lhs), None) => Some(lhs),
+            (None, rhs) => rhs.cloned(),
+        }
+    }
+
+    // Returns, for each index in `0..=str.len()`, whether a match of the regex starts at this
+    // index (`None` meaning it is known in the clear that no match starts there).
+    //
+    // The automaton of the regex is run in reverse, from the last char to the first one. After
+    // processing the char at index `i`, a position is active if the chars from `i` up to some
+    // end of match are matched by a path starting at this position. Hence a match starts at `i`
+    // if one of the first positions of the regex is active.
+    fn regex_match_starts(&self, str: &FheString, regex: &Regex) -> Vec<Option<BooleanBlock>> {
+        let sk = self.inner();
+
+        let chars = str.chars();
+        let len = chars.len();
+
+        // Whether each char belongs to each of the classes of the regex
+        let memberships: Vec<Vec<BooleanBlock>> = chars
+            .par_iter()
+            .map(|char| {
+                regex
+                    .classes()
+                    .par_iter()
+                    .map(|class| self.byte_in_class(char.ciphertext(), class))
+                    .collect()
+            })
+            .collect();
+
+        // For padded strings the end is at the first null, which is only needed for the end
+        // anchor
+        let is_null: Vec<_> = if regex.is_anchored_end() && str.is_padded() {
+            chars
+                .par_iter()
+                .map(|char| sk.scalar_eq_parallelized(char.ciphertext(), 0u8))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let ends_at = |index: usize| {
+            if index == len {
+                Condition::True
+            } else if str.is_padded() {
+                Condition::Enc(&is_null[index])
+            } else {
+                Condition::False
+            }
+        };
+
+        let mut match_starts = Vec::with_capacity(len + 1);
+
+        let mut states: Vec<Option<BooleanBlock>> = vec![None; regex.num_positions()];
+
+        for index in (0..=len).rev() {
+            let can_start_at = |index: usize| !regex.is_anchored_start() || index == 0;
+
+            // A match can be empty if the regex is nullable
+            let empty_match = if regex.is_nullable() && can_start_at(index) {
+                if regex.is_anchored_end() {
+                    ends_at(index)
+                } else {
+                    Condition::True
+                }
+            } else {
+                Condition::False
+            };
+
+            if index == len {
+                match_starts.push(match empty_match {
+                    Condition::True => Some(sk.create_trivial_boolean_block(true)),
+                    Condition::Enc(is_empty_match) => Some(is_empty_match.clone()),
+                    Condition::False => None,
+                });
+                continue;
+            }
+
+            // The last positions can match the current char if a match can end right after it
+            let can_end_after = if regex.is_anchored_end() {
+                ends_at(index + 1)
+            } else {
+                Condition::True
+            };
+
+            states = (0..regex.num_positions())
+                .into_par_iter()
+                .map(|position| {
+                    let char_matches = &memberships[index][regex.class_of(position)];
+
+                    let from_next = regex.follow(position).iter().fold(None, |acc, next| {
+                        self.bitor_options(acc, states[*next].as_ref())
+                    });
+
+                    let reached = if regex.is_last(position) {
+                        match can_end_after {
+                            Condition::True => return Some(char_matches.clone()),
+                            Condition::Enc(can_end_after) => {
+                                self.bitor_options(from_next, Some(can_end_after))
+                            }
+                            Condition::False => from_next,
+                        }
+                    } else {
+                        from_next
+                    };
+
+                    reached.map(|reached| sk.boolean_bitand(char_matches, &reached))
+                })
+                .collect();
+
+            let mut match_start = if can_start_at(index) {
+                regex.first().iter().fold(None, |acc, first| {
+                    self.bitor_options(acc, states[*first].as_ref())
+                })
+            } else {
+                None
+            };
+
+            match empty_match {
+                Condition::True => match_start = Some(sk.create_trivial_boolean_block(true)),
+                Condition::Enc(is_empty_match) => {
+                    match_start = self.bitor_options(match_start, Some(is_empty_match));
+                }
+                Condition::False => {}
+            }
+
+            match_starts.push(match_start);
+        }
+
+        match_starts.reverse();
+
+        match_starts
+    }
+
+    /// Returns `true` if the given regular expression matches some part of the encrypted string,
+    /// `false` otherwise.
+    ///
+    /// The regex is matched byte by byte, padding nulls are never matched. Use the `^` and `$`
+    /// anchors to match the whole string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::regex::Regex;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let regex = Regex::new(r"^[a-z]+@[a-z]+\.com$").unwrap();
+    ///
+    /// let enc_valid = FheString::new(&ck, "bob@zama.com", Some(2));
+    /// let enc_invalid = FheString::new(&ck, "bob@zama", Some(2));
+    ///
+    /// let is_valid = sk.is_match(&enc_valid, &regex);
+    /// let is_invalid = sk.is_match(&enc_invalid, &regex);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&is_valid));
+    /// assert!(!ck.inner().decrypt_bool(&is_invalid));
+    /// ```
+    pub fn is_match(&self, str: &FheString, regex: &Regex) -> BooleanBlock {
+        let sk = self.inner();
+
+        self.regex_match_starts(str, regex)
+            .into_iter()
+            .fold(None, |acc, match_start| {
+                self.bitor_options(acc, match_start.as_ref())
+            })
+            .unwrap_or_else(|| sk.create_trivial_boolean_block(false))
+    }
+
+    /// Returns a tuple containing the byte index at which the leftmost match of the given regular
+    /// expression starts, and a boolean indicating if a match was found.
+    ///
+    /// If the regex doesn't match, the function returns a tuple where the boolean part is
+    /// `false`, indicating the equivalent of `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::regex::Regex;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    ///
+    /// let regex = Regex::new(r"\d{3}").unwrap();
+    ///
+    /// let enc_s = FheString::new(&ck, "id: 4242", None);
+    ///
+    /// let (index, found) = sk.find_regex(&enc_s, &regex);
+    ///
+    /// let index = ck.inner().decrypt_radix::<u32>(&index);
+    /// let found = ck.inner().decrypt_bool(&found);
+    ///
+    /// assert!(found);
+    /// assert_eq!(index, 4);
+    /// ```
+    pub fn find_regex(&self, str: &FheString, regex: &Regex) -> (RadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+
+        let mut result = sk.create_trivial_boolean_block(false);
+        let mut first_match_index = sk.create_trivial_zero_radix(16);
+
+        let match_starts = self.regex_match_starts(str, regex);
+
+        // Going from the end, so that the leftmost match index is the last one written
+        for (i, match_start) in match_starts.into_iter().enumerate().rev() {
+            let Some(is_matched) = match_start else {
+                continue;
+            };
+
+            let index = sk.create_trivial_radix(i as u32, 16);
+
+            rayon::join(
+                || {
+                    first_match_index =
+                        sk.if_then_else_parallelized(&is_matched, &index, &first_match_index)
+                },
+                || sk.boolean_bitor_assign(&mut result, &is_matched),
+            );
+        }
+
+        (first_match_index, result)
+    }
+}
@@ -0,0 +1,452 @@
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, UIntArg};
+use crate::strings::server_key::{FheStringLen, ServerKey};
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    // Returns the `UIntArg` as a 16 blocks index, the same size as encrypted lengths and indices
+    fn uint_arg_to_index(&self, arg: &UIntArg) -> RadixCiphertext {
+        let sk = self.inner();
+
+        match arg {
+            UIntArg::Clear(clear) => sk.create_trivial_radix(*clear, 16),
+            UIntArg::Enc(enc) => sk.cast_to_unsigned(enc.cipher().clone(), 16),
+        }
+    }
+
+    // Returns `lhs - rhs`, or 0 if `rhs > lhs`
+    fn saturating_sub_index(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let sk = self.inner();
+
+        let (diff, overflowed) = sk.unsigned_overflowing_sub_parallelized(lhs, rhs);
+
+        sk.if_then_else_parallelized(
+            &overflowed,
+            &sk.create_trivial_zero_radix(diff.blocks().len()),
+            &diff,
+        )
+    }
+
+    // Extends the chars of the string with nulls up to `len` chars, the result being unpadded
+    fn extend_with_nulls(&self, str: &FheString, len: usize) -> FheString {
+        let mut result = str.clone();
+        result.set_is_padded(false);
+
+        let num_nulls = len.saturating_sub(result.len());
+        result
+            .chars_vec()
+            .extend((0..num_nulls).map(|_| FheAsciiChar::null(self)));
+
+        result
+    }
+
+    /// Returns the bytes of the encrypted string from index `start` (inclusive) to index `end`
+    /// (exclusive), i.e. the equivalent of `&str[start..end]`.
+    ///
+    /// The indices are specified by `UIntArg`s, which can be either `Clear` or `Enc`. Unlike
+    /// Rust slicing the function never panics: the indices are clamped to the length of the
+    /// string, and the result is empty if `start >= end`.
+    ///
+    /// If both indices are clear and the string is not padded the result is not padded either.
+    /// Otherwise the result is padded, as its length is not known in the clear.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "hello world";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// // Using Clear indices
+    /// let result_clear = sk.substring(&enc_s, &UIntArg::Clear(0), &UIntArg::Clear(5));
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result_clear), "hello");
+    ///
+    /// // Using Encrypted indices
+    /// let enc_start = UIntArg::Enc(ck.encrypt_u16(6, None));
+    /// let enc_end = UIntArg::Enc(ck.encrypt_u16(11, None));
+    /// let result_enc = sk.substring(&enc_s, &enc_start, &enc_end);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result_enc), "world");
+    /// ```
+    pub fn substring(&self, str: &FheString, start: &UIntArg, end: &UIntArg) -> FheString {
+        let sk = self.inner();
+
+        // Chars outside of the clear bounds can't be part of the result
+        let clear_start = match start {
+            UIntArg::Clear(start) => (*start as usize).min(str.len()),
+            UIntArg::Enc(_) => 0,
+        };
+        let clear_end = match end {
+            UIntArg::Clear(end) => (*end as usize).min(str.len()),
+            UIntArg::Enc(_) => str.len(),
+        };
+
+        if clear_start >= clear_end {
+            return FheString::empty();
+        }
+
+        let mut result = FheString {
+            enc_string: str.chars()[clear_start..clear_end].to_vec(),
+            padded: false,
+            utf8: str.is_utf8(),
+        };
+
+        if let (UIntArg::Clear(_), UIntArg::Clear(_)) = (start, end) {
+            // The slice of a padded string may end with nulls
+            if str.is_padded() {
+                result.append_null(self);
+            }
+
+            return result;
+        }
+
+        // Shifting the chars before `start` out leaves nulls at the end
+        if let UIntArg::Enc(_) = start {
+            result = self.left_shift_chars(&result, &self.uint_arg_to_index(start));
+        }
+
+        // The char at index `i` of the result is kept if `i < end - start`. If `end` is clear the
+        // chars past it were already removed
+        if let UIntArg::Enc(_) = end {
+            let num_kept = self
+                .saturating_sub_index(&self.uint_arg_to_index(end), &self.uint_arg_to_index(start));
+
+            let null = FheAsciiChar::null(self);
+
+            result
+                .chars_mut()
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(i, char)| {
+                    let is_kept = sk.scalar_gt_parallelized(&num_kept, i as u32);
+
+                    *char.ciphertext_mut() = sk.if_then_else_parallelized(
+                        &is_kept,
+                        char.ciphertext(),
+                        null.ciphertext(),
+                    );
+                });
+        }
+
+        result.append_null(self);
+
+        result
+    }
+
+    /// Returns the first `n` bytes of the encrypted string, i.e. the equivalent of
+    /// `&str[..n]`. If `n` is greater than the length of the string, the whole string is
+    /// returned.
+    ///
+    /// The length `n` is specified by a `UIntArg`, which can be either `Clear` or `Enc`. As for
+    /// [`Self::substring`], the result is padded unless `n` is clear and the string is not padded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "hello";
+    ///
+    /// let enc_s = FheString::new(&ck, s, Some(2));
+    ///
+    /// let enc_n = UIntArg::Enc(ck.encrypt_u16(2, None));
+    /// let result = sk.truncate(&enc_s, &enc_n);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result), "he");
+    /// ```
+    pub fn truncate(&self, str: &FheString, n: &UIntArg) -> FheString {
+        self.substring(str, &UIntArg::Clear(0), n)
+    }
+
+    /// Returns a tuple containing the byte of the encrypted string at the given index, and a
+    /// boolean indicating if the index is within the string.
+    ///
+    /// The index is specified by a `UIntArg`, which can be either `Clear` or `Enc`. If the index
+    /// is out of bounds, the function returns a null char and a boolean that is `false`,
+    /// indicating the equivalent of `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "hello";
+    ///
+    /// let enc_s = FheString::new(&ck, s, Some(1));
+    ///
+    /// let enc_index = UIntArg::Enc(ck.encrypt_u16(1, None));
+    /// let (char, is_some) = sk.char_at(&enc_s, &enc_index);
+    ///
+    /// let char = ck.inner().decrypt_radix::<u8>(char.ciphertext());
+    /// let is_some = ck.inner().decrypt_bool(&is_some);
+    ///
+    /// assert!(is_some);
+    /// assert_eq!(char, b'e');
+    /// ```
+    pub fn char_at(&self, str: &FheString, index: &UIntArg) -> (FheAsciiChar, BooleanBlock) {
+        let sk = self.inner();
+
+        match index {
+            UIntArg::Clear(index) => {
+                let Some(char) = str.chars().get(*index as usize) else {
+                    return (
+                        FheAsciiChar::null(self),
+                        sk.create_trivial_boolean_block(false),
+                    );
+                };
+
+                // The char may be padding
+                let is_some = if str.is_padded() {
+                    sk.scalar_ne_parallelized(char.ciphertext(), 0u8)
+                } else {
+                    sk.create_trivial_boolean_block(true)
+                };
+
+                (char.clone(), is_some)
+            }
+            UIntArg::Enc(enc_index) => {
+                let null = FheAsciiChar::null(self);
+
+                // Only the char at the index is kept, the others being set to null
+                let selected: Vec<_> = str
+                    .chars()
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, char)| {
+                        let is_index = sk.scalar_eq_parallelized(enc_index.cipher(), i as u32);
+
+                        sk.if_then_else_parallelized(
+                            &is_index,
+                            char.ciphertext(),
+                            null.ciphertext(),
+                        )
+                    })
+                    .collect();
+
+                let Some(char) = sk.sum_ciphertexts_parallelized(selected.iter()) else {
+                    return (null, sk.create_trivial_boolean_block(false));
+                };
+
+                // Non null chars are only found at an index within the string
+                let is_some = sk.scalar_ne_parallelized(&char, 0u8);
+
+                (FheAsciiChar { enc_char: char }, is_some)
+            }
+        }
+    }
+
+    /// Returns a new encrypted string padded at the end with the `fill` char, so that its length
+    /// is at least `target_len`, i.e. the equivalent of `format!("{str:fill<target_len$}")`.
+    ///
+    /// The target length is specified by a `UIntArg`, which can be either `Clear` or `Enc`. If it
+    /// is encrypted, the result has as many chars as the maximum value of the target length, so
+    /// providing a `max` when encrypting it is highly recommended.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `fill` is not an ASCII char or if it is the null char.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "ab";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// let max = 5; // Restricts the range of enc_n to 0..=max
+    /// let enc_n = UIntArg::Enc(ck.encrypt_u16(4, Some(max)));
+    /// let result = sk.pad_end(&enc_s, &enc_n, '.');
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result), "ab..");
+    /// ```
+    pub fn pad_end(&self, str: &FheString, target_len: &UIntArg, fill: char) -> FheString {
+        assert!(
+            fill.is_ascii() && fill != '\0',
+            "fill must be a non null ASCII char"
+        );
+
+        let sk = self.inner();
+
+        let str_len = self.byte_len(str);
+
+        if let (FheStringLen::NoPadding(len), UIntArg::Clear(target_len)) = (&str_len, target_len) {
+            let fills = fill
+                .to_string()
+                .repeat((*target_len as usize).saturating_sub(*len));
+
+            let mut result = str.clone();
+            result
+                .chars_vec()
+                .extend_from_slice(FheString::trivial(self, &fills).chars());
+
+            return result;
+        }
+
+        let max_target_len = match target_len {
+            UIntArg::Clear(target_len) => *target_len,
+            UIntArg::Enc(enc) => enc.max().unwrap_or(u16::MAX),
+        };
+
+        let mut result = self.extend_with_nulls(str, max_target_len as usize);
+
+        let target_len = self.uint_arg_to_index(target_len);
+        let fill = sk.create_trivial_radix(fill as u8, self.num_ascii_blocks());
+
+        // A char is a fill char if it is past the end of `str` (hence a null) and before the
+        // target length
+        result
+            .chars_mut()
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, char)| {
+                let is_past_end = match &str_len {
+                    FheStringLen::NoPadding(len) if i < *len => return,
+                    FheStringLen::NoPadding(_) => None,
+                    FheStringLen::Padding(enc_len) => {
+                        Some(sk.scalar_le_parallelized(enc_len, i as u32))
+                    }
+                };
+
+                let mut is_fill = sk.scalar_gt_parallelized(&target_len, i as u32);
+                if let Some(is_past_end) = is_past_end {
+                    sk.boolean_bitand_assign(&mut is_fill, &is_past_end);
+                }
+
+                *char.ciphertext_mut() =
+                    sk.if_then_else_parallelized(&is_fill, &fill, char.ciphertext());
+            });
+
+        // If the target length or the length of `str` are encrypted, there may be nulls left
+        result.append_null(self);
+
+        result
+    }
+
+    /// Returns a new encrypted string padded at the start with the `fill` char, so that its
+    /// length is at least `target_len`, i.e. the equivalent of `format!("{str:fill>target_len$}")`.
+    ///
+    /// The target length is specified by a `UIntArg`, which can be either `Clear` or `Enc`. If it
+    /// is encrypted, the result has as many chars as the maximum value of the target length, so
+    /// providing a `max` when encrypting it is highly recommended.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `fill` is not an ASCII char or if it is the null char.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "42";
+    ///
+    /// let enc_s = FheString::new(&ck, s, Some(1));
+    ///
+    /// let result = sk.pad_start(&enc_s, &UIntArg::Clear(5), '0');
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result), "00042");
+    /// ```
+    pub fn pad_start(&self, str: &FheString, target_len: &UIntArg, fill: char) -> FheString {
+        assert!(
+            fill.is_ascii() && fill != '\0',
+            "fill must be a non null ASCII char"
+        );
+
+        let sk = self.inner();
+
+        let str_len = self.byte_len(str);
+
+        if let (FheStringLen::NoPadding(len), UIntArg::Clear(target_len)) = (&str_len, target_len) {
+            let fills = fill
+                .to_string()
+                .repeat((*target_len as usize).saturating_sub(*len));
+
+            let mut result = FheString::trivial(self, &fills);
+            result.chars_vec().extend_from_slice(str.chars());
+            result.set_is_utf8(str.is_utf8());
+
+            return result;
+        }
+
+        let max_target_len = match target_len {
+            UIntArg::Clear(target_len) => *target_len,
+            UIntArg::Enc(enc) => enc.max().unwrap_or(u16::MAX),
+        };
+
+        let extended = self.extend_with_nulls(str, max_target_len as usize);
+
+        if extended.len() == 0 {
+            return FheString::empty();
+        }
+
+        let str_len = match str_len {
+            FheStringLen::NoPadding(len) => sk.create_trivial_radix(len as u32, 16),
+            FheStringLen::Padding(enc_len) => enc_len,
+        };
+
+        let num_fills = self.saturating_sub_index(&self.uint_arg_to_index(target_len), &str_len);
+
+        // The chars of `str` are moved after the fill chars, which are first nulls. As the string
+        // was extended up to the target length, none of them is shifted out
+        let mut result = self.right_shift_chars(&extended, &num_fills);
+
+        let fill = sk.create_trivial_radix(fill as u8, self.num_ascii_blocks());
+
+        result
+            .chars_mut()
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, char)| {
+                let is_fill = sk.scalar_gt_parallelized(&num_fills, i as u32);
+
+                *char.ciphertext_mut() =
+                    sk.if_then_else_parallelized(&is_fill, &fill, char.ciphertext());
+            });
+
+        // If the target length or the length of `str` are encrypted, there may be nulls left
+        result.append_null(self);
+
+        result
+    }
+}
@@ -229,8 +229,8 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         // before and after the trimming, and use that amount to shift the result left. This
         // makes the result nulls be at the end
         result.set_is_padded(true);
-        if let FheStringLen::Padding(len_after_trim) = self.len(&result) {
-            let original_str_len = match self.len(str) {
+        if let FheStringLen::Padding(len_after_trim) = self.byte_len(&result) {
+            let original_str_len = match self.byte_len(str) {
                 FheStringLen::Padding(enc_val) => enc_val,
                 FheStringLen::NoPadding(val) => sk.create_trivial_radix(val as u32, 16),
             };
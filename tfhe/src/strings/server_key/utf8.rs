@@ -0,0 +1,216 @@
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::FheString;
+use crate::strings::server_key::ServerKey;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+
+// In UTF-8, the letters of the Latin-1 Supplement block (U+00C0 to U+00FE) are encoded as the 0xC3
+// lead byte followed by a continuation byte. As in ASCII, an uppercase letter and its lowercase
+// counterpart only differ by 32, with the exception of U+00D7 and U+00F7 which are the
+// multiplication and division signs.
+const LATIN1_LEAD_BYTE: u8 = 0xC3;
+const LATIN1_UPPERCASE_START: u8 = 0x80;
+const LATIN1_UPPERCASE_END: u8 = 0x9E;
+const LATIN1_MULTIPLICATION_SIGN: u8 = 0x97;
+const LATIN1_LOWERCASE_START: u8 = 0xA0;
+const LATIN1_LOWERCASE_END: u8 = 0xBE;
+const LATIN1_DIVISION_SIGN: u8 = 0xB7;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    // Counts the characters (i.e. Unicode scalar values) of an encrypted UTF-8 string, that is the
+    // bytes that are neither null nor UTF-8 continuation bytes
+    pub(super) fn count_utf8_chars(&self, str: &FheString) -> RadixCiphertext {
+        let sk = self.inner();
+
+        if str.chars().is_empty() {
+            return sk.create_trivial_zero_radix(16);
+        }
+
+        let char_starts: Vec<_> = str
+            .chars()
+            .par_iter()
+            .map(|char| {
+                let (is_not_null, (is_ge_0x80, is_lt_0xc0)) = rayon::join(
+                    || sk.scalar_ne_parallelized(char.ciphertext(), 0u8),
+                    || {
+                        rayon::join(
+                            || sk.scalar_ge_parallelized(char.ciphertext(), 0x80u8),
+                            || sk.scalar_lt_parallelized(char.ciphertext(), 0xC0u8),
+                        )
+                    },
+                );
+
+                let mut is_not_continuation = sk.boolean_bitand(&is_ge_0x80, &is_lt_0xc0);
+                sk.boolean_bitnot_assign(&mut is_not_continuation);
+
+                let is_char_start = sk.boolean_bitand(&is_not_null, &is_not_continuation);
+                is_char_start.into_radix(16, sk)
+            })
+            .collect();
+
+        sk.sum_ciphertexts_parallelized(char_starts.iter())
+            .expect("There's at least one byte")
+    }
+
+    /// Returns a new encrypted UTF-8 string with all characters converted to uppercase.
+    ///
+    /// ASCII letters and the letters of the Latin-1 Supplement block (e.g. 'é' or 'ø') are
+    /// converted, other characters are left untouched. Latin-1 letters whose uppercase is outside
+    /// of the block ('ß', 'ÿ') are also left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "Crème brûlée";
+    ///
+    /// let enc_s = FheString::new_utf8(&ck, s, None);
+    ///
+    /// let result = sk.to_uppercase_utf8(&enc_s);
+    /// let uppercased = ck.decrypt_utf8(&result);
+    ///
+    /// assert_eq!(uppercased, "CRÈME BRÛLÉE");
+    /// ```
+    pub fn to_uppercase_utf8(&self, str: &FheString) -> FheString {
+        let sk = self.inner();
+
+        // ASCII chars are handled as usual, this does not modify the bytes of non-ASCII chars
+        let mut uppercase = self.to_uppercase(str);
+
+        let lowercase_chars = self.latin1_letters_in_range(
+            str,
+            LATIN1_LOWERCASE_START,
+            LATIN1_LOWERCASE_END,
+            LATIN1_DIVISION_SIGN,
+        );
+
+        // Subtraction by 32 makes the continuation byte the one of the uppercase letter
+        uppercase
+            .chars_mut()
+            .par_iter_mut()
+            .zip(lowercase_chars.into_par_iter())
+            .for_each(|(char, is_lowercase)| {
+                let mut subtract = sk.create_trivial_radix(32, self.num_ascii_blocks());
+
+                sk.mul_assign_parallelized(&mut subtract, &is_lowercase.into_radix(1, sk));
+
+                sk.sub_assign_parallelized(char.ciphertext_mut(), &subtract);
+            });
+
+        uppercase
+    }
+
+    /// Returns a new encrypted UTF-8 string with all characters converted to lowercase.
+    ///
+    /// ASCII letters and the letters of the Latin-1 Supplement block (e.g. 'É' or 'Ø') are
+    /// converted, other characters are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "ÀÉÎÕÜ × Ñ";
+    ///
+    /// let enc_s = FheString::new_utf8(&ck, s, None);
+    ///
+    /// let result = sk.to_lowercase_utf8(&enc_s);
+    /// let lowercased = ck.decrypt_utf8(&result);
+    ///
+    /// assert_eq!(lowercased, "àéîõü × ñ");
+    /// ```
+    pub fn to_lowercase_utf8(&self, str: &FheString) -> FheString {
+        let sk = self.inner();
+
+        let mut lowercase = self.to_lowercase(str);
+
+        let uppercase_chars = self.latin1_letters_in_range(
+            str,
+            LATIN1_UPPERCASE_START,
+            LATIN1_UPPERCASE_END,
+            LATIN1_MULTIPLICATION_SIGN,
+        );
+
+        // Addition by 32 makes the continuation byte the one of the lowercase letter
+        lowercase
+            .chars_mut()
+            .par_iter_mut()
+            .zip(uppercase_chars.into_par_iter())
+            .for_each(|(char, is_uppercase)| {
+                let mut add = sk.create_trivial_radix(32, self.num_ascii_blocks());
+
+                sk.mul_assign_parallelized(&mut add, &is_uppercase.into_radix(1, sk));
+
+                sk.add_assign_parallelized(char.ciphertext_mut(), &add);
+            });
+
+        lowercase
+    }
+
+    // Returns, for each byte, whether it is the continuation byte of a Latin-1 letter i.e. it is
+    // preceded by the Latin-1 lead byte and within `start..=end`, except for `excluded`
+    fn latin1_letters_in_range(
+        &self,
+        str: &FheString,
+        start: u8,
+        end: u8,
+        excluded: u8,
+    ) -> Vec<BooleanBlock> {
+        let sk = self.inner();
+
+        if str.chars().is_empty() {
+            return vec![];
+        }
+
+        // The first byte cannot be a continuation byte
+        let mut result = vec![sk.create_trivial_boolean_block(false)];
+
+        let continuation_bytes: Vec<_> = str
+            .chars()
+            .par_windows(2)
+            .map(|window| {
+                let (prev, char) = (window[0].ciphertext(), window[1].ciphertext());
+
+                let ((is_lead, is_ge_start), (is_le_end, is_not_excluded)) = rayon::join(
+                    || {
+                        rayon::join(
+                            || sk.scalar_eq_parallelized(prev, LATIN1_LEAD_BYTE),
+                            || sk.scalar_ge_parallelized(char, start),
+                        )
+                    },
+                    || {
+                        rayon::join(
+                            || sk.scalar_le_parallelized(char, end),
+                            || sk.scalar_ne_parallelized(char, excluded),
+                        )
+                    },
+                );
+
+                let (lhs, rhs) = rayon::join(
+                    || sk.boolean_bitand(&is_lead, &is_ge_start),
+                    || sk.boolean_bitand(&is_le_end, &is_not_excluded),
+                );
+
+                sk.boolean_bitand(&lhs, &rhs)
+            })
+            .collect();
+
+        result.extend(continuation_bytes);
+
+        result
+    }
+}
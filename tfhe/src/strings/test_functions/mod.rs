@@ -1,7 +1,11 @@
 mod test_common;
 mod test_concat;
 mod test_contains;
+mod test_conversion;
 mod test_find_replace;
+mod test_regex;
 mod test_split;
+mod test_substring;
 mod test_up_low_case;
+mod test_utf8;
 mod test_whitespace;
@@ -0,0 +1,122 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+
+// (str, radix)
+const TEST_CASES_PARSE: [(&str, u32); 14] = [
+    ("", 10),
+    ("+", 10),
+    ("0", 10),
+    ("+12", 10),
+    ("12a", 10),
+    ("-1", 10),
+    ("1+", 10),
+    ("ff", 16),
+    ("Ff", 16),
+    ("fg", 16),
+    ("101", 2),
+    ("zz", 36),
+    ("18446744073709551615", 10),
+    ("18446744073709551616", 10),
+];
+
+#[test]
+fn parse_u64_test_parameterized() {
+    parse_u64_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn parse_u64_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for (str, radix) in TEST_CASES_PARSE {
+            let expected = u64::from_str_radix(str, radix).ok();
+
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            let (value, is_valid) = sk.parse_u64(&enc_str, radix);
+            let value = cks.inner().decrypt_radix::<u64>(&value);
+            let is_valid = cks.inner().decrypt_bool(&is_valid);
+
+            assert_eq!(
+                expected,
+                is_valid.then_some(value),
+                "parse_u64 failed for {str:?} in radix {radix} with padding {str_pad}"
+            );
+
+            if !is_valid {
+                assert_eq!(value, 0);
+            }
+        }
+    }
+    // encrypted
+    {
+        for (str, expected) in [("+42", Some(42)), ("4 2", None)] {
+            let enc_str = FheString::new(&cks, str, Some(1));
+
+            let (value, is_valid) = sk.parse_u64(&enc_str, 10);
+            let value = cks.inner().decrypt_radix::<u64>(&value);
+            let is_valid = cks.inner().decrypt_bool(&is_valid);
+
+            assert_eq!(expected, is_valid.then_some(value));
+        }
+    }
+}
+
+#[test]
+fn to_decimal_string_test_parameterized() {
+    to_decimal_string_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn to_decimal_string_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for (value, num_blocks) in [
+        (0u64, 1),
+        (3, 1),
+        (0, 4),
+        (7, 4),
+        (10, 4),
+        (255, 4),
+        (1000, 8),
+        (u64::MAX, 32),
+    ] {
+        let enc_value = sk.inner().create_trivial_radix(value, num_blocks);
+
+        let enc_str = sk.to_decimal_string(&enc_value);
+
+        assert_eq!(
+            value.to_string(),
+            cks.decrypt_ascii(&enc_str),
+            "to_decimal_string failed for {value} on {num_blocks} blocks"
+        );
+    }
+    // encrypted
+    {
+        for value in [0u64, 9, 65535] {
+            let enc_value = cks.inner().encrypt_radix(value, 8);
+
+            let enc_str = sk.to_decimal_string(&enc_value);
+
+            assert_eq!(value.to_string(), cks.decrypt_ascii(&enc_str));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::ClientKey;
+use crate::strings::regex::Regex;
+use crate::strings::server_key::ServerKey;
+
+// (regex, str, expected leftmost match start)
+const TEST_CASES_REGEX: [(&str, &str, Option<usize>); 24] = [
+    ("a", "", None),
+    ("a", "bab", Some(1)),
+    ("", "ab", Some(0)),
+    ("^$", "", Some(0)),
+    ("^$", "a", None),
+    ("b*$", "ab", Some(1)),
+    ("x*$", "ab", Some(2)),
+    ("ab|cd", "xcd", Some(1)),
+    ("a(b|c)+d", "abcbd", Some(0)),
+    ("a(b|c)+d", "ad", None),
+    ("colou?r", "color", Some(0)),
+    ("colou?r", "a colour", Some(2)),
+    ("^ab", "cab", None),
+    ("ab$", "abc", None),
+    ("ab$", "cab", Some(1)),
+    ("^a.c$", "abc", Some(0)),
+    ("^a.c$", "a\nc", None),
+    (r"\d{2,3}", "a1b22", Some(3)),
+    (r"^\d{2}$", "123", None),
+    (r"[^a-z]", "abC", Some(2)),
+    (r"\w+\s\w+", "  hi you", Some(2)),
+    (r"\.", "a.b", Some(1)),
+    (r"[a-c]{2,}", "abd", Some(0)),
+    (r"^[a-z]+@[a-z]+\.(com|org)$", "bob@zama.org", Some(0)),
+];
+
+#[test]
+fn regex_parse() {
+    for pattern in [
+        "a", "", "^$", r"a\$", "(a|b)*c", "[]a]", "[a-]", r"[\d_]", "a{3}", "(?:ab)",
+    ] {
+        let regex = Regex::new(pattern).unwrap();
+        assert_eq!(regex.as_str(), pattern);
+    }
+
+    for pattern in [
+        "(a", "a)", "[a", "*a", "a{2,1}", "a{", "a{1000}", r"\q", "a^", "$a", "[z-a]", "é",
+    ] {
+        assert!(Regex::new(pattern).is_err(), "{pattern} should be invalid");
+    }
+}
+
+#[test]
+fn regex_test_parameterized() {
+    regex_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn regex_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for (pattern, str, expected) in TEST_CASES_REGEX {
+            let regex = Regex::new(pattern).unwrap();
+
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            let is_match = sk.is_match(&enc_str, &regex);
+            assert_eq!(
+                expected.is_some(),
+                cks.inner().decrypt_bool(&is_match),
+                "is_match failed for {pattern} on {str:?} with padding {str_pad}"
+            );
+
+            let (index, is_some) = sk.find_regex(&enc_str, &regex);
+            let index = cks.inner().decrypt_radix::<u32>(&index);
+            let is_some = cks.inner().decrypt_bool(&is_some);
+            assert_eq!(
+                expected,
+                is_some.then_some(index as usize),
+                "find_regex failed for {pattern} on {str:?} with padding {str_pad}"
+            );
+        }
+    }
+    // encrypted
+    {
+        let regex = Regex::new(r"^[a-z]+@[a-z]+\.com$").unwrap();
+
+        for (str, expected) in [("ab@cd.com", true), ("ab@cd.co", false)] {
+            let enc_str = FheString::new(&cks, str, Some(1));
+
+            let is_match = sk.is_match(&enc_str, &regex);
+            assert_eq!(expected, cks.inner().decrypt_bool(&is_match));
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::IntegerKeyKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::{FheString, UIntArg};
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::ServerKey;
+
+const TEST_CASES_SUBSTRING: [&str; 4] = ["", "a", "abc", "abcd"];
+
+// Clear counterpart of `substring`, with indices clamped to the string length
+fn clear_substring(str: &str, start: u16, end: u16) -> &str {
+    let start = (start as usize).min(str.len());
+    let end = (end as usize).min(str.len());
+
+    if start >= end {
+        ""
+    } else {
+        &str[start..end]
+    }
+}
+
+#[test]
+fn substring_test_parameterized() {
+    substring_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn substring_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in TEST_CASES_SUBSTRING {
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            for start in 0..=5 {
+                for end in 0..=5 {
+                    let expected = clear_substring(str, start, end);
+
+                    let clear_start = UIntArg::Clear(start);
+                    let clear_end = UIntArg::Clear(end);
+                    let enc_start = UIntArg::Enc(cks.trivial_encrypt_u16(start, None));
+                    let enc_end = UIntArg::Enc(cks.trivial_encrypt_u16(end, None));
+
+                    for (start_arg, end_arg) in [
+                        (&clear_start, &clear_end),
+                        (&clear_start, &enc_end),
+                        (&enc_start, &clear_end),
+                        (&enc_start, &enc_end),
+                    ] {
+                        let result = sk.substring(&enc_str, start_arg, end_arg);
+
+                        assert_eq!(
+                            expected,
+                            cks.decrypt_ascii(&result),
+                            "substring failed for {str:?}[{start}..{end}] with padding {str_pad}"
+                        );
+                    }
+
+                    // Clear indices on an unpadded string give an unpadded result
+                    if str_pad == 0 {
+                        let result = sk.substring(&enc_str, &clear_start, &clear_end);
+                        assert!(!result.is_padded());
+                    }
+                }
+
+                let result = sk.truncate(&enc_str, &UIntArg::Clear(start));
+                assert_eq!(clear_substring(str, 0, start), cks.decrypt_ascii(&result));
+            }
+        }
+    }
+    // encrypted
+    {
+        let enc_str = FheString::new(&cks, "hello", Some(1));
+
+        let enc_start = UIntArg::Enc(cks.encrypt_u16(1, None));
+        let enc_end = UIntArg::Enc(cks.encrypt_u16(4, None));
+
+        let result = sk.substring(&enc_str, &enc_start, &enc_end);
+        assert_eq!("ell", cks.decrypt_ascii(&result));
+
+        let result = sk.truncate(&enc_str, &enc_end);
+        assert_eq!("hell", cks.decrypt_ascii(&result));
+    }
+}
+
+#[test]
+fn char_at_test_parameterized() {
+    char_at_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn char_at_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in TEST_CASES_SUBSTRING {
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            for index in 0..=5 {
+                let expected = str.as_bytes().get(index as usize).copied();
+
+                for index_arg in [
+                    UIntArg::Clear(index),
+                    UIntArg::Enc(cks.trivial_encrypt_u16(index, None)),
+                ] {
+                    let (char, is_some) = sk.char_at(&enc_str, &index_arg);
+                    let char = cks.inner().decrypt_radix::<u8>(char.ciphertext());
+                    let is_some = cks.inner().decrypt_bool(&is_some);
+
+                    assert_eq!(
+                        expected,
+                        is_some.then_some(char),
+                        "char_at failed for {str:?} at {index} with padding {str_pad}"
+                    );
+
+                    if !is_some {
+                        assert_eq!(char, 0);
+                    }
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let enc_str = FheString::new(&cks, "abc", Some(1));
+
+        for (index, expected) in [(2, Some(b'c')), (3, None)] {
+            let enc_index = UIntArg::Enc(cks.encrypt_u16(index, None));
+
+            let (char, is_some) = sk.char_at(&enc_str, &enc_index);
+            let char = cks.inner().decrypt_radix::<u8>(char.ciphertext());
+            let is_some = cks.inner().decrypt_bool(&is_some);
+
+            assert_eq!(expected, is_some.then_some(char));
+        }
+    }
+}
+
+#[test]
+fn pad_test_parameterized() {
+    pad_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn pad_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    let max = 5;
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in TEST_CASES_SUBSTRING {
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            for target_len in 0..=max {
+                let expected_start = format!("{str:*>width$}", width = target_len as usize);
+                let expected_end = format!("{str:*<width$}", width = target_len as usize);
+
+                for target_arg in [
+                    UIntArg::Clear(target_len),
+                    UIntArg::Enc(cks.trivial_encrypt_u16(target_len, Some(max))),
+                ] {
+                    let result = sk.pad_start(&enc_str, &target_arg, '*');
+                    assert_eq!(
+                        expected_start,
+                        cks.decrypt_ascii(&result),
+                        "pad_start failed for {str:?} to {target_len} with padding {str_pad}"
+                    );
+
+                    let result = sk.pad_end(&enc_str, &target_arg, '*');
+                    assert_eq!(
+                        expected_end,
+                        cks.decrypt_ascii(&result),
+                        "pad_end failed for {str:?} to {target_len} with padding {str_pad}"
+                    );
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let enc_str = FheString::new(&cks, "ab", Some(1));
+        let enc_target_len = UIntArg::Enc(cks.encrypt_u16(4, Some(max)));
+
+        let result = sk.pad_start(&enc_str, &enc_target_len, '0');
+        assert_eq!("00ab", cks.decrypt_ascii(&result));
+
+        let result = sk.pad_end(&enc_str, &enc_target_len, '0');
+        assert_eq!("ab00", cks.decrypt_ascii(&result));
+    }
+}
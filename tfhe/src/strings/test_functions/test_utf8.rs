@@ -0,0 +1,204 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{ClientKey as IntegerClientKey, IntegerKeyKind};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::PBSParameters;
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern};
+use crate::strings::client_key::ClientKey;
+use crate::strings::server_key::{FheStringLen, ServerKey};
+
+const UTF8_STRINGS: [&str; 6] = ["", "a", "é", "aé", "héllo wörld", "日本語 ok"];
+
+// Clear counterpart of the UTF-8 case conversion: only the ASCII and Latin-1 Supplement letters
+// are converted
+fn clear_latin1_to_lowercase(str: &str) -> String {
+    str.chars()
+        .map(|c| match c {
+            'A'..='Z' | 'À'..='Þ' if c != '×' => c.to_lowercase().next().unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+fn clear_latin1_to_uppercase(str: &str) -> String {
+    str.chars()
+        .map(|c| match c {
+            'a'..='z' | 'à'..='þ' if c != '÷' => c.to_uppercase().next().unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+#[test]
+fn utf8_encrypt_decrypt_parameterized() {
+    utf8_encrypt_decrypt(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn utf8_encrypt_decrypt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, _sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+
+    for str in UTF8_STRINGS {
+        for pad in 0..3 {
+            let enc_str = FheString::new_utf8(&cks, str, Some(pad));
+
+            // There is one `FheAsciiChar` per byte of the UTF-8 encoding
+            assert_eq!(enc_str.chars().len(), str.len() + pad as usize);
+
+            let dec = cks.decrypt_utf8(&enc_str);
+
+            assert_eq!(str, &dec);
+        }
+    }
+}
+
+// Decrypts the length of a UTF-8 string, which is always encrypted
+fn decrypt_utf8_len(cks: &ClientKey<IntegerClientKey>, len: FheStringLen) -> u16 {
+    match len {
+        FheStringLen::NoPadding(_) => panic!("The length of a UTF-8 string should be encrypted"),
+        FheStringLen::Padding(len) => cks.inner().decrypt_radix::<u16>(&len),
+    }
+}
+
+#[test]
+fn utf8_len_parameterized() {
+    utf8_len(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn utf8_len<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for str in UTF8_STRINGS {
+        for pad in 0..2 {
+            let expected_result = str.chars().count() as u16;
+
+            let enc_str = FheString::new_trivial_utf8(&cks, str, Some(pad));
+
+            let result = sk.len(&enc_str);
+
+            assert_eq!(expected_result, decrypt_utf8_len(&cks, result));
+        }
+    }
+    // encrypted
+    {
+        let str = "aé€😀";
+
+        let enc_str = FheString::new_utf8(&cks, str, Some(1));
+
+        let result = sk.len(&enc_str);
+
+        assert_eq!(4, decrypt_utf8_len(&cks, result));
+
+        // The byte length is still available
+        match sk.byte_len(&enc_str) {
+            FheStringLen::NoPadding(_) => panic!("Unexpected no padding"),
+            FheStringLen::Padding(len) => {
+                assert_eq!(str.len() as u16, cks.inner().decrypt_radix::<u16>(&len));
+            }
+        }
+    }
+}
+
+#[test]
+fn utf8_to_lower_upper_case_parameterized() {
+    utf8_to_lower_upper_case(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+fn utf8_to_lower_upper_case<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    // trivial
+    for str in [
+        "", "À", "à", "×", "÷", "Þ", "þ", "ß", "ÿ", "Ā", "aÉ", "Ée", "ÀÖØÞ", "àöøþ", "日本",
+    ] {
+        for pad in 0..2 {
+            let enc_str = FheString::new_trivial_utf8(&cks, str, Some(pad));
+
+            let lowercase = sk.to_lowercase_utf8(&enc_str);
+            assert_eq!(clear_latin1_to_lowercase(str), cks.decrypt_utf8(&lowercase));
+
+            let uppercase = sk.to_uppercase_utf8(&enc_str);
+            assert_eq!(clear_latin1_to_uppercase(str), cks.decrypt_utf8(&uppercase));
+        }
+    }
+    // encrypted
+    {
+        let str = "Éa×ç";
+
+        let enc_str = FheString::new_utf8(&cks, str, Some(1));
+
+        let lowercase = sk.to_lowercase_utf8(&enc_str);
+        assert_eq!("éa×ç", cks.decrypt_utf8(&lowercase));
+
+        let uppercase = sk.to_uppercase_utf8(&enc_str);
+        assert_eq!("ÉA×Ç", cks.decrypt_utf8(&uppercase));
+    }
+}
+
+#[test]
+fn utf8_patterns_parameterized() {
+    utf8_patterns(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+// Pattern operations work on the UTF-8 bytes, as a valid UTF-8 pattern can only match a valid
+// UTF-8 string on char boundaries they behave as their clear Rust counterparts (including the
+// indices which are byte indices)
+fn utf8_patterns<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let cks = ClientKey::new(cks);
+    let sk = ServerKey::new(sks);
+
+    for (str, pat) in [("aéb", "é"), ("ébé", "bé"), ("日本", "本"), ("aé", "e")] {
+        let enc_str = FheString::new_trivial_utf8(&cks, str, Some(1));
+        let enc_pat = GenericPattern::Enc(FheString::new_trivial_utf8(&cks, pat, Some(1)));
+        let clear_pat = GenericPattern::Clear(ClearString::new_utf8(pat.to_string()));
+        let enc_to = FheString::new_trivial_utf8(&cks, "ö", None);
+
+        for pat_ref in [enc_pat.as_ref(), clear_pat.as_ref()] {
+            let result = sk.contains(&enc_str, pat_ref);
+            assert_eq!(str.contains(pat), cks.inner().decrypt_bool(&result));
+
+            let (index, is_some) = sk.find(&enc_str, pat_ref);
+            let index = cks.inner().decrypt_radix::<u32>(&index);
+            let is_some = cks.inner().decrypt_bool(&is_some);
+            assert_eq!(str.find(pat), is_some.then_some(index as usize));
+
+            let result = sk.replace(&enc_str, pat_ref, &enc_to);
+            let expected = str.replace(pat, "ö");
+            assert_eq!(expected, cks.decrypt_utf8(&result));
+            assert_eq!(
+                expected.chars().count() as u16,
+                decrypt_utf8_len(&cks, sk.len(&result))
+            );
+
+            let (lhs, rhs, is_some) = sk.split_once(&enc_str, pat_ref);
+            let is_some = cks.inner().decrypt_bool(&is_some);
+            let result = is_some.then(|| (cks.decrypt_utf8(&lhs), cks.decrypt_utf8(&rhs)));
+            assert_eq!(
+                str.split_once(pat)
+                    .map(|(lhs, rhs)| (lhs.to_string(), rhs.to_string())),
+                result
+            );
+        }
+    }
+}
@@ -9,7 +9,9 @@ use tfhe_zk_pok::serialization::InvalidSerializedPublicParamsError;
 type Curve = tfhe_zk_pok::curve_api::Bls12_446;
 
 use super::{
-    CompactPkeCrs, CompactPkeProof, CompressedCompactPkeCrs, SerializableCompactPkePublicParams,
+    CompactPkeCrs, CompactPkeCrsUpdateProof, CompactPkeProof, CompactPublicKeyProof,
+    CompactPublicKeyProofCrs, CompressedCompactPkeCrs, DecryptionProof, DecryptionProofCrs,
+    MessageBitsOpening, SerializableCompactPkePublicParams,
 };
 
 #[derive(Version)]
@@ -80,3 +82,33 @@ pub enum CompactPkeProofVersions {
     V1(CompactPkeProofV1),
     V2(CompactPkeProof),
 }
+
+#[derive(VersionsDispatch)]
+pub enum CompactPkeCrsUpdateProofVersions {
+    V0(CompactPkeCrsUpdateProof),
+}
+
+#[derive(VersionsDispatch)]
+pub enum MessageBitsOpeningVersions {
+    V0(MessageBitsOpening),
+}
+
+#[derive(VersionsDispatch)]
+pub enum DecryptionProofCrsVersions {
+    V0(DecryptionProofCrs),
+}
+
+#[derive(VersionsDispatch)]
+pub enum DecryptionProofVersions {
+    V0(DecryptionProof),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompactPublicKeyProofCrsVersions {
+    V0(CompactPublicKeyProofCrs),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompactPublicKeyProofVersions {
+    V0(CompactPublicKeyProof),
+}
@@ -1,6 +1,7 @@
 pub mod backward_compatibility;
 
 use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::algorithms::slice_algorithms::slice_semi_reverse_negacyclic_convolution;
 use crate::core_crypto::commons::math::random::{
     BoundedDistribution, ByteRandomGenerator, RandomGenerator,
 };
@@ -10,6 +11,7 @@ use crate::named::Named;
 use crate::shortint::parameters::CompactPublicKeyEncryptionParameters;
 use backward_compatibility::*;
 use rand_core::RngCore;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::Bound;
@@ -21,11 +23,25 @@ use tfhe_zk_pok::proofs::pke::{
     Proof as ProofV1, PublicCommit as PublicCommitV1,
 };
 use tfhe_zk_pok::proofs::pke_v2::{
-    commit as commit_v2, crs_gen as crs_gen_v2, prove as prove_v2, verify as verify_v2,
-    Proof as ProofV2, PublicCommit as PublicCommitV2,
+    commit as commit_v2, compute_crs_params, crs_gen as crs_gen_v2, prove as prove_v2,
+    prove_with_message_bits_openings as prove_v2_with_message_bits_openings, verify as verify_v2,
+    verify_batch as verify_batch_v2, verify_crs_update_transcript,
+    verify_message_bits_relation as verify_message_bits_relation_v2, Bound as PkeV2Bound,
+    CrsUpdateProof, MessageBitsOpening as MessageBitsOpeningV2, Proof as ProofV2,
+    PublicCommit as PublicCommitV2,
+};
+use tfhe_zk_pok::proofs::rlwe::{
+    commit as commit_rlwe, crs_gen as crs_gen_rlwe, prove as prove_rlwe, verify as verify_rlwe,
+    Matrix as RlweMatrix, Proof as RlweProof, PublicCommit as RlwePublicCommit,
+    Vector as RlweVector,
 };
 
+#[cfg(feature = "integer")]
+pub use crate::high_level_api::zk::{
+    prove_compact_public_key, prove_decryption, verify_compact_public_key, verify_decryption,
+};
 pub use tfhe_zk_pok::curve_api::Compressible;
+pub(crate) use tfhe_zk_pok::proofs::pke_v2::WeightedMessageBit;
 pub use tfhe_zk_pok::proofs::ComputeLoad as ZkComputeLoad;
 type Curve = tfhe_zk_pok::curve_api::Bls12_446;
 
@@ -171,6 +187,33 @@ impl ZkVerificationOutcome {
     }
 }
 
+/// The outcome of the verification of a batch of proofs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ZkBatchVerificationOutcome {
+    /// All the proofs of the batch were valid
+    Valid,
+    /// The proofs at the given indices of the batch were not valid
+    Invalid(Vec<usize>),
+}
+
+impl ZkBatchVerificationOutcome {
+    pub fn is_valid(&self) -> bool {
+        *self == Self::Valid
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+
+    /// The indices of the invalid proofs in the batch, in increasing order
+    pub fn invalid_indices(&self) -> &[usize] {
+        match self {
+            Self::Valid => &[],
+            Self::Invalid(indices) => indices,
+        }
+    }
+}
+
 /// The Zk Scheme for compact private key encryption is available in 2 versions. In case of doubt,
 /// you should prefer the V2 which is more efficient.
 #[derive(Clone, Copy)]
@@ -412,6 +455,79 @@ impl CompactPkeCrs {
         Ok(Self::PkeV2(public_params))
     }
 
+    /// Contributes to a CRS generation ceremony by updating this CRS with fresh randomness.
+    ///
+    /// Each participant of the ceremony updates the CRS received from the previous one and
+    /// publishes the proof of its update. The resulting CRS can be trusted as long as at least one
+    /// participant was honest and did not keep its randomness. The transcript of the ceremony is
+    /// verified with [Self::verify_update_transcript].
+    ///
+    /// Only CRS for the V2 scheme can be updated.
+    pub fn update(
+        &self,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<(Self, CompactPkeCrsUpdateProof)> {
+        match self {
+            Self::PkeV1(_) => Err(crate::Error::new(
+                "Only CRS for the V2 zk scheme can be updated".to_string(),
+            )),
+            Self::PkeV2(public_params) => {
+                let (updated, proof) = public_params.update(rng);
+                Ok((Self::PkeV2(updated), CompactPkeCrsUpdateProof(proof)))
+            }
+        }
+    }
+
+    /// Verifies that `updated` was obtained from this CRS by the successive updates of a
+    /// ceremony, given the proofs of each participant in order.
+    ///
+    /// This also checks that `updated` is a well formed CRS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::zk::CompactPkeCrs;
+    /// use tfhe::ConfigBuilder;
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).build();
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let initial_crs = CompactPkeCrs::from_config(config, 16).unwrap();
+    ///
+    /// let mut crs = initial_crs.clone();
+    /// let mut transcript = Vec::new();
+    /// for _participant in 0..3 {
+    ///     let (updated_crs, proof) = crs.update(&mut rng).unwrap();
+    ///     crs = updated_crs;
+    ///     transcript.push(proof);
+    /// }
+    ///
+    /// assert!(initial_crs
+    ///     .verify_update_transcript(&crs, &transcript)
+    ///     .is_valid());
+    /// ```
+    pub fn verify_update_transcript(
+        &self,
+        updated: &Self,
+        proofs: &[CompactPkeCrsUpdateProof],
+    ) -> ZkVerificationOutcome {
+        let (Self::PkeV2(initial), Self::PkeV2(updated)) = (self, updated) else {
+            return ZkVerificationOutcome::Invalid;
+        };
+
+        let proofs = proofs
+            .iter()
+            .map(|proof| proof.0.clone())
+            .collect::<Vec<_>>();
+
+        match verify_crs_update_transcript(initial, updated, &proofs) {
+            Ok(()) => ZkVerificationOutcome::Valid,
+            Err(()) => ZkVerificationOutcome::Invalid,
+        }
+    }
+
     /// Maximum number of messages that can be proven in a single list using this CRS
     pub fn max_num_messages(&self) -> LweCiphertextCount {
         match self {
@@ -474,6 +590,50 @@ impl CompactPkeCrs {
         load: ZkComputeLoad,
         random_generator: &mut RandomGenerator<G>,
     ) -> CompactPkeProof
+    where
+        Scalar: UnsignedInteger,
+        i64: CastFrom<Scalar>,
+        KeyCont: Container<Element = Scalar>,
+        InputCont: Container<Element = Scalar>,
+        ListCont: Container<Element = Scalar>,
+        G: ByteRandomGenerator,
+    {
+        let (proof, _) = self.prove_with_message_bits_openings(
+            compact_public_key,
+            messages,
+            lwe_compact_list,
+            binary_random_vector,
+            mask_noise,
+            body_noise,
+            metadata,
+            load,
+            &[],
+            random_generator,
+        );
+        proof
+    }
+
+    /// Prove a ciphertext list encryption using this CRS, along with one opening per linear
+    /// combination of the bits of the messages in `combinations`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some combinations are given with a legacy V1 CRS, or if they contain bits that
+    /// are not message bits of the list.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn prove_with_message_bits_openings<Scalar, KeyCont, InputCont, ListCont, G>(
+        &self,
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+        messages: &InputCont,
+        lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+        binary_random_vector: &[Scalar],
+        mask_noise: &[Scalar],
+        body_noise: &[Scalar],
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        combinations: &[&[WeightedMessageBit]],
+        random_generator: &mut RandomGenerator<G>,
+    ) -> (CompactPkeProof, Vec<MessageBitsOpening>)
     where
         Scalar: UnsignedInteger,
         i64: CastFrom<Scalar>,
@@ -539,6 +699,11 @@ impl CompactPkeCrs {
 
         match self {
             Self::PkeV1(public_params) => {
+                assert!(
+                    combinations.is_empty(),
+                    "Openings of message bits are not supported by the legacy V1 CRS"
+                );
+
                 let (public_commit, private_commit) = commit_v1(
                     key_mask,
                     key_body,
@@ -560,7 +725,7 @@ impl CompactPkeCrs {
                     random_generator,
                 );
 
-                CompactPkeProof::PkeV1(proof)
+                (CompactPkeProof::PkeV1(proof), Vec::new())
             }
             Self::PkeV2(public_params) => {
                 let (public_commit, private_commit) = commit_v2(
@@ -576,15 +741,19 @@ impl CompactPkeCrs {
                     random_generator,
                 );
 
-                let proof = prove_v2(
+                let (proof, openings) = prove_v2_with_message_bits_openings(
                     (public_params, &public_commit),
                     &private_commit,
                     metadata,
                     load,
+                    combinations,
                     random_generator,
                 );
 
-                CompactPkeProof::PkeV2(proof)
+                (
+                    CompactPkeProof::PkeV2(proof),
+                    openings.into_iter().map(MessageBitsOpening).collect(),
+                )
             }
         }
     }
@@ -659,6 +828,218 @@ impl CompactPkeCrs {
             Err(_) => ZkVerificationOutcome::Invalid,
         }
     }
+
+    /// Verify the validity of a batch of proofs using this CRS
+    ///
+    /// Each list comes with its proof and the metadata used to generate it. With a V2 CRS, the
+    /// pairing equations of all the proofs are checked at once, which is significantly cheaper
+    /// than verifying the proofs one by one. When the batch is not valid, the indices of the
+    /// invalid proofs are returned.
+    pub fn verify_batch<Scalar, ListCont, KeyCont>(
+        &self,
+        proven_lists: &[(&LweCompactCiphertextList<ListCont>, &CompactPkeProof, &[u8])],
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+    ) -> ZkBatchVerificationOutcome
+    where
+        Scalar: UnsignedInteger,
+        i64: CastFrom<Scalar>,
+        ListCont: Container<Element = Scalar> + Sync,
+        KeyCont: Container<Element = Scalar> + Sync,
+    {
+        let Self::PkeV2(public_params) = self else {
+            // The legacy scheme has no batched verification
+            let invalid_indices = proven_lists
+                .par_iter()
+                .enumerate()
+                .filter_map(|(i, &(lwe_compact_list, proof, metadata))| {
+                    self.verify(lwe_compact_list, compact_public_key, proof, metadata)
+                        .is_invalid()
+                        .then_some(i)
+                })
+                .collect::<Vec<_>>();
+
+            return if invalid_indices.is_empty() {
+                ZkBatchVerificationOutcome::Valid
+            } else {
+                ZkBatchVerificationOutcome::Invalid(invalid_indices)
+            };
+        };
+
+        if Scalar::BITS > 64 {
+            return ZkBatchVerificationOutcome::Invalid((0..proven_lists.len()).collect());
+        }
+
+        let to_i64_vec =
+            |slice: &[Scalar]| slice.iter().map(|&x| i64::cast_from(x)).collect::<Vec<_>>();
+
+        let key_mask = to_i64_vec(compact_public_key.get_mask().as_ref());
+        let key_body = to_i64_vec(compact_public_key.get_body().as_ref());
+
+        let mut invalid_indices = Vec::new();
+        let mut batch_indices = Vec::new();
+        let mut proofs = Vec::new();
+        let mut public_commits = Vec::new();
+        for (i, &(lwe_compact_list, proof, metadata)) in proven_lists.iter().enumerate() {
+            match proof {
+                CompactPkeProof::PkeV2(proof) => {
+                    batch_indices.push(i);
+                    proofs.push((proof, metadata));
+                    public_commits.push(PublicCommitV2::new(
+                        key_mask.clone(),
+                        key_body.clone(),
+                        to_i64_vec(lwe_compact_list.get_mask_list().as_ref()),
+                        to_i64_vec(lwe_compact_list.get_body_list().as_ref()),
+                    ));
+                }
+                // Proof is not compatible with the CRS, so we refuse it right there
+                CompactPkeProof::PkeV1(_) => invalid_indices.push(i),
+            }
+        }
+
+        let batch = proofs
+            .into_iter()
+            .zip(public_commits.iter())
+            .map(|((proof, metadata), public_commit)| (proof, public_commit, metadata))
+            .collect::<Vec<_>>();
+
+        if let Err(batch_invalid_indices) = verify_batch_v2(&batch, public_params) {
+            invalid_indices.extend(batch_invalid_indices.into_iter().map(|i| batch_indices[i]));
+            invalid_indices.sort_unstable();
+        }
+
+        if invalid_indices.is_empty() {
+            ZkBatchVerificationOutcome::Valid
+        } else {
+            ZkBatchVerificationOutcome::Invalid(invalid_indices)
+        }
+    }
+
+    /// Verify that a linear combination of the message bits of several proven lists is equal to
+    /// `value`
+    ///
+    /// Each term is a list with its proof and the weighted bits of its messages that are part of
+    /// the combination. The `opening` is the sum of the openings generated for each term by
+    /// [Self::prove_with_message_bits_openings]. The proofs themselves are not verified, see
+    /// [Self::verify].
+    pub(crate) fn verify_message_bits_relation<Scalar, ListCont, KeyCont>(
+        &self,
+        opening: &MessageBitsOpening,
+        terms: &[(
+            &LweCompactCiphertextList<ListCont>,
+            &CompactPkeProof,
+            &[WeightedMessageBit],
+        )],
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+        value: i128,
+    ) -> ZkVerificationOutcome
+    where
+        Scalar: UnsignedInteger,
+        i64: CastFrom<Scalar>,
+        ListCont: Container<Element = Scalar>,
+        KeyCont: Container<Element = Scalar>,
+    {
+        let Self::PkeV2(public_params) = self else {
+            // The legacy scheme cannot open the message bits
+            return ZkVerificationOutcome::Invalid;
+        };
+
+        if Scalar::BITS > 64 || !opening.is_usable() {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        let to_i64_vec =
+            |slice: &[Scalar]| slice.iter().map(|&x| i64::cast_from(x)).collect::<Vec<_>>();
+
+        let key_mask = to_i64_vec(compact_public_key.get_mask().as_ref());
+        let key_body = to_i64_vec(compact_public_key.get_body().as_ref());
+
+        let mut proofs = Vec::with_capacity(terms.len());
+        let mut public_commits = Vec::with_capacity(terms.len());
+        for &(lwe_compact_list, proof, _) in terms {
+            let CompactPkeProof::PkeV2(proof) = proof else {
+                return ZkVerificationOutcome::Invalid;
+            };
+
+            proofs.push(proof);
+            public_commits.push(PublicCommitV2::new(
+                key_mask.clone(),
+                key_body.clone(),
+                to_i64_vec(lwe_compact_list.get_mask_list().as_ref()),
+                to_i64_vec(lwe_compact_list.get_body_list().as_ref()),
+            ));
+        }
+
+        let terms = proofs
+            .into_iter()
+            .zip(public_commits.iter())
+            .zip(terms)
+            .map(|((proof, public_commit), &(_, _, bits))| (proof, public_commit, bits))
+            .collect::<Vec<_>>();
+
+        match verify_message_bits_relation_v2(&opening.0, &terms, value, public_params) {
+            Ok(()) => ZkVerificationOutcome::Valid,
+            Err(()) => ZkVerificationOutcome::Invalid,
+        }
+    }
+}
+
+/// Verifies the proofs of encryption of a batch of lists generated with the same metadata
+///
+/// This is equivalent to calling [ProvenCompactCiphertextList::verify] on each list, including
+/// the proofs of their ranges, but the pairing equations of all the proofs are combined with
+/// random coefficients so that the cost of the verification is amortized over the batch. When the
+/// batch is not valid, the indices of the invalid lists are returned.
+///
+/// [ProvenCompactCiphertextList::verify]: crate::ProvenCompactCiphertextList::verify
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+/// use tfhe::zk::{verify_batch, CompactPkeCrs, ZkComputeLoad};
+/// use tfhe::{ClientKey, CompactPublicKey, ConfigBuilder, ProvenCompactCiphertextList};
+///
+/// let config =
+///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).build();
+/// let crs = CompactPkeCrs::from_config(config, 64).unwrap();
+/// let client_key = ClientKey::generate(config);
+/// let public_key = CompactPublicKey::try_new(&client_key).unwrap();
+/// let metadata = b"TFHE-rs";
+///
+/// let lists = [
+///     (1u64, &metadata[..]),
+///     (2, &b"other"[..]),
+///     (3, &metadata[..]),
+/// ]
+/// .into_iter()
+/// .map(|(clear, metadata)| {
+///     ProvenCompactCiphertextList::builder(&public_key)
+///         .push(clear)
+///         .build_with_proof_packed(&crs, metadata, ZkComputeLoad::Proof)
+///         .unwrap()
+/// })
+/// .collect::<Vec<_>>();
+///
+/// let outcome = verify_batch(&[&lists[0], &lists[2]], &crs, &public_key, metadata);
+/// assert!(outcome.is_valid());
+///
+/// // The second list was proven with different metadata
+/// let outcome = verify_batch(
+///     &lists.iter().collect::<Vec<_>>(),
+///     &crs,
+///     &public_key,
+///     metadata,
+/// );
+/// assert_eq!(outcome.invalid_indices(), &[1]);
+/// ```
+#[cfg(feature = "integer")]
+pub fn verify_batch(
+    lists: &[&crate::ProvenCompactCiphertextList],
+    crs: &CompactPkeCrs,
+    public_key: &crate::CompactPublicKey,
+    metadata: &[u8],
+) -> ZkBatchVerificationOutcome {
+    crate::ProvenCompactCiphertextList::verify_batch(lists, crs, public_key, metadata)
 }
 
 impl ParameterSetConformant for CompactPkeCrs {
@@ -672,6 +1053,723 @@ impl ParameterSetConformant for CompactPkeCrs {
     }
 }
 
+/// The proof of the update of a [CompactPkeCrs] by a participant of a CRS ceremony, see
+/// [CompactPkeCrs::update]
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(CompactPkeCrsUpdateProofVersions)]
+pub struct CompactPkeCrsUpdateProof(CrsUpdateProof<Curve>);
+
+impl Named for CompactPkeCrsUpdateProof {
+    const NAME: &'static str = "zk::CompactPkeCrsUpdateProof";
+}
+
+impl CompactPkeCrsUpdateProof {
+    /// Check that the points of the proof are valid points of the curve, in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.0.is_usable()
+    }
+}
+
+/// An opening of a linear combination of the message bits of one or several [CompactPkeProof],
+/// which proves the value of the combination without revealing the messages.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(MessageBitsOpeningVersions)]
+pub struct MessageBitsOpening(MessageBitsOpeningV2<Curve>);
+
+impl Named for MessageBitsOpening {
+    const NAME: &'static str = "zk::MessageBitsOpening";
+}
+
+impl MessageBitsOpening {
+    /// Check that the point of the opening is a valid point of the curve, in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.0.is_usable()
+    }
+}
+
+impl std::iter::Sum for MessageBitsOpening {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|opening| opening.0).sum())
+    }
+}
+
+/// Parameters of the pke proofs used to prove decryptions, see [DecryptionProofCrs].
+///
+/// For a ciphertext of the cleartext `m` with a decryption noise `e`, the noise is shifted and
+/// split as `e + delta / 4 = delta_low * e_high + e_low`. The pke proof shows that `e_high` is in
+/// `[0, delta / (2 * delta_low))`, as the message of the proof, and bounds `e_low`, as its noise.
+/// `delta_low` is chosen so that the bound with slack on `e_low` is below `delta / 4`, this
+/// guarantees that `|e| < delta / 2` and thus that `m` is the correct decryption.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct DecryptionProofParams {
+    lwe_dim: usize,
+    /// log2 of the scaling factor of the cleartexts
+    delta_log: u32,
+    /// log2 of the scaling factor of the high part of the noise
+    delta_low_log: u32,
+}
+
+impl DecryptionProofParams {
+    fn new(lwe_dim: LweDimension, plaintext_modulus: u64) -> crate::Result<Self> {
+        if lwe_dim.0 == 0 {
+            return Err("Decryption proofs require a non zero lwe dimension".into());
+        }
+
+        if plaintext_modulus < 2 || !plaintext_modulus.is_power_of_two() {
+            return Err(crate::Error::new(format!(
+                "Decryption proofs require a power of two plaintext modulus, got \
+                {plaintext_modulus}"
+            )));
+        }
+
+        let delta_log = u64::BITS - plaintext_modulus.ilog2();
+
+        // The bound with slack grows with delta_low, so we keep the largest valid one
+        let mut params = None;
+        for delta_low_log in 1..delta_log.saturating_sub(1) {
+            let candidate = Self {
+                lwe_dim: lwe_dim.0,
+                delta_log,
+                delta_low_log,
+            };
+
+            let (_, _, noise_bound_squared_with_slack, _) = candidate.crs_params();
+            if noise_bound_squared_with_slack >= 1u128 << (2 * (delta_log - 2)) {
+                break;
+            }
+            params = Some(candidate);
+        }
+
+        params.ok_or_else(|| {
+            crate::Error::new(format!(
+                "The plaintext modulus {plaintext_modulus} is too large to prove decryptions for \
+                the lwe dimension {}",
+                lwe_dim.0
+            ))
+        })
+    }
+
+    /// Plaintext modulus of the pke proof, such that its scaling factor is `delta_low`
+    fn pke_plaintext_modulus(&self) -> u64 {
+        1 << (u64::BITS - self.delta_low_log)
+    }
+
+    /// Bits of the pke plaintext modulus that are not used by `e_high`
+    fn msbs_zero_padding_bit_count(&self) -> u64 {
+        (u64::BITS + 1 - self.delta_log) as u64
+    }
+
+    /// Bound on the noise of the pke proof, the honest `e_low` is in `[-delta_low / 2, delta_low
+    /// / 2)`
+    fn noise_bound(&self) -> u64 {
+        1 << (self.delta_low_log - 1)
+    }
+
+    /// Bound on the squared norm of the noise of the pke proof, that contains the noise of the
+    /// public key and `e_low`
+    fn noise_squared_norm_bound(&self) -> u128 {
+        (self.noise_bound() as u128).pow(2) * (self.lwe_dim as u128 + 1)
+    }
+
+    /// Shift applied to the decryption noise so that `e_high` is positive
+    fn noise_offset(&self) -> u64 {
+        1 << (self.delta_log - 2)
+    }
+
+    fn crs_params(&self) -> (usize, usize, u128, usize) {
+        compute_crs_params(
+            self.lwe_dim,
+            1,
+            self.noise_squared_norm_bound(),
+            self.pke_plaintext_modulus(),
+            self.msbs_zero_padding_bit_count(),
+            PkeV2Bound::CS,
+        )
+    }
+
+    /// Body of the statement of the pke proof for a ciphertext body and its claimed cleartext
+    fn statement_body(&self, body: u64, cleartext: u64) -> i64 {
+        body.wrapping_sub(cleartext << self.delta_log)
+            .wrapping_add(self.noise_offset()) as i64
+    }
+}
+
+/// A proof that a list of LWE ciphertexts decrypts to the given cleartexts, see
+/// [DecryptionProofCrs].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(DecryptionProofVersions)]
+pub struct DecryptionProof {
+    proofs: Vec<ProofV2<Curve>>,
+}
+
+impl Named for DecryptionProof {
+    const NAME: &'static str = "zk::DecryptionProof";
+}
+
+impl DecryptionProof {
+    /// Check that the points of the proof are valid points of the curve, in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.proofs.iter().all(|proof| proof.is_usable())
+    }
+}
+
+/// The CRS of the proofs of correct decryption.
+///
+/// A decryption proof shows that LWE ciphertexts decrypt to the given cleartexts under the secret
+/// key of a compact public key, without revealing the secret key. The verifier only needs the
+/// ciphertexts, the cleartexts and the compact public key, which acts as a commitment to the
+/// secret key.
+///
+/// Each ciphertext is proven with the V2 compact PKE scheme, with the secret key in place of the
+/// encryption randomness. The equations of the public key bind the proof to its secret key, and
+/// the equation of the ciphertext shows that its decryption noise is small enough for the
+/// cleartext to be the correct decryption. To fit the bounds of the scheme, the noise of a
+/// proven ciphertext must be below a quarter of the scaling factor of the cleartexts.
+///
+/// Anyone knowing the randomness used to generate the CRS can forge proofs, so it should be
+/// generated by the verifier, never by the prover. Only the native 64 bits ciphertext modulus is
+/// supported.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(DecryptionProofCrsVersions)]
+pub struct DecryptionProofCrs {
+    public_params: ZkCompactPkeV2PublicParams,
+}
+
+impl Named for DecryptionProofCrs {
+    const NAME: &'static str = "zk::DecryptionProofCrs";
+}
+
+impl DecryptionProofCrs {
+    /// Generates a new CRS for decryption proofs of ciphertexts of the given lwe dimension.
+    ///
+    /// The plaintext modulus takes the padding bit into account and must be a power of two.
+    pub fn new(
+        lwe_dim: LweDimension,
+        plaintext_modulus: u64,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self> {
+        let params = DecryptionProofParams::new(lwe_dim, plaintext_modulus)?;
+
+        let public_params = crs_gen_v2(
+            params.lwe_dim,
+            1,
+            params.noise_bound(),
+            0,
+            params.pke_plaintext_modulus(),
+            params.msbs_zero_padding_bit_count(),
+            rng,
+        );
+
+        Ok(Self { public_params })
+    }
+
+    /// Recompute the parameters of the CRS, and check that they match the ones it was generated
+    /// with
+    fn params(&self) -> Option<DecryptionProofParams> {
+        let public_params = &self.public_params;
+
+        let plaintext_modulus = u32::try_from(public_params.msbs_zero_padding_bit_count)
+            .ok()
+            .and_then(|padding| 1u64.checked_shl(padding.checked_sub(1)?))?;
+        let params =
+            DecryptionProofParams::new(LweDimension(public_params.d), plaintext_modulus).ok()?;
+        let (n, _, noise_bound_squared_with_slack, _) = params.crs_params();
+
+        (public_params.k == 1
+            && public_params.q == 0
+            && public_params.t == params.pke_plaintext_modulus()
+            && public_params.msbs_zero_padding_bit_count == params.msbs_zero_padding_bit_count()
+            && public_params.B_inf == params.noise_bound()
+            && public_params.bound_type == PkeV2Bound::CS
+            && public_params.B_bound_squared == noise_bound_squared_with_slack
+            && public_params.n == n)
+            .then_some(params)
+    }
+
+    /// Check that the CRS has the expected parameters and that its points are valid points of the
+    /// curve, in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.params().is_some() && self.public_params.is_usable()
+    }
+
+    /// Lwe dimension of the ciphertexts supported by this CRS
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.public_params.d)
+    }
+
+    /// Decrypt the ciphertexts and prove that the decryption is correct.
+    ///
+    /// The secret key must be the one that was used to generate the compact public key. Return
+    /// the decrypted cleartexts along with the proof.
+    pub fn prove<CtCont, KeyCont, SkCont, G>(
+        &self,
+        lwe_ciphertexts: &[LweCiphertext<CtCont>],
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+        lwe_secret_key: &LweSecretKey<SkCont>,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        random_generator: &mut RandomGenerator<G>,
+    ) -> crate::Result<(Vec<u64>, DecryptionProof)>
+    where
+        CtCont: Container<Element = u64>,
+        KeyCont: Container<Element = u64>,
+        SkCont: Container<Element = u64>,
+        G: ByteRandomGenerator,
+    {
+        let params = self
+            .params()
+            .ok_or_else(|| crate::Error::from("Invalid parameters for the decryption proof CRS"))?;
+
+        if compact_public_key.lwe_dimension().0 != params.lwe_dim
+            || lwe_secret_key.lwe_dimension().0 != params.lwe_dim
+            || lwe_ciphertexts
+                .iter()
+                .any(|ct| ct.lwe_size().to_lwe_dimension().0 != params.lwe_dim)
+        {
+            return Err(
+                "Mismatched lwe dimension between the CRS and the keys or ciphertexts".into(),
+            );
+        }
+
+        if !compact_public_key.ciphertext_modulus().is_native_modulus()
+            || lwe_ciphertexts
+                .iter()
+                .any(|ct| !ct.ciphertext_modulus().is_native_modulus())
+        {
+            return Err("Decryption proofs only support the native ciphertext modulus".into());
+        }
+
+        if lwe_secret_key.as_ref().iter().any(|&s| s > 1) {
+            return Err("Decryption proofs require a binary secret key".into());
+        }
+
+        let (key_mask, key_body) = compact_public_key.get_mask_and_body();
+
+        // The noise of the public key is proven along with the decryption noise, so that the
+        // secret key is bound to the public key
+        let mut key_noise = vec![0u64; params.lwe_dim];
+        slice_semi_reverse_negacyclic_convolution(
+            &mut key_noise,
+            key_mask.as_ref(),
+            lwe_secret_key.as_ref(),
+        );
+        let key_noise = key_body
+            .as_ref()
+            .iter()
+            .zip(key_noise.iter())
+            .map(|(&body, &mask_times_key)| body.wrapping_sub(mask_times_key) as i64)
+            .collect::<Vec<_>>();
+
+        let key_noise_squared_norm = key_noise.iter().fold(0u128, |acc, &e| {
+            acc.saturating_add((e.unsigned_abs() as u128).pow(2))
+        });
+        let Some(noise_budget) = params
+            .noise_squared_norm_bound()
+            .checked_sub(key_noise_squared_norm)
+        else {
+            return Err(
+                "The noise of the compact public key is too large, it may not have been \
+                generated from this secret key"
+                    .into(),
+            );
+        };
+
+        let key_mask = key_mask
+            .as_ref()
+            .iter()
+            .map(|&x| x as i64)
+            .collect::<Vec<_>>();
+        let key_body = key_body
+            .as_ref()
+            .iter()
+            .map(|&x| x as i64)
+            .collect::<Vec<_>>();
+        let secret_key = lwe_secret_key
+            .as_ref()
+            .iter()
+            .map(|&s| s as i64)
+            .collect::<Vec<_>>();
+
+        let mut cleartexts = Vec::with_capacity(lwe_ciphertexts.len());
+        let mut proofs = Vec::with_capacity(lwe_ciphertexts.len());
+
+        for (index, lwe_ciphertext) in lwe_ciphertexts.iter().enumerate() {
+            let (mask, body) = lwe_ciphertext.get_mask_and_body();
+
+            let phase = decrypt_lwe_ciphertext(lwe_secret_key, lwe_ciphertext).0;
+            let cleartext = phase.wrapping_add(1 << (params.delta_log - 1)) >> params.delta_log;
+
+            // Split the shifted noise in a high part, proven as the message of the pke proof,
+            // and a low part, proven as its noise
+            let shifted_noise = phase
+                .wrapping_sub(cleartext << params.delta_log)
+                .wrapping_add(params.noise_offset());
+            let rounded_noise = shifted_noise.wrapping_add(1 << (params.delta_low_log - 1));
+            if rounded_noise >= 1 << (params.delta_log - 1) {
+                return Err(crate::Error::new(format!(
+                    "The noise of the ciphertext at index {index} is too large to prove its \
+                    decryption"
+                )));
+            }
+            let noise_high = rounded_noise >> params.delta_low_log;
+            let noise_low = shifted_noise.wrapping_sub(noise_high << params.delta_low_log) as i64;
+
+            if (noise_low.unsigned_abs() as u128).pow(2) > noise_budget {
+                return Err(crate::Error::new(format!(
+                    "The noise of the ciphertext at index {index} does not fit the noise bound \
+                    of the CRS"
+                )));
+            }
+
+            // The public key is the first equation of the pke statement and the ciphertext the
+            // second one: key_body = key_mask * s + key_noise and
+            // statement_body = <mask, s> + delta_low * noise_high + noise_low
+            let (public_commit, private_commit) = commit_v2(
+                key_mask.clone(),
+                mask.as_ref().iter().map(|&x| x as i64).collect(),
+                key_body.clone(),
+                vec![params.statement_body(*body.data, cleartext)],
+                secret_key.clone(),
+                key_noise.clone(),
+                vec![noise_high as i64],
+                vec![noise_low],
+                &self.public_params,
+                random_generator,
+            );
+
+            proofs.push(prove_v2(
+                (&self.public_params, &public_commit),
+                &private_commit,
+                metadata,
+                load,
+                random_generator,
+            ));
+            cleartexts.push(cleartext);
+        }
+
+        Ok((cleartexts, DecryptionProof { proofs }))
+    }
+
+    /// Verify that the ciphertexts decrypt to the given cleartexts under the secret key of the
+    /// compact public key.
+    ///
+    /// The proofs of all the ciphertexts are verified at once, see [CompactPkeCrs::verify_batch].
+    pub fn verify<CtCont, KeyCont>(
+        &self,
+        lwe_ciphertexts: &[LweCiphertext<CtCont>],
+        cleartexts: &[u64],
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+        proof: &DecryptionProof,
+        metadata: &[u8],
+    ) -> ZkVerificationOutcome
+    where
+        CtCont: Container<Element = u64>,
+        KeyCont: Container<Element = u64>,
+    {
+        let Some(params) = self.params() else {
+            return ZkVerificationOutcome::Invalid;
+        };
+
+        let plaintext_modulus = 1u64 << (u64::BITS - params.delta_log);
+
+        if lwe_ciphertexts.len() != cleartexts.len()
+            || lwe_ciphertexts.len() != proof.proofs.len()
+            || compact_public_key.lwe_dimension().0 != params.lwe_dim
+            || !compact_public_key.ciphertext_modulus().is_native_modulus()
+            || lwe_ciphertexts.iter().any(|ct| {
+                ct.lwe_size().to_lwe_dimension().0 != params.lwe_dim
+                    || !ct.ciphertext_modulus().is_native_modulus()
+            })
+            || cleartexts
+                .iter()
+                .any(|&cleartext| cleartext >= plaintext_modulus)
+            || !proof.is_usable()
+        {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        let (key_mask, key_body) = compact_public_key.get_mask_and_body();
+        let key_mask = key_mask
+            .as_ref()
+            .iter()
+            .map(|&x| x as i64)
+            .collect::<Vec<_>>();
+        let key_body = key_body
+            .as_ref()
+            .iter()
+            .map(|&x| x as i64)
+            .collect::<Vec<_>>();
+
+        let public_commits = lwe_ciphertexts
+            .iter()
+            .zip(cleartexts.iter())
+            .map(|(lwe_ciphertext, &cleartext)| {
+                let (mask, body) = lwe_ciphertext.get_mask_and_body();
+                PublicCommitV2::new(
+                    key_mask.clone(),
+                    mask.as_ref().iter().map(|&x| x as i64).collect(),
+                    key_body.clone(),
+                    vec![params.statement_body(*body.data, cleartext)],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let proven_statements = proof
+            .proofs
+            .iter()
+            .zip(public_commits.iter())
+            .map(|(proof, public_commit)| (proof, public_commit, metadata))
+            .collect::<Vec<_>>();
+
+        match verify_batch_v2(&proven_statements, &self.public_params) {
+            Ok(()) => ZkVerificationOutcome::Valid,
+            Err(_) => ZkVerificationOutcome::Invalid,
+        }
+    }
+}
+
+pub type ZkRlwePublicParams = tfhe_zk_pok::proofs::rlwe::PublicParams<Curve>;
+
+/// A proof that a compact public key was honestly generated, see [CompactPublicKeyProofCrs].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(CompactPublicKeyProofVersions)]
+pub struct CompactPublicKeyProof {
+    proof: RlweProof<Curve>,
+}
+
+impl Named for CompactPublicKeyProof {
+    const NAME: &'static str = "zk::CompactPublicKeyProof";
+}
+
+impl CompactPublicKeyProof {
+    /// Check that the points of the proof are valid points of the curve, in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.proof.is_usable()
+    }
+}
+
+/// The CRS of the proofs of well-formed compact public keys.
+///
+/// A compact public key is a pair `(a, b)` of polynomials with `b = a * s + e`, where `s` is the
+/// secret key and `e` is the noise. The proof shows, without revealing them, that `s` is binary
+/// and that the coefficients of `e` are in `[-2^k, 2^k)`, with `2^k` the smallest power of two
+/// strictly above the bound of the noise distribution of the key.
+///
+/// The statement is proven with the RLWE proof of `tfhe-zk-pok`, with one row for the reversed
+/// and negated secret key, whose coefficients are bounded by 1, and one row for the noise.
+///
+/// Only the compact public key is covered, the keyswitching and bootstrapping keys of a server
+/// key are not. Anyone knowing the randomness used to generate the CRS can forge proofs, so it
+/// should be generated by the verifier, never by the prover. Only the native 64 bits ciphertext
+/// modulus and bounded noise distributions are supported.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(CompactPublicKeyProofCrsVersions)]
+pub struct CompactPublicKeyProofCrs {
+    public_params: ZkRlwePublicParams,
+}
+
+impl Named for CompactPublicKeyProofCrs {
+    const NAME: &'static str = "zk::CompactPublicKeyProofCrs";
+}
+
+impl CompactPublicKeyProofCrs {
+    /// Generates a new CRS for proofs of compact public keys of the given lwe dimension and noise
+    /// distribution
+    pub fn new<NoiseDistribution>(
+        lwe_dim: LweDimension,
+        noise_distribution: NoiseDistribution,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self>
+    where
+        NoiseDistribution: BoundedDistribution<i64>,
+    {
+        if lwe_dim.0 < 2 {
+            return Err("Compact public key proofs require a lwe dimension of at least 2".into());
+        }
+
+        let noise_bound: u64 = CompactPkeCrs::compute_bound_v2(noise_distribution)?;
+
+        // The proven range of the noise is [-noise_row_bound, noise_row_bound), so it must be
+        // strictly above the inclusive bound of the distribution
+        let Some(noise_row_bound) = noise_bound
+            .checked_ilog2()
+            .map_or(Some(1), |log| 1u64.checked_shl(log + 1))
+            .filter(|&bound| (bound as u128 + 1) * lwe_dim.0 as u128 <= u64::MAX as u128)
+        else {
+            return Err("The noise bound is too large for compact public key proofs".into());
+        };
+
+        let public_params = crs_gen_rlwe(lwe_dim.0, 1, 2, vec![1, noise_row_bound], 0, rng);
+
+        Ok(Self { public_params })
+    }
+
+    /// Check that the CRS has the expected shape, and return the bound on the noise row
+    fn noise_row_bound(&self) -> Option<u64> {
+        let public_params = &self.public_params;
+
+        match public_params.b_i() {
+            &[1, noise_row_bound]
+                if noise_row_bound.is_power_of_two()
+                    && public_params.big_n() == 1
+                    && public_params.big_m() == 2
+                    && public_params.q() == 0 =>
+            {
+                Some(noise_row_bound)
+            }
+            _ => None,
+        }
+    }
+
+    /// Check that the CRS has the expected parameters and that its points are valid points of the
+    /// curve, in the correct subgroup
+    pub fn is_usable(&self) -> bool {
+        self.noise_row_bound().is_some() && self.public_params.is_usable()
+    }
+
+    /// Lwe dimension of the compact public keys supported by this CRS
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.public_params.d())
+    }
+
+    /// Mask and body of the statement of the RLWE proof, the secret key is negated so that its
+    /// coefficients are in `{-1, 0}`
+    fn statement<KeyCont>(
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+    ) -> (RlweMatrix<i64>, RlweVector<i64>)
+    where
+        KeyCont: Container<Element = u64>,
+    {
+        let lwe_dim = compact_public_key.lwe_dimension().0;
+        let (key_mask, key_body) = compact_public_key.get_mask_and_body();
+
+        let mut a = RlweMatrix::new(lwe_dim, 2, 1, 0i64);
+        for (dst, &src) in a[(1, 1)].iter_mut().zip(key_mask.as_ref().iter()) {
+            *dst = (src as i64).wrapping_neg();
+        }
+        a[(2, 1)][0] = 1;
+
+        let mut c = RlweVector::new(lwe_dim, 1, 0i64);
+        for (dst, &src) in c[1].iter_mut().zip(key_body.as_ref().iter()) {
+            *dst = src as i64;
+        }
+
+        (a, c)
+    }
+
+    /// Prove that the compact public key was generated from the given secret key, with a bounded
+    /// noise
+    pub fn prove<KeyCont, SkCont, G>(
+        &self,
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+        lwe_secret_key: &LweSecretKey<SkCont>,
+        load: ZkComputeLoad,
+        random_generator: &mut RandomGenerator<G>,
+    ) -> crate::Result<CompactPublicKeyProof>
+    where
+        KeyCont: Container<Element = u64>,
+        SkCont: Container<Element = u64>,
+        G: ByteRandomGenerator,
+    {
+        let noise_row_bound = self.noise_row_bound().ok_or_else(|| {
+            crate::Error::from("Invalid parameters for the compact public key proof CRS")
+        })?;
+        let lwe_dim = self.public_params.d();
+
+        if compact_public_key.lwe_dimension().0 != lwe_dim
+            || lwe_secret_key.lwe_dimension().0 != lwe_dim
+        {
+            return Err("Mismatched lwe dimension between the CRS and the keys".into());
+        }
+
+        if !compact_public_key.ciphertext_modulus().is_native_modulus() {
+            return Err(
+                "Compact public key proofs only support the native ciphertext modulus".into(),
+            );
+        }
+
+        if lwe_secret_key.as_ref().iter().any(|&s| s > 1) {
+            return Err("Compact public key proofs require a binary secret key".into());
+        }
+
+        let (key_mask, key_body) = compact_public_key.get_mask_and_body();
+
+        let mut key_noise = vec![0u64; lwe_dim];
+        slice_semi_reverse_negacyclic_convolution(
+            &mut key_noise,
+            key_mask.as_ref(),
+            lwe_secret_key.as_ref(),
+        );
+        let key_noise = key_body
+            .as_ref()
+            .iter()
+            .zip(key_noise.iter())
+            .map(|(&body, &mask_times_key)| body.wrapping_sub(mask_times_key) as i64)
+            .collect::<Vec<_>>();
+
+        let half_range = noise_row_bound as i64;
+        if key_noise
+            .iter()
+            .any(|e| !(-half_range..half_range).contains(e))
+        {
+            return Err(
+                "The noise of the compact public key is too large, it may not have been \
+                generated from this secret key"
+                    .into(),
+            );
+        }
+
+        // The semi reverse convolution of the public key is a negacyclic product with the
+        // reversed secret key
+        let mut s = RlweVector::new(lwe_dim, 2, 0i64);
+        for (dst, &src) in s[1].iter_mut().zip(lwe_secret_key.as_ref().iter().rev()) {
+            *dst = -(src as i64);
+        }
+        s[2].copy_from_slice(&key_noise);
+
+        let (a, c) = Self::statement(compact_public_key);
+
+        let (public_commit, private_commit) =
+            commit_rlwe(a, c, s, &self.public_params, random_generator);
+
+        let proof = prove_rlwe(
+            (&self.public_params, &public_commit),
+            &private_commit,
+            load,
+            random_generator,
+        );
+
+        Ok(CompactPublicKeyProof { proof })
+    }
+
+    /// Verify that the compact public key was generated from a binary secret key with a bounded
+    /// noise
+    pub fn verify<KeyCont>(
+        &self,
+        compact_public_key: &LweCompactPublicKey<KeyCont>,
+        proof: &CompactPublicKeyProof,
+    ) -> ZkVerificationOutcome
+    where
+        KeyCont: Container<Element = u64>,
+    {
+        if self.noise_row_bound().is_none()
+            || compact_public_key.lwe_dimension().0 != self.public_params.d()
+            || !compact_public_key.ciphertext_modulus().is_native_modulus()
+            || !proof.is_usable()
+        {
+            return ZkVerificationOutcome::Invalid;
+        }
+
+        let (a, c) = Self::statement(compact_public_key);
+        let public_commit = RlwePublicCommit::new(a, c);
+
+        match verify_rlwe(&proof.proof, (&self.public_params, &public_commit)) {
+            Ok(()) => ZkVerificationOutcome::Valid,
+            Err(()) => ZkVerificationOutcome::Invalid,
+        }
+    }
+}
+
 /// The CRS can be compressed by only storing the `x` part of the elliptic curve coordinates.
 #[derive(Serialize, Deserialize, Versionize)]
 #[versionize(CompressedCompactPkeCrsVersions)]
@@ -713,9 +1811,12 @@ impl Compressible for CompactPkeCrs {
 #[cfg(all(test, feature = "shortint"))]
 mod test {
     use super::*;
-    use crate::safe_serialization::{safe_deserialize_conformant, safe_serialize};
+    use crate::safe_serialization::{
+        safe_deserialize, safe_deserialize_conformant, safe_serialize,
+    };
     use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::V0_11_PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
-    use crate::shortint::{CarryModulus, MessageModulus};
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::{CarryModulus, ClientKey, CompactPublicKey, MessageModulus};
 
     #[test]
     fn test_crs_conformance() {
@@ -788,4 +1889,212 @@ mod test {
             safe_deserialize_conformant(serialized.as_slice(), 1 << 30, &conformance_params)
                 .unwrap();
     }
+
+    #[test]
+    fn test_crs_ceremony() {
+        let params = V0_11_PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let mut rng = rand::thread_rng();
+
+        let new_crs = |rng: &mut rand::rngs::ThreadRng| {
+            CompactPkeCrs::new(
+                params.encryption_lwe_dimension,
+                LweCiphertextCount(4),
+                params.encryption_noise_distribution,
+                params.ciphertext_modulus,
+                params.message_modulus.0 * params.carry_modulus.0 * 2,
+                ZkMSBZeroPaddingBitCount(1),
+                rng,
+            )
+            .unwrap()
+        };
+        let initial_crs = new_crs(&mut rng);
+
+        let conformance_params =
+            CompactPkeCrsConformanceParams::new(params, LweCiphertextCount(4)).unwrap();
+
+        // Each participant receives the serialized CRS and transcript of the previous one
+        let mut serialized_crs = Vec::new();
+        safe_serialize(&initial_crs, &mut serialized_crs, 1 << 30).unwrap();
+        let mut transcript: Vec<CompactPkeCrsUpdateProof> = Vec::new();
+        for _ in 0..3 {
+            let crs: CompactPkeCrs = safe_deserialize_conformant(
+                serialized_crs.as_slice(),
+                1 << 30,
+                &conformance_params,
+            )
+            .unwrap();
+            assert!(initial_crs
+                .verify_update_transcript(&crs, &transcript)
+                .is_valid());
+
+            let (updated_crs, proof) = crs.update(&mut rng).unwrap();
+
+            serialized_crs.clear();
+            safe_serialize(&updated_crs, &mut serialized_crs, 1 << 30).unwrap();
+            let mut serialized_proof = Vec::new();
+            safe_serialize(&proof, &mut serialized_proof, 1 << 20).unwrap();
+            let proof: CompactPkeCrsUpdateProof =
+                safe_deserialize(serialized_proof.as_slice(), 1 << 20).unwrap();
+            assert!(proof.is_usable());
+            transcript.push(proof);
+        }
+
+        let crs: CompactPkeCrs =
+            safe_deserialize_conformant(serialized_crs.as_slice(), 1 << 30, &conformance_params)
+                .unwrap();
+        assert!(initial_crs
+            .verify_update_transcript(&crs, &transcript)
+            .is_valid());
+        assert!(initial_crs
+            .verify_update_transcript(&crs, &transcript[..2])
+            .is_invalid());
+        assert!(initial_crs
+            .verify_update_transcript(&new_crs(&mut rng), &transcript)
+            .is_invalid());
+
+        let legacy_crs = CompactPkeCrs::new_legacy_v1(
+            params.encryption_lwe_dimension,
+            LweCiphertextCount(4),
+            params.encryption_noise_distribution,
+            params.ciphertext_modulus,
+            params.message_modulus.0 * params.carry_modulus.0 * 2,
+            ZkMSBZeroPaddingBitCount(1),
+            &mut rng,
+        )
+        .unwrap();
+        assert!(legacy_crs.update(&mut rng).is_err());
+    }
+
+    #[test]
+    fn test_decryption_proof() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        let mut seeder = new_seeder();
+        let mut random_generator = RandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+        let metadata = b"tfhe-rs";
+
+        let cks = ClientKey::new(params);
+        let pk = CompactPublicKey::new(&cks);
+        let crs = DecryptionProofCrs::from_shortint_params(params).unwrap();
+        assert!(crs.is_usable());
+
+        let msgs = [0u64, 1, 3];
+        let cts = msgs
+            .iter()
+            .map(|&msg| cks.encrypt(msg).ct)
+            .collect::<Vec<_>>();
+
+        let (cleartexts, proof) = crs
+            .prove(
+                &cts,
+                &pk.key,
+                &cks.large_lwe_secret_key(),
+                metadata,
+                ZkComputeLoad::Proof,
+                &mut random_generator,
+            )
+            .unwrap();
+        assert_eq!(cleartexts, msgs);
+
+        assert!(crs
+            .verify(&cts, &cleartexts, &pk.key, &proof, metadata)
+            .is_valid());
+        assert!(crs
+            .verify(&cts, &[0, 1, 2], &pk.key, &proof, metadata)
+            .is_invalid());
+        assert!(crs
+            .verify(&cts, &cleartexts, &pk.key, &proof, b"other")
+            .is_invalid());
+        assert!(crs
+            .verify(&cts[..2], &cleartexts[..2], &pk.key, &proof, metadata)
+            .is_invalid());
+
+        // The proof is bound to the secret key of the public key
+        let other_pk = CompactPublicKey::new(&ClientKey::new(params));
+        assert!(crs
+            .verify(&cts, &cleartexts, &other_pk.key, &proof, metadata)
+            .is_invalid());
+        assert!(crs
+            .prove(
+                &cts,
+                &other_pk.key,
+                &cks.large_lwe_secret_key(),
+                metadata,
+                ZkComputeLoad::Proof,
+                &mut random_generator,
+            )
+            .is_err());
+
+        let mut serialized = Vec::new();
+        safe_serialize(&crs, &mut serialized, 1 << 30).unwrap();
+        let crs: DecryptionProofCrs = safe_deserialize(serialized.as_slice(), 1 << 30).unwrap();
+        assert!(crs.is_usable());
+
+        let mut serialized = Vec::new();
+        safe_serialize(&proof, &mut serialized, 1 << 20).unwrap();
+        let proof: DecryptionProof = safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert!(proof.is_usable());
+
+        assert!(crs
+            .verify(&cts, &cleartexts, &pk.key, &proof, metadata)
+            .is_valid());
+    }
+
+    #[test]
+    fn test_compact_public_key_proof() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        let mut seeder = new_seeder();
+        let mut random_generator = RandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+
+        let cks = ClientKey::new(params);
+        let pk = CompactPublicKey::new(&cks);
+        let crs = CompactPublicKeyProofCrs::from_shortint_params(params).unwrap();
+        assert!(crs.is_usable());
+
+        let proof = crs
+            .prove(
+                &pk.key,
+                &cks.large_lwe_secret_key(),
+                ZkComputeLoad::Proof,
+                &mut random_generator,
+            )
+            .unwrap();
+        assert!(crs.verify(&pk.key, &proof).is_valid());
+
+        let other_cks = ClientKey::new(params);
+        let other_pk = CompactPublicKey::new(&other_cks);
+        assert!(crs.verify(&other_pk.key, &proof).is_invalid());
+        assert!(crs
+            .prove(
+                &other_pk.key,
+                &cks.large_lwe_secret_key(),
+                ZkComputeLoad::Proof,
+                &mut random_generator,
+            )
+            .is_err());
+
+        let verify_load_proof = crs
+            .prove(
+                &other_pk.key,
+                &other_cks.large_lwe_secret_key(),
+                ZkComputeLoad::Verify,
+                &mut random_generator,
+            )
+            .unwrap();
+        assert!(crs.verify(&other_pk.key, &verify_load_proof).is_valid());
+
+        let mut serialized = Vec::new();
+        safe_serialize(&crs, &mut serialized, 1 << 30).unwrap();
+        let crs: CompactPublicKeyProofCrs =
+            safe_deserialize(serialized.as_slice(), 1 << 30).unwrap();
+        assert!(crs.is_usable());
+
+        let mut serialized = Vec::new();
+        safe_serialize(&proof, &mut serialized, 1 << 20).unwrap();
+        let proof: CompactPublicKeyProof =
+            safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert!(proof.is_usable());
+
+        assert!(crs.verify(&pk.key, &proof).is_valid());
+    }
 }